@@ -0,0 +1,31 @@
+//! Generates the table of JSON schemas embedded by `src/schema.rs` from the files in `schemas/`.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+fn main() {
+    let dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("schemas");
+    println!("cargo::rerun-if-changed=schemas");
+
+    let mut files: Vec<_> = fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == "json")
+        })
+        .collect();
+    files.sort();
+
+    let mut table = String::from("&[\n");
+    for path in files {
+        let name = path.file_stem().unwrap().to_str().unwrap();
+        let path = path.to_str().unwrap();
+        table.push_str(&format!("    ({name:?}, include_str!({path:?})),\n"));
+    }
+    table.push_str("]\n");
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("schemas.rs");
+    fs::write(out, table).unwrap();
+}
@@ -104,7 +104,7 @@ pub mod trigger_message_status_enum_type;
 pub mod trigger_reason_enum_type;
 pub mod unlock_status_enum_type;
 pub mod unpublish_firmware_status_enum_type;
-mod update_enum_type;
+pub mod update_enum_type;
 pub mod update_firmware_status_enum_type;
 pub mod upload_log_status_enum_type;
 pub mod vpn_enum_type;
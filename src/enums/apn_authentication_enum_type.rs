@@ -1,16 +1,38 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
 #[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
 pub enum APNAuthenticationEnumType {
     /// Use PAP authentication
+    #[serde(rename = "PAP")]
     Pap,
     /// Use CHAP authentication
+    #[serde(rename = "CHAP")]
     Chap,
     /// Use no authentication
+    #[serde(rename = "NONE")]
     None,
     /// Sequentially try CHAP, PAP, NONE.
+    #[serde(rename = "AUTO")]
     Auto,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for APNAuthenticationEnumType {
+    const NAME: &'static str = "APNAuthenticationEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for APNAuthenticationEnumType {
@@ -22,6 +44,9 @@ impl TryFrom<String> for APNAuthenticationEnumType {
             "CHAP" => Ok(APNAuthenticationEnumType::Chap),
             "NONE" => Ok(APNAuthenticationEnumType::None),
             "AUTO" => Ok(APNAuthenticationEnumType::Auto),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(APNAuthenticationEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!("'{}' is not a valid APNAuthenticationEnumType", s)),
         }
     }
@@ -34,6 +59,8 @@ impl From<APNAuthenticationEnumType> for String {
             APNAuthenticationEnumType::Chap => "CHAP".to_string(),
             APNAuthenticationEnumType::None => "NONE".to_string(),
             APNAuthenticationEnumType::Auto => "AUTO".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            APNAuthenticationEnumType::Unknown(value) => value,
         }
     }
 }
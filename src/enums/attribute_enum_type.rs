@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -11,6 +12,23 @@ pub enum AttributeEnumType {
     MinSet,
     /// The maximum allowed value for this variable
     MaxSet,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for AttributeEnumType {
+    const NAME: &'static str = "AttributeEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for AttributeEnumType {
@@ -22,6 +40,9 @@ impl TryFrom<String> for AttributeEnumType {
             "Target" => Ok(AttributeEnumType::Target),
             "MinSet" => Ok(AttributeEnumType::MinSet),
             "MaxSet" => Ok(AttributeEnumType::MaxSet),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(AttributeEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!("'{}' is not a valid AttributeEnumType", s)),
         }
     }
@@ -34,6 +55,8 @@ impl From<AttributeEnumType> for String {
             AttributeEnumType::Target => "Target".to_string(),
             AttributeEnumType::MinSet => "MinSet".to_string(),
             AttributeEnumType::MaxSet => "MaxSet".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            AttributeEnumType::Unknown(value) => value,
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -24,6 +25,23 @@ pub enum AuthorizationStatusEnumType {
     NotAtThisTime,
     /// Identifier is unknown. Not allowed for charging.
     Unknown,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    UnknownValue(String),
+}
+
+impl OcppEnum for AuthorizationStatusEnumType {
+    const NAME: &'static str = "AuthorizationStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::UnknownValue(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for AuthorizationStatusEnumType {
@@ -41,6 +59,9 @@ impl TryFrom<String> for AuthorizationStatusEnumType {
             "NotAtThisLocation" => Ok(AuthorizationStatusEnumType::NotAtThisLocation),
             "NotAtThisTime" => Ok(AuthorizationStatusEnumType::NotAtThisTime),
             "Unknown" => Ok(AuthorizationStatusEnumType::Unknown),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(AuthorizationStatusEnumType::UnknownValue(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!(
                 "'{}' is not a valid AuthorizationStatusEnumType",
                 s
@@ -62,6 +83,8 @@ impl From<AuthorizationStatusEnumType> for String {
             AuthorizationStatusEnumType::NotAtThisLocation => "NotAtThisLocation".to_string(),
             AuthorizationStatusEnumType::NotAtThisTime => "NotAtThisTime".to_string(),
             AuthorizationStatusEnumType::Unknown => "Unknown".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            AuthorizationStatusEnumType::UnknownValue(value) => value,
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -17,6 +18,23 @@ pub enum AuthorizeCertificateStatusEnumType {
     CertChainError,
     /// If the EMAID provided by EVCC is invalid, unknown, expired or blocked.
     ContractCancelled,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for AuthorizeCertificateStatusEnumType {
+    const NAME: &'static str = "AuthorizeCertificateStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for AuthorizeCertificateStatusEnumType {
@@ -33,6 +51,9 @@ impl TryFrom<String> for AuthorizeCertificateStatusEnumType {
             }
             "CertChainError" => Ok(AuthorizeCertificateStatusEnumType::CertChainError),
             "ContractCancelled" => Ok(AuthorizeCertificateStatusEnumType::ContractCancelled),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(AuthorizeCertificateStatusEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!(
                 "'{}' is not a valid AuthorizeCertificateStatusEnumType",
                 s
@@ -59,6 +80,8 @@ impl From<AuthorizeCertificateStatusEnumType> for String {
             AuthorizeCertificateStatusEnumType::ContractCancelled => {
                 "ContractCancelled".to_string()
             }
+            #[cfg(feature = "lenient-enums")]
+            AuthorizeCertificateStatusEnumType::Unknown(value) => value,
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -10,6 +11,23 @@ pub enum BatterySwapEventEnumType {
     BatteryOut,
     /// The offered batteries have not been removed within timeout.
     BatteryOutTimeout,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for BatterySwapEventEnumType {
+    const NAME: &'static str = "BatterySwapEventEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for BatterySwapEventEnumType {
@@ -20,6 +38,9 @@ impl TryFrom<String> for BatterySwapEventEnumType {
             "BatteryIn" => Ok(BatterySwapEventEnumType::BatteryIn),
             "BatteryOut" => Ok(BatterySwapEventEnumType::BatteryOut),
             "BatteryOutTimeout" => Ok(BatterySwapEventEnumType::BatteryOutTimeout),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(BatterySwapEventEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!("'{}' is not a valid BatterySwapEventEnumType", s)),
         }
     }
@@ -31,6 +52,8 @@ impl From<BatterySwapEventEnumType> for String {
             BatterySwapEventEnumType::BatteryIn => "BatteryIn".to_string(),
             BatterySwapEventEnumType::BatteryOut => "BatteryOut".to_string(),
             BatterySwapEventEnumType::BatteryOutTimeout => "BatteryOutTimeout".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            BatterySwapEventEnumType::Unknown(value) => value,
         }
     }
 }
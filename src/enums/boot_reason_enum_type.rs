@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -22,6 +23,23 @@ pub enum BootReasonEnumType {
     Unknown,
     /// The Charging Station rebooted due to an elapsed watchdog timer.
     Watchdog,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    UnknownValue(String),
+}
+
+impl OcppEnum for BootReasonEnumType {
+    const NAME: &'static str = "BootReasonEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::UnknownValue(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for BootReasonEnumType {
@@ -38,6 +56,9 @@ impl TryFrom<String> for BootReasonEnumType {
             "Triggered" => Ok(BootReasonEnumType::Triggered),
             "Unknown" => Ok(BootReasonEnumType::Unknown),
             "Watchdog" => Ok(BootReasonEnumType::Watchdog),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(BootReasonEnumType::UnknownValue(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!("'{}' is not a valid BootReasonEnumType", s)),
         }
     }
@@ -55,6 +76,8 @@ impl From<BootReasonEnumType> for String {
             BootReasonEnumType::Triggered => "Triggered".to_string(),
             BootReasonEnumType::Unknown => "Unknown".to_string(),
             BootReasonEnumType::Watchdog => "Watchdog".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            BootReasonEnumType::UnknownValue(value) => value,
         }
     }
 }
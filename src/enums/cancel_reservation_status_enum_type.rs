@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -8,6 +9,23 @@ pub enum CancelReservationStatusEnumType {
     Accepted,
     /// Reservation could not be canceled, because there is no reservation active for the identifier.
     Rejected,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for CancelReservationStatusEnumType {
+    const NAME: &'static str = "CancelReservationStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for CancelReservationStatusEnumType {
@@ -17,6 +35,9 @@ impl TryFrom<String> for CancelReservationStatusEnumType {
         match s.as_str() {
             "Accepted" => Ok(CancelReservationStatusEnumType::Accepted),
             "Rejected" => Ok(CancelReservationStatusEnumType::Rejected),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(CancelReservationStatusEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!(
                 "'{}' is not a valid CancelReservationStatusEnumType",
                 s
@@ -30,6 +51,8 @@ impl From<CancelReservationStatusEnumType> for String {
         match val {
             CancelReservationStatusEnumType::Accepted => "Accepted".to_string(),
             CancelReservationStatusEnumType::Rejected => "Rejected".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            CancelReservationStatusEnumType::Unknown(value) => value,
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -8,6 +9,23 @@ pub enum CertificateActionEnumType {
     Install,
     /// Update the provided certificate.
     Update,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for CertificateActionEnumType {
+    const NAME: &'static str = "CertificateActionEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for CertificateActionEnumType {
@@ -17,6 +35,9 @@ impl TryFrom<String> for CertificateActionEnumType {
         match s.as_str() {
             "Install" => Ok(CertificateActionEnumType::Install),
             "Update" => Ok(CertificateActionEnumType::Update),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(CertificateActionEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!("'{}' is not a valid CertificateActionEnumType", s)),
         }
     }
@@ -27,6 +48,8 @@ impl From<CertificateActionEnumType> for String {
         match val {
             CertificateActionEnumType::Install => "Install".to_string(),
             CertificateActionEnumType::Update => "Update".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            CertificateActionEnumType::Unknown(value) => value,
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -8,6 +9,23 @@ pub enum CertificateSignedStatusEnumType {
     Accepted,
     /// Signed certificate is invalid or requestId is unknown.
     Rejected,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for CertificateSignedStatusEnumType {
+    const NAME: &'static str = "CertificateSignedStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for CertificateSignedStatusEnumType {
@@ -17,6 +35,9 @@ impl TryFrom<String> for CertificateSignedStatusEnumType {
         match s.as_str() {
             "Accepted" => Ok(CertificateSignedStatusEnumType::Accepted),
             "Rejected" => Ok(CertificateSignedStatusEnumType::Rejected),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(CertificateSignedStatusEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!(
                 "'{}' is not a valid CertificateSignedStatusEnumType",
                 s
@@ -30,6 +51,8 @@ impl From<CertificateSignedStatusEnumType> for String {
         match val {
             CertificateSignedStatusEnumType::Accepted => "Accepted".to_string(),
             CertificateSignedStatusEnumType::Rejected => "Rejected".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            CertificateSignedStatusEnumType::Unknown(value) => value,
         }
     }
 }
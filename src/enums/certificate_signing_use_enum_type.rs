@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -9,6 +10,23 @@ pub enum CertificateSigningUseEnumType {
     V2GCertificate,
     /// Use for certificate for ISO 15118-20 connections. This means that the certificate should be derived from the V2G root.
     V2G20Certificate,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for CertificateSigningUseEnumType {
+    const NAME: &'static str = "CertificateSigningUseEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for CertificateSigningUseEnumType {
@@ -21,6 +39,9 @@ impl TryFrom<String> for CertificateSigningUseEnumType {
             }
             "V2GCertificate" => Ok(CertificateSigningUseEnumType::V2GCertificate),
             "V2G20Certificate" => Ok(CertificateSigningUseEnumType::V2G20Certificate),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(CertificateSigningUseEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!(
                 "'{}' is not a valid CertificateSigningUseEnumType",
                 s
@@ -37,6 +58,8 @@ impl From<CertificateSigningUseEnumType> for String {
             }
             CertificateSigningUseEnumType::V2GCertificate => "V2GCertificate".to_string(),
             CertificateSigningUseEnumType::V2G20Certificate => "V2G20Certificate".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            CertificateSigningUseEnumType::Unknown(value) => value,
         }
     }
 }
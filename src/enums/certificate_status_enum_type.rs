@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -11,6 +12,23 @@ pub enum CertificateStatusEnumType {
     Unknown,
     /// The request to OCSP responder or CRL distribution point failed.
     Failed,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    UnknownValue(String),
+}
+
+impl OcppEnum for CertificateStatusEnumType {
+    const NAME: &'static str = "CertificateStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::UnknownValue(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for CertificateStatusEnumType {
@@ -22,6 +40,9 @@ impl TryFrom<String> for CertificateStatusEnumType {
             "Revoked" => Ok(CertificateStatusEnumType::Revoked),
             "Unknown" => Ok(CertificateStatusEnumType::Unknown),
             "Failed" => Ok(CertificateStatusEnumType::Failed),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(CertificateStatusEnumType::UnknownValue(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!("'{}' is not a valid CertificateStatusEnumType", s)),
         }
     }
@@ -34,6 +55,8 @@ impl From<CertificateStatusEnumType> for String {
             CertificateStatusEnumType::Revoked => "Revoked".to_string(),
             CertificateStatusEnumType::Unknown => "Unknown".to_string(),
             CertificateStatusEnumType::Failed => "Failed".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            CertificateStatusEnumType::UnknownValue(value) => value,
         }
     }
 }
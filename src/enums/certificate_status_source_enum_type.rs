@@ -1,12 +1,32 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
 #[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
 pub enum CertificateStatusSourceEnumType {
     /// Checked in a certificate revocation list.
+    #[serde(rename = "CRL")]
     Crl,
     /// Checked via OCSP request.
+    #[serde(rename = "OCSP")]
     Ocsp,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for CertificateStatusSourceEnumType {
+    const NAME: &'static str = "CertificateStatusSourceEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for CertificateStatusSourceEnumType {
@@ -16,6 +36,9 @@ impl TryFrom<String> for CertificateStatusSourceEnumType {
         match s.as_str() {
             "CRL" => Ok(CertificateStatusSourceEnumType::Crl),
             "OCSP" => Ok(CertificateStatusSourceEnumType::Ocsp),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(CertificateStatusSourceEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!(
                 "'{}' is not a valid CertificateStatusSourceEnumType",
                 s
@@ -29,6 +52,8 @@ impl From<CertificateStatusSourceEnumType> for String {
         match val {
             CertificateStatusSourceEnumType::Crl => "CRL".to_string(),
             CertificateStatusSourceEnumType::Ocsp => "OCSP".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            CertificateStatusSourceEnumType::Unknown(value) => value,
         }
     }
 }
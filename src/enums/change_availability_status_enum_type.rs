@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -10,6 +11,23 @@ pub enum ChangeAvailabilityStatusEnumType {
     Rejected,
     /// Request has been accepted and will be executed when transaction(s) in progress have finished.
     Scheduled,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for ChangeAvailabilityStatusEnumType {
+    const NAME: &'static str = "ChangeAvailabilityStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for ChangeAvailabilityStatusEnumType {
@@ -20,6 +38,9 @@ impl TryFrom<String> for ChangeAvailabilityStatusEnumType {
             "Accepted" => Ok(ChangeAvailabilityStatusEnumType::Accepted),
             "Rejected" => Ok(ChangeAvailabilityStatusEnumType::Rejected),
             "Scheduled" => Ok(ChangeAvailabilityStatusEnumType::Scheduled),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(ChangeAvailabilityStatusEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!(
                 "'{}' is not a valid ChangeAvailabilityStatusEnumType",
                 s
@@ -34,6 +55,8 @@ impl From<ChangeAvailabilityStatusEnumType> for String {
             ChangeAvailabilityStatusEnumType::Accepted => "Accepted".to_string(),
             ChangeAvailabilityStatusEnumType::Rejected => "Rejected".to_string(),
             ChangeAvailabilityStatusEnumType::Scheduled => "Scheduled".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            ChangeAvailabilityStatusEnumType::Unknown(value) => value,
         }
     }
 }
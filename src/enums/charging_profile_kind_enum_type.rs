@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -11,6 +12,23 @@ pub enum ChargingProfileKindEnumType {
     Relative,
     /// The schedule consists of only one charging schedule period, which is updated dynamically by CSMS.
     Dynamic,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for ChargingProfileKindEnumType {
+    const NAME: &'static str = "ChargingProfileKindEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for ChargingProfileKindEnumType {
@@ -22,6 +40,9 @@ impl TryFrom<String> for ChargingProfileKindEnumType {
             "Recurring" => Ok(ChargingProfileKindEnumType::Recurring),
             "Relative" => Ok(ChargingProfileKindEnumType::Relative),
             "Dynamic" => Ok(ChargingProfileKindEnumType::Dynamic),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(ChargingProfileKindEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!(
                 "'{}' is not a valid ChargingProfileKindEnumType",
                 s
@@ -37,6 +58,8 @@ impl From<ChargingProfileKindEnumType> for String {
             ChargingProfileKindEnumType::Recurring => "Recurring".to_string(),
             ChargingProfileKindEnumType::Relative => "Relative".to_string(),
             ChargingProfileKindEnumType::Dynamic => "Dynamic".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            ChargingProfileKindEnumType::Unknown(value) => value,
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -15,6 +16,23 @@ pub enum ChargingProfilePurposeEnumType {
     PriorityCharging,
     /// This profile adds capacity from local generation. Its capacity is added on top of other charging profiles.
     LocalGeneration,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for ChargingProfilePurposeEnumType {
+    const NAME: &'static str = "ChargingProfilePurposeEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for ChargingProfilePurposeEnumType {
@@ -32,6 +50,9 @@ impl TryFrom<String> for ChargingProfilePurposeEnumType {
             "TxProfile" => Ok(ChargingProfilePurposeEnumType::TxProfile),
             "PriorityCharging" => Ok(ChargingProfilePurposeEnumType::PriorityCharging),
             "LocalGeneration" => Ok(ChargingProfilePurposeEnumType::LocalGeneration),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(ChargingProfilePurposeEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!(
                 "'{}' is not a valid ChargingProfilePurposeEnumType",
                 s
@@ -53,6 +74,8 @@ impl From<ChargingProfilePurposeEnumType> for String {
             ChargingProfilePurposeEnumType::TxProfile => "TxProfile".to_string(),
             ChargingProfilePurposeEnumType::PriorityCharging => "PriorityCharging".to_string(),
             ChargingProfilePurposeEnumType::LocalGeneration => "LocalGeneration".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            ChargingProfilePurposeEnumType::Unknown(value) => value,
         }
     }
 }
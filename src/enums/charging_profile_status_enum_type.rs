@@ -1,12 +1,31 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
-#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize, Default)]
 pub enum ChargingProfileStatusEnumType {
     /// Request has been accepted and will be executed.
+    #[default]
     Accepted,
     /// Request has not been accepted and will not be executed.
     Rejected,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for ChargingProfileStatusEnumType {
+    const NAME: &'static str = "ChargingProfileStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for ChargingProfileStatusEnumType {
@@ -16,6 +35,9 @@ impl TryFrom<String> for ChargingProfileStatusEnumType {
         match s.as_str() {
             "Accepted" => Ok(ChargingProfileStatusEnumType::Accepted),
             "Rejected" => Ok(ChargingProfileStatusEnumType::Rejected),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(ChargingProfileStatusEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!(
                 "'{}' is not a valid ChargingProfileStatusEnumType",
                 s
@@ -29,6 +51,8 @@ impl From<ChargingProfileStatusEnumType> for String {
         match val {
             ChargingProfileStatusEnumType::Accepted => "Accepted".to_string(),
             ChargingProfileStatusEnumType::Rejected => "Rejected".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            ChargingProfileStatusEnumType::Unknown(value) => value,
         }
     }
 }
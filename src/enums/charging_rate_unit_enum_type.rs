@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -7,6 +8,23 @@ pub enum ChargingRateUnitEnumType {
     W,
     /// Amperes (current). The amount of Ampere per phase, not the sum of all phases. It is usually more convenient to use this for AC charging.
     A,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for ChargingRateUnitEnumType {
+    const NAME: &'static str = "ChargingRateUnitEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for ChargingRateUnitEnumType {
@@ -16,6 +34,9 @@ impl TryFrom<String> for ChargingRateUnitEnumType {
         match s.as_str() {
             "W" => Ok(ChargingRateUnitEnumType::W),
             "A" => Ok(ChargingRateUnitEnumType::A),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(ChargingRateUnitEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!("'{}' is not a valid ChargingRateUnitEnumType", s)),
         }
     }
@@ -26,6 +47,8 @@ impl From<ChargingRateUnitEnumType> for String {
         match val {
             ChargingRateUnitEnumType::W => "W".to_string(),
             ChargingRateUnitEnumType::A => "A".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            ChargingRateUnitEnumType::Unknown(value) => value,
         }
     }
 }
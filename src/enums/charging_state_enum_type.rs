@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -13,6 +14,23 @@ pub enum ChargingStateEnumType {
     SuspendedEVSE,
     /// There is no connection between EV and EVSE.
     Idle,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for ChargingStateEnumType {
+    const NAME: &'static str = "ChargingStateEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for ChargingStateEnumType {
@@ -25,6 +43,9 @@ impl TryFrom<String> for ChargingStateEnumType {
             "SuspendedEV" => Ok(ChargingStateEnumType::SuspendedEV),
             "SuspendedEVSE" => Ok(ChargingStateEnumType::SuspendedEVSE),
             "Idle" => Ok(ChargingStateEnumType::Idle),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(ChargingStateEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!("'{}' is not a valid ChargingStateEnumType", s)),
         }
     }
@@ -38,6 +59,8 @@ impl From<ChargingStateEnumType> for String {
             ChargingStateEnumType::SuspendedEV => "SuspendedEV".to_string(),
             ChargingStateEnumType::SuspendedEVSE => "SuspendedEVSE".to_string(),
             ChargingStateEnumType::Idle => "Idle".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            ChargingStateEnumType::Unknown(value) => value,
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -8,6 +9,23 @@ pub enum ClearCacheStatusEnumType {
     Accepted,
     /// Command has not been executed.
     Rejected,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for ClearCacheStatusEnumType {
+    const NAME: &'static str = "ClearCacheStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for ClearCacheStatusEnumType {
@@ -17,6 +35,9 @@ impl TryFrom<String> for ClearCacheStatusEnumType {
         match s.as_str() {
             "Accepted" => Ok(ClearCacheStatusEnumType::Accepted),
             "Rejected" => Ok(ClearCacheStatusEnumType::Rejected),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(ClearCacheStatusEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!("'{}' is not a valid ClearCacheStatusEnumType", s)),
         }
     }
@@ -27,6 +48,8 @@ impl From<ClearCacheStatusEnumType> for String {
         match val {
             ClearCacheStatusEnumType::Accepted => "Accepted".to_string(),
             ClearCacheStatusEnumType::Rejected => "Rejected".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            ClearCacheStatusEnumType::Unknown(value) => value,
         }
     }
 }
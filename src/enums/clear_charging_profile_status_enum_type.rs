@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -8,6 +9,23 @@ pub enum ClearChargingProfileStatusEnumType {
     Accepted,
     /// No Charging Profile(s) were found matching the request.
     Unknown,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    UnknownValue(String),
+}
+
+impl OcppEnum for ClearChargingProfileStatusEnumType {
+    const NAME: &'static str = "ClearChargingProfileStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::UnknownValue(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for ClearChargingProfileStatusEnumType {
@@ -17,6 +35,9 @@ impl TryFrom<String> for ClearChargingProfileStatusEnumType {
         match s.as_str() {
             "Accepted" => Ok(ClearChargingProfileStatusEnumType::Accepted),
             "Unknown" => Ok(ClearChargingProfileStatusEnumType::Unknown),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(ClearChargingProfileStatusEnumType::UnknownValue(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!(
                 "'{}' is not a valid ClearChargingProfileStatusEnumType",
                 s
@@ -30,6 +51,8 @@ impl From<ClearChargingProfileStatusEnumType> for String {
         match val {
             ClearChargingProfileStatusEnumType::Accepted => "Accepted".to_string(),
             ClearChargingProfileStatusEnumType::Unknown => "Unknown".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            ClearChargingProfileStatusEnumType::UnknownValue(value) => value,
         }
     }
 }
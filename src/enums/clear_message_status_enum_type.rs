@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -10,6 +11,23 @@ pub enum ClearMessageStatusEnumType {
     Unknown,
     /// Request could not be executed.
     Rejected,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    UnknownValue(String),
+}
+
+impl OcppEnum for ClearMessageStatusEnumType {
+    const NAME: &'static str = "ClearMessageStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::UnknownValue(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for ClearMessageStatusEnumType {
@@ -20,6 +38,9 @@ impl TryFrom<String> for ClearMessageStatusEnumType {
             "Accepted" => Ok(ClearMessageStatusEnumType::Accepted),
             "Unknown" => Ok(ClearMessageStatusEnumType::Unknown),
             "Rejected" => Ok(ClearMessageStatusEnumType::Rejected),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(ClearMessageStatusEnumType::UnknownValue(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!("'{}' is not a valid ClearMessageStatusEnumType", s)),
         }
     }
@@ -31,6 +52,8 @@ impl From<ClearMessageStatusEnumType> for String {
             ClearMessageStatusEnumType::Accepted => "Accepted".to_string(),
             ClearMessageStatusEnumType::Unknown => "Unknown".to_string(),
             ClearMessageStatusEnumType::Rejected => "Rejected".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            ClearMessageStatusEnumType::UnknownValue(value) => value,
         }
     }
 }
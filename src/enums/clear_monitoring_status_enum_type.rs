@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -9,6 +10,23 @@ pub enum ClearMonitoringStatusEnumType {
     Rejected,
     /// Monitor Id is not found.
     NotFound,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for ClearMonitoringStatusEnumType {
+    const NAME: &'static str = "ClearMonitoringStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for ClearMonitoringStatusEnumType {
@@ -19,6 +37,9 @@ impl TryFrom<String> for ClearMonitoringStatusEnumType {
             "Accepted" => Ok(ClearMonitoringStatusEnumType::Accepted),
             "Rejected" => Ok(ClearMonitoringStatusEnumType::Rejected),
             "NotFound" => Ok(ClearMonitoringStatusEnumType::NotFound),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(ClearMonitoringStatusEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!(
                 "'{}' is not a valid ClearMonitoringStatusEnumType",
                 s
@@ -33,6 +54,8 @@ impl From<ClearMonitoringStatusEnumType> for String {
             ClearMonitoringStatusEnumType::Accepted => "Accepted".to_string(),
             ClearMonitoringStatusEnumType::Rejected => "Rejected".to_string(),
             ClearMonitoringStatusEnumType::NotFound => "NotFound".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            ClearMonitoringStatusEnumType::Unknown(value) => value,
         }
     }
 }
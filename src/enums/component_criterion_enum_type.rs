@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -11,6 +12,23 @@ pub enum ComponentCriterionEnumType {
     Enabled,
     /// Components that reported a problem, i.e. having Problem = 1.
     Problem,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for ComponentCriterionEnumType {
+    const NAME: &'static str = "ComponentCriterionEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for ComponentCriterionEnumType {
@@ -22,6 +40,9 @@ impl TryFrom<String> for ComponentCriterionEnumType {
             "Available" => Ok(ComponentCriterionEnumType::Available),
             "Enabled" => Ok(ComponentCriterionEnumType::Enabled),
             "Problem" => Ok(ComponentCriterionEnumType::Problem),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(ComponentCriterionEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!("'{}' is not a valid ComponentCriterionEnumType", s)),
         }
     }
@@ -34,6 +55,8 @@ impl From<ComponentCriterionEnumType> for String {
             ComponentCriterionEnumType::Available => "Available".to_string(),
             ComponentCriterionEnumType::Enabled => "Enabled".to_string(),
             ComponentCriterionEnumType::Problem => "Problem".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            ComponentCriterionEnumType::Unknown(value) => value,
         }
     }
 }
@@ -1,9 +1,11 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
-#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize, Default)]
 pub enum ConnectorStatusEnumType {
     /// When a Connector becomes available for a new User (Operative).
+    #[default]
     Available,
     /// When a Connector becomes occupied, so it is not available for a new EV driver. (Operative).
     Occupied,
@@ -13,6 +15,23 @@ pub enum ConnectorStatusEnumType {
     Unavailable,
     /// When a Connector (or the EVSE or the entire Charging Station it belongs to) has reported an error and is not available for energy delivery. (Inoperative).
     Faulted,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for ConnectorStatusEnumType {
+    const NAME: &'static str = "ConnectorStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for ConnectorStatusEnumType {
@@ -25,6 +44,9 @@ impl TryFrom<String> for ConnectorStatusEnumType {
             "Reserved" => Ok(ConnectorStatusEnumType::Reserved),
             "Unavailable" => Ok(ConnectorStatusEnumType::Unavailable),
             "Faulted" => Ok(ConnectorStatusEnumType::Faulted),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(ConnectorStatusEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!("'{}' is not a valid ConnectorStatusEnumType", s)),
         }
     }
@@ -38,6 +60,8 @@ impl From<ConnectorStatusEnumType> for String {
             ConnectorStatusEnumType::Reserved => "Reserved".to_string(),
             ConnectorStatusEnumType::Unavailable => "Unavailable".to_string(),
             ConnectorStatusEnumType::Faulted => "Faulted".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            ConnectorStatusEnumType::Unknown(value) => value,
         }
     }
 }
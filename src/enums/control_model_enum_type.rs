@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -7,6 +8,23 @@ pub enum ControlModeEnumType {
     ScheduledControl,
     /// Dynamic control mode, EVSE executes a single schedule by sending setpoints to EV at every interval.
     DynamicControl,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for ControlModeEnumType {
+    const NAME: &'static str = "ControlModeEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for ControlModeEnumType {
@@ -16,6 +34,9 @@ impl TryFrom<String> for ControlModeEnumType {
         match s.as_str() {
             "ScheduledControl" => Ok(ControlModeEnumType::ScheduledControl),
             "DynamicControl" => Ok(ControlModeEnumType::DynamicControl),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(ControlModeEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!("'{}' is not a valid ControlModeEnumType", s)),
         }
     }
@@ -26,6 +47,8 @@ impl From<ControlModeEnumType> for String {
         match val {
             ControlModeEnumType::ScheduledControl => "ScheduledControl".to_string(),
             ControlModeEnumType::DynamicControl => "DynamicControl".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            ControlModeEnumType::Unknown(value) => value,
         }
     }
 }
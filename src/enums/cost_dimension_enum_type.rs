@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -17,6 +18,23 @@ pub enum CostDimensionEnumType {
     IdleTime,
     /// Time charging during this charging period: defined in seconds.
     ChargingTime,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for CostDimensionEnumType {
+    const NAME: &'static str = "CostDimensionEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for CostDimensionEnumType {
@@ -31,6 +49,9 @@ impl TryFrom<String> for CostDimensionEnumType {
             "MinPower" => Ok(CostDimensionEnumType::MinPower),
             "IdleTime" => Ok(CostDimensionEnumType::IdleTime),
             "ChargingTime" => Ok(CostDimensionEnumType::ChargingTime),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(CostDimensionEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!("'{}' is not a valid CostDimensionEnumType", s)),
         }
     }
@@ -46,6 +67,8 @@ impl From<CostDimensionEnumType> for String {
             CostDimensionEnumType::MinPower => "MinPower".to_string(),
             CostDimensionEnumType::IdleTime => "IdleTime".to_string(),
             CostDimensionEnumType::ChargingTime => "ChargingTime".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            CostDimensionEnumType::Unknown(value) => value,
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -9,6 +10,23 @@ pub enum CostKindEnumType {
     RelativePricePercentage,
     /// Relative value. Price per kWh, as percentage relative to the maximum price stated in any of all tariffs indicated to the EV.
     RenewableGenerationPercentage,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for CostKindEnumType {
+    const NAME: &'static str = "CostKindEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for CostKindEnumType {
@@ -19,6 +37,9 @@ impl TryFrom<String> for CostKindEnumType {
             "CarbonDioxideEmission" => Ok(CostKindEnumType::CarbonDioxideEmission),
             "RelativePricePercentage" => Ok(CostKindEnumType::RelativePricePercentage),
             "RenewableGenerationPercentage" => Ok(CostKindEnumType::RenewableGenerationPercentage),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(CostKindEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!("'{}' is not a valid CostKindEnumType", s)),
         }
     }
@@ -32,6 +53,8 @@ impl From<CostKindEnumType> for String {
             CostKindEnumType::RenewableGenerationPercentage => {
                 "RenewableGenerationPercentage".to_string()
             }
+            #[cfg(feature = "lenient-enums")]
+            CostKindEnumType::Unknown(value) => value,
         }
     }
 }
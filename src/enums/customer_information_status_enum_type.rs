@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -10,6 +11,23 @@ pub enum CustomerInformationStatusEnumType {
     Rejected,
     /// In a request to the Charging Station no reference to a customer is included.
     Invalid,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for CustomerInformationStatusEnumType {
+    const NAME: &'static str = "CustomerInformationStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for CustomerInformationStatusEnumType {
@@ -20,6 +38,9 @@ impl TryFrom<String> for CustomerInformationStatusEnumType {
             "Accepted" => Ok(CustomerInformationStatusEnumType::Accepted),
             "Rejected" => Ok(CustomerInformationStatusEnumType::Rejected),
             "Invalid" => Ok(CustomerInformationStatusEnumType::Invalid),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(CustomerInformationStatusEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!(
                 "'{}' is not a valid CustomerInformationStatusEnumType",
                 s
@@ -34,6 +55,8 @@ impl From<CustomerInformationStatusEnumType> for String {
             CustomerInformationStatusEnumType::Accepted => "Accepted".to_string(),
             CustomerInformationStatusEnumType::Rejected => "Rejected".to_string(),
             CustomerInformationStatusEnumType::Invalid => "Invalid".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            CustomerInformationStatusEnumType::Unknown(value) => value,
         }
     }
 }
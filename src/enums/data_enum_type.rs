@@ -1,17 +1,23 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
 #[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
 pub enum DataEnumType {
     /// This variable is of the type string.
+    #[serde(rename = "string")]
     String,
     /// This variable is of the type decimal.
+    #[serde(rename = "decimal")]
     Decimal,
     /// This variable is of the type integer.
+    #[serde(rename = "integer")]
     Integer,
     /// DateTime following the [RFC3339] specification.
+    #[serde(rename = "dateTime")]
     DateTime,
     /// This variable is of the type boolean.
+    #[serde(rename = "boolean")]
     Boolean,
     /// Supported/allowed values for a single choice, enumerated, text variable.
     OptionList,
@@ -19,6 +25,23 @@ pub enum DataEnumType {
     SequenceList,
     /// Supported/allowed values for a mathematical set variable.
     MemberList,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for DataEnumType {
+    const NAME: &'static str = "DataEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for DataEnumType {
@@ -34,6 +57,9 @@ impl TryFrom<String> for DataEnumType {
             "OptionList" => Ok(DataEnumType::OptionList),
             "SequenceList" => Ok(DataEnumType::SequenceList),
             "MemberList" => Ok(DataEnumType::MemberList),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(DataEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!("'{}' is not a valid DataEnumType", s)),
         }
     }
@@ -50,6 +76,8 @@ impl From<DataEnumType> for String {
             DataEnumType::OptionList => "OptionList".to_string(),
             DataEnumType::SequenceList => "SequenceList".to_string(),
             DataEnumType::MemberList => "MemberList".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            DataEnumType::Unknown(value) => value,
         }
     }
 }
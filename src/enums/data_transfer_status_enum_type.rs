@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -12,6 +13,23 @@ pub enum DataTransferStatusEnumType {
     UnknownMessageId,
     /// Message could not be interpreted due to unknown vendorId string.
     UnknownVendorId,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for DataTransferStatusEnumType {
+    const NAME: &'static str = "DataTransferStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for DataTransferStatusEnumType {
@@ -23,6 +41,9 @@ impl TryFrom<String> for DataTransferStatusEnumType {
             "Rejected" => Ok(DataTransferStatusEnumType::Rejected),
             "UnknownMessageId" => Ok(DataTransferStatusEnumType::UnknownMessageId),
             "UnknownVendorId" => Ok(DataTransferStatusEnumType::UnknownVendorId),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(DataTransferStatusEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!("'{}' is not a valid DataTransferStatusEnumType", s)),
         }
     }
@@ -35,6 +56,8 @@ impl From<DataTransferStatusEnumType> for String {
             DataTransferStatusEnumType::Rejected => "Rejected".to_string(),
             DataTransferStatusEnumType::UnknownMessageId => "UnknownMessageId".to_string(),
             DataTransferStatusEnumType::UnknownVendorId => "UnknownVendorId".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            DataTransferStatusEnumType::Unknown(value) => value,
         }
     }
 }
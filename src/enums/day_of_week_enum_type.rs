@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -10,6 +11,23 @@ pub enum DayOfWeekEnumType {
     Friday,
     Saturday,
     Sunday,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for DayOfWeekEnumType {
+    const NAME: &'static str = "DayOfWeekEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for DayOfWeekEnumType {
@@ -24,6 +42,9 @@ impl TryFrom<String> for DayOfWeekEnumType {
             "Friday" => Ok(DayOfWeekEnumType::Friday),
             "Saturday" => Ok(DayOfWeekEnumType::Saturday),
             "Sunday" => Ok(DayOfWeekEnumType::Sunday),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(DayOfWeekEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!("'{}' is not a valid DayOfWeekEnumType", s)),
         }
     }
@@ -39,6 +60,8 @@ impl From<DayOfWeekEnumType> for String {
             DayOfWeekEnumType::Friday => "Friday".to_string(),
             DayOfWeekEnumType::Saturday => "Saturday".to_string(),
             DayOfWeekEnumType::Sunday => "Sunday".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            DayOfWeekEnumType::Unknown(value) => value,
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -10,6 +11,23 @@ pub enum DeleteCertificateStatusEnumType {
     Failed,
     /// Requested resource not found.
     NotFound,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for DeleteCertificateStatusEnumType {
+    const NAME: &'static str = "DeleteCertificateStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for DeleteCertificateStatusEnumType {
@@ -20,6 +38,9 @@ impl TryFrom<String> for DeleteCertificateStatusEnumType {
             "Accepted" => Ok(DeleteCertificateStatusEnumType::Accepted),
             "Failed" => Ok(DeleteCertificateStatusEnumType::Failed),
             "NotFound" => Ok(DeleteCertificateStatusEnumType::NotFound),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(DeleteCertificateStatusEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!(
                 "'{}' is not a valid DeleteCertificateStatusEnumType",
                 s
@@ -34,6 +55,8 @@ impl From<DeleteCertificateStatusEnumType> for String {
             DeleteCertificateStatusEnumType::Accepted => "Accepted".to_string(),
             DeleteCertificateStatusEnumType::Failed => "Failed".to_string(),
             DeleteCertificateStatusEnumType::NotFound => "NotFound".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            DeleteCertificateStatusEnumType::Unknown(value) => value,
         }
     }
 }
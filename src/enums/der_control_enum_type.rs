@@ -1,9 +1,11 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
-#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize, Default)]
 pub enum DERControlEnumType {
     /// Enter Service parameters setting
+    #[default]
     EnterService,
     /// Frequency droop settings
     FreqDroop,
@@ -45,6 +47,23 @@ pub enum DERControlEnumType {
     WattPF,
     /// Watt-Var curve
     WattVar,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for DERControlEnumType {
+    const NAME: &'static str = "DERControlEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for DERControlEnumType {
@@ -73,6 +92,9 @@ impl TryFrom<String> for DERControlEnumType {
             "VoltWatt" => Ok(DERControlEnumType::VoltWatt),
             "WattPF" => Ok(DERControlEnumType::WattPF),
             "WattVar" => Ok(DERControlEnumType::WattVar),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(DERControlEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!("'{}' is not a valid DERControlEnumType", s)),
         }
     }
@@ -102,6 +124,8 @@ impl From<DERControlEnumType> for String {
             DERControlEnumType::VoltWatt => "VoltWatt".to_string(),
             DERControlEnumType::WattPF => "WattPF".to_string(),
             DERControlEnumType::WattVar => "WattVar".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            DERControlEnumType::Unknown(value) => value,
         }
     }
 }
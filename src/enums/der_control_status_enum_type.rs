@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -12,6 +13,23 @@ pub enum DERControlStatusEnumType {
     NotSupported,
     /// Type or Id in clear/get request was not found.
     NotFound,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for DERControlStatusEnumType {
+    const NAME: &'static str = "DERControlStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for DERControlStatusEnumType {
@@ -23,6 +41,9 @@ impl TryFrom<String> for DERControlStatusEnumType {
             "Rejected" => Ok(DERControlStatusEnumType::Rejected),
             "NotSupported" => Ok(DERControlStatusEnumType::NotSupported),
             "NotFound" => Ok(DERControlStatusEnumType::NotFound),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(DERControlStatusEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!("'{}' is not a valid DERControlStatusEnumType", s)),
         }
     }
@@ -35,6 +56,8 @@ impl From<DERControlStatusEnumType> for String {
             DERControlStatusEnumType::Rejected => "Rejected".to_string(),
             DERControlStatusEnumType::NotSupported => "NotSupported".to_string(),
             DERControlStatusEnumType::NotFound => "NotFound".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            DERControlStatusEnumType::Unknown(value) => value,
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -15,6 +16,23 @@ pub enum DERUnitEnumType {
     PctVarAvail,
     /// Percentage of effective voltage
     PctEffectiveV,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for DERUnitEnumType {
+    const NAME: &'static str = "DERUnitEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for DERUnitEnumType {
@@ -28,6 +46,9 @@ impl TryFrom<String> for DERUnitEnumType {
             "PctWAvail" => Ok(DERUnitEnumType::PctWAvail),
             "PctVarAvail" => Ok(DERUnitEnumType::PctVarAvail),
             "PctEffectiveV" => Ok(DERUnitEnumType::PctEffectiveV),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(DERUnitEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!("'{}' is not a valid DERUnitEnumType", s)),
         }
     }
@@ -42,6 +63,8 @@ impl From<DERUnitEnumType> for String {
             DERUnitEnumType::PctWAvail => "PctWAvail".to_string(),
             DERUnitEnumType::PctVarAvail => "PctVarAvail".to_string(),
             DERUnitEnumType::PctEffectiveV => "PctEffectiveV".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            DERUnitEnumType::Unknown(value) => value,
         }
     }
 }
@@ -1,9 +1,11 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
-#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize, Default)]
 pub enum DisplayMessageStatusEnumType {
     /// Request to display message accepted.
+    #[default]
     Accepted,
     /// None of the formats in the given message are supported.
     NotSupportedMessageFormat,
@@ -17,6 +19,23 @@ pub enum DisplayMessageStatusEnumType {
     UnknownTransaction,
     /// Message contains one or more languages that are not supported by Charging Station.
     LanguageNotSupported,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for DisplayMessageStatusEnumType {
+    const NAME: &'static str = "DisplayMessageStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for DisplayMessageStatusEnumType {
@@ -33,6 +52,9 @@ impl TryFrom<String> for DisplayMessageStatusEnumType {
             "NotSupportedState" => Ok(DisplayMessageStatusEnumType::NotSupportedState),
             "UnknownTransaction" => Ok(DisplayMessageStatusEnumType::UnknownTransaction),
             "LanguageNotSupported" => Ok(DisplayMessageStatusEnumType::LanguageNotSupported),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(DisplayMessageStatusEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!(
                 "'{}' is not a valid DisplayMessageStatusEnumType",
                 s
@@ -57,6 +79,8 @@ impl From<DisplayMessageStatusEnumType> for String {
             DisplayMessageStatusEnumType::LanguageNotSupported => {
                 "LanguageNotSupported".to_string()
             }
+            #[cfg(feature = "lenient-enums")]
+            DisplayMessageStatusEnumType::Unknown(value) => value,
         }
     }
 }
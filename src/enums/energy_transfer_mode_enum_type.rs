@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -25,6 +26,23 @@ pub enum EnergyTransferModeEnumType {
     DC_ACDP_BPT,
     /// Wireless power transfer, ISO 15118-20
     WPT,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for EnergyTransferModeEnumType {
+    const NAME: &'static str = "EnergyTransferModeEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for EnergyTransferModeEnumType {
@@ -43,6 +61,9 @@ impl TryFrom<String> for EnergyTransferModeEnumType {
             "DC_ACDP" => Ok(EnergyTransferModeEnumType::DC_ACDP),
             "DC_ACDP_BPT" => Ok(EnergyTransferModeEnumType::DC_ACDP_BPT),
             "WPT" => Ok(EnergyTransferModeEnumType::WPT),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(EnergyTransferModeEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!("'{}' is not a valid EnergyTransferModeEnumType", s)),
         }
     }
@@ -62,6 +83,8 @@ impl From<EnergyTransferModeEnumType> for String {
             EnergyTransferModeEnumType::DC_ACDP => "DC_ACDP".to_string(),
             EnergyTransferModeEnumType::DC_ACDP_BPT => "DC_ACDP_BPT".to_string(),
             EnergyTransferModeEnumType::WPT => "WPT".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            EnergyTransferModeEnumType::Unknown(value) => value,
         }
     }
 }
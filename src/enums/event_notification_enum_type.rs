@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -11,6 +12,23 @@ pub enum EventNotificationEnumType {
     PreconfiguredMonitor,
     /// Triggered by a monitor, which is set with the setvariablemonitoringrequest message by the Charging Station Operator.
     CustomMonitor,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for EventNotificationEnumType {
+    const NAME: &'static str = "EventNotificationEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for EventNotificationEnumType {
@@ -22,6 +40,9 @@ impl TryFrom<String> for EventNotificationEnumType {
             "HardWiredMonitor" => Ok(EventNotificationEnumType::HardWiredMonitor),
             "PreconfiguredMonitor" => Ok(EventNotificationEnumType::PreconfiguredMonitor),
             "CustomMonitor" => Ok(EventNotificationEnumType::CustomMonitor),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(EventNotificationEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!("'{}' is not a valid EventNotificationEnumType", s)),
         }
     }
@@ -34,6 +55,8 @@ impl From<EventNotificationEnumType> for String {
             EventNotificationEnumType::HardWiredMonitor => "HardWiredMonitor".to_string(),
             EventNotificationEnumType::PreconfiguredMonitor => "PreconfiguredMonitor".to_string(),
             EventNotificationEnumType::CustomMonitor => "CustomMonitor".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            EventNotificationEnumType::Unknown(value) => value,
         }
     }
 }
@@ -1,14 +1,33 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
-#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize, Default)]
 pub enum EventTriggerEnumType {
     /// Monitored variable has passed a Lower or Upper Threshold. Also used as trigger type for a HardwiredNotification.
+    #[default]
     Alerting,
     /// Delta Monitored Variable value has changed by more than specified amount
     Delta,
     /// Periodic Monitored Variable has been sampled for reporting at the specified interval
     Periodic,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for EventTriggerEnumType {
+    const NAME: &'static str = "EventTriggerEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for EventTriggerEnumType {
@@ -19,6 +38,9 @@ impl TryFrom<String> for EventTriggerEnumType {
             "Alerting" => Ok(EventTriggerEnumType::Alerting),
             "Delta" => Ok(EventTriggerEnumType::Delta),
             "Periodic" => Ok(EventTriggerEnumType::Periodic),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(EventTriggerEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!("'{}' is not a valid EventTriggerEnumType", s)),
         }
     }
@@ -30,6 +52,8 @@ impl From<EventTriggerEnumType> for String {
             EventTriggerEnumType::Alerting => "Alerting".to_string(),
             EventTriggerEnumType::Delta => "Delta".to_string(),
             EventTriggerEnumType::Periodic => "Periodic".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            EventTriggerEnumType::Unknown(value) => value,
         }
     }
 }
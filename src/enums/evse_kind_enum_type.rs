@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -7,6 +8,23 @@ pub enum EvseKindEnumType {
     AC,
     /// DC current EVSE
     DC,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for EvseKindEnumType {
+    const NAME: &'static str = "EvseKindEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for EvseKindEnumType {
@@ -16,6 +34,9 @@ impl TryFrom<String> for EvseKindEnumType {
         match s.as_str() {
             "AC" => Ok(EvseKindEnumType::AC),
             "DC" => Ok(EvseKindEnumType::DC),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(EvseKindEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!("'{}' is not a valid EvseKindEnumType", s)),
         }
     }
@@ -26,6 +47,8 @@ impl From<EvseKindEnumType> for String {
         match val {
             EvseKindEnumType::AC => "AC".to_string(),
             EvseKindEnumType::DC => "DC".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            EvseKindEnumType::Unknown(value) => value,
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -32,6 +33,23 @@ pub enum FirmwareStatusEnumType {
     InvalidSignature,
     /// Intermediate state. Provide signature successfully verified.
     SignatureVerified,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for FirmwareStatusEnumType {
+    const NAME: &'static str = "FirmwareStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for FirmwareStatusEnumType {
@@ -53,6 +71,9 @@ impl TryFrom<String> for FirmwareStatusEnumType {
             "InstallVerificationFailed" => Ok(FirmwareStatusEnumType::InstallVerificationFailed),
             "InvalidSignature" => Ok(FirmwareStatusEnumType::InvalidSignature),
             "SignatureVerified" => Ok(FirmwareStatusEnumType::SignatureVerified),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(FirmwareStatusEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!("'{}' is not a valid FirmwareStatusEnumType", s)),
         }
     }
@@ -77,6 +98,8 @@ impl From<FirmwareStatusEnumType> for String {
             }
             FirmwareStatusEnumType::InvalidSignature => "InvalidSignature".to_string(),
             FirmwareStatusEnumType::SignatureVerified => "SignatureVerified".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            FirmwareStatusEnumType::Unknown(value) => value,
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -12,6 +13,23 @@ pub enum GenericDeviceModelStatusEnumType {
     NotSupported,
     /// If the combination of received criteria result in an empty result set.
     EmptyResultSet,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for GenericDeviceModelStatusEnumType {
+    const NAME: &'static str = "GenericDeviceModelStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for GenericDeviceModelStatusEnumType {
@@ -23,6 +41,9 @@ impl TryFrom<String> for GenericDeviceModelStatusEnumType {
             "Rejected" => Ok(GenericDeviceModelStatusEnumType::Rejected),
             "NotSupported" => Ok(GenericDeviceModelStatusEnumType::NotSupported),
             "EmptyResultSet" => Ok(GenericDeviceModelStatusEnumType::EmptyResultSet),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(GenericDeviceModelStatusEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!(
                 "'{}' is not a valid GenericDeviceModelStatusEnumType",
                 s
@@ -38,6 +59,8 @@ impl From<GenericDeviceModelStatusEnumType> for String {
             GenericDeviceModelStatusEnumType::Rejected => "Rejected".to_string(),
             GenericDeviceModelStatusEnumType::NotSupported => "NotSupported".to_string(),
             GenericDeviceModelStatusEnumType::EmptyResultSet => "EmptyResultSet".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            GenericDeviceModelStatusEnumType::Unknown(value) => value,
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -8,6 +9,23 @@ pub enum GenericStatusEnumType {
     Accepted,
     /// Request has not been accepted and will not be executed.
     Rejected,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for GenericStatusEnumType {
+    const NAME: &'static str = "GenericStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for GenericStatusEnumType {
@@ -17,6 +35,9 @@ impl TryFrom<String> for GenericStatusEnumType {
         match s.as_str() {
             "Accepted" => Ok(GenericStatusEnumType::Accepted),
             "Rejected" => Ok(GenericStatusEnumType::Rejected),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(GenericStatusEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!("'{}' is not a valid GenericStatusEnumType", s)),
         }
     }
@@ -27,6 +48,8 @@ impl From<GenericStatusEnumType> for String {
         match val {
             GenericStatusEnumType::Accepted => "Accepted".to_string(),
             GenericStatusEnumType::Rejected => "Rejected".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            GenericStatusEnumType::Unknown(value) => value,
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -15,6 +16,23 @@ pub enum GetCertificateIdUseEnumType {
     ManufacturerRootCertificate,
     /// OEM root certificate for 2-way TLS with EV.
     OEMRootCertificate,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for GetCertificateIdUseEnumType {
+    const NAME: &'static str = "GetCertificateIdUseEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for GetCertificateIdUseEnumType {
@@ -30,6 +48,9 @@ impl TryFrom<String> for GetCertificateIdUseEnumType {
                 Ok(GetCertificateIdUseEnumType::ManufacturerRootCertificate)
             }
             "OEMRootCertificate" => Ok(GetCertificateIdUseEnumType::OEMRootCertificate),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(GetCertificateIdUseEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!(
                 "'{}' is not a valid GetCertificateIdUseEnumType",
                 s
@@ -49,6 +70,8 @@ impl From<GetCertificateIdUseEnumType> for String {
                 "ManufacturerRootCertificate".to_string()
             }
             GetCertificateIdUseEnumType::OEMRootCertificate => "OEMRootCertificate".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            GetCertificateIdUseEnumType::Unknown(value) => value,
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -8,6 +9,23 @@ pub enum GetCertificateStatusEnumType {
     Accepted,
     /// Failed to retrieve the OCSP certificate status.
     Failed,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for GetCertificateStatusEnumType {
+    const NAME: &'static str = "GetCertificateStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for GetCertificateStatusEnumType {
@@ -17,6 +35,9 @@ impl TryFrom<String> for GetCertificateStatusEnumType {
         match s.as_str() {
             "Accepted" => Ok(GetCertificateStatusEnumType::Accepted),
             "Failed" => Ok(GetCertificateStatusEnumType::Failed),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(GetCertificateStatusEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!(
                 "'{}' is not a valid GetCertificateStatusEnumType",
                 s
@@ -30,6 +51,8 @@ impl From<GetCertificateStatusEnumType> for String {
         match val {
             GetCertificateStatusEnumType::Accepted => "Accepted".to_string(),
             GetCertificateStatusEnumType::Failed => "Failed".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            GetCertificateStatusEnumType::Unknown(value) => value,
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -8,6 +9,23 @@ pub enum GetChargingProfileStatusEnumType {
     Accepted,
     /// No ChargingProfiles found that match the information in the GetChargingProfilesRequest.
     NoProfiles,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for GetChargingProfileStatusEnumType {
+    const NAME: &'static str = "GetChargingProfileStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for GetChargingProfileStatusEnumType {
@@ -17,6 +35,9 @@ impl TryFrom<String> for GetChargingProfileStatusEnumType {
         match s.as_str() {
             "Accepted" => Ok(GetChargingProfileStatusEnumType::Accepted),
             "NoProfiles" => Ok(GetChargingProfileStatusEnumType::NoProfiles),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(GetChargingProfileStatusEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!(
                 "'{}' is not a valid GetChargingProfileStatusEnumType",
                 s
@@ -30,6 +51,8 @@ impl From<GetChargingProfileStatusEnumType> for String {
         match val {
             GetChargingProfileStatusEnumType::Accepted => "Accepted".to_string(),
             GetChargingProfileStatusEnumType::NoProfiles => "NoProfiles".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            GetChargingProfileStatusEnumType::Unknown(value) => value,
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -8,6 +9,23 @@ pub enum GetDisplayMessagesStatusEnumType {
     Accepted,
     /// No messages found that match the given criteria.
     Unknown,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    UnknownValue(String),
+}
+
+impl OcppEnum for GetDisplayMessagesStatusEnumType {
+    const NAME: &'static str = "GetDisplayMessagesStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::UnknownValue(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for GetDisplayMessagesStatusEnumType {
@@ -17,6 +35,9 @@ impl TryFrom<String> for GetDisplayMessagesStatusEnumType {
         match s.as_str() {
             "Accepted" => Ok(GetDisplayMessagesStatusEnumType::Accepted),
             "Unknown" => Ok(GetDisplayMessagesStatusEnumType::Unknown),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(GetDisplayMessagesStatusEnumType::UnknownValue(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!(
                 "'{}' is not a valid GetDisplayMessagesStatusEnumType",
                 s
@@ -30,6 +51,8 @@ impl From<GetDisplayMessagesStatusEnumType> for String {
         match val {
             GetDisplayMessagesStatusEnumType::Accepted => "Accepted".to_string(),
             GetDisplayMessagesStatusEnumType::Unknown => "Unknown".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            GetDisplayMessagesStatusEnumType::UnknownValue(value) => value,
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -8,6 +9,23 @@ pub enum GetInstalledCertificateStatusEnumType {
     Accepted,
     /// Requested resource not found.
     NotFound,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for GetInstalledCertificateStatusEnumType {
+    const NAME: &'static str = "GetInstalledCertificateStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for GetInstalledCertificateStatusEnumType {
@@ -17,6 +35,9 @@ impl TryFrom<String> for GetInstalledCertificateStatusEnumType {
         match s.as_str() {
             "Accepted" => Ok(GetInstalledCertificateStatusEnumType::Accepted),
             "NotFound" => Ok(GetInstalledCertificateStatusEnumType::NotFound),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(GetInstalledCertificateStatusEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!(
                 "'{}' is not a valid GetInstalledCertificateStatusEnumType",
                 s
@@ -30,6 +51,8 @@ impl From<GetInstalledCertificateStatusEnumType> for String {
         match val {
             GetInstalledCertificateStatusEnumType::Accepted => "Accepted".to_string(),
             GetInstalledCertificateStatusEnumType::NotFound => "NotFound".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            GetInstalledCertificateStatusEnumType::Unknown(value) => value,
         }
     }
 }
@@ -1,9 +1,11 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
-#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize, Default)]
 pub enum GetVariableStatusEnumType {
     /// Variable successfully retrieved.
+    #[default]
     Accepted,
     /// Request is rejected.
     Rejected,
@@ -13,6 +15,23 @@ pub enum GetVariableStatusEnumType {
     UnknownVariable,
     /// The AttributeType is not supported.
     NotSupportedAttributeType,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for GetVariableStatusEnumType {
+    const NAME: &'static str = "GetVariableStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for GetVariableStatusEnumType {
@@ -25,6 +44,9 @@ impl TryFrom<String> for GetVariableStatusEnumType {
             "UnknownComponent" => Ok(GetVariableStatusEnumType::UnknownComponent),
             "UnknownVariable" => Ok(GetVariableStatusEnumType::UnknownVariable),
             "NotSupportedAttributeType" => Ok(GetVariableStatusEnumType::NotSupportedAttributeType),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(GetVariableStatusEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!("'{}' is not a valid GetVariableStatusEnumType", s)),
         }
     }
@@ -40,6 +62,8 @@ impl From<GetVariableStatusEnumType> for String {
             GetVariableStatusEnumType::NotSupportedAttributeType => {
                 "NotSupportedAttributeType".to_string()
             }
+            #[cfg(feature = "lenient-enums")]
+            GetVariableStatusEnumType::Unknown(value) => value,
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -25,6 +26,23 @@ pub enum GridEventFaultEnumType {
     UnderVoltage,
     /// Voltage imbalance detected
     VoltageImbalance,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for GridEventFaultEnumType {
+    const NAME: &'static str = "GridEventFaultEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for GridEventFaultEnumType {
@@ -43,6 +61,9 @@ impl TryFrom<String> for GridEventFaultEnumType {
             "UnderFrequency" => Ok(GridEventFaultEnumType::UnderFrequency),
             "UnderVoltage" => Ok(GridEventFaultEnumType::UnderVoltage),
             "VoltageImbalance" => Ok(GridEventFaultEnumType::VoltageImbalance),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(GridEventFaultEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!("'{}' is not a valid GridEventFaultEnumType", s)),
         }
     }
@@ -62,6 +83,8 @@ impl From<GridEventFaultEnumType> for String {
             GridEventFaultEnumType::UnderFrequency => "UnderFrequency".to_string(),
             GridEventFaultEnumType::UnderVoltage => "UnderVoltage".to_string(),
             GridEventFaultEnumType::VoltageImbalance => "VoltageImbalance".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            GridEventFaultEnumType::Unknown(value) => value,
         }
     }
 }
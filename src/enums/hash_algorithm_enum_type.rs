@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -9,6 +10,23 @@ pub enum HashAlgorithmEnumType {
     SHA384,
     /// SHA-512 hash algorithm.
     SHA512,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for HashAlgorithmEnumType {
+    const NAME: &'static str = "HashAlgorithmEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for HashAlgorithmEnumType {
@@ -19,6 +37,9 @@ impl TryFrom<String> for HashAlgorithmEnumType {
             "SHA256" => Ok(HashAlgorithmEnumType::SHA256),
             "SHA384" => Ok(HashAlgorithmEnumType::SHA384),
             "SHA512" => Ok(HashAlgorithmEnumType::SHA512),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(HashAlgorithmEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!("'{}' is not a valid HashAlgorithmEnumType", s)),
         }
     }
@@ -30,6 +51,8 @@ impl From<HashAlgorithmEnumType> for String {
             HashAlgorithmEnumType::SHA256 => "SHA256".to_string(),
             HashAlgorithmEnumType::SHA384 => "SHA384".to_string(),
             HashAlgorithmEnumType::SHA512 => "SHA512".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            HashAlgorithmEnumType::Unknown(value) => value,
         }
     }
 }
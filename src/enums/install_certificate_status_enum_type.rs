@@ -1,14 +1,33 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
-#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize, Default)]
 pub enum InstallCertificateStatusEnumType {
     /// The installation of the certificate succeeded.
+    #[default]
     Accepted,
     /// The certificate is invalid and/or incorrect OR the CSO tries to install more certificates than allowed.
     Rejected,
     /// The certificate is valid and correct, but there is another reason the installation did not succeed.
     Failed,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for InstallCertificateStatusEnumType {
+    const NAME: &'static str = "InstallCertificateStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for InstallCertificateStatusEnumType {
@@ -19,6 +38,9 @@ impl TryFrom<String> for InstallCertificateStatusEnumType {
             "Accepted" => Ok(InstallCertificateStatusEnumType::Accepted),
             "Rejected" => Ok(InstallCertificateStatusEnumType::Rejected),
             "Failed" => Ok(InstallCertificateStatusEnumType::Failed),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(InstallCertificateStatusEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!(
                 "'{}' is not a valid InstallCertificateStatusEnumType",
                 s
@@ -33,6 +55,8 @@ impl From<InstallCertificateStatusEnumType> for String {
             InstallCertificateStatusEnumType::Accepted => "Accepted".to_string(),
             InstallCertificateStatusEnumType::Rejected => "Rejected".to_string(),
             InstallCertificateStatusEnumType::Failed => "Failed".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            InstallCertificateStatusEnumType::Unknown(value) => value,
         }
     }
 }
@@ -1,9 +1,11 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
-#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize, Default)]
 pub enum InstallCertificateUseEnumType {
     /// Use for certificate of the ISO 15118 V2G Root. A V2G Charging Station Certificate MUST be derived from one of the installed V2GRootCertificate certificates.
+    #[default]
     V2GRootCertificate,
     /// Use for certificate from an eMobility Service provider. To support PnC charging with contracts from service providers that not derived their certificates from the V2G root.
     MORootCertificate,
@@ -13,6 +15,23 @@ pub enum InstallCertificateUseEnumType {
     CSMSRootCertificate,
     /// OEM root certificate for 2-way TLS with EV.
     OEMRootCertificate,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for InstallCertificateUseEnumType {
+    const NAME: &'static str = "InstallCertificateUseEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for InstallCertificateUseEnumType {
@@ -27,6 +46,9 @@ impl TryFrom<String> for InstallCertificateUseEnumType {
             }
             "CSMSRootCertificate" => Ok(InstallCertificateUseEnumType::CSMSRootCertificate),
             "OEMRootCertificate" => Ok(InstallCertificateUseEnumType::OEMRootCertificate),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(InstallCertificateUseEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!(
                 "'{}' is not a valid InstallCertificateUseEnumType",
                 s
@@ -45,6 +67,8 @@ impl From<InstallCertificateUseEnumType> for String {
             }
             InstallCertificateUseEnumType::CSMSRootCertificate => "CSMSRootCertificate".to_string(),
             InstallCertificateUseEnumType::OEMRootCertificate => "OEMRootCertificate".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            InstallCertificateUseEnumType::Unknown(value) => value,
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -33,6 +34,23 @@ pub enum IslandingDetectionEnumType {
     RCLQFactor,
     /// Other active anti-island detection method supported
     OtherActive,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for IslandingDetectionEnumType {
+    const NAME: &'static str = "IslandingDetectionEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for IslandingDetectionEnumType {
@@ -55,6 +73,9 @@ impl TryFrom<String> for IslandingDetectionEnumType {
             "FrequencyJump" => Ok(IslandingDetectionEnumType::FrequencyJump),
             "RCLQFactor" => Ok(IslandingDetectionEnumType::RCLQFactor),
             "OtherActive" => Ok(IslandingDetectionEnumType::OtherActive),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(IslandingDetectionEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!("'{}' is not a valid IslandingDetectionEnumType", s)),
         }
     }
@@ -84,6 +105,8 @@ impl From<IslandingDetectionEnumType> for String {
             IslandingDetectionEnumType::FrequencyJump => "FrequencyJump".to_string(),
             IslandingDetectionEnumType::RCLQFactor => "RCLQFactor".to_string(),
             IslandingDetectionEnumType::OtherActive => "OtherActive".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            IslandingDetectionEnumType::Unknown(value) => value,
         }
     }
 }
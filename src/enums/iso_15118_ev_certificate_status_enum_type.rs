@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -8,6 +9,23 @@ pub enum Iso15118EVCertificateStatusEnumType {
     Accepted,
     /// Processing of the message was not successful, no exiResponse included.
     Failed,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for Iso15118EVCertificateStatusEnumType {
+    const NAME: &'static str = "Iso15118EVCertificateStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for Iso15118EVCertificateStatusEnumType {
@@ -17,6 +35,9 @@ impl TryFrom<String> for Iso15118EVCertificateStatusEnumType {
         match s.as_str() {
             "Accepted" => Ok(Iso15118EVCertificateStatusEnumType::Accepted),
             "Failed" => Ok(Iso15118EVCertificateStatusEnumType::Failed),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Iso15118EVCertificateStatusEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!(
                 "'{}' is not a valid Iso15118EVCertificateStatusEnumType",
                 s
@@ -30,6 +51,8 @@ impl From<Iso15118EVCertificateStatusEnumType> for String {
         match val {
             Iso15118EVCertificateStatusEnumType::Accepted => "Accepted".to_string(),
             Iso15118EVCertificateStatusEnumType::Failed => "Failed".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            Iso15118EVCertificateStatusEnumType::Unknown(value) => value,
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -15,6 +16,23 @@ pub enum LocationEnumType {
     Outlet,
     /// Measurement taken from an upstream local grid meter of the premise. This can be useful for charging stations that are connected "behind the meter" of a building, and that are able to read the building energy meter.
     Upstream,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for LocationEnumType {
+    const NAME: &'static str = "LocationEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for LocationEnumType {
@@ -28,6 +46,9 @@ impl TryFrom<String> for LocationEnumType {
             "Inlet" => Ok(LocationEnumType::Inlet),
             "Outlet" => Ok(LocationEnumType::Outlet),
             "Upstream" => Ok(LocationEnumType::Upstream),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(LocationEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!("'{}' is not a valid LocationEnumType", s)),
         }
     }
@@ -42,6 +63,8 @@ impl From<LocationEnumType> for String {
             LocationEnumType::Inlet => "Inlet".to_string(),
             LocationEnumType::Outlet => "Outlet".to_string(),
             LocationEnumType::Upstream => "Upstream".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            LocationEnumType::Unknown(value) => value,
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -10,6 +11,23 @@ pub enum LogEnumType {
     SecurityLog,
     /// The log of sampled measurements from the DataCollector component.
     DataCollectorLog,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for LogEnumType {
+    const NAME: &'static str = "LogEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for LogEnumType {
@@ -20,6 +38,9 @@ impl TryFrom<String> for LogEnumType {
             "DiagnosticsLog" => Ok(LogEnumType::DiagnosticsLog),
             "SecurityLog" => Ok(LogEnumType::SecurityLog),
             "DataCollectorLog" => Ok(LogEnumType::DataCollectorLog),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(LogEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!("'{}' is not a valid LogEnumType", s)),
         }
     }
@@ -31,6 +52,8 @@ impl From<LogEnumType> for String {
             LogEnumType::DiagnosticsLog => "DiagnosticsLog".to_string(),
             LogEnumType::SecurityLog => "SecurityLog".to_string(),
             LogEnumType::DataCollectorLog => "DataCollectorLog".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            LogEnumType::Unknown(value) => value,
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -10,6 +11,23 @@ pub enum LogStatusEnumType {
     Rejected,
     /// Accepted this log upload, but in doing this has canceled an ongoing log file upload.
     AcceptedCanceled,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for LogStatusEnumType {
+    const NAME: &'static str = "LogStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl TryFrom<String> for LogStatusEnumType {
@@ -20,6 +38,9 @@ impl TryFrom<String> for LogStatusEnumType {
             "Accepted" => Ok(LogStatusEnumType::Accepted),
             "Rejected" => Ok(LogStatusEnumType::Rejected),
             "AcceptedCanceled" => Ok(LogStatusEnumType::AcceptedCanceled),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(LogStatusEnumType::Unknown(s)),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(format!("'{}' is not a valid LogStatusEnumType", s)),
         }
     }
@@ -31,6 +52,8 @@ impl From<LogStatusEnumType> for String {
             LogStatusEnumType::Accepted => "Accepted".to_string(),
             LogStatusEnumType::Rejected => "Rejected".to_string(),
             LogStatusEnumType::AcceptedCanceled => "AcceptedCanceled".to_string(),
+            #[cfg(feature = "lenient-enums")]
+            LogStatusEnumType::Unknown(value) => value,
         }
     }
 }
@@ -1,4 +1,5 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
@@ -118,6 +119,23 @@ pub enum MeasurandEnumType {
     VoltageMinimum,
     #[serde(rename = "Voltage.Maximum")]
     VoltageMaximum,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for MeasurandEnumType {
+    const NAME: &'static str = "MeasurandEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for MeasurandEnumType {
@@ -185,6 +203,8 @@ impl fmt::Display for MeasurandEnumType {
             Self::Voltage => write!(f, "Voltage"),
             Self::VoltageMinimum => write!(f, "Voltage.Minimum"),
             Self::VoltageMaximum => write!(f, "Voltage.Maximum"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -258,6 +278,9 @@ impl TryFrom<&str> for MeasurandEnumType {
             "Voltage" => Ok(Self::Voltage),
             "Voltage.Minimum" => Ok(Self::VoltageMinimum),
             "Voltage.Maximum" => Ok(Self::VoltageMaximum),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "MeasurandEnumType".to_string(),
                 value: value.to_string(),
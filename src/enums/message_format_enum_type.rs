@@ -1,4 +1,5 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
@@ -17,6 +18,23 @@ pub enum MessageFormatEnumType {
     UTF8,
     /// Message content is a text (usually a URL) that Charging Station will display as a QR code on the display. Note: this is not a dynamic QR code and should not be used for payments.
     QRCODE,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for MessageFormatEnumType {
+    const NAME: &'static str = "MessageFormatEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for MessageFormatEnumType {
@@ -27,6 +45,8 @@ impl fmt::Display for MessageFormatEnumType {
             Self::URI => write!(f, "URI"),
             Self::UTF8 => write!(f, "UTF8"),
             Self::QRCODE => write!(f, "QRCODE"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -47,6 +67,9 @@ impl TryFrom<&str> for MessageFormatEnumType {
             "URI" => Ok(Self::URI),
             "UTF8" => Ok(Self::UTF8),
             "QRCODE" => Ok(Self::QRCODE),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "MessageFormatEnumType".to_string(),
                 value: value.to_string(),
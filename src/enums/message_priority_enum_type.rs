@@ -1,10 +1,11 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
 
 /// Priority with which a message should be displayed on a Charging Station.
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 #[serde(rename_all = "PascalCase")]
 pub enum MessagePriorityEnumType {
     /// Show this message always in front. Highest priority, don't cycle with other messages. When a newer message with this MessagePriority is received, this message is replaced. No Charging Station own message may override this message.
@@ -12,7 +13,25 @@ pub enum MessagePriorityEnumType {
     /// Show this message in front of the normal cycle of messages. When more messages with this priority are to be shown, they SHALL be cycled.
     InFront,
     /// Show this message in the cycle of messages.
+    #[default]
     NormalCycle,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for MessagePriorityEnumType {
+    const NAME: &'static str = "MessagePriorityEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for MessagePriorityEnumType {
@@ -21,6 +40,8 @@ impl fmt::Display for MessagePriorityEnumType {
             Self::AlwaysFront => write!(f, "AlwaysFront"),
             Self::InFront => write!(f, "InFront"),
             Self::NormalCycle => write!(f, "NormalCycle"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -39,6 +60,9 @@ impl TryFrom<&str> for MessagePriorityEnumType {
             "AlwaysFront" => Ok(Self::AlwaysFront),
             "InFront" => Ok(Self::InFront),
             "NormalCycle" => Ok(Self::NormalCycle),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "MessagePriorityEnumType".to_string(),
                 value: value.to_string(),
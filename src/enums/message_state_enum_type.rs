@@ -1,4 +1,5 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
@@ -19,6 +20,23 @@ pub enum MessageStateEnumType {
     Suspended,
     /// (2.1) Message only to be shown while the EV is discharging.
     Discharging,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for MessageStateEnumType {
+    const NAME: &'static str = "MessageStateEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for MessageStateEnumType {
@@ -30,6 +48,8 @@ impl fmt::Display for MessageStateEnumType {
             Self::Unavailable => write!(f, "Unavailable"),
             Self::Suspended => write!(f, "Suspended"),
             Self::Discharging => write!(f, "Discharging"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -51,6 +71,9 @@ impl TryFrom<&str> for MessageStateEnumType {
             "Unavailable" => Ok(Self::Unavailable),
             "Suspended" => Ok(Self::Suspended),
             "Discharging" => Ok(Self::Discharging),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "MessageStateEnumType".to_string(),
                 value: value.to_string(),
@@ -1,13 +1,15 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
 
 /// Type of request to be triggered by trigger messages.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 #[serde(rename_all = "PascalCase")]
 pub enum MessageTriggerEnumType {
     /// To trigger BootNotification.
+    #[default]
     BootNotification,
     /// To trigger LogStatusNotification.
     LogStatusNotification,
@@ -33,6 +35,23 @@ pub enum MessageTriggerEnumType {
     PublishFirmwareStatusNotification,
     /// (2.1) To trigger the message referred to in customTrigger field.
     CustomTrigger,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for MessageTriggerEnumType {
+    const NAME: &'static str = "MessageTriggerEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for MessageTriggerEnumType {
@@ -53,6 +72,8 @@ impl fmt::Display for MessageTriggerEnumType {
                 write!(f, "PublishFirmwareStatusNotification")
             }
             Self::CustomTrigger => write!(f, "CustomTrigger"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -81,6 +102,9 @@ impl TryFrom<&str> for MessageTriggerEnumType {
             "SignCombinedCertificate" => Ok(Self::SignCombinedCertificate),
             "PublishFirmwareStatusNotification" => Ok(Self::PublishFirmwareStatusNotification),
             "CustomTrigger" => Ok(Self::CustomTrigger),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "MessageTriggerEnumType".to_string(),
                 value: value.to_string(),
@@ -1,4 +1,5 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
@@ -10,6 +11,23 @@ pub enum MobilityNeedsModeEnumType {
     EVCC,
     /// Charging station or CSMS may also update min/target SOC and departure time.
     EVCC_SECC,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for MobilityNeedsModeEnumType {
+    const NAME: &'static str = "MobilityNeedsModeEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for MobilityNeedsModeEnumType {
@@ -17,6 +35,8 @@ impl fmt::Display for MobilityNeedsModeEnumType {
         match self {
             Self::EVCC => write!(f, "EVCC"),
             Self::EVCC_SECC => write!(f, "EVCC_SECC"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -34,6 +54,9 @@ impl TryFrom<&str> for MobilityNeedsModeEnumType {
         match value {
             "EVCC" => Ok(Self::EVCC),
             "EVCC_SECC" => Ok(Self::EVCC_SECC),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "MobilityNeedsModeEnumType".to_string(),
                 value: value.to_string(),
@@ -1,4 +1,5 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
@@ -20,6 +21,23 @@ pub enum MonitorEnumType {
     TargetDelta,
     /// (2.1) Triggers an event notice when the actual value differs from the target value more than plus or minus (value * target value) since the time that this monitor was set or since the last time this event notice was sent, whichever was last. Behavior of this type of monitor for a variable that is not numeric, is not defined.
     TargetDeltaRelative,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for MonitorEnumType {
+    const NAME: &'static str = "MonitorEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for MonitorEnumType {
@@ -32,6 +50,8 @@ impl fmt::Display for MonitorEnumType {
             Self::PeriodicClockAligned => write!(f, "PeriodicClockAligned"),
             Self::TargetDelta => write!(f, "TargetDelta"),
             Self::TargetDeltaRelative => write!(f, "TargetDeltaRelative"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -54,6 +74,9 @@ impl TryFrom<&str> for MonitorEnumType {
             "PeriodicClockAligned" => Ok(Self::PeriodicClockAligned),
             "TargetDelta" => Ok(Self::TargetDelta),
             "TargetDeltaRelative" => Ok(Self::TargetDeltaRelative),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "MonitorEnumType".to_string(),
                 value: value.to_string(),
@@ -1,17 +1,36 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 #[serde(rename_all = "PascalCase")]
 pub enum MonitoringBaseEnumType {
     /// Activate all pre-configured monitors while leaving custom monitors intact, including those that overrule a pre-configured monitor.
+    #[default]
     All,
     /// (Re)activate the default monitors of the charging station and remove all custom monitors.
     FactoryDefault,
     /// Removes all custom monitors and disables all pre-configured monitors.
     HardWiredOnly,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for MonitoringBaseEnumType {
+    const NAME: &'static str = "MonitoringBaseEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for MonitoringBaseEnumType {
@@ -20,6 +39,8 @@ impl fmt::Display for MonitoringBaseEnumType {
             Self::All => write!(f, "All"),
             Self::FactoryDefault => write!(f, "FactoryDefault"),
             Self::HardWiredOnly => write!(f, "HardWiredOnly"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -38,6 +59,9 @@ impl TryFrom<&str> for MonitoringBaseEnumType {
             "All" => Ok(Self::All),
             "FactoryDefault" => Ok(Self::FactoryDefault),
             "HardWiredOnly" => Ok(Self::HardWiredOnly),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "MonitoringBaseEnumType".to_string(),
                 value: value.to_string(),
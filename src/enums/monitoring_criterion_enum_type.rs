@@ -1,4 +1,5 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
@@ -13,6 +14,23 @@ pub enum MonitoringCriterionEnumType {
     DeltaMonitoring,
     /// Report variables and components with a monitor of type Periodic or PeriodicClockAligned.
     PeriodicMonitoring,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for MonitoringCriterionEnumType {
+    const NAME: &'static str = "MonitoringCriterionEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for MonitoringCriterionEnumType {
@@ -21,6 +39,8 @@ impl fmt::Display for MonitoringCriterionEnumType {
             Self::ThresholdMonitoring => write!(f, "ThresholdMonitoring"),
             Self::DeltaMonitoring => write!(f, "DeltaMonitoring"),
             Self::PeriodicMonitoring => write!(f, "PeriodicMonitoring"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -39,6 +59,9 @@ impl TryFrom<&str> for MonitoringCriterionEnumType {
             "ThresholdMonitoring" => Ok(Self::ThresholdMonitoring),
             "DeltaMonitoring" => Ok(Self::DeltaMonitoring),
             "PeriodicMonitoring" => Ok(Self::PeriodicMonitoring),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "MonitoringCriterionEnumType".to_string(),
                 value: value.to_string(),
@@ -1,4 +1,5 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
@@ -13,6 +14,23 @@ pub enum MutabilityEnumType {
     WriteOnly,
     /// This variable is read-write.
     ReadWrite,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for MutabilityEnumType {
+    const NAME: &'static str = "MutabilityEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for MutabilityEnumType {
@@ -21,6 +39,8 @@ impl fmt::Display for MutabilityEnumType {
             Self::ReadOnly => write!(f, "ReadOnly"),
             Self::WriteOnly => write!(f, "WriteOnly"),
             Self::ReadWrite => write!(f, "ReadWrite"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -39,6 +59,9 @@ impl TryFrom<&str> for MutabilityEnumType {
             "ReadOnly" => Ok(Self::ReadOnly),
             "WriteOnly" => Ok(Self::WriteOnly),
             "ReadWrite" => Ok(Self::ReadWrite),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "MutabilityEnumType".to_string(),
                 value: value.to_string(),
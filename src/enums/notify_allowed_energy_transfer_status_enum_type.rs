@@ -1,16 +1,35 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
 
 /// Status result of a NotifyAllowedEnergyTransferRequest
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 #[serde(rename_all = "PascalCase")]
 pub enum NotifyAllowedEnergyTransferStatusEnumType {
     /// Request has been accepted.
+    #[default]
     Accepted,
     /// Request has been rejected. Should not occur, unless there are some technical problems.
     Rejected,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for NotifyAllowedEnergyTransferStatusEnumType {
+    const NAME: &'static str = "NotifyAllowedEnergyTransferStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for NotifyAllowedEnergyTransferStatusEnumType {
@@ -18,6 +37,8 @@ impl fmt::Display for NotifyAllowedEnergyTransferStatusEnumType {
         match self {
             Self::Accepted => write!(f, "Accepted"),
             Self::Rejected => write!(f, "Rejected"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -35,6 +56,9 @@ impl TryFrom<&str> for NotifyAllowedEnergyTransferStatusEnumType {
         match value {
             "Accepted" => Ok(Self::Accepted),
             "Rejected" => Ok(Self::Rejected),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "NotifyAllowedEnergyTransferStatusEnumType".to_string(),
                 value: value.to_string(),
@@ -1,13 +1,15 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
 
 /// Status result of a NotifyAllowedEnergyTransferRequest
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 #[serde(rename_all = "PascalCase")]
 pub enum NotifyEVChargingNeedsStatusEnumType {
     /// A schedule will be provided momentarily.
+    #[default]
     Accepted,
     /// (2.1) Service not available. No charging profile can be provided. For an ISO 15118-20 session this is used to convey that the requested energy transfer type is not possible.
     Rejected,
@@ -15,6 +17,23 @@ pub enum NotifyEVChargingNeedsStatusEnumType {
     Processing,
     /// (2.1) CSMS will not provide a charging profile at this time. CS should not wait for it. For an ISO 15118-20 session this value is used instead of Rejected to differentiate between the situation where no charging profile is available (NoChargingProfile) and requested energy transfer type is not available (Rejected).
     NoChargingProfile,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for NotifyEVChargingNeedsStatusEnumType {
+    const NAME: &'static str = "NotifyEVChargingNeedsStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for NotifyEVChargingNeedsStatusEnumType {
@@ -24,6 +43,8 @@ impl fmt::Display for NotifyEVChargingNeedsStatusEnumType {
             Self::Rejected => write!(f, "Rejected"),
             Self::Processing => write!(f, "Processing"),
             Self::NoChargingProfile => write!(f, "NoChargingProfile"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -43,6 +64,9 @@ impl TryFrom<&str> for NotifyEVChargingNeedsStatusEnumType {
             "Rejected" => Ok(Self::Rejected),
             "Processing" => Ok(Self::Processing),
             "NoChargingProfile" => Ok(Self::NoChargingProfile),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "NotifyEVChargingNeedsStatusEnumType".to_string(),
                 value: value.to_string(),
@@ -1,4 +1,5 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
@@ -25,6 +26,23 @@ pub enum OCPPInterfaceEnumType {
     Wireless3,
     /// (2.1) Use any interface.
     Any,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for OCPPInterfaceEnumType {
+    const NAME: &'static str = "OCPPInterfaceEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for OCPPInterfaceEnumType {
@@ -39,6 +57,8 @@ impl fmt::Display for OCPPInterfaceEnumType {
             Self::Wireless2 => write!(f, "Wireless2"),
             Self::Wireless3 => write!(f, "Wireless3"),
             Self::Any => write!(f, "Any"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -63,6 +83,9 @@ impl TryFrom<&str> for OCPPInterfaceEnumType {
             "Wireless2" => Ok(Self::Wireless2),
             "Wireless3" => Ok(Self::Wireless3),
             "Any" => Ok(Self::Any),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "OCPPInterfaceEnumType".to_string(),
                 value: value.to_string(),
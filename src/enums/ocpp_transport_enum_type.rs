@@ -1,4 +1,5 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
@@ -11,6 +12,23 @@ pub enum OCPPTransportEnumType {
     SOAP,
     /// Use JSON over WebSockets for transport of OCPP PDU's
     JSON,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for OCPPTransportEnumType {
+    const NAME: &'static str = "OCPPTransportEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for OCPPTransportEnumType {
@@ -18,6 +36,8 @@ impl fmt::Display for OCPPTransportEnumType {
         match self {
             Self::SOAP => write!(f, "SOAP"),
             Self::JSON => write!(f, "JSON"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -35,6 +55,9 @@ impl TryFrom<&str> for OCPPTransportEnumType {
         match value {
             "SOAP" => Ok(Self::SOAP),
             "JSON" => Ok(Self::JSON),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "OCPPTransportEnumType".to_string(),
                 value: value.to_string(),
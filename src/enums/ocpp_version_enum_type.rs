@@ -1,4 +1,5 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
@@ -19,6 +20,23 @@ pub enum OCPPVersionEnumType {
     OCPP201,
     /// (2.1) OCPP version 2.1, websocket subprotocol: ocpp2.1
     OCPP21,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for OCPPVersionEnumType {
+    const NAME: &'static str = "OCPPVersionEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for OCPPVersionEnumType {
@@ -30,6 +48,8 @@ impl fmt::Display for OCPPVersionEnumType {
             Self::OCPP20 => write!(f, "OCPP20"),
             Self::OCPP201 => write!(f, "OCPP201"),
             Self::OCPP21 => write!(f, "OCPP21"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -51,6 +71,9 @@ impl TryFrom<&str> for OCPPVersionEnumType {
             "OCPP20" => Ok(Self::OCPP20),
             "OCPP201" => Ok(Self::OCPP201),
             "OCPP21" => Ok(Self::OCPP21),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "OCPPVersionEnumType".to_string(),
                 value: value.to_string(),
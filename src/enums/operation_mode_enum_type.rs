@@ -1,4 +1,5 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
@@ -23,6 +24,23 @@ pub enum OperationModeEnumType {
     LocalFrequency,
     /// Load-balancing performed by the Charging Station.
     LocalLoadBalancing,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for OperationModeEnumType {
+    const NAME: &'static str = "OperationModeEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for OperationModeEnumType {
@@ -36,6 +54,8 @@ impl fmt::Display for OperationModeEnumType {
             Self::CentralFrequency => write!(f, "CentralFrequency"),
             Self::LocalFrequency => write!(f, "LocalFrequency"),
             Self::LocalLoadBalancing => write!(f, "LocalLoadBalancing"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -59,6 +79,9 @@ impl TryFrom<&str> for OperationModeEnumType {
             "CentralFrequency" => Ok(Self::CentralFrequency),
             "LocalFrequency" => Ok(Self::LocalFrequency),
             "LocalLoadBalancing" => Ok(Self::LocalLoadBalancing),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "OperationModeEnumType".to_string(),
                 value: value.to_string(),
@@ -1,4 +1,5 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
@@ -12,6 +13,23 @@ pub enum OperationalStatusEnumType {
     Inoperative,
     /// Charging Station is available for charging.
     Operative,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for OperationalStatusEnumType {
+    const NAME: &'static str = "OperationalStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for OperationalStatusEnumType {
@@ -19,6 +37,8 @@ impl fmt::Display for OperationalStatusEnumType {
         match self {
             Self::Inoperative => write!(f, "Inoperative"),
             Self::Operative => write!(f, "Operative"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -36,6 +56,9 @@ impl TryFrom<&str> for OperationalStatusEnumType {
         match value {
             "Inoperative" => Ok(Self::Inoperative),
             "Operative" => Ok(Self::Operative),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "OperationalStatusEnumType".to_string(),
                 value: value.to_string(),
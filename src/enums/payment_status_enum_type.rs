@@ -1,13 +1,15 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
 
 /// (2.1) Status of the settlement of an ad hoc payment.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 #[serde(rename_all = "PascalCase")]
 pub enum PaymentStatusEnumType {
     /// Settled successfully by the PSP.
+    #[default]
     Settled,
     /// No billable part of the OCPP transaction, cancellation sent to the PSP.
     Canceled, // sic
@@ -15,6 +17,23 @@ pub enum PaymentStatusEnumType {
     Rejected,
     /// Sent after the final attempt that fails due to communication problems.
     Failed,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for PaymentStatusEnumType {
+    const NAME: &'static str = "PaymentStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for PaymentStatusEnumType {
@@ -24,6 +43,8 @@ impl fmt::Display for PaymentStatusEnumType {
             Self::Canceled => write!(f, "Canceled"), // sic
             Self::Rejected => write!(f, "Rejected"),
             Self::Failed => write!(f, "Failed"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -43,6 +64,9 @@ impl TryFrom<&str> for PaymentStatusEnumType {
             "Canceled" => Ok(Self::Canceled), // sic
             "Rejected" => Ok(Self::Rejected),
             "Failed" => Ok(Self::Failed),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "PaymentStatusEnumType".to_string(),
                 value: value.to_string(),
@@ -1,4 +1,5 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
@@ -33,6 +34,23 @@ pub enum PhaseEnumType {
     /// Measured between L3 and L1
     #[serde(rename = "L3-L1")]
     L3L1,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for PhaseEnumType {
+    const NAME: &'static str = "PhaseEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for PhaseEnumType {
@@ -48,6 +66,8 @@ impl fmt::Display for PhaseEnumType {
             Self::L1L2 => write!(f, "L1-L2"),
             Self::L2L3 => write!(f, "L2-L3"),
             Self::L3L1 => write!(f, "L3-L1"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -73,6 +93,9 @@ impl TryFrom<&str> for PhaseEnumType {
             "L1-L2" => Ok(Self::L1L2),
             "L2-L3" => Ok(Self::L2L3),
             "L3-L1" => Ok(Self::L3L1),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "PhaseEnumType".to_string(),
                 value: value.to_string(),
@@ -1,4 +1,5 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
@@ -11,6 +12,23 @@ pub enum PowerDuringCessationEnumType {
     Active,
     /// Reactive power
     Reactive,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for PowerDuringCessationEnumType {
+    const NAME: &'static str = "PowerDuringCessationEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for PowerDuringCessationEnumType {
@@ -18,6 +36,8 @@ impl fmt::Display for PowerDuringCessationEnumType {
         match self {
             Self::Active => write!(f, "Active"),
             Self::Reactive => write!(f, "Reactive"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -35,6 +55,9 @@ impl TryFrom<&str> for PowerDuringCessationEnumType {
         match value {
             "Active" => Ok(Self::Active),
             "Reactive" => Ok(Self::Reactive),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "PowerDuringCessationEnumType".to_string(),
                 value: value.to_string(),
@@ -1,10 +1,11 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
 
 /// (2.1) Preconditioning status of the battery
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub enum PreconditioningStatusEnumType {
     /// No information available on the status of preconditioning
@@ -15,6 +16,23 @@ pub enum PreconditioningStatusEnumType {
     NotReady,
     /// The battery is not preconditioned and not able to directly react to given setpoint.
     Preconditioning,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    UnknownValue(String),
+}
+
+impl OcppEnum for PreconditioningStatusEnumType {
+    const NAME: &'static str = "PreconditioningStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::UnknownValue(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for PreconditioningStatusEnumType {
@@ -24,6 +42,8 @@ impl fmt::Display for PreconditioningStatusEnumType {
             Self::Ready => write!(f, "Ready"),
             Self::NotReady => write!(f, "NotReady"),
             Self::Preconditioning => write!(f, "Preconditioning"),
+            #[cfg(feature = "lenient-enums")]
+            Self::UnknownValue(value) => write!(f, "{value}"),
         }
     }
 }
@@ -43,6 +63,9 @@ impl TryFrom<&str> for PreconditioningStatusEnumType {
             "Ready" => Ok(Self::Ready),
             "NotReady" => Ok(Self::NotReady),
             "Preconditioning" => Ok(Self::Preconditioning),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::UnknownValue(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "PreconditioningStatusEnumType".to_string(),
                 value: value.to_string(),
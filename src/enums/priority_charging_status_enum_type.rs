@@ -1,18 +1,37 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
 
 /// (2.1) Status of a UsePriorityChargingRequest
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 #[serde(rename_all = "PascalCase")]
 pub enum PriorityChargingStatusEnumType {
     /// Request has been accepted.
+    #[default]
     Accepted,
     /// Request has been rejected.
     Rejected,
     /// No priority charging profile present.
     NoProfile,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for PriorityChargingStatusEnumType {
+    const NAME: &'static str = "PriorityChargingStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for PriorityChargingStatusEnumType {
@@ -21,6 +40,8 @@ impl fmt::Display for PriorityChargingStatusEnumType {
             Self::Accepted => write!(f, "Accepted"),
             Self::Rejected => write!(f, "Rejected"),
             Self::NoProfile => write!(f, "NoProfile"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -39,6 +60,9 @@ impl TryFrom<&str> for PriorityChargingStatusEnumType {
             "Accepted" => Ok(Self::Accepted),
             "Rejected" => Ok(Self::Rejected),
             "NoProfile" => Ok(Self::NoProfile),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "PriorityChargingStatusEnumType".to_string(),
                 value: value.to_string(),
@@ -1,13 +1,15 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
 
 /// Status for when publishing a Firmware.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 #[serde(rename_all = "PascalCase")]
 pub enum PublishFirmwareStatusEnumType {
     /// Intermediate state.
+    #[default]
     Idle,
     /// Intermediate state. Downloading of new firmware has been scheduled.
     DownloadScheduled,
@@ -27,6 +29,23 @@ pub enum PublishFirmwareStatusEnumType {
     ChecksumVerified,
     /// Publishing the new firmware has failed.
     PublishFailed,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for PublishFirmwareStatusEnumType {
+    const NAME: &'static str = "PublishFirmwareStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for PublishFirmwareStatusEnumType {
@@ -42,6 +61,8 @@ impl fmt::Display for PublishFirmwareStatusEnumType {
             Self::InvalidChecksum => write!(f, "InvalidChecksum"),
             Self::ChecksumVerified => write!(f, "ChecksumVerified"),
             Self::PublishFailed => write!(f, "PublishFailed"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -67,6 +88,9 @@ impl TryFrom<&str> for PublishFirmwareStatusEnumType {
             "InvalidChecksum" => Ok(Self::InvalidChecksum),
             "ChecksumVerified" => Ok(Self::ChecksumVerified),
             "PublishFailed" => Ok(Self::PublishFailed),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "PublishFirmwareStatusEnumType".to_string(),
                 value: value.to_string(),
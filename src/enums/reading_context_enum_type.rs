@@ -1,4 +1,5 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
@@ -28,6 +29,23 @@ pub enum ReadingContextEnumType {
     TransactionEnd,
     /// Value taken in response to TriggerMessageRequest.
     Trigger,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for ReadingContextEnumType {
+    const NAME: &'static str = "ReadingContextEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for ReadingContextEnumType {
@@ -41,6 +59,8 @@ impl fmt::Display for ReadingContextEnumType {
             Self::TransactionBegin => write!(f, "Transaction.Begin"),
             Self::TransactionEnd => write!(f, "Transaction.End"),
             Self::Trigger => write!(f, "Trigger"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -64,6 +84,9 @@ impl TryFrom<&str> for ReadingContextEnumType {
             "Transaction.Begin" => Ok(Self::TransactionBegin),
             "Transaction.End" => Ok(Self::TransactionEnd),
             "Trigger" => Ok(Self::Trigger),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "ReadingContextEnumType".to_string(),
                 value: value.to_string(),
@@ -1,4 +1,5 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
@@ -47,6 +48,23 @@ pub enum ReasonEnumType {
     Timeout,
     /// (2.1) CSMS cannot accept the requested energy transfer type. (Failed)
     ReqEnergyTransferRejected,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for ReasonEnumType {
+    const NAME: &'static str = "ReasonEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for ReasonEnumType {
@@ -72,6 +90,8 @@ impl fmt::Display for ReasonEnumType {
             Self::TimeLimitReached => write!(f, "TimeLimitReached"),
             Self::Timeout => write!(f, "Timeout"),
             Self::ReqEnergyTransferRejected => write!(f, "ReqEnergyTransferRejected"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -107,6 +127,9 @@ impl TryFrom<&str> for ReasonEnumType {
             "TimeLimitReached" => Ok(Self::TimeLimitReached),
             "Timeout" => Ok(Self::Timeout),
             "ReqEnergyTransferRejected" => Ok(Self::ReqEnergyTransferRejected),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "ReasonEnumType".to_string(),
                 value: value.to_string(),
@@ -1,4 +1,5 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
@@ -11,6 +12,23 @@ pub enum RecurrencyKindEnumType {
     Daily,
     /// The schedule restarts every 7 days, at the same time and day-of-the-week as in the startSchedule.
     Weekly,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for RecurrencyKindEnumType {
+    const NAME: &'static str = "RecurrencyKindEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for RecurrencyKindEnumType {
@@ -18,6 +36,8 @@ impl fmt::Display for RecurrencyKindEnumType {
         match self {
             Self::Daily => write!(f, "Daily"),
             Self::Weekly => write!(f, "Weekly"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -35,6 +55,9 @@ impl TryFrom<&str> for RecurrencyKindEnumType {
         match value {
             "Daily" => Ok(Self::Daily),
             "Weekly" => Ok(Self::Weekly),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "RecurrencyKindEnumType".to_string(),
                 value: value.to_string(),
@@ -1,4 +1,5 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
@@ -14,6 +15,23 @@ pub enum RegistrationStatusEnumType {
     Pending,
     /// Charging Station is not accepted by CSMS. This may happen when the Charging Station id is not known by CSMS.
     Rejected,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for RegistrationStatusEnumType {
+    const NAME: &'static str = "RegistrationStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for RegistrationStatusEnumType {
@@ -22,6 +40,8 @@ impl fmt::Display for RegistrationStatusEnumType {
             Self::Accepted => write!(f, "Accepted"),
             Self::Pending => write!(f, "Pending"),
             Self::Rejected => write!(f, "Rejected"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -40,6 +60,9 @@ impl TryFrom<&str> for RegistrationStatusEnumType {
             "Accepted" => Ok(Self::Accepted),
             "Pending" => Ok(Self::Pending),
             "Rejected" => Ok(Self::Rejected),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "RegistrationStatusEnumType".to_string(),
                 value: value.to_string(),
@@ -1,4 +1,5 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
@@ -29,6 +30,23 @@ pub enum ReportBaseEnumType {
     /// Availability, monitoring alerts, and MAY limit problem reporting detail to just the active Problem boolean
     /// Variable.
     SummaryInventory,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for ReportBaseEnumType {
+    const NAME: &'static str = "ReportBaseEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for ReportBaseEnumType {
@@ -37,6 +55,8 @@ impl fmt::Display for ReportBaseEnumType {
             Self::ConfigurationInventory => write!(f, "ConfigurationInventory"),
             Self::FullInventory => write!(f, "FullInventory"),
             Self::SummaryInventory => write!(f, "SummaryInventory"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -55,6 +75,9 @@ impl TryFrom<&str> for ReportBaseEnumType {
             "ConfigurationInventory" => Ok(Self::ConfigurationInventory),
             "FullInventory" => Ok(Self::FullInventory),
             "SummaryInventory" => Ok(Self::SummaryInventory),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "ReportBaseEnumType".to_string(),
                 value: value.to_string(),
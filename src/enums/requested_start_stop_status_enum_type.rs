@@ -1,16 +1,35 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
 
 /// The result of a RequestStartTransactionRequest or RequestStopTransactionRequest.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 #[serde(rename_all = "PascalCase")]
 pub enum RequestStartStopStatusEnumType {
     /// Command will be executed.
+    #[default]
     Accepted,
     /// Command will not be executed.
     Rejected,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for RequestStartStopStatusEnumType {
+    const NAME: &'static str = "RequestStartStopStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for RequestStartStopStatusEnumType {
@@ -18,6 +37,8 @@ impl fmt::Display for RequestStartStopStatusEnumType {
         match self {
             Self::Accepted => write!(f, "Accepted"),
             Self::Rejected => write!(f, "Rejected"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -35,6 +56,9 @@ impl TryFrom<&str> for RequestStartStopStatusEnumType {
         match value {
             "Accepted" => Ok(Self::Accepted),
             "Rejected" => Ok(Self::Rejected),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "RequestStartStopStatusEnumType".to_string(),
                 value: value.to_string(),
@@ -1,18 +1,37 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
 
 /// Enumeration
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 #[serde(rename_all = "PascalCase")]
 pub enum ReservationUpdateStatusEnumType {
     /// The reservation is expired.
+    #[default]
     Expired,
     /// The reservation is removed.
     Removed,
     /// (2.1) The reservation was used, but no transaction was started.
     NoTransaction,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for ReservationUpdateStatusEnumType {
+    const NAME: &'static str = "ReservationUpdateStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for ReservationUpdateStatusEnumType {
@@ -21,6 +40,8 @@ impl fmt::Display for ReservationUpdateStatusEnumType {
             Self::Expired => write!(f, "Expired"),
             Self::Removed => write!(f, "Removed"),
             Self::NoTransaction => write!(f, "NoTransaction"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -39,6 +60,9 @@ impl TryFrom<&str> for ReservationUpdateStatusEnumType {
             "Expired" => Ok(Self::Expired),
             "Removed" => Ok(Self::Removed),
             "NoTransaction" => Ok(Self::NoTransaction),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "ReservationUpdateStatusEnumType".to_string(),
                 value: value.to_string(),
@@ -1,13 +1,15 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
 
 /// Status in ReserveNowResponse.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 #[serde(rename_all = "PascalCase")]
 pub enum ReserveNowStatusEnumType {
     /// Reservation has been made.
+    #[default]
     Accepted,
     /// Reservation has not been made, because evse, connectors or specified connector are in a faulted state.
     Faulted,
@@ -17,6 +19,23 @@ pub enum ReserveNowStatusEnumType {
     Rejected,
     /// Reservation has not been made, because evse, connectors or specified connector are in an unavailable state.
     Unavailable,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for ReserveNowStatusEnumType {
+    const NAME: &'static str = "ReserveNowStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for ReserveNowStatusEnumType {
@@ -27,6 +46,8 @@ impl fmt::Display for ReserveNowStatusEnumType {
             Self::Occupied => write!(f, "Occupied"),
             Self::Rejected => write!(f, "Rejected"),
             Self::Unavailable => write!(f, "Unavailable"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -47,6 +68,9 @@ impl TryFrom<&str> for ReserveNowStatusEnumType {
             "Occupied" => Ok(Self::Occupied),
             "Rejected" => Ok(Self::Rejected),
             "Unavailable" => Ok(Self::Unavailable),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "ReserveNowStatusEnumType".to_string(),
                 value: value.to_string(),
@@ -1,18 +1,37 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
 
 /// Type of reset requested.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 #[serde(rename_all = "PascalCase")]
 pub enum ResetEnumType {
     /// Immediate reset of the Charging Station or EVSE.
+    #[default]
     Immediate,
     /// Delay reset until no more transactions are active.
     OnIdle,
     /// (2.1) Immediate reset and resume transaction(s) afterwards
     ImmediateAndResume,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for ResetEnumType {
+    const NAME: &'static str = "ResetEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for ResetEnumType {
@@ -21,6 +40,8 @@ impl fmt::Display for ResetEnumType {
             Self::Immediate => write!(f, "Immediate"),
             Self::OnIdle => write!(f, "OnIdle"),
             Self::ImmediateAndResume => write!(f, "ImmediateAndResume"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -39,6 +60,9 @@ impl TryFrom<&str> for ResetEnumType {
             "Immediate" => Ok(Self::Immediate),
             "OnIdle" => Ok(Self::OnIdle),
             "ImmediateAndResume" => Ok(Self::ImmediateAndResume),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "ResetEnumType".to_string(),
                 value: value.to_string(),
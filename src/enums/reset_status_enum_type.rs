@@ -1,18 +1,37 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
 
 /// Result of ResetRequest.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 #[serde(rename_all = "PascalCase")]
 pub enum ResetStatusEnumType {
     /// Command will be executed.
+    #[default]
     Accepted,
     /// Command will not be executed.
     Rejected,
     /// Reset command is scheduled, Charging Station is busy with a process that cannot be interrupted at the moment. Reset will be executed when process is finished.
     Scheduled,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for ResetStatusEnumType {
+    const NAME: &'static str = "ResetStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for ResetStatusEnumType {
@@ -21,6 +40,8 @@ impl fmt::Display for ResetStatusEnumType {
             Self::Accepted => write!(f, "Accepted"),
             Self::Rejected => write!(f, "Rejected"),
             Self::Scheduled => write!(f, "Scheduled"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -39,6 +60,9 @@ impl TryFrom<&str> for ResetStatusEnumType {
             "Accepted" => Ok(Self::Accepted),
             "Rejected" => Ok(Self::Rejected),
             "Scheduled" => Ok(Self::Scheduled),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "ResetStatusEnumType".to_string(),
                 value: value.to_string(),
@@ -1,18 +1,37 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
 
 /// Type of update for SendLocalListRequest.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 #[serde(rename_all = "PascalCase")]
 pub enum SendLocalListStatusEnumType {
     /// Local Authorization List successfully updated.
+    #[default]
     Accepted,
     /// Failed to update the Local Authorization List.
     Failed,
     /// Version number in the request for a differential update is less or equal then version number of current list.
     VersionMismatch,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for SendLocalListStatusEnumType {
+    const NAME: &'static str = "SendLocalListStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for SendLocalListStatusEnumType {
@@ -21,6 +40,8 @@ impl fmt::Display for SendLocalListStatusEnumType {
             Self::Accepted => write!(f, "Accepted"),
             Self::Failed => write!(f, "Failed"),
             Self::VersionMismatch => write!(f, "VersionMismatch"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -39,6 +60,9 @@ impl TryFrom<&str> for SendLocalListStatusEnumType {
             "Accepted" => Ok(Self::Accepted),
             "Failed" => Ok(Self::Failed),
             "VersionMismatch" => Ok(Self::VersionMismatch),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "SendLocalListStatusEnumType".to_string(),
                 value: value.to_string(),
@@ -1,4 +1,5 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
@@ -19,6 +20,23 @@ pub enum SetMonitoringStatusEnumType {
     Rejected,
     /// A monitor already exists for the given type/severity combination.
     Duplicate,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for SetMonitoringStatusEnumType {
+    const NAME: &'static str = "SetMonitoringStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for SetMonitoringStatusEnumType {
@@ -30,6 +48,8 @@ impl fmt::Display for SetMonitoringStatusEnumType {
             Self::UnsupportedMonitorType => write!(f, "UnsupportedMonitorType"),
             Self::Rejected => write!(f, "Rejected"),
             Self::Duplicate => write!(f, "Duplicate"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -51,6 +71,9 @@ impl TryFrom<&str> for SetMonitoringStatusEnumType {
             "UnsupportedMonitorType" => Ok(Self::UnsupportedMonitorType),
             "Rejected" => Ok(Self::Rejected),
             "Duplicate" => Ok(Self::Duplicate),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "SetMonitoringStatusEnumType".to_string(),
                 value: value.to_string(),
@@ -1,18 +1,37 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
 
 /// Possible values of SetNetworkProfileStatus as used in SetNetworkProfileResponse.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 #[serde(rename_all = "PascalCase")]
 pub enum SetNetworkProfileStatusEnumType {
     /// Setting new data successful
+    #[default]
     Accepted,
     /// Setting new data rejected
     Rejected,
     /// Setting new data failed
     Failed,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for SetNetworkProfileStatusEnumType {
+    const NAME: &'static str = "SetNetworkProfileStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for SetNetworkProfileStatusEnumType {
@@ -21,6 +40,8 @@ impl fmt::Display for SetNetworkProfileStatusEnumType {
             Self::Accepted => write!(f, "Accepted"),
             Self::Rejected => write!(f, "Rejected"),
             Self::Failed => write!(f, "Failed"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -39,6 +60,9 @@ impl TryFrom<&str> for SetNetworkProfileStatusEnumType {
             "Accepted" => Ok(Self::Accepted),
             "Rejected" => Ok(Self::Rejected),
             "Failed" => Ok(Self::Failed),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "SetNetworkProfileStatusEnumType".to_string(),
                 value: value.to_string(),
@@ -1,4 +1,5 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
@@ -19,6 +20,23 @@ pub enum SetVariableStatusEnumType {
     NotSupportedAttributeType,
     /// A reboot is required.
     RebootRequired,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for SetVariableStatusEnumType {
+    const NAME: &'static str = "SetVariableStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for SetVariableStatusEnumType {
@@ -30,6 +48,8 @@ impl fmt::Display for SetVariableStatusEnumType {
             Self::UnknownVariable => write!(f, "UnknownVariable"),
             Self::NotSupportedAttributeType => write!(f, "NotSupportedAttributeType"),
             Self::RebootRequired => write!(f, "RebootRequired"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -51,6 +71,9 @@ impl TryFrom<&str> for SetVariableStatusEnumType {
             "UnknownVariable" => Ok(Self::UnknownVariable),
             "NotSupportedAttributeType" => Ok(Self::NotSupportedAttributeType),
             "RebootRequired" => Ok(Self::RebootRequired),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "SetVariableStatusEnumType".to_string(),
                 value: value.to_string(),
@@ -1,4 +1,5 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
@@ -20,6 +21,23 @@ pub enum TariffChangeStatusEnumType {
     TxNotFound,
     /// Cannot change currency during a transaction.
     NoCurrencyChange,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for TariffChangeStatusEnumType {
+    const NAME: &'static str = "TariffChangeStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for TariffChangeStatusEnumType {
@@ -31,6 +49,8 @@ impl fmt::Display for TariffChangeStatusEnumType {
             Self::ConditionNotSupported => write!(f, "ConditionNotSupported"),
             Self::TxNotFound => write!(f, "TxNotFound"),
             Self::NoCurrencyChange => write!(f, "NoCurrencyChange"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -52,6 +72,9 @@ impl TryFrom<&str> for TariffChangeStatusEnumType {
             "ConditionNotSupported" => Ok(Self::ConditionNotSupported),
             "TxNotFound" => Ok(Self::TxNotFound),
             "NoCurrencyChange" => Ok(Self::NoCurrencyChange),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "TariffChangeStatusEnumType".to_string(),
                 value: value.to_string(),
@@ -1,4 +1,5 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
@@ -13,6 +14,23 @@ pub enum TariffClearStatusEnumType {
     Rejected,
     /// No tariff for EVSE of IdToken
     NoTariff,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for TariffClearStatusEnumType {
+    const NAME: &'static str = "TariffClearStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for TariffClearStatusEnumType {
@@ -21,6 +39,8 @@ impl fmt::Display for TariffClearStatusEnumType {
             Self::Accepted => write!(f, "Accepted"),
             Self::Rejected => write!(f, "Rejected"),
             Self::NoTariff => write!(f, "NoTariff"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -39,6 +59,9 @@ impl TryFrom<&str> for TariffClearStatusEnumType {
             "Accepted" => Ok(Self::Accepted),
             "Rejected" => Ok(Self::Rejected),
             "NoTariff" => Ok(Self::NoTariff),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "TariffClearStatusEnumType".to_string(),
                 value: value.to_string(),
@@ -1,4 +1,5 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
@@ -13,6 +14,23 @@ pub enum TariffCostEnumType {
     MinCost,
     /// Cost is the maximum cost for this tariff.
     MaxCost,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for TariffCostEnumType {
+    const NAME: &'static str = "TariffCostEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for TariffCostEnumType {
@@ -21,6 +39,8 @@ impl fmt::Display for TariffCostEnumType {
             Self::NormalCost => write!(f, "NormalCost"),
             Self::MinCost => write!(f, "MinCost"),
             Self::MaxCost => write!(f, "MaxCost"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -39,6 +59,9 @@ impl TryFrom<&str> for TariffCostEnumType {
             "NormalCost" => Ok(Self::NormalCost),
             "MinCost" => Ok(Self::MinCost),
             "MaxCost" => Ok(Self::MaxCost),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "TariffCostEnumType".to_string(),
                 value: value.to_string(),
@@ -1,4 +1,5 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
@@ -14,6 +15,23 @@ pub enum TariffGetStatusEnumType {
     Rejected,
     /// No tariff present on Charging Station or EVSE.
     NoTariff,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for TariffGetStatusEnumType {
+    const NAME: &'static str = "TariffGetStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for TariffGetStatusEnumType {
@@ -22,6 +40,8 @@ impl fmt::Display for TariffGetStatusEnumType {
             Self::Accepted => write!(f, "Accepted"),
             Self::Rejected => write!(f, "Rejected"),
             Self::NoTariff => write!(f, "NoTariff"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -40,6 +60,9 @@ impl TryFrom<&str> for TariffGetStatusEnumType {
             "Accepted" => Ok(Self::Accepted),
             "Rejected" => Ok(Self::Rejected),
             "NoTariff" => Ok(Self::NoTariff),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "TariffGetStatusEnumType".to_string(),
                 value: value.to_string(),
@@ -1,4 +1,5 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
@@ -11,6 +12,23 @@ pub enum TariffKindEnumType {
     DefaultTariff,
     /// Driver-specific tariff
     DriverTariff,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for TariffKindEnumType {
+    const NAME: &'static str = "TariffKindEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for TariffKindEnumType {
@@ -18,6 +36,8 @@ impl fmt::Display for TariffKindEnumType {
         match self {
             Self::DefaultTariff => write!(f, "DefaultTariff"),
             Self::DriverTariff => write!(f, "DriverTariff"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -35,6 +55,9 @@ impl TryFrom<&str> for TariffKindEnumType {
         match value {
             "DefaultTariff" => Ok(Self::DefaultTariff),
             "DriverTariff" => Ok(Self::DriverTariff),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "TariffKindEnumType".to_string(),
                 value: value.to_string(),
@@ -1,13 +1,15 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
 
 /// Enumeration
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 #[serde(rename_all = "PascalCase")]
 pub enum TariffSetStatusEnumType {
     /// Tariff has been accepted.
+    #[default]
     Accepted,
     /// Tariff has been rejected. More info in statusInfo.
     Rejected,
@@ -17,6 +19,23 @@ pub enum TariffSetStatusEnumType {
     ConditionNotSupported,
     /// TariffId already exists in Charging Station.
     DuplicateTariffId,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for TariffSetStatusEnumType {
+    const NAME: &'static str = "TariffSetStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for TariffSetStatusEnumType {
@@ -27,6 +46,8 @@ impl fmt::Display for TariffSetStatusEnumType {
             Self::TooManyElements => write!(f, "TooManyElements"),
             Self::ConditionNotSupported => write!(f, "ConditionNotSupported"),
             Self::DuplicateTariffId => write!(f, "DuplicateTariffId"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -47,6 +68,9 @@ impl TryFrom<&str> for TariffSetStatusEnumType {
             "TooManyElements" => Ok(Self::TooManyElements),
             "ConditionNotSupported" => Ok(Self::ConditionNotSupported),
             "DuplicateTariffId" => Ok(Self::DuplicateTariffId),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "TariffSetStatusEnumType".to_string(),
                 value: value.to_string(),
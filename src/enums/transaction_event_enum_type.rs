@@ -1,18 +1,37 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
 
 /// Enumeration
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 #[serde(rename_all = "PascalCase")]
 pub enum TransactionEventEnumType {
     /// Last event of a transaction
+    #[default]
     Ended,
     /// First event of a transaction.
     Started,
     /// Transaction event in between 'Started' and 'Ended'.
     Updated,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for TransactionEventEnumType {
+    const NAME: &'static str = "TransactionEventEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for TransactionEventEnumType {
@@ -21,6 +40,8 @@ impl fmt::Display for TransactionEventEnumType {
             Self::Ended => write!(f, "Ended"),
             Self::Started => write!(f, "Started"),
             Self::Updated => write!(f, "Updated"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -39,6 +60,9 @@ impl TryFrom<&str> for TransactionEventEnumType {
             "Ended" => Ok(Self::Ended),
             "Started" => Ok(Self::Started),
             "Updated" => Ok(Self::Updated),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "TransactionEventEnumType".to_string(),
                 value: value.to_string(),
@@ -1,13 +1,15 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
 
 /// Reason that triggered a transactionEventRequest.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 #[serde(rename_all = "PascalCase")]
 pub enum TriggerReasonEnumType {
     /// An Abnormal Error or Fault Condition has occurred.
+    #[default]
     AbnormalCondition,
     /// Charging is authorized, by any means. Might be an RFID, or other authorization means.
     Authorized,
@@ -65,6 +67,23 @@ pub enum TriggerReasonEnumType {
     TxResumed,
     /// CSMS sent an Unlock Connector command.
     UnlockCommand,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for TriggerReasonEnumType {
+    const NAME: &'static str = "TriggerReasonEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for TriggerReasonEnumType {
@@ -99,6 +118,8 @@ impl fmt::Display for TriggerReasonEnumType {
             Self::Trigger => write!(f, "Trigger"),
             Self::TxResumed => write!(f, "TxResumed"),
             Self::UnlockCommand => write!(f, "UnlockCommand"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -143,6 +164,9 @@ impl TryFrom<&str> for TriggerReasonEnumType {
             "Trigger" => Ok(Self::Trigger),
             "TxResumed" => Ok(Self::TxResumed),
             "UnlockCommand" => Ok(Self::UnlockCommand),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "TriggerReasonEnumType".to_string(),
                 value: value.to_string(),
@@ -1,13 +1,15 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
 
 /// Status in response to UnlockConnectorRequest.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 #[serde(rename_all = "PascalCase")]
 pub enum UnlockStatusEnumType {
     /// Connector has successfully been unlocked.
+    #[default]
     Unlocked,
     /// Failed to unlock the connector.
     UnlockFailed,
@@ -15,6 +17,23 @@ pub enum UnlockStatusEnumType {
     OngoingAuthorizedTransaction,
     /// The specified connector is not known by the Charging Station.
     UnknownConnector,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for UnlockStatusEnumType {
+    const NAME: &'static str = "UnlockStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for UnlockStatusEnumType {
@@ -24,6 +43,8 @@ impl fmt::Display for UnlockStatusEnumType {
             Self::UnlockFailed => write!(f, "UnlockFailed"),
             Self::OngoingAuthorizedTransaction => write!(f, "OngoingAuthorizedTransaction"),
             Self::UnknownConnector => write!(f, "UnknownConnector"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -43,6 +64,9 @@ impl TryFrom<&str> for UnlockStatusEnumType {
             "UnlockFailed" => Ok(Self::UnlockFailed),
             "OngoingAuthorizedTransaction" => Ok(Self::OngoingAuthorizedTransaction),
             "UnknownConnector" => Ok(Self::UnknownConnector),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "UnlockStatusEnumType".to_string(),
                 value: value.to_string(),
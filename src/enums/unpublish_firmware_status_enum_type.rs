@@ -1,18 +1,37 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
 
 /// Status for when publishing a Firmware.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 #[serde(rename_all = "PascalCase")]
 pub enum UnpublishFirmwareStatusEnumType {
     /// Intermediate state. Firmware is being downloaded.
+    #[default]
     DownloadOngoing,
     /// There is no published file.
     NoFirmware,
     /// Successful end state. Firmware file no longer being published.
     Unpublished,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for UnpublishFirmwareStatusEnumType {
+    const NAME: &'static str = "UnpublishFirmwareStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for UnpublishFirmwareStatusEnumType {
@@ -21,6 +40,8 @@ impl fmt::Display for UnpublishFirmwareStatusEnumType {
             Self::DownloadOngoing => write!(f, "DownloadOngoing"),
             Self::NoFirmware => write!(f, "NoFirmware"),
             Self::Unpublished => write!(f, "Unpublished"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -39,6 +60,9 @@ impl TryFrom<&str> for UnpublishFirmwareStatusEnumType {
             "DownloadOngoing" => Ok(Self::DownloadOngoing),
             "NoFirmware" => Ok(Self::NoFirmware),
             "Unpublished" => Ok(Self::Unpublished),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "UnpublishFirmwareStatusEnumType".to_string(),
                 value: value.to_string(),
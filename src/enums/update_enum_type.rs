@@ -1,16 +1,35 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
 
 /// Indicates how the Local Authorization List must be updated.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 #[serde(rename_all = "PascalCase")]
 pub enum UpdateEnumType {
     /// Indicates that the current Local Authorization List must be updated with the values in this message.
+    #[default]
     Differential,
     /// Indicates that the current Local Authorization List must be replaced by the values in this message.
     Full,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for UpdateEnumType {
+    const NAME: &'static str = "UpdateEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for UpdateEnumType {
@@ -18,6 +37,8 @@ impl fmt::Display for UpdateEnumType {
         match self {
             Self::Differential => write!(f, "Differential"),
             Self::Full => write!(f, "Full"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -35,6 +56,9 @@ impl TryFrom<&str> for UpdateEnumType {
         match value {
             "Differential" => Ok(Self::Differential),
             "Full" => Ok(Self::Full),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "UpdateEnumType".to_string(),
                 value: value.to_string(),
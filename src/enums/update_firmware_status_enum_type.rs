@@ -1,13 +1,15 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
 
 /// Generic message response status
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 #[serde(rename_all = "PascalCase")]
 pub enum UpdateFirmwareStatusEnumType {
     /// Accepted this firmware update request. This does not mean the firmware update is successful, the Charging Station will now start the firmware update process.
+    #[default]
     Accepted,
     /// Firmware update request rejected.
     Rejected,
@@ -17,6 +19,23 @@ pub enum UpdateFirmwareStatusEnumType {
     InvalidCertificate,
     /// Failure end state. The Firmware Signing certificate has been revoked.
     RevokedCertificate,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for UpdateFirmwareStatusEnumType {
+    const NAME: &'static str = "UpdateFirmwareStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for UpdateFirmwareStatusEnumType {
@@ -27,6 +46,8 @@ impl fmt::Display for UpdateFirmwareStatusEnumType {
             Self::AcceptedCanceled => write!(f, "AcceptedCanceled"),
             Self::InvalidCertificate => write!(f, "InvalidCertificate"),
             Self::RevokedCertificate => write!(f, "RevokedCertificate"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -47,6 +68,9 @@ impl TryFrom<&str> for UpdateFirmwareStatusEnumType {
             "AcceptedCanceled" => Ok(Self::AcceptedCanceled),
             "InvalidCertificate" => Ok(Self::InvalidCertificate),
             "RevokedCertificate" => Ok(Self::RevokedCertificate),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "UpdateFirmwareStatusEnumType".to_string(),
                 value: value.to_string(),
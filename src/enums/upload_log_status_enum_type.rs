@@ -1,13 +1,15 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
 
 /// Status of the log upload process.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 #[serde(rename_all = "PascalCase")]
 pub enum UploadLogStatusEnumType {
     /// A badly formatted packet or other protocol incompatibility was detected.
+    #[default]
     BadMessage,
     /// The Charging Station is not uploading a log file. Idle SHALL only be used when the message was triggered by a TriggerMessageRequest.
     Idle,
@@ -23,6 +25,23 @@ pub enum UploadLogStatusEnumType {
     Uploading,
     /// On-going log upload is canceled and a new request to upload log has been accepted.
     AcceptedCanceled,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for UploadLogStatusEnumType {
+    const NAME: &'static str = "UploadLogStatusEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for UploadLogStatusEnumType {
@@ -36,6 +55,8 @@ impl fmt::Display for UploadLogStatusEnumType {
             Self::UploadFailure => write!(f, "UploadFailure"),
             Self::Uploading => write!(f, "Uploading"),
             Self::AcceptedCanceled => write!(f, "AcceptedCanceled"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -59,6 +80,9 @@ impl TryFrom<&str> for UploadLogStatusEnumType {
             "UploadFailure" => Ok(Self::UploadFailure),
             "Uploading" => Ok(Self::Uploading),
             "AcceptedCanceled" => Ok(Self::AcceptedCanceled),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "UploadLogStatusEnumType".to_string(),
                 value: value.to_string(),
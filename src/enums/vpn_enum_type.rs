@@ -1,4 +1,5 @@
 use crate::errors::OcppError;
+use crate::traits::OcppEnum;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
@@ -14,6 +15,23 @@ pub enum VPNEnumType {
     L2Tp,
     #[serde(rename = "PPTP")]
     Pptp,
+    /// Any value not defined by the specification, kept as-is. Requires `lenient-enums`.
+    #[cfg(feature = "lenient-enums")]
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl OcppEnum for VPNEnumType {
+    const NAME: &'static str = "VPNEnumType";
+
+    fn unknown_value(&self) -> Option<&str> {
+        #[cfg(feature = "lenient-enums")]
+        if let Self::Unknown(value) = self {
+            return Some(value);
+        }
+
+        None
+    }
 }
 
 impl fmt::Display for VPNEnumType {
@@ -23,6 +41,8 @@ impl fmt::Display for VPNEnumType {
             Self::Ipsec => write!(f, "IPSec"),
             Self::L2Tp => write!(f, "L2TP"),
             Self::Pptp => write!(f, "PPTP"),
+            #[cfg(feature = "lenient-enums")]
+            Self::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
@@ -42,6 +62,9 @@ impl TryFrom<&str> for VPNEnumType {
             "IPSec" => Ok(Self::Ipsec),
             "L2TP" => Ok(Self::L2Tp),
             "PPTP" => Ok(Self::Pptp),
+            #[cfg(feature = "lenient-enums")]
+            _ => Ok(Self::Unknown(value.to_string())),
+            #[cfg(not(feature = "lenient-enums"))]
             _ => Err(OcppError::InvalidEnumValueError {
                 enum_name: "VPNEnumType".to_string(),
                 value: value.to_string(),
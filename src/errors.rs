@@ -1,9 +1,11 @@
 use crate::errors::OcppError::{
     FieldBoundsError, FieldCardinalityError, FieldRelationshipError, FieldValidationError,
-    StructureValidationError,
+    InvalidEnumValueError, StructureValidationError,
 };
-use crate::traits::OcppEntity;
+use crate::ocppj::RpcErrorCode;
+use crate::traits::{OcppEntity, OcppEnum};
 use miette::Diagnostic;
+use serde_json::Value;
 use thiserror::Error;
 
 #[derive(Error, Diagnostic, Debug, Clone)]
@@ -65,6 +67,94 @@ pub enum OcppError {
     #[error("Builder Error: {builder_type} encountered an error while building!")]
     #[diagnostic(help("{help}"))]
     BuilderError { builder_type: String, help: String },
+
+    #[error("Schema Validation Error: {schema}")]
+    #[diagnostic()]
+    SchemaValidationError {
+        schema: String,
+
+        #[related]
+        related: Vec<OcppError>,
+    },
+
+    #[error("Schema Violation Error: {pointer} violates `{keyword}`: {reason}")]
+    #[diagnostic()]
+    SchemaViolationError {
+        pointer: String,
+        keyword: String,
+        reason: String,
+    },
+
+    #[error("Missing Schema Error: no JSON schema is embedded for {schema}")]
+    #[diagnostic()]
+    MissingSchemaError { schema: String },
+
+    #[error("Unknown Action Error: {action} is not a known OCPP action")]
+    #[diagnostic()]
+    UnknownActionError { action: String },
+
+    #[error("Malformed Frame Error: {reason}")]
+    #[diagnostic(help(
+        "OCPP-J frames are JSON arrays of the form [messageTypeId, messageId, ...]"
+    ))]
+    MalformedFrameError { reason: String },
+
+    #[error("Payload Deserialization Error: {action} payload does not match its schema: {reason}")]
+    #[diagnostic()]
+    PayloadDeserializationError { action: String, reason: String },
+
+    #[error("Unmatched Message Id Error: no pending CALL has message id {message_id}")]
+    #[diagnostic()]
+    UnmatchedMessageIdError { message_id: String },
+
+    #[error("Duplicate Message Id Error: a CALL with message id {message_id} is already pending")]
+    #[diagnostic(help("Message ids must be unique per sender"))]
+    DuplicateMessageIdError { message_id: String },
+
+    #[error("Call Timeout Error: {action} CALL {message_id} was not answered in time")]
+    #[diagnostic()]
+    CallTimeoutError { message_id: String, action: String },
+
+    #[error(
+        "Call Error Received: {action} CALL {message_id} failed with {error_code}: {error_description}"
+    )]
+    #[diagnostic()]
+    CallErrorReceived {
+        message_id: String,
+        action: String,
+        error_code: RpcErrorCode,
+        error_description: String,
+        error_details: Value,
+    },
+
+    #[error("Message Type Mismatch Error: {action} cannot be sent as a {message_type}")]
+    #[diagnostic(help(
+        "Unconfirmed messages such as NotifyPeriodicEventStream must be sent with SEND, all other messages with CALL"
+    ))]
+    MessageTypeMismatchError {
+        action: String,
+        message_type: String,
+    },
+
+    #[error("Transport Error: {reason}")]
+    #[diagnostic()]
+    TransportError { reason: String },
+
+    #[error("Subprotocol Negotiation Error: no supported subprotocol in \"{offered}\"")]
+    #[diagnostic(help("Offer ocpp2.1 and/or ocpp2.0.1 in the Sec-WebSocket-Protocol header"))]
+    SubprotocolNegotiationError { offered: String },
+
+    #[error("Unhandled Action Error: no handler is implemented for {action}")]
+    #[diagnostic()]
+    UnhandledActionError { action: String },
+
+    #[error("Station Not Connected Error: no Charging Station is connected as {identity}")]
+    #[diagnostic()]
+    StationNotConnectedError { identity: String },
+
+    #[error("Authentication Error: {reason}")]
+    #[diagnostic()]
+    AuthenticationError { reason: String },
 }
 
 impl OcppError {
@@ -221,6 +311,35 @@ impl StructureValidationBuilder {
         self
     }
 
+    /// For a given enum field, check that its value is defined by the specification. If it is
+    /// not, add a `OcppError::InvalidEnumValueError` to the list.
+    pub fn check_enum<T: OcppEnum>(&mut self, field: &str, value: &T) -> &Self {
+        if let Some(unknown) = value.unknown_value() {
+            self.errors.push(
+                InvalidEnumValueError {
+                    enum_name: T::NAME.to_string(),
+                    value: unknown.to_string(),
+                }
+                .to_field_validation_error(field),
+            );
+        }
+
+        self
+    }
+
+    /// For a given Iterator of enum values, check each of them as per `check_enum`.
+    pub fn check_iter_enum<'a, T: OcppEnum + 'a>(
+        &mut self,
+        field: &str,
+        iter: impl Iterator<Item = &'a T>,
+    ) -> &Self {
+        for (i, value) in iter.enumerate() {
+            self.check_enum(format!("{field}[{i}]").as_str(), value);
+        }
+
+        self
+    }
+
     pub fn push_relation_error(&mut self, this: &str, other: &str, help: &str) {
         let fre = FieldRelationshipError {
             this: this.to_string(),
@@ -3,5 +3,9 @@ pub mod errors;
 mod iso;
 pub mod messages;
 pub mod ocppj;
+pub mod schema;
+pub mod security;
 pub mod structures;
 pub mod traits;
+#[cfg(feature = "transport")]
+pub mod transport;
@@ -35,3 +35,461 @@ pub mod get_monitoring_report;
 pub mod get_periodic_event_stream;
 pub mod get_report;
 pub mod get_tariffs;
+pub mod get_transaction_status;
+pub mod get_variables;
+pub mod heartbeat;
+pub mod install_certificate;
+pub mod log_status_notification;
+pub mod meter_values;
+pub mod notify_allowed_energy_transfer;
+pub mod notify_charging_limit;
+pub mod notify_customer_information;
+pub mod notify_der_alarm;
+pub mod notify_der_start_stop;
+pub mod notify_display_messages;
+pub mod notify_ev_charging_needs;
+pub mod notify_ev_charging_schedule;
+pub mod notify_event;
+pub mod notify_monitoring_report;
+pub mod notify_periodic_event_stream;
+pub mod notify_priority_charging;
+pub mod notify_qr_code_scanned;
+pub mod notify_report;
+pub mod notify_settlement;
+pub mod notify_web_payment_started;
+pub mod open_periodic_event_stream;
+pub mod publish_firmware;
+pub mod publish_firmware_status_notification;
+pub mod pull_dynamic_schedule_update;
+pub mod report_charging_profiles;
+pub mod report_der_control;
+pub mod request_battery_swap;
+pub mod request_start_transaction;
+pub mod request_stop_transaction;
+pub mod reservation_status_update;
+pub mod reserve_now;
+pub mod reset;
+pub mod security_event_notification;
+pub mod send_local_list;
+pub mod set_charging_profile;
+pub mod set_default_tariff;
+pub mod set_der_control;
+pub mod set_display_message;
+pub mod set_monitoring_base;
+pub mod set_monitoring_level;
+pub mod set_network_profile;
+pub mod set_variable_monitoring;
+pub mod set_variables;
+pub mod sign_certificate;
+pub mod status_notification;
+pub mod transaction_event;
+pub mod trigger_message;
+pub mod unlock_connector;
+pub mod unpublish_firmware;
+pub mod update_dynamic_schedule;
+pub mod update_firmware;
+pub mod use_priority_charging;
+pub mod vat_number_validation;
+
+/// Invokes `$callback!` with every OCPP message implemented by this crate, given as a
+/// comma-separated list of `module::Message` paths relative to `crate::messages`. The message
+/// struct name doubles as the OCPP-J action string.
+macro_rules! for_each_action {
+    ($callback:ident) => {
+        $callback! {
+            adjust_periodic_event_stream::AdjustPeriodicEventStream,
+            afr_signal::AFRRSignal,
+            authorize::Authorize,
+            battery_swap::BatterySwap,
+            boot_notification::BootNotification,
+            cancel_reservation::CancelReservation,
+            certificate_signed::CertificateSigned,
+            change_availability::ChangeAvailability,
+            change_transaction_tariff::ChangeTransactionTariff,
+            clear_cache::ClearCache,
+            clear_charging_profile::ClearChargingProfile,
+            clear_der_control::ClearDERControl,
+            clear_display_message::ClearDisplayMessage,
+            clear_tariffs::ClearTariffs,
+            clear_variable_monitoring::ClearVariableMonitoring,
+            cleared_charging_limit::ClearedChargingLimit,
+            close_periodic_event_stream::ClosePeriodicEventStream,
+            cost_updated::CostUpdated,
+            customer_information::CustomerInformation,
+            data_transfer::DataTransfer,
+            delete_certificate::DeleteCertificate,
+            firmware_status_notification::FirmwareStatusNotification,
+            get_15118_ev_certificate::Get15118EVCertificate,
+            get_base_report::GetBaseReport,
+            get_certificate_chain_status::GetCertificateChainStatus,
+            get_certificate_status::GetCertificateStatus,
+            get_charging_profiles::GetChargingProfiles,
+            get_composite_schedule::GetCompositeSchedule,
+            get_der_control::GetDERControl,
+            get_display_messages::GetDisplayMessages,
+            get_installed_certificate_ids::GetInstalledCertificateIds,
+            get_local_list_version::GetLocalListVersion,
+            get_log::GetLog,
+            get_monitoring_report::GetMonitoringReport,
+            get_periodic_event_stream::GetPeriodicEventStream,
+            get_report::GetReport,
+            get_tariffs::GetTariffs,
+            get_transaction_status::GetTransactionStatus,
+            get_variables::GetVariables,
+            heartbeat::Heartbeat,
+            install_certificate::InstallCertificate,
+            log_status_notification::LogStatusNotification,
+            meter_values::MeterValues,
+            notify_allowed_energy_transfer::NotifyAllowedEnergyTransfer,
+            notify_charging_limit::NotifyChargingLimit,
+            notify_customer_information::NotifyCustomerInformation,
+            notify_der_alarm::NotifyDERAlarm,
+            notify_der_start_stop::NotifyDERStartStop,
+            notify_display_messages::NotifyDisplayMessages,
+            notify_ev_charging_needs::NotifyEVChargingNeeds,
+            notify_ev_charging_schedule::NotifyEVChargingSchedule,
+            notify_event::NotifyEvent,
+            notify_monitoring_report::NotifyMonitoringReport,
+            notify_priority_charging::NotifyPriorityCharging,
+            notify_qr_code_scanned::NotifyQRCodeScanned,
+            notify_report::NotifyReport,
+            notify_settlement::NotifySettlement,
+            notify_web_payment_started::NotifyWebPaymentStarted,
+            open_periodic_event_stream::OpenPeriodicEventStream,
+            publish_firmware::PublishFirmware,
+            publish_firmware_status_notification::PublishFirmwareStatusNotification,
+            pull_dynamic_schedule_update::PullDynamicScheduleUpdate,
+            report_charging_profiles::ReportChargingProfiles,
+            report_der_control::ReportDERControl,
+            request_battery_swap::RequestBatterySwap,
+            request_start_transaction::RequestStartTransaction,
+            request_stop_transaction::RequestStopTransaction,
+            reservation_status_update::ReservationStatusUpdate,
+            reserve_now::ReserveNow,
+            reset::Reset,
+            security_event_notification::SecurityEventNotification,
+            send_local_list::SendLocalList,
+            set_charging_profile::SetChargingProfile,
+            set_default_tariff::SetDefaultTariff,
+            set_der_control::SetDERControl,
+            set_display_message::SetDisplayMessage,
+            set_monitoring_base::SetMonitoringBase,
+            set_monitoring_level::SetMonitoringLevel,
+            set_network_profile::SetNetworkProfile,
+            set_variable_monitoring::SetVariableMonitoring,
+            set_variables::SetVariables,
+            sign_certificate::SignCertificate,
+            status_notification::StatusNotification,
+            transaction_event::TransactionEvent,
+            trigger_message::TriggerMessage,
+            unlock_connector::UnlockConnector,
+            unpublish_firmware::UnpublishFirmware,
+            update_dynamic_schedule::UpdateDynamicSchedule,
+            update_firmware::UpdateFirmware,
+            use_priority_charging::UsePriorityCharging,
+            vat_number_validation::VatNumberValidation,
+        }
+    };
+}
+
+pub(crate) use for_each_action;
+
+/// Invokes `$callback!` with every OCPP message of `for_each_action` that a Charging Station sends
+/// to the CSMS, in the same format. DataTransfer may be sent in either direction and is listed here
+/// as well.
+#[cfg_attr(not(feature = "transport"), allow(unused_macros))]
+macro_rules! for_each_station_action {
+    ($callback:ident) => {
+        $callback! {
+            authorize::Authorize,
+            battery_swap::BatterySwap,
+            boot_notification::BootNotification,
+            cleared_charging_limit::ClearedChargingLimit,
+            close_periodic_event_stream::ClosePeriodicEventStream,
+            data_transfer::DataTransfer,
+            firmware_status_notification::FirmwareStatusNotification,
+            get_15118_ev_certificate::Get15118EVCertificate,
+            get_certificate_chain_status::GetCertificateChainStatus,
+            get_certificate_status::GetCertificateStatus,
+            heartbeat::Heartbeat,
+            log_status_notification::LogStatusNotification,
+            meter_values::MeterValues,
+            notify_charging_limit::NotifyChargingLimit,
+            notify_customer_information::NotifyCustomerInformation,
+            notify_der_alarm::NotifyDERAlarm,
+            notify_der_start_stop::NotifyDERStartStop,
+            notify_display_messages::NotifyDisplayMessages,
+            notify_ev_charging_needs::NotifyEVChargingNeeds,
+            notify_ev_charging_schedule::NotifyEVChargingSchedule,
+            notify_event::NotifyEvent,
+            notify_monitoring_report::NotifyMonitoringReport,
+            notify_priority_charging::NotifyPriorityCharging,
+            notify_qr_code_scanned::NotifyQRCodeScanned,
+            notify_report::NotifyReport,
+            notify_settlement::NotifySettlement,
+            open_periodic_event_stream::OpenPeriodicEventStream,
+            publish_firmware_status_notification::PublishFirmwareStatusNotification,
+            pull_dynamic_schedule_update::PullDynamicScheduleUpdate,
+            report_charging_profiles::ReportChargingProfiles,
+            report_der_control::ReportDERControl,
+            reservation_status_update::ReservationStatusUpdate,
+            security_event_notification::SecurityEventNotification,
+            sign_certificate::SignCertificate,
+            status_notification::StatusNotification,
+            transaction_event::TransactionEvent,
+            vat_number_validation::VatNumberValidation,
+        }
+    };
+}
+
+#[cfg_attr(not(feature = "transport"), allow(unused_imports))]
+pub(crate) use for_each_station_action;
+
+/// Invokes `$callback!` with every unconfirmed OCPP message implemented by this crate, in the same
+/// format as `for_each_action`. These messages are sent with SEND instead of CALL and have no
+/// response.
+macro_rules! for_each_unconfirmed_action {
+    ($callback:ident) => {
+        $callback! {
+            notify_periodic_event_stream::NotifyPeriodicEventStream,
+        }
+    };
+}
+
+pub(crate) use for_each_unconfirmed_action;
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::traits::{OcppEntity, OcppMessage, OcppRequest, OcppUnconfirmedMessage};
+    use serde::Serialize;
+    use serde::de::DeserializeOwned;
+    use serde_json::Value;
+    use std::collections::BTreeSet;
+
+    /// Every action of the OCPP 2.1 message list (Part 2, Messages section).
+    const SPEC_ACTIONS: [&str; 92] = [
+        "AdjustPeriodicEventStream",
+        "AFRRSignal",
+        "Authorize",
+        "BatterySwap",
+        "BootNotification",
+        "CancelReservation",
+        "CertificateSigned",
+        "ChangeAvailability",
+        "ChangeTransactionTariff",
+        "ClearCache",
+        "ClearChargingProfile",
+        "ClearDERControl",
+        "ClearDisplayMessage",
+        "ClearedChargingLimit",
+        "ClearTariffs",
+        "ClearVariableMonitoring",
+        "ClosePeriodicEventStream",
+        "CostUpdated",
+        "CustomerInformation",
+        "DataTransfer",
+        "DeleteCertificate",
+        "FirmwareStatusNotification",
+        "Get15118EVCertificate",
+        "GetBaseReport",
+        "GetCertificateChainStatus",
+        "GetCertificateStatus",
+        "GetChargingProfiles",
+        "GetCompositeSchedule",
+        "GetDERControl",
+        "GetDisplayMessages",
+        "GetInstalledCertificateIds",
+        "GetLocalListVersion",
+        "GetLog",
+        "GetMonitoringReport",
+        "GetPeriodicEventStream",
+        "GetReport",
+        "GetTariffs",
+        "GetTransactionStatus",
+        "GetVariables",
+        "Heartbeat",
+        "InstallCertificate",
+        "LogStatusNotification",
+        "MeterValues",
+        "NotifyAllowedEnergyTransfer",
+        "NotifyChargingLimit",
+        "NotifyCustomerInformation",
+        "NotifyDERAlarm",
+        "NotifyDERStartStop",
+        "NotifyDisplayMessages",
+        "NotifyEVChargingNeeds",
+        "NotifyEVChargingSchedule",
+        "NotifyEvent",
+        "NotifyMonitoringReport",
+        "NotifyPeriodicEventStream",
+        "NotifyPriorityCharging",
+        "NotifyQRCodeScanned",
+        "NotifyReport",
+        "NotifySettlement",
+        "NotifyWebPaymentStarted",
+        "OpenPeriodicEventStream",
+        "PublishFirmware",
+        "PublishFirmwareStatusNotification",
+        "PullDynamicScheduleUpdate",
+        "ReportChargingProfiles",
+        "ReportDERControl",
+        "RequestBatterySwap",
+        "RequestStartTransaction",
+        "RequestStopTransaction",
+        "ReservationStatusUpdate",
+        "ReserveNow",
+        "Reset",
+        "SecurityEventNotification",
+        "SendLocalList",
+        "SetChargingProfile",
+        "SetDefaultTariff",
+        "SetDERControl",
+        "SetDisplayMessage",
+        "SetMonitoringBase",
+        "SetMonitoringLevel",
+        "SetNetworkProfile",
+        "SetVariableMonitoring",
+        "SetVariables",
+        "SignCertificate",
+        "StatusNotification",
+        "TransactionEvent",
+        "TriggerMessage",
+        "UnlockConnector",
+        "UnpublishFirmware",
+        "UpdateDynamicSchedule",
+        "UpdateFirmware",
+        "UsePriorityCharging",
+        "VatNumberValidation",
+    ];
+
+    macro_rules! action_names {
+        ($($module:ident::$message:ident),* $(,)?) => {
+            vec![$((
+                stringify!($message),
+                <super::$module::$message as OcppMessage>::request().get_message_type(),
+            )),*]
+        };
+    }
+
+    macro_rules! unconfirmed_action_names {
+        ($($module:ident::$message:ident),* $(,)?) => {
+            vec![$((
+                stringify!($message),
+                <super::$module::$message as OcppUnconfirmedMessage>::request().get_message_type(),
+            )),*]
+        };
+    }
+
+    #[test]
+    fn test_every_spec_action_has_a_module() {
+        let mut actions = for_each_action!(action_names);
+        actions.extend(for_each_unconfirmed_action!(unconfirmed_action_names));
+
+        for (action, message_type) in &actions {
+            assert_eq!(
+                *action, message_type,
+                "{action} reports a different message type"
+            );
+        }
+
+        let implemented: BTreeSet<&str> = actions.iter().map(|(action, _)| *action).collect();
+        let spec: BTreeSet<&str> = SPEC_ACTIONS.into_iter().collect();
+        let missing: Vec<_> = spec.difference(&implemented).collect();
+        let unknown: Vec<_> = implemented.difference(&spec).collect();
+        assert!(missing.is_empty(), "actions without a module: {missing:?}");
+        assert!(
+            unknown.is_empty(),
+            "modules without a spec action: {unknown:?}"
+        );
+        assert_eq!(actions.len(), SPEC_ACTIONS.len());
+    }
+
+    /// Loads the example payload `tests/payloads/<name>.json` at compile time, so that an action
+    /// without an example fails to build rather than being skipped.
+    macro_rules! payload {
+        ($message:ident, $kind:literal) => {
+            include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/tests/payloads/",
+                stringify!($message),
+                $kind,
+                ".json"
+            ))
+        };
+    }
+
+    /// Rewrites every number as a float, so that `32` and `32.0` compare equal.
+    fn normalize(value: Value) -> Value {
+        match value {
+            Value::Number(n) => serde_json::json!(n.as_f64().unwrap()),
+            Value::Array(items) => Value::Array(items.into_iter().map(normalize).collect()),
+            Value::Object(map) => {
+                Value::Object(map.into_iter().map(|(k, v)| (k, normalize(v))).collect())
+            }
+            other => other,
+        }
+    }
+
+    /// Deserializes `payload` into `T`, validates it and checks that serializing it again yields
+    /// the same JSON: no field renamed, dropped or added as `null`.
+    pub(crate) fn round_trip<T: Serialize + DeserializeOwned + OcppEntity>(
+        payload: &str,
+    ) -> Result<(), String> {
+        let expected: Value =
+            serde_json::from_str(payload).map_err(|e| format!("example is not valid JSON: {e}"))?;
+        let entity: T = serde_json::from_value(expected.clone())
+            .map_err(|e| format!("failed to deserialize: {e}"))?;
+        entity
+            .validate()
+            .map_err(|e| format!("example failed validation: {e:?}"))?;
+        let actual = serde_json::to_value(&entity).unwrap();
+        if normalize(actual.clone()) != normalize(expected) {
+            return Err(format!("serialized differently: {actual}"));
+        }
+        Ok(())
+    }
+
+    macro_rules! round_trip_actions {
+        ($($module:ident::$message:ident),* $(,)?) => {
+            vec![$(
+                (
+                    concat!(stringify!($message), "Request"),
+                    round_trip::<<super::$module::$message as OcppMessage>::Request>(
+                        payload!($message, "Request"),
+                    ),
+                ),
+                (
+                    concat!(stringify!($message), "Response"),
+                    round_trip::<<super::$module::$message as OcppMessage>::Response>(
+                        payload!($message, "Response"),
+                    ),
+                ),
+            )*]
+        };
+    }
+
+    macro_rules! round_trip_unconfirmed_actions {
+        ($($module:ident::$message:ident),* $(,)?) => {
+            vec![$(
+                (
+                    concat!(stringify!($message), "Request"),
+                    round_trip::<<super::$module::$message as OcppUnconfirmedMessage>::Request>(
+                        payload!($message, "Request"),
+                    ),
+                ),
+            )*]
+        };
+    }
+
+    #[test]
+    fn test_every_action_round_trips_example_payloads() {
+        let mut results = for_each_action!(round_trip_actions);
+        results.extend(for_each_unconfirmed_action!(round_trip_unconfirmed_actions));
+
+        let failures: Vec<String> = results
+            .into_iter()
+            .filter_map(|(name, result)| result.err().map(|e| format!("{name}: {e}")))
+            .collect();
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}
@@ -1,5 +1,6 @@
 use crate::enums::generic_status_enum_type::GenericStatusEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::periodic_event_stream_params_type::PeriodicEventStreamParamsType;
 use crate::structures::status_info_type::StatusInfoType;
 use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
@@ -17,11 +18,15 @@ impl OcppMessage for AdjustPeriodicEventStream {
 /// This message is used by the CSMS to tell the Charging Station that it wants to change the reporting
 /// rate of a Periodic Event Stream.
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct AdjustPeriodicEventStreamRequest {
     /// The ID of the event stream to be adjusted.
     pub id: i32,
     /// Updated rate of sending data.
     pub params: PeriodicEventStreamParamsType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 #[typetag::serde]
@@ -34,7 +39,11 @@ impl OcppRequest for AdjustPeriodicEventStreamRequest {
 impl AdjustPeriodicEventStreamRequest {
     /// Creates a new `AdjustPeriodicEventStreamRequest`.
     pub fn new(id: i32, params: PeriodicEventStreamParamsType) -> Self {
-        Self { id, params }
+        Self {
+            id,
+            params,
+            custom_data: None,
+        }
     }
 }
 #[typetag::serde]
@@ -45,6 +54,10 @@ impl OcppEntity for AdjustPeriodicEventStreamRequest {
         err.check_bounds("id", 0, i32::MAX, self.id);
         err.check_member("params", &self.params);
 
+        if let Some(custom_data) = &self.custom_data {
+            err.check_member("custom_data", custom_data);
+        }
+
         err.build("AdjustPeriodicEventStreamRequest")
     }
 }
@@ -54,12 +67,16 @@ impl OcppEntity for AdjustPeriodicEventStreamRequest {
 /// This message is sent by the Charging Station to the CSMS to indicate the success or failure of the
 /// `AdjustPeriodicEventStreamRequest`.
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct AdjustPeriodicEventStreamResponse {
     /// The status of the operation.
     pub status: GenericStatusEnumType,
     /// Detailed status information.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl AdjustPeriodicEventStreamResponse {
@@ -68,6 +85,7 @@ impl AdjustPeriodicEventStreamResponse {
         Self {
             status,
             status_info,
+            custom_data: None,
         }
     }
 }
@@ -80,6 +98,12 @@ impl OcppEntity for AdjustPeriodicEventStreamResponse {
             builder.check_member("statusInfo", si);
         }
 
+        builder.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            builder.check_member("custom_data", custom_data);
+        }
+
         builder.build("AdjustPeriodicEventStreamResponse")
     }
 }
@@ -127,6 +151,7 @@ mod tests {
             Some(StatusInfoType {
                 reason_code: "a".repeat(21),
                 additional_info: None,
+                custom_data: None,
             }),
         );
         assert!(resp.validate().is_err());
@@ -1,5 +1,6 @@
 use crate::enums::generic_status_enum_type::GenericStatusEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::status_info_type::StatusInfoType;
 use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
 use chrono::{DateTime, Utc};
@@ -24,11 +25,20 @@ pub struct AFRRSignalRequest {
     pub timestamp: DateTime<Utc>,
     /// Required. Value of signal in v2xSignalWattCurve.
     pub signal: i32,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for AFRRSignalRequest {
     fn validate(&self) -> Result<(), OcppError> {
-        Ok(())
+        let mut e = StructureValidationBuilder::new();
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("AFRRSignalRequest")
     }
 }
 
@@ -48,7 +58,11 @@ pub struct AFRRSignalResponse {
     /// Required.
     pub status: GenericStatusEnumType,
     /// Optional. Additional information on status.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for AFRRSignalResponse {
@@ -59,6 +73,12 @@ impl OcppEntity for AFRRSignalResponse {
             b.check_member("statusInfo", status_info);
         }
 
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("AFRRSignalResponse")
     }
 }
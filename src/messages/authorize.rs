@@ -1,6 +1,7 @@
 use crate::enums::authorize_certificate_status_enum_type::AuthorizeCertificateStatusEnumType;
 use crate::enums::energy_transfer_mode_enum_type::EnergyTransferModeEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::id_token_info_type::IdTokenInfoType;
 use crate::structures::id_token_type::IdTokenType;
 use crate::structures::ocsp_request_data_type::OCSPRequestDataType;
@@ -25,12 +26,17 @@ pub struct AuthorizeRequest {
     /// Optional. (2.1) The X.509 certificate chain presented by EV and encoded in PEM format.
     /// Order of certificates in chain is from leaf up to (but excluding) root certificate.
     /// Only needed in case of central contract validation when Charging Station cannot validate the contract certificate.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub certificate: Option<String>,
     /// Required. This contains the identifier that needs to be authorized.
     pub id_token: IdTokenType,
     /// Optional. (2.1) Contains the information needed to verify the EV Contract Certificate via OCSP.
     /// Not needed if certificate is provided.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub iso15118_certificate_hash_data: Option<Vec<OCSPRequestDataType>>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for AuthorizeRequest {
@@ -56,6 +62,10 @@ impl OcppEntity for AuthorizeRequest {
             );
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("AuthorizeRequest")
     }
 }
@@ -76,13 +86,19 @@ pub struct AuthorizeResponse {
     /// Optional. Certificate status information.
     /// - if all certificates are valid: return 'Accepted'.
     /// - if one of the certificates was revoked, return 'CertificateRevoked'.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub certificate_status: Option<AuthorizeCertificateStatusEnumType>,
     /// Optional. (2.1) List of allowed energy transfer modes the EV can choose from. If omitted this defaults to charging only.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub allowed_energy_transfer: Option<Vec<EnergyTransferModeEnumType>>,
     /// Required. This contains information about authorization status, expiry and group id.
     pub id_token_info: IdTokenInfoType,
     /// Optional. (2.1) Tariff for this IdToken.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tariff: Option<TariffType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for AuthorizeResponse {
@@ -95,6 +111,18 @@ impl OcppEntity for AuthorizeResponse {
             b.check_member("tariff", tariff);
         }
 
+        if let Some(certificate_status) = &self.certificate_status {
+            b.check_enum("certificate_status", certificate_status);
+        }
+
+        if let Some(allowed_energy_transfer) = &self.allowed_energy_transfer {
+            b.check_iter_enum("allowed_energy_transfer", allowed_energy_transfer.iter());
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("AuthorizeResponse")
     }
 }
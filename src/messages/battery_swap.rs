@@ -1,6 +1,7 @@
 use crate::enums::battery_swap_event_enum_types::BatterySwapEventEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::battery_data_type::BatteryDataType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::id_token_type::IdTokenType;
 use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
 use serde::{Deserialize, Serialize};
@@ -27,6 +28,9 @@ pub struct BatterySwapRequest {
     pub id_token: IdTokenType,
     /// Required. Info on batteries inserted or taken out.
     pub battery_data: Vec<BatteryDataType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for BatterySwapRequest {
@@ -38,6 +42,12 @@ impl OcppEntity for BatterySwapRequest {
         b.check_cardinality("battery_data", 1, usize::MAX, &self.battery_data.iter());
         b.check_iter_member("battery_data", self.battery_data.iter());
 
+        b.check_enum("event_type", &self.event_type);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("BatterySwapRequest")
     }
 }
@@ -53,11 +63,21 @@ impl OcppRequest for BatterySwapRequest {
 /// Empty response by CSMS to confirm receipt of BatterySwapRequest.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct BatterySwapResponse {}
+pub struct BatterySwapResponse {
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
 
 #[typetag::serde]
 impl OcppEntity for BatterySwapResponse {
     fn validate(&self) -> Result<(), OcppError> {
-        Ok(())
+        let mut e = StructureValidationBuilder::new();
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("BatterySwapResponse")
     }
 }
@@ -2,6 +2,7 @@ use crate::enums::boot_reason_enum_type::BootReasonEnumType;
 use crate::enums::registration_status_enum_type::RegistrationStatusEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::charging_station_type::ChargingStationType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::status_info_type::StatusInfoType;
 use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
 use chrono::{DateTime, Utc};
@@ -25,6 +26,9 @@ pub struct BootNotificationRequest {
     pub reason: BootReasonEnumType,
     /// Required. Identifies the Charging Station
     pub charging_station: ChargingStationType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for BootNotificationRequest {
@@ -33,6 +37,12 @@ impl OcppEntity for BootNotificationRequest {
 
         b.check_member("charging_station", &self.charging_station);
 
+        b.check_enum("reason", &self.reason);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("BootNotificationRequest")
     }
 }
@@ -57,7 +67,11 @@ pub struct BootNotificationResponse {
     /// Required. This contains whether the Charging Station has been registered within the CSMS.
     pub status: RegistrationStatusEnumType,
     /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for BootNotificationResponse {
@@ -68,6 +82,12 @@ impl OcppEntity for BootNotificationResponse {
             b.check_member("status_info", status_info);
         }
 
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("BootNotificationResponse")
     }
 }
@@ -107,4 +127,56 @@ mod tests {
     fn test_response_validate() {
         assert!(BootNotification::response().validate().is_ok());
     }
+
+    const VENDOR_REASON: &str =
+        r#"{"reason":"VendorReboot","chargingStation":{"model":"M","vendorName":"V"}}"#;
+
+    #[test]
+    #[cfg(not(feature = "lenient-enums"))]
+    fn test_request_unknown_enum_value_is_rejected() {
+        assert!(serde_json::from_str::<BootNotificationRequest>(VENDOR_REASON).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "lenient-enums")]
+    fn test_request_unknown_enum_value_is_preserved() {
+        use crate::errors::{assert_invalid_fields, assert_num_field_errors};
+
+        let req: BootNotificationRequest = serde_json::from_str(VENDOR_REASON).unwrap();
+        assert_eq!(
+            req.reason,
+            BootReasonEnumType::UnknownValue("VendorReboot".to_string())
+        );
+        assert_eq!(serde_json::to_string(&req).unwrap(), VENDOR_REASON);
+        assert_eq!(
+            BootReasonEnumType::try_from("VendorReboot".to_string()).unwrap(),
+            req.reason
+        );
+        assert_eq!(String::from(req.reason.clone()), "VendorReboot");
+
+        let err = req.validate().unwrap_err();
+        assert_num_field_errors(&err, 1);
+        assert_invalid_fields(&err, &["reason"]);
+        let OcppError::StructureValidationError { related, .. } = err else {
+            unreachable!()
+        };
+        let OcppError::FieldValidationError { related, .. } = &related[0] else {
+            panic!("expected a FieldValidationError, got {:?}", related[0])
+        };
+        assert!(matches!(
+            &related[0],
+            OcppError::InvalidEnumValueError { enum_name, value }
+                if enum_name == "BootReasonEnumType" && value == "VendorReboot"
+        ));
+
+        // Values defined by the specification are still parsed as such.
+        let req: BootNotificationRequest =
+            serde_json::from_str(&VENDOR_REASON.replace("VendorReboot", "Unknown")).unwrap();
+        assert_eq!(req.reason, BootReasonEnumType::Unknown);
+        assert_eq!(
+            BootReasonEnumType::try_from("Unknown".to_string()).unwrap(),
+            BootReasonEnumType::Unknown
+        );
+        assert!(req.validate().is_ok());
+    }
 }
@@ -1,5 +1,6 @@
 use crate::enums::cancel_reservation_status_enum_type::CancelReservationStatusEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::status_info_type::StatusInfoType;
 use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
 use serde::{Deserialize, Serialize};
@@ -20,6 +21,9 @@ impl OcppMessage for CancelReservation {
 pub struct CancelReservationRequest {
     /// Required. Id of the reservation to cancel.
     pub reservation_id: i32,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for CancelReservationRequest {
@@ -28,6 +32,10 @@ impl OcppEntity for CancelReservationRequest {
 
         b.check_bounds("reservation_id", 0, i32::MAX, self.reservation_id);
 
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("CancelReservationRequest")
     }
 }
@@ -40,7 +48,11 @@ pub struct CancelReservationResponse {
     /// Required. This indicates the success or failure of the canceling of a reservation by CSMS.
     pub status: CancelReservationStatusEnumType,
     /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for CancelReservationResponse {
@@ -51,6 +63,12 @@ impl OcppEntity for CancelReservationResponse {
             b.check_member("status_info", status_info);
         }
 
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("CancelReservationResponse")
     }
 }
@@ -1,6 +1,7 @@
 use crate::enums::certificate_signed_status_enum_type::CertificateSignedStatusEnumType;
 use crate::enums::certificate_signing_use_enum_type::CertificateSigningUseEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::status_info_type::StatusInfoType;
 use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
 use serde::{Deserialize, Serialize};
@@ -22,9 +23,14 @@ pub struct CertificateSignedRequest {
     /// Required. The signed PEM encoded X.509 certificate. This SHALL also contain the necessary sub CA certificates, when applicable. The order of the bundle follows the certificate chain, starting from the leaf certificate. The Configuration Variable `MaxCertificateChainSize` can be used to limit the maximum size of this field.
     pub certificate_chain: String,
     /// Optional. Indicates the type of the signed certificate that is returned. When omitted the certificate is used for both the 15118 connection (if implemented) and the Charging Station to CSMS connection. This field is required when a `typeOfCertificate` was included in the `SignCertificateRequest` that requested this certificate to be signed AND both the 15118 connection and the Charging Station connection are implemented.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub certificate_type: Option<CertificateSigningUseEnumType>,
     /// Optional. (2.1) RequestId to correlate this message with the `SignCertificateRequest`.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub request_id: Option<i32>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for CertificateSignedRequest {
@@ -38,6 +44,14 @@ impl OcppEntity for CertificateSignedRequest {
             &self.certificate_chain.chars(),
         );
 
+        if let Some(certificate_type) = &self.certificate_type {
+            b.check_enum("certificate_type", certificate_type);
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("CertificateSignedRequest")
     }
 }
@@ -57,7 +71,11 @@ pub struct CertificateSignedResponse {
     /// Required. Returns whether certificate signing has been accepted, otherwise rejected.
     pub status: CertificateSignedStatusEnumType,
     /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for CertificateSignedResponse {
@@ -68,6 +86,12 @@ impl OcppEntity for CertificateSignedResponse {
             b.check_member("status_info", status_info);
         }
 
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("CertificateSignedResponse")
     }
 }
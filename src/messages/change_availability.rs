@@ -1,6 +1,7 @@
 use crate::enums::change_availability_status_enum_type::ChangeAvailabilityStatusEnumType;
 use crate::enums::operational_status_enum_type::OperationalStatusEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::evse_type::EVSEType;
 use crate::structures::status_info_type::StatusInfoType;
 use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
@@ -23,7 +24,11 @@ pub struct ChangeAvailabilityRequest {
     /// Required. This contains the type of availability change that the Charging Station should perform.
     pub operational_status: OperationalStatusEnumType,
     /// Optional. Contains Id's to designate a specific EVSE/connector by index numbers. When omitted, the message refers to the Charging Station as a whole.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub evse: Option<EVSEType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for ChangeAvailabilityRequest {
@@ -35,6 +40,12 @@ impl OcppEntity for ChangeAvailabilityRequest {
             b.check_member("evse", evse);
         }
 
+        b.check_enum("operational_status", &self.operational_status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("ChangeAvailabilityRequest")
     }
 }
@@ -54,7 +65,11 @@ pub struct ChangeAvailabilityResponse {
     /// Required. This indicates whether the Charging Station is able to perform the availability change.
     pub status: ChangeAvailabilityStatusEnumType,
     /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for ChangeAvailabilityResponse {
@@ -66,6 +81,12 @@ impl OcppEntity for ChangeAvailabilityResponse {
             b.check_member("status_info", status_info);
         }
 
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("ChangeAvailabilityResponse")
     }
 }
@@ -1,5 +1,6 @@
 use crate::enums::tariff_change_status_enum_type::TariffChangeStatusEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::status_info_type::StatusInfoType;
 use crate::structures::tariff_type::TariffType;
 use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
@@ -23,6 +24,9 @@ pub struct ChangeTransactionTariffRequest {
     pub transaction_id: String,
     /// Required. New tariff to use for transaction.
     pub tariff: TariffType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for ChangeTransactionTariffRequest {
@@ -32,6 +36,10 @@ impl OcppEntity for ChangeTransactionTariffRequest {
         b.check_cardinality("transaction_id", 0, 36, &self.transaction_id.chars());
         b.check_member("tariff", &self.tariff);
 
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("ChangeTransactionTariffRequest")
     }
 }
@@ -51,7 +59,11 @@ pub struct ChangeTransactionTariffResponse {
     /// Required. Status of the operation
     pub status: TariffChangeStatusEnumType,
     /// Optional. Detailed status information
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for ChangeTransactionTariffResponse {
@@ -63,6 +75,12 @@ impl OcppEntity for ChangeTransactionTariffResponse {
             b.check_member("status_info", status_info);
         }
 
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("ChangeTransactionTariffResponse")
     }
 }
@@ -1,5 +1,6 @@
 use crate::enums::clear_cache_status_enum_type::ClearCacheStatusEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::status_info_type::StatusInfoType;
 use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
 use serde::{Deserialize, Serialize};
@@ -17,11 +18,19 @@ impl OcppMessage for ClearCache {
 /// This contains the field definition of the ClearCacheRequest PDU sent by the CSMS to the Charging Station. No fields are defined.
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub struct ClearCacheRequest {}
+pub struct ClearCacheRequest {
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
 #[typetag::serde]
 impl OcppEntity for ClearCacheRequest {
     fn validate(&self) -> Result<(), OcppError> {
-        let b = StructureValidationBuilder::new();
+        let mut b = StructureValidationBuilder::new();
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("ClearCacheRequest")
     }
 }
@@ -41,7 +50,11 @@ pub struct ClearCacheResponse {
     /// Required. Accepted if the Charging Station has executed the request, otherwise rejected.
     pub status: ClearCacheStatusEnumType,
     /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for ClearCacheResponse {
@@ -52,6 +65,12 @@ impl OcppEntity for ClearCacheResponse {
             b.check_member("status_info", status_info);
         }
 
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("ClearCacheResponse")
     }
 }
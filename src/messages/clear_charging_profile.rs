@@ -1,6 +1,7 @@
 use crate::enums::clear_charging_profile_status_enum_type::ClearChargingProfileStatusEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::clear_charging_profile_type::ClearChargingProfileType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::status_info_type::StatusInfoType;
 use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
 use serde::{Deserialize, Serialize};
@@ -20,9 +21,14 @@ impl OcppMessage for ClearChargingProfile {
 #[serde(rename_all = "camelCase")]
 pub struct ClearChargingProfileRequest {
     /// Optional. The Id of the charging profile to clear.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub charging_profile_id: Option<i32>,
     /// Optional. Specifies the charging profile.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub charging_profile_criteria: Option<ClearChargingProfileType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for ClearChargingProfileRequest {
@@ -33,6 +39,10 @@ impl OcppEntity for ClearChargingProfileRequest {
             b.check_member("charging_profile_criteria", charging_profile_criteria);
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("ClearChargingProfileRequest")
     }
 }
@@ -52,7 +62,11 @@ pub struct ClearChargingProfileResponse {
     /// Required. Indicates if the Charging Station was able to execute the request.
     pub status: ClearChargingProfileStatusEnumType,
     /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for ClearChargingProfileResponse {
@@ -63,6 +77,12 @@ impl OcppEntity for ClearChargingProfileResponse {
             b.check_member("status_info", status_info);
         }
 
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("ClearChargingProfileResponse")
     }
 }
@@ -1,6 +1,7 @@
 use crate::enums::der_control_enum_type::DERControlEnumType;
 use crate::enums::der_control_status_enum_type::DERControlStatusEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::status_info_type::StatusInfoType;
 use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
 use serde::{Deserialize, Serialize};
@@ -22,9 +23,14 @@ pub struct ClearDERControlRequest {
     /// Required. True: clearing default DER controls. False: clearing scheduled controls.
     pub is_default: bool,
     /// Optional. Name of control settings to clear. Not used when `controlId` is provided.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub control_type: Option<DERControlEnumType>,
     /// Optional. Id of control setting to clear. When omitted all settings for `controlType` are cleared.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub control_id: Option<String>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for ClearDERControlRequest {
@@ -35,6 +41,14 @@ impl OcppEntity for ClearDERControlRequest {
             b.check_cardinality("control_id", 0, 36, &control_id.chars());
         }
 
+        if let Some(control_type) = &self.control_type {
+            b.check_enum("control_type", control_type);
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("ClearDERControlRequest")
     }
 }
@@ -54,7 +68,11 @@ pub struct ClearDERControlResponse {
     /// Required. Result of operation.
     pub status: DERControlStatusEnumType,
     /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for ClearDERControlResponse {
@@ -65,6 +83,12 @@ impl OcppEntity for ClearDERControlResponse {
             b.check_member("status_info", status_info);
         }
 
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("ClearDERControlResponse")
     }
 }
@@ -1,5 +1,6 @@
 use crate::enums::clear_message_status_enum_type::ClearMessageStatusEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::status_info_type::StatusInfoType;
 use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
 use serde::{Deserialize, Serialize};
@@ -20,6 +21,9 @@ impl OcppMessage for ClearDisplayMessage {
 pub struct ClearDisplayMessageRequest {
     /// Required. Id of the message that SHALL be removed from the Charging Station.
     pub id: i32,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for ClearDisplayMessageRequest {
@@ -28,6 +32,10 @@ impl OcppEntity for ClearDisplayMessageRequest {
 
         b.check_bounds("id", 0, i32::MAX, self.id);
 
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("ClearDisplayMessageRequest")
     }
 }
@@ -47,7 +55,11 @@ pub struct ClearDisplayMessageResponse {
     /// Required. Returns whether the Charging Station has been able to remove the message.
     pub status: ClearMessageStatusEnumType,
     /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for ClearDisplayMessageResponse {
@@ -59,6 +71,12 @@ impl OcppEntity for ClearDisplayMessageResponse {
             b.check_member("status_info", status_info);
         }
 
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("ClearDisplayMessageResponse")
     }
 }
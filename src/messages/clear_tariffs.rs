@@ -1,5 +1,6 @@
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::clear_tarrifs_result_type::ClearTariffsResultType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
 use serde::{Deserialize, Serialize};
 use std::default::Default;
@@ -18,9 +19,14 @@ impl OcppMessage for ClearTariffs {
 #[serde(rename_all = "camelCase")]
 pub struct ClearTariffsRequest {
     /// Optional. List of tariff Ids to clear. When absent clears all tariffs at `evseId`.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tariff_ids: Option<Vec<String>>,
     /// Optional. When present only clear tariffs matching `tariffIds` at EVSE `evseId`.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub evse_id: Option<i32>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for ClearTariffsRequest {
@@ -37,6 +43,10 @@ impl OcppEntity for ClearTariffsRequest {
             b.check_bounds("evse_id", 0, i32::MAX, evse_id);
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("ClearTariffsRequest")
     }
 }
@@ -55,6 +65,9 @@ impl OcppRequest for ClearTariffsRequest {
 pub struct ClearTariffsResponse {
     /// Required. Result per tariff.
     pub clear_tariffs_result: Vec<ClearTariffsResultType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for ClearTariffsResponse {
@@ -69,6 +82,10 @@ impl OcppEntity for ClearTariffsResponse {
         );
         b.check_iter_member("clear_tariffs_result", self.clear_tariffs_result.iter());
 
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("ClearTariffsResponse")
     }
 }
@@ -77,6 +94,7 @@ impl Default for ClearTariffsResponse {
     fn default() -> Self {
         Self {
             clear_tariffs_result: vec![Default::default()],
+            custom_data: None,
         }
     }
 }
@@ -1,5 +1,6 @@
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::clear_monitoring_result_type::ClearMonitoringResultType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
 use serde::{Deserialize, Serialize};
 use std::default::Default;
@@ -19,6 +20,9 @@ impl OcppMessage for ClearVariableMonitoring {
 pub struct ClearVariableMonitoringRequest {
     /// Required. List of the monitors to be cleared, identified by their Id.
     pub id: Vec<i32>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for ClearVariableMonitoringRequest {
@@ -30,13 +34,20 @@ impl OcppEntity for ClearVariableMonitoringRequest {
             b.check_bounds("id", 0, i32::MAX, id);
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("ClearVariableMonitoringRequest")
     }
 }
 
 impl Default for ClearVariableMonitoringRequest {
     fn default() -> ClearVariableMonitoringRequest {
-        Self { id: vec![0] }
+        Self {
+            id: vec![0],
+            custom_data: None,
+        }
     }
 }
 
@@ -54,6 +65,9 @@ impl OcppRequest for ClearVariableMonitoringRequest {
 pub struct ClearVariableMonitoringResponse {
     /// Required. List of status per monitor.
     pub clear_monitoring_result: Vec<ClearMonitoringResultType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for ClearVariableMonitoringResponse {
@@ -71,6 +85,10 @@ impl OcppEntity for ClearVariableMonitoringResponse {
             self.clear_monitoring_result.iter(),
         );
 
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("ClearVariableMonitoringResponse")
     }
 }
@@ -79,6 +97,7 @@ impl Default for ClearVariableMonitoringResponse {
     fn default() -> Self {
         Self {
             clear_monitoring_result: vec![Default::default()],
+            custom_data: None,
         }
     }
 }
@@ -1,4 +1,5 @@
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
 use serde::{Deserialize, Serialize};
 use std::default::Default;
@@ -19,7 +20,11 @@ pub struct ClearedChargingLimitRequest {
     /// Required. Source of the charging limit. Allowed values defined in Appendix as ChargingLimitSourceEnumStringType.
     pub charging_limit_source: String,
     /// Optional. EVSE Identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub evse_id: Option<i32>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for ClearedChargingLimitRequest {
@@ -37,6 +42,10 @@ impl OcppEntity for ClearedChargingLimitRequest {
             b.check_bounds("evse_id", 0, i32::MAX, evse_id);
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("ClearedChargingLimitRequest")
     }
 }
@@ -52,11 +61,19 @@ impl OcppRequest for ClearedChargingLimitRequest {
 /// This contains the field definition of the ClearedChargingLimitResponse PDU sent by the CSMS to the Charging Station. No fields are defined.
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub struct ClearedChargingLimitResponse {}
+pub struct ClearedChargingLimitResponse {
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
 #[typetag::serde]
 impl OcppEntity for ClearedChargingLimitResponse {
     fn validate(&self) -> Result<(), OcppError> {
-        let b = StructureValidationBuilder::new();
+        let mut b = StructureValidationBuilder::new();
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("ClearedChargingLimitResponse")
     }
 }
@@ -1,4 +1,5 @@
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
 use serde::{Deserialize, Serialize};
 use std::default::Default;
@@ -14,9 +15,13 @@ impl OcppMessage for ClosePeriodicEventStream {
 /// 1.17.1. ClosePeriodicEventStreamRequest
 /// This contains the field definition of the ClosePeriodicEventStreamRequest PDU sent by the CSMS to the Charging Station.
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
 pub struct ClosePeriodicEventStreamRequest {
     /// Required. Id of stream to close.
     pub id: i32,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for ClosePeriodicEventStreamRequest {
@@ -25,6 +30,10 @@ impl OcppEntity for ClosePeriodicEventStreamRequest {
 
         b.check_bounds("id", 0, i32::MAX, self.id);
 
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("ClosePeriodicEventStreamRequest")
     }
 }
@@ -39,11 +48,22 @@ impl OcppRequest for ClosePeriodicEventStreamRequest {
 /// 1.17.2. ClosePeriodicEventStreamResponse
 /// This contains the field definition of the ClosePeriodicEventStreamResponse PDU sent by the Charging Station to the CSMS. No fields are defined in the visible part of the specification.
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
-pub struct ClosePeriodicEventStreamResponse {}
+#[serde(rename_all = "camelCase")]
+pub struct ClosePeriodicEventStreamResponse {
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
 #[typetag::serde]
 impl OcppEntity for ClosePeriodicEventStreamResponse {
     fn validate(&self) -> Result<(), OcppError> {
-        Ok(())
+        let mut e = StructureValidationBuilder::new();
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("ClosePeriodicEventStreamResponse")
     }
 }
 
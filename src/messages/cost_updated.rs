@@ -1,4 +1,5 @@
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
 use serde::{Deserialize, Serialize};
 use std::default::Default;
@@ -20,6 +21,9 @@ pub struct CostUpdatedRequest {
     pub total_cost: f64,
     /// Required. Transaction Id of the transaction the current cost are asked for.
     pub transaction_id: String,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for CostUpdatedRequest {
@@ -28,6 +32,10 @@ impl OcppEntity for CostUpdatedRequest {
 
         b.check_cardinality("transaction_id", 0, 36, &self.transaction_id.chars());
 
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("CostUpdatedRequest")
     }
 }
@@ -43,11 +51,21 @@ impl OcppRequest for CostUpdatedRequest {
 /// This contains the field definition of the CostUpdatedResponse PDU sent by the Charging Station to the CSMS in response to CostUpdatedRequest. No fields are defined.
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub struct CostUpdatedResponse {}
+pub struct CostUpdatedResponse {
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
 #[typetag::serde]
 impl OcppEntity for CostUpdatedResponse {
     fn validate(&self) -> Result<(), OcppError> {
-        Ok(())
+        let mut e = StructureValidationBuilder::new();
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("CostUpdatedResponse")
     }
 }
 
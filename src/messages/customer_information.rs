@@ -1,6 +1,7 @@
 use crate::enums::customer_information_status_enum_type::CustomerInformationStatusEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::certificate_hash_data_type::CertificateHashDataType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::id_token_type::IdTokenType;
 use crate::structures::status_info_type::StatusInfoType;
 use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
@@ -27,11 +28,17 @@ pub struct CustomerInformationRequest {
     /// Required. Flag indicating whether the Charging Station should clear all information about the customer referred to.
     pub clear: bool,
     /// Optional. A (e.g. vendor specific) identifier of the customer this request refers to. This field contains a custom identifier other than `IdToken` and `Certificate`. One of the possible identifiers (`customerIdentifier`, `idToken` or `customerCertificate`) should be in the request message.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub customer_identifier: Option<String>,
     /// Optional. The `idToken` of the customer this request refers to. One of the possible identifiers (`customerIdentifier`, `idToken` or `customerCertificate`) should be in the request message.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id_token: Option<IdTokenType>,
     /// Optional. The Certificate of the customer this request refers to. One of the possible identifiers (`customerIdentifier`, `idToken` or `customerCertificate`) should be in the request message.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub customer_certificate: Option<CertificateHashDataType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for CustomerInformationRequest {
@@ -52,6 +59,10 @@ impl OcppEntity for CustomerInformationRequest {
             b.check_member("customer_certificate", customer_certificate);
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("CustomerInformationRequest")
     }
 }
@@ -71,7 +82,11 @@ pub struct CustomerInformationResponse {
     /// Required. Indicates whether the request was accepted.
     pub status: CustomerInformationStatusEnumType,
     /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for CustomerInformationResponse {
@@ -82,6 +97,12 @@ impl OcppEntity for CustomerInformationResponse {
             b.check_member("status_info", status_info);
         }
 
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("CustomerInformationResponse")
     }
 }
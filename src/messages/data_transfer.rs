@@ -1,5 +1,6 @@
 use crate::enums::data_transfer_status_enum_type::DataTransferStatusEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::status_info_type::StatusInfoType;
 use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
 use serde::{Deserialize, Serialize};
@@ -19,12 +20,17 @@ impl OcppMessage for DataTransfer {
 #[serde(rename_all = "camelCase")]
 pub struct DataTransferRequest {
     /// Optional. May be used to indicate a specific message or implementation.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub message_id: Option<String>,
     /// Optional. Data without specified length or format. This needs to be decided by both parties (Open to implementation).
     /// Note: 'anyType' is often represented as a String or a flexible type like serde_json::Value
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub data: Option<String>,
     /// Required. This identifies the Vendor specific implementation.
     pub vendor_id: String,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for DataTransferRequest {
@@ -39,6 +45,10 @@ impl OcppEntity for DataTransferRequest {
 
         b.check_cardinality("vendor_id", 0, 255, &self.vendor_id.chars());
 
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("DataTransferRequest")
     }
 }
@@ -59,9 +69,14 @@ pub struct DataTransferResponse {
     pub status: DataTransferStatusEnumType,
     /// Optional. Data without specified length or format, in response to request.
     /// Note: 'anyType' is often represented as a String or a flexible type like serde_json::Value
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub data: Option<String>,
     /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for DataTransferResponse {
@@ -72,6 +87,12 @@ impl OcppEntity for DataTransferResponse {
             b.check_member("status_info", status_info);
         }
 
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("DataTransferResponse")
     }
 }
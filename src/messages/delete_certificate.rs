@@ -1,6 +1,7 @@
 use crate::enums::delete_certificate_status_enum_type::DeleteCertificateStatusEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::certificate_hash_data_type::CertificateHashDataType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::status_info_type::StatusInfoType;
 use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
 use serde::{Deserialize, Serialize};
@@ -21,6 +22,9 @@ impl OcppMessage for DeleteCertificate {
 pub struct DeleteCertificateRequest {
     /// Required. Indicates the certificate of which deletion is requested.
     pub certificate_hash_data: CertificateHashDataType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for DeleteCertificateRequest {
@@ -29,6 +33,10 @@ impl OcppEntity for DeleteCertificateRequest {
 
         b.check_member("certificate_hash_data", &self.certificate_hash_data);
 
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("DeleteCertificateRequest")
     }
 }
@@ -48,7 +56,11 @@ pub struct DeleteCertificateResponse {
     /// Required. Charging Station indicates if it can process the request.
     pub status: DeleteCertificateStatusEnumType,
     /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for DeleteCertificateResponse {
@@ -60,6 +72,12 @@ impl OcppEntity for DeleteCertificateResponse {
             b.check_member("status_info", status_info);
         }
 
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("DeleteCertificateResponse")
     }
 }
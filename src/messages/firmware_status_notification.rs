@@ -1,5 +1,6 @@
 use crate::enums::firmware_status_enum_type::FirmwareStatusEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::status_info_type::StatusInfoType;
 use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
 use serde::{Deserialize, Serialize};
@@ -21,9 +22,14 @@ pub struct FirmwareStatusNotificationRequest {
     /// Required. This contains the progress status of the firmware installation.
     pub status: FirmwareStatusEnumType,
     /// Optional. The request id that was provided in the UpdateFirmwareRequest that started this firmware update. This field is mandatory, unless the message was triggered by a TriggerMessageRequest AND there is no firmware update ongoing.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub request_id: Option<i32>,
     /// Optional. Detailed status info.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for FirmwareStatusNotificationRequest {
@@ -38,6 +44,12 @@ impl OcppEntity for FirmwareStatusNotificationRequest {
             b.check_member("status_info", status_info);
         }
 
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("FirmwareStatusNotificationRequest")
     }
 }
@@ -53,11 +65,19 @@ impl OcppRequest for FirmwareStatusNotificationRequest {
 /// This contains the field definition of the FirmwareStatusNotificationResponse PDU sent by the CSMS to the Charging Station in response to a FirmwareStatusNotificationRequest. No fields are defined.
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub struct FirmwareStatusNotificationResponse {}
+pub struct FirmwareStatusNotificationResponse {
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
 #[typetag::serde]
 impl OcppEntity for FirmwareStatusNotificationResponse {
     fn validate(&self) -> Result<(), OcppError> {
-        let b = StructureValidationBuilder::new();
+        let mut b = StructureValidationBuilder::new();
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("FirmwareStatusNotificationResponse")
     }
 }
@@ -1,6 +1,7 @@
 use crate::enums::certificate_action_enum_type::CertificateActionEnumType;
 use crate::enums::iso_15118_ev_certificate_status_enum_type::Iso15118EVCertificateStatusEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::status_info_type::StatusInfoType;
 use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
 use serde::{Deserialize, Serialize};
@@ -26,9 +27,14 @@ pub struct Get15118EVCertificateRequest {
     /// Required. (2.1) Raw CertificateInstallationReq request from EV, Base64 encoded.
     pub exi_request: String,
     /// Optional. (2.1) Absent during ISO 15118-2 session. Required during ISO 15118-20 session. Maximum number of contracts that EV wants to install.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub maximum_contract_certificate_chains: Option<i32>,
     /// Optional. (2.1) Absent during ISO 15118-2 session. Optional during ISO 15118-20 session. List of email IDs for which contract certificates must be requested first, in case there are more certificates than allowed by `maximumContractCertificateChains`.
+    #[serde(rename = "prioritizedEMAIDs", skip_serializing_if = "Option::is_none")]
     pub prioritized_em_aids: Option<Vec<String>>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for Get15118EVCertificateRequest {
@@ -59,6 +65,12 @@ impl OcppEntity for Get15118EVCertificateRequest {
             }
         }
 
+        b.check_enum("action", &self.action);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("Get15118EVCertificateRequest")
     }
 }
@@ -80,9 +92,14 @@ pub struct Get15118EVCertificateResponse {
     /// Required. (2/1) Raw CertificateInstallationRes response for the EV, Base64 encoded.
     pub exi_response: String,
     /// Optional. (2.1) Number of contracts that can be retrieved with additional requests.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub remaining_contracts: Option<i32>,
     /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for Get15118EVCertificateResponse {
@@ -100,6 +117,12 @@ impl OcppEntity for Get15118EVCertificateResponse {
             b.check_member("status_info", status_info);
         }
 
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("Get15118EVCertificateResponse")
     }
 }
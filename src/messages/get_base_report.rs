@@ -1,6 +1,7 @@
 use crate::enums::generic_device_model_status::GenericDeviceModelStatusEnumType;
 use crate::enums::report_base_enum_type::ReportBaseEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::status_info_type::StatusInfoType;
 use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
 use serde::{Deserialize, Serialize};
@@ -23,6 +24,9 @@ pub struct GetBaseReportRequest {
     pub request_id: i32,
     /// Required. This field specifies the report base.
     pub report_base: ReportBaseEnumType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for GetBaseReportRequest {
@@ -33,6 +37,12 @@ impl OcppEntity for GetBaseReportRequest {
         b.check_bounds("request_id", 0, i32::MAX, self.request_id);
         // `report_base` is an enum, no validation needed.
 
+        b.check_enum("report_base", &self.report_base);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("GetBaseReportRequest")
     }
 }
@@ -52,7 +62,11 @@ pub struct GetBaseReportResponse {
     /// Required. This indicates whether the Charging Station is able to accept this request.
     pub status: GenericDeviceModelStatusEnumType,
     /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for GetBaseReportResponse {
@@ -64,6 +78,12 @@ impl OcppEntity for GetBaseReportResponse {
             b.check_member("status_info", status_info);
         }
 
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("GetBaseReportResponse")
     }
 }
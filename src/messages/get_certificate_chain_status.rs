@@ -1,6 +1,7 @@
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::certificate_status_request_info_type::CertificateStatusRequestInfoType;
 use crate::structures::certificate_status_type::CertificateStatusType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
 use serde::{Deserialize, Serialize};
 use std::default::Default;
@@ -20,6 +21,9 @@ impl OcppMessage for GetCertificateChainStatus {
 pub struct GetCertificateChainStatusRequest {
     /// Required. Certificate to check revocation status for.
     pub certificate_status_requests: Vec<CertificateStatusRequestInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for GetCertificateChainStatusRequest {
@@ -37,6 +41,10 @@ impl OcppEntity for GetCertificateChainStatusRequest {
             self.certificate_status_requests.iter(),
         );
 
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("GetCertificateChainStatusRequest")
     }
 }
@@ -55,6 +63,9 @@ impl OcppRequest for GetCertificateChainStatusRequest {
 pub struct GetCertificateChainStatusResponse {
     /// Required. Status of the certificate revocation check.
     pub certificate_status: Vec<CertificateStatusType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for GetCertificateChainStatusResponse {
@@ -64,6 +75,10 @@ impl OcppEntity for GetCertificateChainStatusResponse {
         b.check_cardinality("certificate_status", 1, 4, &self.certificate_status.iter());
         b.check_iter_member("certificate_status", self.certificate_status.iter());
 
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("GetCertificateChainStatusResponse")
     }
 }
@@ -72,6 +87,7 @@ impl Default for GetCertificateChainStatusResponse {
     fn default() -> GetCertificateChainStatusResponse {
         Self {
             certificate_status: vec![Default::default()],
+            custom_data: None,
         }
     }
 }
@@ -80,6 +96,7 @@ impl Default for GetCertificateChainStatusRequest {
     fn default() -> GetCertificateChainStatusRequest {
         Self {
             certificate_status_requests: vec![Default::default()],
+            custom_data: None,
         }
     }
 }
@@ -1,5 +1,6 @@
 use crate::enums::get_certificate_status_enum_type::GetCertificateStatusEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::ocsp_request_data_type::OCSPRequestDataType;
 use crate::structures::status_info_type::StatusInfoType;
 use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
@@ -21,6 +22,9 @@ impl OcppMessage for GetCertificateStatus {
 pub struct GetCertificateStatusRequest {
     /// Required. Indicates the certificate of which the status is requested.
     pub ocsp_request_data: OCSPRequestDataType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for GetCertificateStatusRequest {
@@ -29,6 +33,10 @@ impl OcppEntity for GetCertificateStatusRequest {
 
         b.check_member("ocsp_request_data", &self.ocsp_request_data);
 
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("GetCertificateStatusRequest")
     }
 }
@@ -49,9 +57,14 @@ pub struct GetCertificateStatusResponse {
     /// Required. This indicates whether the charging station was able to retrieve the OCSP certificate status.
     pub status: GetCertificateStatusEnumType,
     /// Optional. (2.1) OCSPResponse class as defined in IETF RFC 6960, DER encoded (as defined in IETF RFC 6960), and then base64 encoded. MAY only be omitted when status is not Accepted.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ocsp_result: Option<String>,
     /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for GetCertificateStatusResponse {
@@ -67,6 +80,12 @@ impl OcppEntity for GetCertificateStatusResponse {
             b.check_member("status_info", status_info);
         }
 
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("GetCertificateStatusResponse")
     }
 }
@@ -1,6 +1,7 @@
 use crate::enums::get_charging_profile_status_enum_type::GetChargingProfileStatusEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::charging_profile_criterion_type::ChargingProfileCriterionType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::status_info_type::StatusInfoType;
 use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
 use serde::{Deserialize, Serialize};
@@ -22,9 +23,13 @@ pub struct GetChargingProfilesRequest {
     /// Required. Reference identification that is to be used by the Charging Station in the ReportChargingProfilesRequest when provided.
     pub request_id: i32,
     /// Optional. For which EVSE installed charging profiles SHALL be reported. If 0, only charging profiles installed on the Charging Station itself (the grid connection) SHALL be reported. If omitted, all installed charging profiles SHALL be reported.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub evse_id: Option<i32>,
     /// Required. Specifies the charging profile.
     pub charging_profile: ChargingProfileCriterionType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for GetChargingProfilesRequest {
@@ -41,6 +46,10 @@ impl OcppEntity for GetChargingProfilesRequest {
 
         b.check_member("charging_profile", &self.charging_profile);
 
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("GetChargingProfilesRequest")
     }
 }
@@ -61,7 +70,11 @@ pub struct GetChargingProfilesResponse {
     /// Required. This indicates whether the Charging Station is able to process this request and will send ReportChargingProfilesRequest messages.
     pub status: GetChargingProfileStatusEnumType,
     /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for GetChargingProfilesResponse {
@@ -73,6 +86,12 @@ impl OcppEntity for GetChargingProfilesResponse {
             b.check_member("status_info", status_info);
         }
 
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("GetChargingProfilesResponse")
     }
 }
@@ -2,6 +2,7 @@ use crate::enums::charging_rate_unit_enum_type::ChargingRateUnitEnumType;
 use crate::enums::generic_status_enum_type::GenericStatusEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::composite_schedule_type::CompositeScheduleType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::status_info_type::StatusInfoType;
 use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
 use serde::{Deserialize, Serialize};
@@ -23,9 +24,13 @@ pub struct GetCompositeScheduleRequest {
     /// Required. Length of the requested schedule in seconds.
     pub duration: i32,
     /// Optional. Can be used to force a power or current profile.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub charging_rate_unit: Option<ChargingRateUnitEnumType>,
     /// Required. The ID of the EVSE for which the schedule is requested. When evseId=0, the Charging Station will calculate the expected consumption for the grid connection.
     pub evse_id: i32,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for GetCompositeScheduleRequest {
@@ -39,6 +44,14 @@ impl OcppEntity for GetCompositeScheduleRequest {
         // "integer, 0 <= val"
         b.check_bounds("evse_id", 0, i32::MAX, self.evse_id);
 
+        if let Some(charging_rate_unit) = &self.charging_rate_unit {
+            b.check_enum("charging_rate_unit", charging_rate_unit);
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("GetCompositeScheduleRequest")
     }
 }
@@ -59,9 +72,14 @@ pub struct GetCompositeScheduleResponse {
     /// Required. The Charging Station will indicate if it was able to process the request.
     pub status: GenericStatusEnumType,
     /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status_info: Option<StatusInfoType>,
     /// Optional. This field contains the calculated composite schedule. It may only be omitted when this message contains status Rejected.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub schedule: Option<CompositeScheduleType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for GetCompositeScheduleResponse {
@@ -76,6 +94,12 @@ impl OcppEntity for GetCompositeScheduleResponse {
             b.check_member("schedule", schedule);
         }
 
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("GetCompositeScheduleResponse")
     }
 }
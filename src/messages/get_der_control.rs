@@ -1,6 +1,7 @@
 use crate::enums::der_control_enum_type::DERControlEnumType;
 use crate::enums::der_control_status_enum_type::DERControlStatusEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::status_info_type::StatusInfoType;
 use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
 use serde::{Deserialize, Serialize};
@@ -22,11 +23,17 @@ pub struct GetDERControlRequest {
     /// Required. RequestId to be used in ReportDERControlRequest.
     pub request_id: i32,
     /// Optional. True: get a default DER control. False: get a scheduled control.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_default: Option<bool>,
     /// Optional. Type of control settings to retrieve. Not used when `controlId` is provided.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub control_type: Option<DERControlEnumType>,
     /// Optional. Id of setting to get. When omitted all settings for `controlType` are retrieved.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub control_id: Option<String>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for GetDERControlRequest {
@@ -40,6 +47,14 @@ impl OcppEntity for GetDERControlRequest {
             b.check_cardinality("control_id", 0, 36, &control_id.chars());
         }
 
+        if let Some(control_type) = &self.control_type {
+            b.check_enum("control_type", control_type);
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("GetDERControlRequest")
     }
 }
@@ -60,7 +75,11 @@ pub struct GetDERControlResponse {
     /// Required. Result of operation.
     pub status: DERControlStatusEnumType,
     /// Optional. Detailed status info.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for GetDERControlResponse {
@@ -71,6 +90,12 @@ impl OcppEntity for GetDERControlResponse {
             b.check_member("status_info", status_info);
         }
 
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("GetDERControlResponse")
     }
 }
@@ -2,6 +2,7 @@ use crate::enums::get_display_messages_status_enum_type::GetDisplayMessagesStatu
 use crate::enums::message_priority_enum_type::MessagePriorityEnumType;
 use crate::enums::message_state_enum_type::MessageStateEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::status_info_type::StatusInfoType;
 use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
 use serde::{Deserialize, Serialize};
@@ -21,13 +22,19 @@ impl OcppMessage for GetDisplayMessages {
 #[serde(rename_all = "camelCase")]
 pub struct GetDisplayMessagesRequest {
     /// Optional. If provided the Charging Station shall return Display Messages of the given Ids. This field SHALL NOT contain more Ids than set in NumberOfDisplayMessages.maxLimit.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<Vec<i32>>,
     /// Required. The Id of this request.
     pub request_id: i32,
     /// Optional. If provided the Charging Station shall return Display Messages with the given priority only.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub priority: Option<MessagePriorityEnumType>,
     /// Optional. If provided the Charging Station shall return Display Messages with the given state only.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub state: Option<MessageStateEnumType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for GetDisplayMessagesRequest {
@@ -42,6 +49,17 @@ impl OcppEntity for GetDisplayMessagesRequest {
         }
 
         b.check_bounds("request_id", 0, i32::MAX, self.request_id);
+        if let Some(priority) = &self.priority {
+            b.check_enum("priority", priority);
+        }
+        if let Some(state) = &self.state {
+            b.check_enum("state", state);
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("GetDisplayMessagesRequest")
     }
 }
@@ -62,7 +80,11 @@ pub struct GetDisplayMessagesResponse {
     /// Required. Indicates if the Charging Station has Display Messages that match the request criteria in the GetDisplayMessagesRequest.
     pub status: GetDisplayMessagesStatusEnumType,
     /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for GetDisplayMessagesResponse {
@@ -73,6 +95,12 @@ impl OcppEntity for GetDisplayMessagesResponse {
             b.check_member("status_info", status_info);
         }
 
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("GetDisplayMessagesResponse")
     }
 }
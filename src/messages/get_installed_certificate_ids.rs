@@ -2,6 +2,7 @@ use crate::enums::get_certificate_id_use_enum_type::GetCertificateIdUseEnumType;
 use crate::enums::get_installed_certificate_status_enum_type::GetInstalledCertificateStatusEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::certificate_hash_data_chain_type::CertificateHashDataChainType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::status_info_type::StatusInfoType;
 use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
 use serde::{Deserialize, Serialize};
@@ -21,7 +22,11 @@ impl OcppMessage for GetInstalledCertificateIds {
 #[serde(rename_all = "camelCase")]
 pub struct GetInstalledCertificateIdsRequest {
     /// Optional. Indicates the type of certificates requested. When omitted, all certificate types are requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub certificate_type: Option<Vec<GetCertificateIdUseEnumType>>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for GetInstalledCertificateIdsRequest {
@@ -32,6 +37,14 @@ impl OcppEntity for GetInstalledCertificateIdsRequest {
             b.check_cardinality("certificate_type", 0, usize::MAX, &certificate_types.iter());
         }
 
+        if let Some(certificate_type) = &self.certificate_type {
+            b.check_iter_enum("certificate_type", certificate_type.iter());
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("GetInstalledCertificateIdsRequest")
     }
 }
@@ -52,9 +65,14 @@ pub struct GetInstalledCertificateIdsResponse {
     /// Required. Charging Station indicates if it can process the request.
     pub status: GetInstalledCertificateStatusEnumType,
     /// Optional. The Charging Station includes the Certificate information for each available certificate.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub certificate_hash_data_chain: Option<Vec<CertificateHashDataChainType>>,
     /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for GetInstalledCertificateIdsResponse {
@@ -74,6 +92,12 @@ impl OcppEntity for GetInstalledCertificateIdsResponse {
             b.check_member("status_info", status_info);
         }
 
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("GetInstalledCertificateIdsResponse")
     }
 }
@@ -113,4 +137,34 @@ mod tests {
     fn test_response_validate() {
         assert!(GetInstalledCertificateIds::response().validate().is_ok());
     }
+
+    #[test]
+    #[cfg(feature = "lenient-enums")]
+    fn test_request_unknown_certificate_types_are_rejected() {
+        use crate::errors::{assert_invalid_fields, assert_num_field_errors};
+
+        let req: GetInstalledCertificateIdsRequest = serde_json::from_str(
+            r#"{"certificateType":["V2GRootCertificate","VendorRootCertificate"]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            req.certificate_type.as_ref().unwrap()[1],
+            GetCertificateIdUseEnumType::Unknown("VendorRootCertificate".to_string())
+        );
+
+        let err = req.validate().unwrap_err();
+        assert_num_field_errors(&err, 1);
+        assert_invalid_fields(&err, &["certificate_type[1]"]);
+        let OcppError::StructureValidationError { related, .. } = err else {
+            unreachable!()
+        };
+        let OcppError::FieldValidationError { related, .. } = &related[0] else {
+            panic!("expected a FieldValidationError, got {:?}", related[0])
+        };
+        assert!(matches!(
+            &related[0],
+            OcppError::InvalidEnumValueError { enum_name, value }
+                if enum_name == "GetCertificateIdUseEnumType" && value == "VendorRootCertificate"
+        ));
+    }
 }
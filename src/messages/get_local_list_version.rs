@@ -1,4 +1,5 @@
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
 use serde::{Deserialize, Serialize};
 use std::default::Default;
@@ -15,11 +16,21 @@ impl OcppMessage for GetLocalListVersion {
 /// This contains the field definition of the GetLocalListVersionRequest PDU sent by the CSMS to the Charging Station. No fields are defined.
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub struct GetLocalListVersionRequest {}
+pub struct GetLocalListVersionRequest {
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
 #[typetag::serde]
 impl OcppEntity for GetLocalListVersionRequest {
     fn validate(&self) -> Result<(), OcppError> {
-        Ok(())
+        let mut e = StructureValidationBuilder::new();
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("GetLocalListVersionRequest")
     }
 }
 
@@ -38,6 +49,9 @@ impl OcppRequest for GetLocalListVersionRequest {
 pub struct GetLocalListVersionResponse {
     /// Required. This contains the current version number of the local authorization list in the Charging Station.
     pub version_number: i32,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for GetLocalListVersionResponse {
@@ -47,6 +61,10 @@ impl OcppEntity for GetLocalListVersionResponse {
         // versionNumber is a required integer, assuming non-negative for a version number
         b.check_bounds("version_number", 0, i32::MAX, self.version_number);
 
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("GetLocalListVersionResponse")
     }
 }
@@ -1,6 +1,7 @@
 use crate::enums::log_enum_type::LogEnumType;
 use crate::enums::log_status_enum_type::LogStatusEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::log_parameters_type::LogParametersType;
 use crate::structures::status_info_type::StatusInfoType;
 use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
@@ -25,11 +26,16 @@ pub struct GetLogRequest {
     /// Required. The Id of this request.
     pub request_id: i32,
     /// Optional. This specifies how many times the Charging Station must retry to upload the log before giving up. If this field is not present, it is left to Charging Station to decide how many times it wants to retry. If the value is 0, it means: no retries.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub retries: Option<i32>,
     /// Optional. The interval in seconds after which a retry may be attempted. If this field is not present, it is left to Charging Station to decide how long to wait between attempts.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub retry_interval: Option<i32>,
     /// Required. This field specifies the requested log and the location to which the log should be sent.
     pub log: LogParametersType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for GetLogRequest {
@@ -48,6 +54,12 @@ impl OcppEntity for GetLogRequest {
 
         b.check_member("log", &self.log);
 
+        b.check_enum("log_type", &self.log_type);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("GetLogRequest")
     }
 }
@@ -68,9 +80,14 @@ pub struct GetLogResponse {
     /// Required. This field indicates whether the Charging Station was able to accept the request.
     pub status: LogStatusEnumType,
     /// Optional. This contains the name of the log file that will be uploaded. This field is not present when no logging information is available.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub filename: Option<String>,
     /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for GetLogResponse {
@@ -85,6 +102,12 @@ impl OcppEntity for GetLogResponse {
             b.check_member("status_info", status_info);
         }
 
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("GetLogResponse")
     }
 }
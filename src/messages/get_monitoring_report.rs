@@ -2,6 +2,7 @@ use crate::enums::generic_device_model_status::GenericDeviceModelStatusEnumType;
 use crate::enums::monitoring_criterion_enum_type::MonitoringCriterionEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::component_variable_type::ComponentVariableType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::status_info_type::StatusInfoType;
 use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
 use serde::{Deserialize, Serialize};
@@ -23,9 +24,14 @@ pub struct GetMonitoringReportRequest {
     /// Required. The Id of the request.
     pub request_id: i32,
     /// Optional. This field contains criteria for components for which a monitoring report is requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub monitoring_criteria: Option<Vec<MonitoringCriterionEnumType>>,
     /// Optional. This field specifies the components and variables for which a monitoring report is requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub component_variable: Option<Vec<ComponentVariableType>>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for GetMonitoringReportRequest {
@@ -44,6 +50,14 @@ impl OcppEntity for GetMonitoringReportRequest {
             b.check_iter_member("component_variable", variables.iter());
         }
 
+        if let Some(monitoring_criteria) = &self.monitoring_criteria {
+            b.check_iter_enum("monitoring_criteria", monitoring_criteria.iter());
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("GetMonitoringReportRequest")
     }
 }
@@ -64,7 +78,11 @@ pub struct GetMonitoringReportResponse {
     /// Required. This field indicates whether the Charging Station was able to accept the request.
     pub status: GenericDeviceModelStatusEnumType,
     /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for GetMonitoringReportResponse {
@@ -75,6 +93,12 @@ impl OcppEntity for GetMonitoringReportResponse {
             b.check_member("status_info", status_info);
         }
 
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("GetMonitoringReportResponse")
     }
 }
@@ -1,5 +1,6 @@
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::constant_stream_data_type::ConstantStreamDataType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
 use serde::{Deserialize, Serialize};
 use std::default::Default;
@@ -16,11 +17,21 @@ impl OcppMessage for GetPeriodicEventStream {
 /// This contains the field definition of the GetPeriodicEventStreamRequest PDU sent by the CSMS to the Charging Station. No fields are defined.
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub struct GetPeriodicEventStreamRequest {}
+pub struct GetPeriodicEventStreamRequest {
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
 #[typetag::serde]
 impl OcppEntity for GetPeriodicEventStreamRequest {
     fn validate(&self) -> Result<(), OcppError> {
-        Ok(())
+        let mut e = StructureValidationBuilder::new();
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("GetPeriodicEventStreamRequest")
     }
 }
 
@@ -38,7 +49,11 @@ impl OcppRequest for GetPeriodicEventStreamRequest {
 #[serde(rename_all = "camelCase")]
 pub struct GetPeriodicEventStreamResponse {
     /// Optional. List of constant part of streams
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub constant_stream_data: Option<Vec<ConstantStreamDataType>>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for GetPeriodicEventStreamResponse {
@@ -52,6 +67,10 @@ impl OcppEntity for GetPeriodicEventStreamResponse {
             b.check_iter_member("constant_stream_data", stream_data.iter());
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("GetPeriodicEventStreamResponse")
     }
 }
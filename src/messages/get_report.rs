@@ -2,6 +2,7 @@ use crate::enums::component_criterion_enum_type::ComponentCriterionEnumType;
 use crate::enums::generic_device_model_status::GenericDeviceModelStatusEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::component_variable_type::ComponentVariableType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::status_info_type::StatusInfoType;
 use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
 use serde::{Deserialize, Serialize};
@@ -23,9 +24,14 @@ pub struct GetReportRequest {
     /// Required. The Id of the request.
     pub request_id: i32,
     /// Optional. This field contains criteria for components for which a report is requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub component_criteria: Option<Vec<ComponentCriterionEnumType>>,
     /// Optional. This field specifies the components and variables for which a report is requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub component_variable: Option<Vec<ComponentVariableType>>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for GetReportRequest {
@@ -44,6 +50,14 @@ impl OcppEntity for GetReportRequest {
             b.check_iter_member("component_variable", variables.iter());
         }
 
+        if let Some(component_criteria) = &self.component_criteria {
+            b.check_iter_enum("component_criteria", component_criteria.iter());
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("GetReportRequest")
     }
 }
@@ -64,7 +78,11 @@ pub struct GetReportResponse {
     /// Required. This field indicates whether the Charging Station was able to accept the request.
     pub status: GenericDeviceModelStatusEnumType,
     /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for GetReportResponse {
@@ -75,6 +93,12 @@ impl OcppEntity for GetReportResponse {
             b.check_member("status_info", status_info);
         }
 
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("GetReportResponse")
     }
 }
@@ -1,5 +1,6 @@
 use crate::enums::tariff_get_status_enum_type::TariffGetStatusEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::status_info_type::StatusInfoType;
 use crate::structures::tariff_assignment_type::TariffAssignmentType;
 use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
@@ -21,6 +22,9 @@ impl OcppMessage for GetTariffs {
 pub struct GetTariffsRequest {
     /// Required. EVSE id to get tariff from. When evseId = 0, this gets tariffs from all EVSEs.
     pub evse_id: i32,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for GetTariffsRequest {
@@ -30,6 +34,10 @@ impl OcppEntity for GetTariffsRequest {
         // evseId is integer, 0 <= val
         b.check_bounds("evse_id", 0, i32::MAX, self.evse_id);
 
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("GetTariffsRequest")
     }
 }
@@ -50,9 +58,14 @@ pub struct GetTariffsResponse {
     /// Required. Status of operation.
     pub status: TariffGetStatusEnumType,
     /// Optional. Installed default and user-specific tariffs per EVSE.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tariff_assignments: Option<Vec<TariffAssignmentType>>,
     /// Optional. Details status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for GetTariffsResponse {
@@ -70,6 +83,12 @@ impl OcppEntity for GetTariffsResponse {
             b.check_member("status_info", status_info);
         }
 
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
         b.build("GetTariffsResponse")
     }
 }
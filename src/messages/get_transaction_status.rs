@@ -0,0 +1,125 @@
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.38. GetTransactionStatus
+pub struct GetTransactionStatus;
+
+impl OcppMessage for GetTransactionStatus {
+    type Request = GetTransactionStatusRequest;
+    type Response = GetTransactionStatusResponse;
+}
+
+/// 1.38.1. GetTransactionStatusRequest
+/// This contains the field definition of the GetTransactionStatusRequest PDU sent by the CSMS to the Charging Station.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTransactionStatusRequest {
+    /// Optional. The Id of the transaction for which the status is requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_id: Option<String>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for GetTransactionStatusRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(transaction_id) = &self.transaction_id {
+            b.check_cardinality("transaction_id", 0, 36, &transaction_id.chars());
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("GetTransactionStatusRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for GetTransactionStatusRequest {
+    fn get_message_type(&self) -> String {
+        String::from("GetTransactionStatus")
+    }
+}
+
+/// 1.38.2. GetTransactionStatusResponse
+/// This contains the field definition of the GetTransactionStatusResponse PDU sent by the Charging Station to the CSMS in response
+/// to a GetTransactionStatusRequest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTransactionStatusResponse {
+    /// Optional. Whether the transaction is still ongoing.
+    /// Only present when a transactionId was given in the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ongoing_indicator: Option<bool>,
+    /// Required. Whether there are still message to be delivered.
+    pub messages_in_queue: bool,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for GetTransactionStatusResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut e = StructureValidationBuilder::new();
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("GetTransactionStatusResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_transaction_status() {
+        let req = GetTransactionStatus::request();
+        let resp = GetTransactionStatus::response();
+
+        assert!(req.validate().is_ok());
+        assert!(resp.validate().is_ok());
+    }
+
+    #[test]
+    fn test_get_transaction_status_request_transaction_id_long() {
+        let mut req = GetTransactionStatus::request();
+        req.transaction_id = Some("a".repeat(36));
+        assert!(req.validate().is_ok());
+        req.transaction_id = Some("a".repeat(37));
+        assert!(req.validate().is_err());
+    }
+
+    #[test]
+    fn test_get_transaction_status_request_serialize_deserialize() {
+        let req = GetTransactionStatusRequest {
+            transaction_id: Some("tx-1".to_string()),
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        let deserialized: GetTransactionStatusRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+
+    #[test]
+    fn test_get_transaction_status_response_serialize_deserialize() {
+        let resp = GetTransactionStatusResponse {
+            ongoing_indicator: Some(true),
+            messages_in_queue: false,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&resp).unwrap();
+        assert!(serialized.contains("\"messagesInQueue\":false"));
+        let deserialized: GetTransactionStatusResponse = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
@@ -0,0 +1,149 @@
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::get_variable_data_type::GetVariableDataType;
+use crate::structures::get_variable_result_type::GetVariableResultType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.39. GetVariables
+pub struct GetVariables;
+
+impl OcppMessage for GetVariables {
+    type Request = GetVariablesRequest;
+    type Response = GetVariablesResponse;
+}
+
+/// 1.39.1. GetVariablesRequest
+/// This contains the field definition of the GetVariablesRequest PDU sent by the CSMS to the Charging Station.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GetVariablesRequest {
+    /// Required. List of requested variables.
+    pub get_variable_data: Vec<GetVariableDataType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for GetVariablesRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_cardinality(
+            "get_variable_data",
+            1,
+            usize::MAX,
+            &self.get_variable_data.iter(),
+        );
+        b.check_iter_member("get_variable_data", self.get_variable_data.iter());
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("GetVariablesRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for GetVariablesRequest {
+    fn get_message_type(&self) -> String {
+        String::from("GetVariables")
+    }
+}
+
+impl Default for GetVariablesRequest {
+    fn default() -> GetVariablesRequest {
+        Self {
+            get_variable_data: vec![Default::default()],
+            custom_data: None,
+        }
+    }
+}
+
+/// 1.39.2. GetVariablesResponse
+/// This contains the field definition of the GetVariablesResponse PDU sent by the Charging Station to the CSMS in response to
+/// GetVariablesRequest.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GetVariablesResponse {
+    /// Required. List of requested variables and their values.
+    pub get_variable_result: Vec<GetVariableResultType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for GetVariablesResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_cardinality(
+            "get_variable_result",
+            1,
+            usize::MAX,
+            &self.get_variable_result.iter(),
+        );
+        b.check_iter_member("get_variable_result", self.get_variable_result.iter());
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("GetVariablesResponse")
+    }
+}
+
+impl Default for GetVariablesResponse {
+    fn default() -> GetVariablesResponse {
+        Self {
+            get_variable_result: vec![Default::default()],
+            custom_data: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_get_variables() {
+        assert!(GetVariables::request().validate().is_ok());
+        assert!(GetVariables::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_get_variables_empty() {
+        let req = GetVariablesRequest {
+            get_variable_data: vec![],
+            custom_data: None,
+        };
+        assert_invalid_fields(&req.validate().unwrap_err(), &["get_variable_data"]);
+
+        let resp = GetVariablesResponse {
+            get_variable_result: vec![],
+            custom_data: None,
+        };
+        assert_invalid_fields(&resp.validate().unwrap_err(), &["get_variable_result"]);
+    }
+
+    #[test]
+    fn test_get_variables_request_serialize_deserialize() {
+        let req = GetVariables::request();
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("getVariableData"));
+        let deserialized: GetVariablesRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+
+    #[test]
+    fn test_get_variables_response_serialize_deserialize() {
+        let resp = GetVariables::response();
+        let serialized = serde_json::to_string(&resp).unwrap();
+        let deserialized: GetVariablesResponse = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
@@ -0,0 +1,119 @@
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.40. Heartbeat
+pub struct Heartbeat;
+
+impl OcppMessage for Heartbeat {
+    type Request = HeartbeatRequest;
+    type Response = HeartbeatResponse;
+}
+
+/// 1.40.1. HeartbeatRequest
+/// This contains the field definition of the HeartbeatRequest PDU sent by the Charging Station to the CSMS. No fields are defined.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct HeartbeatRequest {
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for HeartbeatRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut e = StructureValidationBuilder::new();
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("HeartbeatRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for HeartbeatRequest {
+    fn get_message_type(&self) -> String {
+        String::from("Heartbeat")
+    }
+}
+
+/// 1.40.2. HeartbeatResponse
+/// This contains the field definition of the HeartbeatResponse PDU sent by the CSMS to the Charging Station in response to a
+/// HeartbeatRequest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct HeartbeatResponse {
+    /// Required. Contains the current time of the CSMS.
+    pub current_time: DateTime<Utc>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for HeartbeatResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut e = StructureValidationBuilder::new();
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("HeartbeatResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::{assert_invalid_fields, assert_num_field_errors};
+
+    #[test]
+    fn test_heartbeat() {
+        assert!(Heartbeat::request().validate().is_ok());
+        assert!(Heartbeat::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_heartbeat_request_serialize_deserialize() {
+        let req = Heartbeat::request();
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert_eq!(serialized, "{}");
+        let deserialized: HeartbeatRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+
+    #[test]
+    fn test_heartbeat_response_serialize_deserialize() {
+        let resp = HeartbeatResponse {
+            current_time: Utc::now(),
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&resp).unwrap();
+        assert!(serialized.contains("currentTime"));
+        let deserialized: HeartbeatResponse = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+
+    #[test]
+    fn test_heartbeat_request_custom_data() {
+        let serialized = r#"{"customData":{"vendorId":"com.example","uptime":3600}}"#;
+        let req: HeartbeatRequest = serde_json::from_str(serialized).unwrap();
+        let custom_data = req.custom_data.as_ref().unwrap();
+        assert_eq!(custom_data.vendor_id, "com.example");
+        assert_eq!(custom_data.get::<u32>("uptime").unwrap().unwrap(), 3600);
+        assert!(req.validate().is_ok());
+        assert_eq!(serde_json::to_string(&req).unwrap(), serialized);
+
+        let req = HeartbeatRequest {
+            custom_data: Some(CustomDataType::new(&"a".repeat(256))),
+        };
+        let err = req.validate().unwrap_err();
+        assert_num_field_errors(&err, 1);
+        assert_invalid_fields(&err, &["custom_data"]);
+    }
+}
@@ -0,0 +1,132 @@
+use crate::enums::install_certificate_status_enum_type::InstallCertificateStatusEnumType;
+use crate::enums::install_certificate_use_enum_type::InstallCertificateUseEnumType;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::status_info_type::StatusInfoType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.41. InstallCertificate
+pub struct InstallCertificate;
+
+impl OcppMessage for InstallCertificate {
+    type Request = InstallCertificateRequest;
+    type Response = InstallCertificateResponse;
+}
+
+/// 1.41.1. InstallCertificateRequest
+/// This contains the field definition of the InstallCertificateRequest PDU sent by the CSMS to the Charging Station.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct InstallCertificateRequest {
+    /// Required. Indicates the certificate type that is sent.
+    pub certificate_type: InstallCertificateUseEnumType,
+    /// Required. A PEM encoded X.509 certificate.
+    pub certificate: String,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for InstallCertificateRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_cardinality("certificate", 0, 10000, &self.certificate.chars());
+
+        b.check_enum("certificate_type", &self.certificate_type);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("InstallCertificateRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for InstallCertificateRequest {
+    fn get_message_type(&self) -> String {
+        String::from("InstallCertificate")
+    }
+}
+
+/// 1.41.2. InstallCertificateResponse
+/// This contains the field definition of the InstallCertificateResponse PDU sent by the Charging Station to the CSMS in response
+/// to InstallCertificateRequest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct InstallCertificateResponse {
+    /// Required. Charging Station indicates if installation was successful.
+    pub status: InstallCertificateStatusEnumType,
+    /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for InstallCertificateResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(status_info) = &self.status_info {
+            b.check_member("status_info", status_info);
+        }
+
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("InstallCertificateResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_install_certificate() {
+        assert!(InstallCertificate::request().validate().is_ok());
+        assert!(InstallCertificate::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_install_certificate_request_certificate_long() {
+        let req = InstallCertificateRequest {
+            certificate: "a".repeat(10001),
+            ..Default::default()
+        };
+        assert!(req.validate().is_err());
+    }
+
+    #[test]
+    fn test_install_certificate_request_serialize_deserialize() {
+        let req = InstallCertificateRequest {
+            certificate_type: InstallCertificateUseEnumType::CSMSRootCertificate,
+            certificate: "-----BEGIN CERTIFICATE-----".to_string(),
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("\"certificateType\":\"CSMSRootCertificate\""));
+        let deserialized: InstallCertificateRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+
+    #[test]
+    fn test_install_certificate_response_serialize_deserialize() {
+        let resp = InstallCertificateResponse {
+            status: InstallCertificateStatusEnumType::Failed,
+            status_info: None,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&resp).unwrap();
+        let deserialized: InstallCertificateResponse = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
@@ -0,0 +1,129 @@
+use crate::enums::upload_log_status_enum_type::UploadLogStatusEnumType;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::status_info_type::StatusInfoType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.42. LogStatusNotification
+pub struct LogStatusNotification;
+
+impl OcppMessage for LogStatusNotification {
+    type Request = LogStatusNotificationRequest;
+    type Response = LogStatusNotificationResponse;
+}
+
+/// 1.42.1. LogStatusNotificationRequest
+/// This contains the field definition of the LogStatusNotificationRequest PDU sent by the Charging Station to the CSMS.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LogStatusNotificationRequest {
+    /// Required. This contains the status of the log upload.
+    pub status: UploadLogStatusEnumType,
+    /// Optional. The request id that was provided in GetLogRequest that started this log upload. This field is mandatory,
+    /// unless the message was triggered by a TriggerMessageRequest AND there is no log upload ongoing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<i32>,
+    /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for LogStatusNotificationRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(request_id) = self.request_id {
+            b.check_bounds("request_id", 0, i32::MAX, request_id);
+        }
+
+        if let Some(status_info) = &self.status_info {
+            b.check_member("status_info", status_info);
+        }
+
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("LogStatusNotificationRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for LogStatusNotificationRequest {
+    fn get_message_type(&self) -> String {
+        String::from("LogStatusNotification")
+    }
+}
+
+/// 1.42.2. LogStatusNotificationResponse
+/// This contains the field definition of the LogStatusNotificationResponse PDU sent by the CSMS to the Charging Station in
+/// response to LogStatusNotificationRequest. No fields are defined.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LogStatusNotificationResponse {
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for LogStatusNotificationResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut e = StructureValidationBuilder::new();
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("LogStatusNotificationResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_status_notification() {
+        assert!(LogStatusNotification::request().validate().is_ok());
+        assert!(LogStatusNotification::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_log_status_notification_request_negative_request_id() {
+        let req = LogStatusNotificationRequest {
+            request_id: Some(-1),
+            ..Default::default()
+        };
+        assert!(req.validate().is_err());
+    }
+
+    #[test]
+    fn test_log_status_notification_request_serialize_deserialize() {
+        let req = LogStatusNotificationRequest {
+            status: UploadLogStatusEnumType::Uploaded,
+            request_id: Some(5),
+            status_info: None,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("\"status\":\"Uploaded\""));
+        let deserialized: LogStatusNotificationRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+
+    #[test]
+    fn test_log_status_notification_response_serialize_deserialize() {
+        let resp = LogStatusNotification::response();
+        let serialized = serde_json::to_string(&resp).unwrap();
+        let deserialized: LogStatusNotificationResponse =
+            serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
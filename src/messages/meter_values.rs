@@ -0,0 +1,137 @@
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::meter_value_type::MeterValueType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.43. MeterValues
+pub struct MeterValues;
+
+impl OcppMessage for MeterValues {
+    type Request = MeterValuesRequest;
+    type Response = MeterValuesResponse;
+}
+
+/// 1.43.1. MeterValuesRequest
+/// This contains the field definition of the MeterValuesRequest PDU sent by the Charging Station to the CSMS.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MeterValuesRequest {
+    /// Required. This contains a number (>0) designating an EVSE of the Charging Station.
+    /// '0' (zero) is used to designate the main power meter.
+    pub evse_id: i32,
+    /// Required. The sampled meter values with timestamps.
+    pub meter_value: Vec<MeterValueType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for MeterValuesRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_bounds("evse_id", 0, i32::MAX, self.evse_id);
+        b.check_cardinality("meter_value", 1, usize::MAX, &self.meter_value.iter());
+        b.check_iter_member("meter_value", self.meter_value.iter());
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("MeterValuesRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for MeterValuesRequest {
+    fn get_message_type(&self) -> String {
+        String::from("MeterValues")
+    }
+}
+
+/// 1.43.2. MeterValuesResponse
+/// This contains the field definition of the MeterValuesResponse PDU sent by the CSMS to the Charging Station in response to
+/// MeterValuesRequest. No fields are defined.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MeterValuesResponse {
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for MeterValuesResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut e = StructureValidationBuilder::new();
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("MeterValuesResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+    use chrono::Utc;
+
+    fn valid_request() -> MeterValuesRequest {
+        MeterValuesRequest {
+            evse_id: 1,
+            meter_value: vec![MeterValueType {
+                timestamp: Utc::now(),
+                sampled_value: vec![Default::default()],
+                custom_data: None,
+            }],
+            custom_data: None,
+        }
+    }
+
+    #[test]
+    fn test_meter_values() {
+        assert!(valid_request().validate().is_ok());
+        assert!(MeterValues::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_meter_values_request_empty_meter_value() {
+        let req = MeterValues::request();
+        assert_invalid_fields(&req.validate().unwrap_err(), &["meter_value"]);
+    }
+
+    #[test]
+    fn test_meter_values_request_negative_evse_id() {
+        let mut req = valid_request();
+        req.evse_id = -1;
+        assert_invalid_fields(&req.validate().unwrap_err(), &["evse_id"]);
+    }
+
+    #[test]
+    fn test_meter_values_request_invalid_meter_value() {
+        let mut req = valid_request();
+        req.meter_value[0].sampled_value.clear();
+        assert_invalid_fields(&req.validate().unwrap_err(), &["meter_value[0]"]);
+    }
+
+    #[test]
+    fn test_meter_values_request_serialize_deserialize() {
+        let req = valid_request();
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("\"evseId\":1"));
+        let deserialized: MeterValuesRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+
+    #[test]
+    fn test_meter_values_response_serialize_deserialize() {
+        let resp = MeterValues::response();
+        let serialized = serde_json::to_string(&resp).unwrap();
+        let deserialized: MeterValuesResponse = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
@@ -0,0 +1,149 @@
+use crate::enums::energy_transfer_mode_enum_type::EnergyTransferModeEnumType;
+use crate::enums::notify_allowed_energy_transfer_status_enum_type::NotifyAllowedEnergyTransferStatusEnumType;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::status_info_type::StatusInfoType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.44. NotifyAllowedEnergyTransfer
+pub struct NotifyAllowedEnergyTransfer;
+
+impl OcppMessage for NotifyAllowedEnergyTransfer {
+    type Request = NotifyAllowedEnergyTransferRequest;
+    type Response = NotifyAllowedEnergyTransferResponse;
+}
+
+/// 1.44.1. NotifyAllowedEnergyTransferRequest
+/// (2.1) This contains the field definition of the NotifyAllowedEnergyTransferRequest PDU sent by the CSMS to the Charging
+/// Station.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyAllowedEnergyTransferRequest {
+    /// Required. The transaction for which the allowed energy transfer modes apply.
+    pub transaction_id: String,
+    /// Required. Modes of energy transfer that are accepted by CSMS.
+    pub allowed_energy_transfer: Vec<EnergyTransferModeEnumType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for NotifyAllowedEnergyTransferRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_cardinality("transaction_id", 0, 36, &self.transaction_id.chars());
+        b.check_cardinality(
+            "allowed_energy_transfer",
+            1,
+            usize::MAX,
+            &self.allowed_energy_transfer.iter(),
+        );
+
+        b.check_iter_enum(
+            "allowed_energy_transfer",
+            self.allowed_energy_transfer.iter(),
+        );
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("NotifyAllowedEnergyTransferRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for NotifyAllowedEnergyTransferRequest {
+    fn get_message_type(&self) -> String {
+        String::from("NotifyAllowedEnergyTransfer")
+    }
+}
+
+impl Default for NotifyAllowedEnergyTransferRequest {
+    fn default() -> NotifyAllowedEnergyTransferRequest {
+        Self {
+            transaction_id: "".to_string(),
+            allowed_energy_transfer: vec![EnergyTransferModeEnumType::DC],
+            custom_data: None,
+        }
+    }
+}
+
+/// 1.44.2. NotifyAllowedEnergyTransferResponse
+/// (2.1) This contains the field definition of the NotifyAllowedEnergyTransferResponse PDU sent by the Charging Station to the
+/// CSMS in response to NotifyAllowedEnergyTransferRequest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyAllowedEnergyTransferResponse {
+    /// Required. Accepted or Rejected.
+    pub status: NotifyAllowedEnergyTransferStatusEnumType,
+    /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for NotifyAllowedEnergyTransferResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(status_info) = &self.status_info {
+            b.check_member("status_info", status_info);
+        }
+
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("NotifyAllowedEnergyTransferResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_notify_allowed_energy_transfer() {
+        assert!(NotifyAllowedEnergyTransfer::request().validate().is_ok());
+        assert!(NotifyAllowedEnergyTransfer::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_notify_allowed_energy_transfer_invalid() {
+        let req = NotifyAllowedEnergyTransferRequest {
+            transaction_id: "a".repeat(37),
+            allowed_energy_transfer: vec![],
+            custom_data: None,
+        };
+        assert_invalid_fields(
+            &req.validate().unwrap_err(),
+            &["transaction_id", "allowed_energy_transfer"],
+        );
+    }
+
+    #[test]
+    fn test_notify_allowed_energy_transfer_serialize_deserialize() {
+        let req = NotifyAllowedEnergyTransferRequest {
+            transaction_id: "tx-1".to_string(),
+            allowed_energy_transfer: vec![
+                EnergyTransferModeEnumType::AC_three_phase,
+                EnergyTransferModeEnumType::DC,
+            ],
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("\"allowedEnergyTransfer\":[\"AC_three_phase\",\"DC\"]"));
+        let deserialized: NotifyAllowedEnergyTransferRequest =
+            serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+}
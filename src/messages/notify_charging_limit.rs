@@ -0,0 +1,149 @@
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::charging_limit_type::ChargingLimitType;
+use crate::structures::charging_schedule_type::ChargingScheduleType;
+use crate::structures::custom_data_type::CustomDataType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.45. NotifyChargingLimit
+pub struct NotifyChargingLimit;
+
+impl OcppMessage for NotifyChargingLimit {
+    type Request = NotifyChargingLimitRequest;
+    type Response = NotifyChargingLimitResponse;
+}
+
+/// 1.45.1. NotifyChargingLimitRequest
+/// This contains the field definition of the NotifyChargingLimitRequest PDU sent by the Charging Station to the CSMS.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyChargingLimitRequest {
+    /// Optional. The EVSE to which the charging limit is set. If absent or when zero, it applies to the entire Charging Station.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evse_id: Option<i32>,
+    /// Required. This contains the source of the charging limit and whether it is grid critical.
+    pub charging_limit: ChargingLimitType,
+    /// Optional. Contains limits for the available power or current over time, as set by the external source.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charging_schedule: Option<Vec<ChargingScheduleType>>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for NotifyChargingLimitRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(evse_id) = self.evse_id {
+            b.check_bounds("evse_id", 0, i32::MAX, evse_id);
+        }
+
+        b.check_member("charging_limit", &self.charging_limit);
+
+        if let Some(charging_schedule) = &self.charging_schedule {
+            b.check_cardinality(
+                "charging_schedule",
+                1,
+                usize::MAX,
+                &charging_schedule.iter(),
+            );
+            b.check_iter_member("charging_schedule", charging_schedule.iter());
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("NotifyChargingLimitRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for NotifyChargingLimitRequest {
+    fn get_message_type(&self) -> String {
+        String::from("NotifyChargingLimit")
+    }
+}
+
+impl Default for NotifyChargingLimitRequest {
+    fn default() -> NotifyChargingLimitRequest {
+        Self {
+            evse_id: None,
+            charging_limit: ChargingLimitType {
+                charging_limit_source: "EMS".to_string(),
+                ..Default::default()
+            },
+            charging_schedule: None,
+            custom_data: None,
+        }
+    }
+}
+
+/// 1.45.2. NotifyChargingLimitResponse
+/// This contains the field definition of the NotifyChargingLimitResponse PDU sent by the CSMS to the Charging Station in
+/// response to NotifyChargingLimitRequest. No fields are defined.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyChargingLimitResponse {
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for NotifyChargingLimitResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut e = StructureValidationBuilder::new();
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("NotifyChargingLimitResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_notify_charging_limit() {
+        assert!(NotifyChargingLimit::request().validate().is_ok());
+        assert!(NotifyChargingLimit::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_notify_charging_limit_request_invalid() {
+        let mut req = NotifyChargingLimit::request();
+        req.evse_id = Some(-1);
+        req.charging_limit.charging_limit_source = "a".repeat(21);
+        req.charging_schedule = Some(vec![]);
+        assert_invalid_fields(
+            &req.validate().unwrap_err(),
+            &["evse_id", "charging_limit", "charging_schedule"],
+        );
+    }
+
+    #[test]
+    fn test_notify_charging_limit_request_serialize_deserialize() {
+        let mut req = NotifyChargingLimit::request();
+        req.evse_id = Some(1);
+        req.charging_schedule = Some(vec![Default::default()]);
+        assert!(req.validate().is_ok());
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("chargingLimit"));
+        let deserialized: NotifyChargingLimitRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+
+    #[test]
+    fn test_notify_charging_limit_response_serialize_deserialize() {
+        let resp = NotifyChargingLimit::response();
+        let serialized = serde_json::to_string(&resp).unwrap();
+        let deserialized: NotifyChargingLimitResponse = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
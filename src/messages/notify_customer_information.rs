@@ -0,0 +1,121 @@
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.46. NotifyCustomerInformation
+pub struct NotifyCustomerInformation;
+
+impl OcppMessage for NotifyCustomerInformation {
+    type Request = NotifyCustomerInformationRequest;
+    type Response = NotifyCustomerInformationResponse;
+}
+
+/// 1.46.1. NotifyCustomerInformationRequest
+/// This contains the field definition of the NotifyCustomerInformationRequest PDU sent by the Charging Station to the CSMS.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyCustomerInformationRequest {
+    /// Required. (Part of) the requested data. No format specified in which the data is returned. Should be human readable.
+    pub data: String,
+    /// Optional. "to be continued" indicator. Indicates whether another part of the monitoringData follows in an upcoming
+    /// notifyMonitoringReportRequest message. Default value when omitted is false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tbc: Option<bool>,
+    /// Required. Sequence number of this message. First message starts at 0.
+    pub seq_no: i32,
+    /// Required. Timestamp of the moment this message was generated at the Charging Station.
+    pub generated_at: DateTime<Utc>,
+    /// Required. The Id of the request.
+    pub request_id: i32,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for NotifyCustomerInformationRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_cardinality("data", 0, 512, &self.data.chars());
+        b.check_bounds("seq_no", 0, i32::MAX, self.seq_no);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("NotifyCustomerInformationRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for NotifyCustomerInformationRequest {
+    fn get_message_type(&self) -> String {
+        String::from("NotifyCustomerInformation")
+    }
+}
+
+/// 1.46.2. NotifyCustomerInformationResponse
+/// This contains the field definition of the NotifyCustomerInformationResponse PDU sent by the CSMS to the Charging Station in
+/// response to NotifyCustomerInformationRequest. No fields are defined.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyCustomerInformationResponse {
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for NotifyCustomerInformationResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut e = StructureValidationBuilder::new();
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("NotifyCustomerInformationResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_notify_customer_information() {
+        assert!(NotifyCustomerInformation::request().validate().is_ok());
+        assert!(NotifyCustomerInformation::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_notify_customer_information_invalid() {
+        let req = NotifyCustomerInformationRequest {
+            data: "a".repeat(513),
+            seq_no: -1,
+            ..Default::default()
+        };
+        assert_invalid_fields(&req.validate().unwrap_err(), &["data", "seq_no"]);
+    }
+
+    #[test]
+    fn test_notify_customer_information_serialize_deserialize() {
+        let req = NotifyCustomerInformationRequest {
+            data: "customer data".to_string(),
+            tbc: Some(true),
+            seq_no: 0,
+            generated_at: Utc::now(),
+            request_id: 9,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("\"seqNo\":0"));
+        assert!(serialized.contains("\"generatedAt\""));
+        let deserialized: NotifyCustomerInformationRequest =
+            serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+}
@@ -0,0 +1,128 @@
+use crate::enums::der_control_enum_type::DERControlEnumType;
+use crate::enums::grid_event_fault_enum_type::GridEventFaultEnumType;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.47. NotifyDERAlarm
+pub struct NotifyDERAlarm;
+
+impl OcppMessage for NotifyDERAlarm {
+    type Request = NotifyDERAlarmRequest;
+    type Response = NotifyDERAlarmResponse;
+}
+
+/// 1.47.1. NotifyDERAlarmRequest
+/// (2.1) This contains the field definition of the NotifyDERAlarmRequest PDU sent by the Charging Station to the CSMS.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyDERAlarmRequest {
+    /// Required. Name of DER control, e.g. LFMustTrip.
+    pub control_type: DERControlEnumType,
+    /// Optional. Type of grid event that caused this alarm.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grid_event_fault: Option<GridEventFaultEnumType>,
+    /// Optional. True when error condition has ended. Absent or false when alarm has started.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alarm_ended: Option<bool>,
+    /// Required. Time of start or end of alarm.
+    pub timestamp: DateTime<Utc>,
+    /// Optional. Optional info provided by EV.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extra_info: Option<String>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for NotifyDERAlarmRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(extra_info) = &self.extra_info {
+            b.check_cardinality("extra_info", 0, 200, &extra_info.chars());
+        }
+
+        b.check_enum("control_type", &self.control_type);
+
+        if let Some(grid_event_fault) = &self.grid_event_fault {
+            b.check_enum("grid_event_fault", grid_event_fault);
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("NotifyDERAlarmRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for NotifyDERAlarmRequest {
+    fn get_message_type(&self) -> String {
+        String::from("NotifyDERAlarm")
+    }
+}
+
+/// 1.47.2. NotifyDERAlarmResponse
+/// (2.1) This contains the field definition of the NotifyDERAlarmResponse PDU sent by the CSMS to the Charging Station in
+/// response to NotifyDERAlarmRequest. No fields are defined.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyDERAlarmResponse {
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for NotifyDERAlarmResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut e = StructureValidationBuilder::new();
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("NotifyDERAlarmResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_notify_der_alarm() {
+        assert!(NotifyDERAlarm::request().validate().is_ok());
+        assert!(NotifyDERAlarm::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_notify_der_alarm_extra_info_long() {
+        let req = NotifyDERAlarmRequest {
+            extra_info: Some("a".repeat(201)),
+            ..Default::default()
+        };
+        assert_invalid_fields(&req.validate().unwrap_err(), &["extra_info"]);
+    }
+
+    #[test]
+    fn test_notify_der_alarm_serialize_deserialize() {
+        let req = NotifyDERAlarmRequest {
+            control_type: DERControlEnumType::LVMustTrip,
+            grid_event_fault: Some(GridEventFaultEnumType::UnderVoltage),
+            alarm_ended: Some(false),
+            timestamp: Utc::now(),
+            extra_info: None,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("\"gridEventFault\":\"UnderVoltage\""));
+        let deserialized: NotifyDERAlarmRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+}
@@ -0,0 +1,125 @@
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.48. NotifyDERStartStop
+pub struct NotifyDERStartStop;
+
+impl OcppMessage for NotifyDERStartStop {
+    type Request = NotifyDERStartStopRequest;
+    type Response = NotifyDERStartStopResponse;
+}
+
+/// 1.48.1. NotifyDERStartStopRequest
+/// (2.1) This contains the field definition of the NotifyDERStartStopRequest PDU sent by the Charging Station to the CSMS.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyDERStartStopRequest {
+    /// Required. Id of the started or stopped DER control. Corresponds to the controlId of the SetDERControlRequest.
+    pub control_id: String,
+    /// Required. True if DER control has started. False if it has ended.
+    pub started: bool,
+    /// Required. Time of start or end of event.
+    pub timestamp: DateTime<Utc>,
+    /// Optional. List of controlIds that are superseded as a result of this control starting.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub superseded_ids: Option<Vec<String>>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for NotifyDERStartStopRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_cardinality("control_id", 0, 36, &self.control_id.chars());
+
+        if let Some(superseded_ids) = &self.superseded_ids {
+            b.check_cardinality("superseded_ids", 1, 24, &superseded_ids.iter());
+            for (i, id) in superseded_ids.iter().enumerate() {
+                b.check_cardinality(&format!("superseded_ids[{i}]"), 0, 36, &id.chars());
+            }
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("NotifyDERStartStopRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for NotifyDERStartStopRequest {
+    fn get_message_type(&self) -> String {
+        String::from("NotifyDERStartStop")
+    }
+}
+
+/// 1.48.2. NotifyDERStartStopResponse
+/// (2.1) This contains the field definition of the NotifyDERStartStopResponse PDU sent by the CSMS to the Charging Station in
+/// response to NotifyDERStartStopRequest. No fields are defined.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyDERStartStopResponse {
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for NotifyDERStartStopResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut e = StructureValidationBuilder::new();
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("NotifyDERStartStopResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_notify_der_start_stop() {
+        assert!(NotifyDERStartStop::request().validate().is_ok());
+        assert!(NotifyDERStartStop::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_notify_der_start_stop_invalid() {
+        let req = NotifyDERStartStopRequest {
+            control_id: "a".repeat(37),
+            superseded_ids: Some(vec!["b".repeat(37)]),
+            ..Default::default()
+        };
+        assert_invalid_fields(
+            &req.validate().unwrap_err(),
+            &["control_id", "superseded_ids[0]"],
+        );
+    }
+
+    #[test]
+    fn test_notify_der_start_stop_serialize_deserialize() {
+        let req = NotifyDERStartStopRequest {
+            control_id: "ctrl-1".to_string(),
+            started: true,
+            timestamp: Utc::now(),
+            superseded_ids: Some(vec!["ctrl-0".to_string()]),
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("\"controlId\":\"ctrl-1\""));
+        assert!(serialized.contains("\"supersededIds\":[\"ctrl-0\"]"));
+        let deserialized: NotifyDERStartStopRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+}
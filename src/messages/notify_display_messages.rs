@@ -0,0 +1,125 @@
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::message_info_type::MessageInfoType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.49. NotifyDisplayMessages
+pub struct NotifyDisplayMessages;
+
+impl OcppMessage for NotifyDisplayMessages {
+    type Request = NotifyDisplayMessagesRequest;
+    type Response = NotifyDisplayMessagesResponse;
+}
+
+/// 1.49.1. NotifyDisplayMessagesRequest
+/// This contains the field definition of the NotifyDisplayMessagesRequest PDU sent by the Charging Station to the CSMS.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyDisplayMessagesRequest {
+    /// Required. The id of the GetDisplayMessagesRequest that requested this message.
+    pub request_id: i32,
+    /// Optional. "to be continued" indicator. Indicates whether another part of the report follows in an upcoming
+    /// NotifyDisplayMessagesRequest message. Default value when omitted is false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tbc: Option<bool>,
+    /// Optional. The requested display message as configured in the Charging Station.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_info: Option<Vec<MessageInfoType>>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for NotifyDisplayMessagesRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(message_info) = &self.message_info {
+            b.check_cardinality("message_info", 1, usize::MAX, &message_info.iter());
+            b.check_iter_member("message_info", message_info.iter());
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("NotifyDisplayMessagesRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for NotifyDisplayMessagesRequest {
+    fn get_message_type(&self) -> String {
+        String::from("NotifyDisplayMessages")
+    }
+}
+
+/// 1.49.2. NotifyDisplayMessagesResponse
+/// This contains the field definition of the NotifyDisplayMessagesResponse PDU sent by the CSMS to the Charging Station in
+/// response to NotifyDisplayMessagesRequest. No fields are defined.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyDisplayMessagesResponse {
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for NotifyDisplayMessagesResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut e = StructureValidationBuilder::new();
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("NotifyDisplayMessagesResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_notify_display_messages() {
+        assert!(NotifyDisplayMessages::request().validate().is_ok());
+        assert!(NotifyDisplayMessages::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_notify_display_messages_invalid() {
+        let req = NotifyDisplayMessagesRequest {
+            message_info: Some(vec![MessageInfoType {
+                id: -1,
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+        assert_invalid_fields(&req.validate().unwrap_err(), &["message_info[0]"]);
+
+        let req = NotifyDisplayMessagesRequest {
+            message_info: Some(vec![]),
+            ..Default::default()
+        };
+        assert_invalid_fields(&req.validate().unwrap_err(), &["message_info"]);
+    }
+
+    #[test]
+    fn test_notify_display_messages_serialize_deserialize() {
+        let req = NotifyDisplayMessagesRequest {
+            request_id: 4,
+            tbc: Some(false),
+            message_info: Some(vec![MessageInfoType::default()]),
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("\"requestId\":4"));
+        assert!(serialized.contains("\"messageInfo\""));
+        let deserialized: NotifyDisplayMessagesRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+}
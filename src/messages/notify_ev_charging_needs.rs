@@ -0,0 +1,167 @@
+use crate::enums::notify_ev_charging_needs_status_enum_type::NotifyEVChargingNeedsStatusEnumType;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::charging_needs_type::ChargingNeedsType;
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::status_info_type::StatusInfoType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.50. NotifyEVChargingNeeds
+pub struct NotifyEVChargingNeeds;
+
+impl OcppMessage for NotifyEVChargingNeeds {
+    type Request = NotifyEVChargingNeedsRequest;
+    type Response = NotifyEVChargingNeedsResponse;
+}
+
+/// 1.50.1. NotifyEVChargingNeedsRequest
+/// This contains the field definition of the NotifyEVChargingNeedsRequest PDU sent by the Charging Station to the CSMS.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyEVChargingNeedsRequest {
+    /// Required. Defines the EVSE and connector to which the EV is connected. EvseId may not be 0.
+    pub evse_id: i32,
+    /// Optional. Contains the maximum elements the EV supports for:
+    /// - ISO 15118-2: schedule tuples in SASchedule (both Pmax and Tariff).
+    /// - ISO 15118-20: PowerScheduleEntry, PriceRule and PriceLevelScheduleEntries.
+    ///
+    /// The Charging Station SHALL limit the elements in any ChargingSchedules to this value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_schedule_tuples: Option<i32>,
+    /// Required. The characteristics of the energy delivery required.
+    pub charging_needs: ChargingNeedsType,
+    /// Optional. (2.1) Time when EV charging needs were received.
+    /// Field can be added when charging station was offline when charging needs were received.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<DateTime<Utc>>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for NotifyEVChargingNeedsRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_bounds("evse_id", 1, i32::MAX, self.evse_id);
+
+        if let Some(max_schedule_tuples) = self.max_schedule_tuples {
+            b.check_bounds("max_schedule_tuples", 0, i32::MAX, max_schedule_tuples);
+        }
+
+        b.check_member("charging_needs", &self.charging_needs);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("NotifyEVChargingNeedsRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for NotifyEVChargingNeedsRequest {
+    fn get_message_type(&self) -> String {
+        String::from("NotifyEVChargingNeeds")
+    }
+}
+
+impl Default for NotifyEVChargingNeedsRequest {
+    fn default() -> NotifyEVChargingNeedsRequest {
+        Self {
+            evse_id: 1,
+            max_schedule_tuples: None,
+            charging_needs: Default::default(),
+            timestamp: None,
+            custom_data: None,
+        }
+    }
+}
+
+/// 1.50.2. NotifyEVChargingNeedsResponse
+/// This contains the field definition of the NotifyEVChargingNeedsResponse PDU sent by the CSMS to the Charging Station in
+/// response to NotifyEVChargingNeedsRequest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyEVChargingNeedsResponse {
+    /// Required. Returns whether the CSMS has been able to process the message successfully. It does not imply that the
+    /// evChargingNeeds can be met with the current charging profile.
+    pub status: NotifyEVChargingNeedsStatusEnumType,
+    /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for NotifyEVChargingNeedsResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(status_info) = &self.status_info {
+            b.check_member("status_info", status_info);
+        }
+
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("NotifyEVChargingNeedsResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_notify_ev_charging_needs() {
+        assert!(NotifyEVChargingNeeds::request().validate().is_ok());
+        assert!(NotifyEVChargingNeeds::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_notify_ev_charging_needs_request_invalid() {
+        let req = NotifyEVChargingNeedsRequest {
+            evse_id: 0,
+            max_schedule_tuples: Some(-1),
+            ..Default::default()
+        };
+        assert_invalid_fields(
+            &req.validate().unwrap_err(),
+            &["evse_id", "max_schedule_tuples"],
+        );
+    }
+
+    #[test]
+    fn test_notify_ev_charging_needs_request_serialize_deserialize() {
+        let req = NotifyEVChargingNeedsRequest {
+            max_schedule_tuples: Some(12),
+            timestamp: Some(Utc::now()),
+            ..Default::default()
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("\"maxScheduleTuples\":12"));
+        let deserialized: NotifyEVChargingNeedsRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+
+    #[test]
+    fn test_notify_ev_charging_needs_response_serialize_deserialize() {
+        let resp = NotifyEVChargingNeedsResponse {
+            status: NotifyEVChargingNeedsStatusEnumType::Processing,
+            status_info: None,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&resp).unwrap();
+        let deserialized: NotifyEVChargingNeedsResponse =
+            serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
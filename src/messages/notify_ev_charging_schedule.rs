@@ -0,0 +1,169 @@
+use crate::enums::generic_status_enum_type::GenericStatusEnumType;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::charging_schedule_type::ChargingScheduleType;
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::status_info_type::StatusInfoType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.51. NotifyEVChargingSchedule
+pub struct NotifyEVChargingSchedule;
+
+impl OcppMessage for NotifyEVChargingSchedule {
+    type Request = NotifyEVChargingScheduleRequest;
+    type Response = NotifyEVChargingScheduleResponse;
+}
+
+/// 1.51.1. NotifyEVChargingScheduleRequest
+/// This contains the field definition of the NotifyEVChargingScheduleRequest PDU sent by the Charging Station to the CSMS.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyEVChargingScheduleRequest {
+    /// Required. Periods contained in the charging profile are relative to this point in time.
+    pub time_base: DateTime<Utc>,
+    /// Required. The charging schedule contained in this notification applies to an EVSE. EvseId must be > 0.
+    pub evse_id: i32,
+    /// Required. Planned energy consumption of the EV over time. Always relative to timeBase.
+    pub charging_schedule: ChargingScheduleType,
+    /// Optional. (2.1) Id of the chargingSchedule that EV selected from the provided ChargingProfile.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_charging_schedule_id: Option<i32>,
+    /// Optional. (2.1) True when power tolerance is accepted by EV.
+    /// This value is taken from EVPowerProfile.PowerToleranceAcceptance in the ISO 15118-20 PowerDeliverReq message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub power_tolerance_acceptance: Option<bool>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for NotifyEVChargingScheduleRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_bounds("evse_id", 1, i32::MAX, self.evse_id);
+        b.check_member("charging_schedule", &self.charging_schedule);
+
+        if let Some(selected_charging_schedule_id) = self.selected_charging_schedule_id {
+            b.check_bounds(
+                "selected_charging_schedule_id",
+                0,
+                i32::MAX,
+                selected_charging_schedule_id,
+            );
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("NotifyEVChargingScheduleRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for NotifyEVChargingScheduleRequest {
+    fn get_message_type(&self) -> String {
+        String::from("NotifyEVChargingSchedule")
+    }
+}
+
+impl Default for NotifyEVChargingScheduleRequest {
+    fn default() -> NotifyEVChargingScheduleRequest {
+        Self {
+            time_base: Default::default(),
+            evse_id: 1,
+            charging_schedule: Default::default(),
+            selected_charging_schedule_id: None,
+            power_tolerance_acceptance: None,
+            custom_data: None,
+        }
+    }
+}
+
+/// 1.51.2. NotifyEVChargingScheduleResponse
+/// This contains the field definition of the NotifyEVChargingScheduleResponse PDU sent by the CSMS to the Charging Station in
+/// response to NotifyEVChargingScheduleRequest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyEVChargingScheduleResponse {
+    /// Required. Returns whether the CSMS has been able to process the message successfully. It does not imply any agreement.
+    pub status: GenericStatusEnumType,
+    /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for NotifyEVChargingScheduleResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(status_info) = &self.status_info {
+            b.check_member("status_info", status_info);
+        }
+
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("NotifyEVChargingScheduleResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_notify_ev_charging_schedule() {
+        assert!(NotifyEVChargingSchedule::request().validate().is_ok());
+        assert!(NotifyEVChargingSchedule::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_notify_ev_charging_schedule_request_invalid() {
+        let mut req = NotifyEVChargingSchedule::request();
+        req.evse_id = 0;
+        req.charging_schedule.charging_schedule_period = vec![];
+        req.selected_charging_schedule_id = Some(-1);
+        assert_invalid_fields(
+            &req.validate().unwrap_err(),
+            &[
+                "evse_id",
+                "charging_schedule",
+                "selected_charging_schedule_id",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_notify_ev_charging_schedule_request_serialize_deserialize() {
+        let req = NotifyEVChargingScheduleRequest {
+            time_base: Utc::now(),
+            power_tolerance_acceptance: Some(true),
+            ..Default::default()
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("timeBase"));
+        let deserialized: NotifyEVChargingScheduleRequest =
+            serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+
+    #[test]
+    fn test_notify_ev_charging_schedule_response_serialize_deserialize() {
+        let resp = NotifyEVChargingSchedule::response();
+        let serialized = serde_json::to_string(&resp).unwrap();
+        let deserialized: NotifyEVChargingScheduleResponse =
+            serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
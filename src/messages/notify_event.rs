@@ -0,0 +1,140 @@
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::event_data_type::EventDataType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.52. NotifyEvent
+pub struct NotifyEvent;
+
+impl OcppMessage for NotifyEvent {
+    type Request = NotifyEventRequest;
+    type Response = NotifyEventResponse;
+}
+
+/// 1.52.1. NotifyEventRequest
+/// This contains the field definition of the NotifyEventRequest PDU sent by the Charging Station to the CSMS.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyEventRequest {
+    /// Required. Timestamp of the moment this message was generated at the Charging Station.
+    pub generated_at: DateTime<Utc>,
+    /// Optional. "to be continued" indicator. Indicates whether another part of the report follows in an upcoming
+    /// notifyEventRequest message. Default value when omitted is false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tbc: Option<bool>,
+    /// Required. Sequence number of this message. First message starts at 0.
+    pub seq_no: i32,
+    /// Required. List of EventData. An EventData element contains only the Component, Variable and VariableMonitoring data
+    /// that caused the event.
+    pub event_data: Vec<EventDataType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for NotifyEventRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_bounds("seq_no", 0, i32::MAX, self.seq_no);
+        b.check_cardinality("event_data", 1, usize::MAX, &self.event_data.iter());
+        b.check_iter_member("event_data", self.event_data.iter());
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("NotifyEventRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for NotifyEventRequest {
+    fn get_message_type(&self) -> String {
+        String::from("NotifyEvent")
+    }
+}
+
+impl Default for NotifyEventRequest {
+    fn default() -> NotifyEventRequest {
+        Self {
+            generated_at: Default::default(),
+            tbc: None,
+            seq_no: 0,
+            event_data: vec![Default::default()],
+            custom_data: None,
+        }
+    }
+}
+
+/// 1.52.2. NotifyEventResponse
+/// This contains the field definition of the NotifyEventResponse PDU sent by the CSMS to the Charging Station in response to
+/// NotifyEventRequest. No fields are defined.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyEventResponse {
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for NotifyEventResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut e = StructureValidationBuilder::new();
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("NotifyEventResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_notify_event() {
+        assert!(NotifyEvent::request().validate().is_ok());
+        assert!(NotifyEvent::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_notify_event_request_invalid() {
+        let mut req = NotifyEvent::request();
+        req.seq_no = -1;
+        req.event_data = vec![];
+        assert_invalid_fields(&req.validate().unwrap_err(), &["seq_no", "event_data"]);
+    }
+
+    #[test]
+    fn test_notify_event_request_invalid_event_data() {
+        let mut req = NotifyEvent::request();
+        req.event_data[0].actual_value = "a".repeat(2501);
+        assert_invalid_fields(&req.validate().unwrap_err(), &["event_data[0]"]);
+    }
+
+    #[test]
+    fn test_notify_event_request_serialize_deserialize() {
+        let mut req = NotifyEvent::request();
+        req.generated_at = Utc::now();
+        req.tbc = Some(false);
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("eventData"));
+        let deserialized: NotifyEventRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+
+    #[test]
+    fn test_notify_event_response_serialize_deserialize() {
+        let resp = NotifyEvent::response();
+        let serialized = serde_json::to_string(&resp).unwrap();
+        let deserialized: NotifyEventResponse = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
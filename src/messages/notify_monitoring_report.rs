@@ -0,0 +1,163 @@
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::monitoring_data_type::MonitoringDataType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.53. NotifyMonitoringReport
+pub struct NotifyMonitoringReport;
+
+impl OcppMessage for NotifyMonitoringReport {
+    type Request = NotifyMonitoringReportRequest;
+    type Response = NotifyMonitoringReportResponse;
+}
+
+/// 1.53.1. NotifyMonitoringReportRequest
+/// This contains the field definition of the NotifyMonitoringReportRequest PDU sent by the Charging Station to the CSMS.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyMonitoringReportRequest {
+    /// Required. The id of the GetMonitoringRequest that requested this report.
+    pub request_id: i32,
+    /// Optional. "to be continued" indicator. Indicates whether another part of the monitoringData follows in an upcoming
+    /// notifyMonitoringReportRequest message. Default value when omitted is false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tbc: Option<bool>,
+    /// Required. Sequence number of this message. First message starts at 0.
+    pub seq_no: i32,
+    /// Required. Timestamp of the moment this message was generated at the Charging Station.
+    pub generated_at: DateTime<Utc>,
+    /// Optional. List of MonitoringData containing monitoring settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub monitor: Option<Vec<MonitoringDataType>>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for NotifyMonitoringReportRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_bounds("seq_no", 0, i32::MAX, self.seq_no);
+
+        if let Some(monitor) = &self.monitor {
+            b.check_cardinality("monitor", 1, usize::MAX, &monitor.iter());
+            b.check_iter_member("monitor", monitor.iter());
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("NotifyMonitoringReportRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for NotifyMonitoringReportRequest {
+    fn get_message_type(&self) -> String {
+        String::from("NotifyMonitoringReport")
+    }
+}
+
+/// 1.53.2. NotifyMonitoringReportResponse
+/// This contains the field definition of the NotifyMonitoringReportResponse PDU sent by the CSMS to the Charging Station in
+/// response to NotifyMonitoringReportRequest. No fields are defined.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyMonitoringReportResponse {
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for NotifyMonitoringReportResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut e = StructureValidationBuilder::new();
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("NotifyMonitoringReportResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+    use crate::structures::component_type::ComponentType;
+    use crate::structures::variable_type::VariableType;
+
+    fn monitoring_data() -> MonitoringDataType {
+        MonitoringDataType {
+            component: ComponentType::default(),
+            variable: VariableType::default(),
+            variable_monitoring: vec![Default::default()],
+            custom_data: None,
+        }
+    }
+
+    #[test]
+    fn test_notify_monitoring_report() {
+        assert!(NotifyMonitoringReport::request().validate().is_ok());
+        assert!(NotifyMonitoringReport::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_notify_monitoring_report_request_monitor() {
+        let mut req = NotifyMonitoringReportRequest {
+            monitor: Some(vec![monitoring_data()]),
+            ..Default::default()
+        };
+        assert!(req.validate().is_ok());
+
+        req.monitor = Some(vec![]);
+        assert_invalid_fields(&req.validate().unwrap_err(), &["monitor"]);
+
+        req.monitor = Some(vec![MonitoringDataType {
+            variable_monitoring: vec![],
+            ..monitoring_data()
+        }]);
+        assert_invalid_fields(&req.validate().unwrap_err(), &["monitor[0]"]);
+    }
+
+    #[test]
+    fn test_notify_monitoring_report_request_seq_no_negative() {
+        let req = NotifyMonitoringReportRequest {
+            seq_no: -1,
+            ..Default::default()
+        };
+        assert_invalid_fields(&req.validate().unwrap_err(), &["seq_no"]);
+    }
+
+    #[test]
+    fn test_notify_monitoring_report_request_serialize_deserialize() {
+        let req = NotifyMonitoringReportRequest {
+            request_id: 3,
+            tbc: Some(true),
+            seq_no: 0,
+            generated_at: Utc::now(),
+            monitor: Some(vec![monitoring_data()]),
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("\"requestId\":3"));
+        let deserialized: NotifyMonitoringReportRequest =
+            serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+
+    #[test]
+    fn test_notify_monitoring_report_response_serialize_deserialize() {
+        let resp = NotifyMonitoringReport::response();
+        let serialized = serde_json::to_string(&resp).unwrap();
+        let deserialized: NotifyMonitoringReportResponse =
+            serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
@@ -0,0 +1,101 @@
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::stream_data_element_type::StreamDataElementType;
+use crate::traits::{OcppEntity, OcppRequest, OcppUnconfirmedMessage};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.54. NotifyPeriodicEventStream
+pub struct NotifyPeriodicEventStream;
+
+impl OcppUnconfirmedMessage for NotifyPeriodicEventStream {
+    type Request = NotifyPeriodicEventStreamRequest;
+}
+
+/// 1.54.1. NotifyPeriodicEventStreamRequest
+/// (2.1) This contains the field definition of the NotifyPeriodicEventStreamRequest PDU sent by the Charging Station to the CSMS.
+/// This message is sent using the SEND message type and therefore has no response.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyPeriodicEventStreamRequest {
+    /// Required. Id of stream.
+    pub id: i32,
+    /// Required. Number of data elements still pending to be sent.
+    pub pending: i32,
+    /// Required. Base timestamp to add to time offset values.
+    pub basetime: DateTime<Utc>,
+    /// Required. The data elements of this stream.
+    pub data: Vec<StreamDataElementType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for NotifyPeriodicEventStreamRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_bounds("id", 0, i32::MAX, self.id);
+        b.check_bounds("pending", 0, i32::MAX, self.pending);
+        b.check_cardinality("data", 1, usize::MAX, &self.data.iter());
+        b.check_iter_member("data", self.data.iter());
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("NotifyPeriodicEventStreamRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for NotifyPeriodicEventStreamRequest {
+    fn get_message_type(&self) -> String {
+        String::from("NotifyPeriodicEventStream")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn valid_request() -> NotifyPeriodicEventStreamRequest {
+        NotifyPeriodicEventStreamRequest {
+            id: 1,
+            pending: 0,
+            basetime: Utc::now(),
+            data: vec![StreamDataElementType {
+                t: 0.5,
+                v: "230.1".to_string(),
+                custom_data: None,
+            }],
+            custom_data: None,
+        }
+    }
+
+    #[test]
+    fn test_request_serialize_deserialize() {
+        let req = valid_request();
+        let json = serde_json::to_string(&req).unwrap();
+        let deserialized: NotifyPeriodicEventStreamRequest = serde_json::from_str(&json).unwrap();
+        assert_eq!(req, deserialized);
+    }
+
+    #[test]
+    fn test_request_validate() {
+        assert!(valid_request().validate().is_ok());
+    }
+
+    #[test]
+    fn test_request_validate_empty_data() {
+        assert!(NotifyPeriodicEventStream::request().validate().is_err());
+    }
+
+    #[test]
+    fn test_request_validate_negative_pending() {
+        let mut req = valid_request();
+        req.pending = -1;
+        assert!(req.validate().is_err());
+    }
+}
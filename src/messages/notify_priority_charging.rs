@@ -0,0 +1,115 @@
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.55. NotifyPriorityCharging
+pub struct NotifyPriorityCharging;
+
+impl OcppMessage for NotifyPriorityCharging {
+    type Request = NotifyPriorityChargingRequest;
+    type Response = NotifyPriorityChargingResponse;
+}
+
+/// 1.55.1. NotifyPriorityChargingRequest
+/// (2.1) This contains the field definition of the NotifyPriorityChargingRequest PDU sent by the Charging Station to the CSMS.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyPriorityChargingRequest {
+    /// Required. The transaction for which priority charging is requested.
+    pub transaction_id: String,
+    /// Required. True if priority charging was activated. False if it has stopped using the priority charging profile.
+    pub activated: bool,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for NotifyPriorityChargingRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_cardinality("transaction_id", 0, 36, &self.transaction_id.chars());
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("NotifyPriorityChargingRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for NotifyPriorityChargingRequest {
+    fn get_message_type(&self) -> String {
+        String::from("NotifyPriorityCharging")
+    }
+}
+
+/// 1.55.2. NotifyPriorityChargingResponse
+/// (2.1) This contains the field definition of the NotifyPriorityChargingResponse PDU sent by the CSMS to the Charging Station
+/// in response to NotifyPriorityChargingRequest. No fields are defined.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyPriorityChargingResponse {
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for NotifyPriorityChargingResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut e = StructureValidationBuilder::new();
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("NotifyPriorityChargingResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notify_priority_charging() {
+        assert!(NotifyPriorityCharging::request().validate().is_ok());
+        assert!(NotifyPriorityCharging::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_notify_priority_charging_request_transaction_id_long() {
+        let req = NotifyPriorityChargingRequest {
+            transaction_id: "a".repeat(37),
+            activated: false,
+            custom_data: None,
+        };
+        assert!(req.validate().is_err());
+    }
+
+    #[test]
+    fn test_notify_priority_charging_request_serialize_deserialize() {
+        let req = NotifyPriorityChargingRequest {
+            transaction_id: "tx-1".to_string(),
+            activated: true,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("\"transactionId\":\"tx-1\""));
+        let deserialized: NotifyPriorityChargingRequest =
+            serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+
+    #[test]
+    fn test_notify_priority_charging_response_serialize_deserialize() {
+        let resp = NotifyPriorityCharging::response();
+        let serialized = serde_json::to_string(&resp).unwrap();
+        let deserialized: NotifyPriorityChargingResponse =
+            serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
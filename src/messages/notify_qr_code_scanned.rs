@@ -0,0 +1,109 @@
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.56. NotifyQRCodeScanned
+pub struct NotifyQRCodeScanned;
+
+impl OcppMessage for NotifyQRCodeScanned {
+    type Request = NotifyQRCodeScannedRequest;
+    type Response = NotifyQRCodeScannedResponse;
+}
+
+/// 1.56.1. NotifyQRCodeScannedRequest
+/// (2.1) This contains the field definition of the NotifyQRCodeScannedRequest PDU sent by the Charging Station to the
+/// CSMS.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyQRCodeScannedRequest {
+    /// Required. EVSE id for which the QR code was scanned.
+    pub evse_id: i32,
+    /// Required. Timeout value in seconds after which no result of web payment process (e.g. QR code scanning) is to be
+    /// expected anymore.
+    pub timeout: i32,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for NotifyQRCodeScannedRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_bounds("evse_id", 0, i32::MAX, self.evse_id);
+        b.check_bounds("timeout", 0, i32::MAX, self.timeout);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("NotifyQRCodeScannedRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for NotifyQRCodeScannedRequest {
+    fn get_message_type(&self) -> String {
+        String::from("NotifyQRCodeScanned")
+    }
+}
+
+/// 1.56.2. NotifyQRCodeScannedResponse
+/// (2.1) This contains the field definition of the NotifyQRCodeScannedResponse PDU sent by the CSMS to the Charging
+/// Station in response to NotifyQRCodeScannedRequest. No fields are defined.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyQRCodeScannedResponse {
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for NotifyQRCodeScannedResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut e = StructureValidationBuilder::new();
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("NotifyQRCodeScannedResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_notify_qr_code_scanned() {
+        assert!(NotifyQRCodeScanned::request().validate().is_ok());
+        assert!(NotifyQRCodeScanned::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_notify_qr_code_scanned_invalid() {
+        let req = NotifyQRCodeScannedRequest {
+            evse_id: -1,
+            timeout: -1,
+            custom_data: None,
+        };
+        assert_invalid_fields(&req.validate().unwrap_err(), &["evse_id", "timeout"]);
+    }
+
+    #[test]
+    fn test_notify_qr_code_scanned_serialize_deserialize() {
+        let req = NotifyQRCodeScannedRequest {
+            evse_id: 1,
+            timeout: 60,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert_eq!(serialized, r#"{"evseId":1,"timeout":60}"#);
+        let deserialized: NotifyQRCodeScannedRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+}
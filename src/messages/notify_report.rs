@@ -0,0 +1,146 @@
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::report_data_type::ReportDataType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.57. NotifyReport
+pub struct NotifyReport;
+
+impl OcppMessage for NotifyReport {
+    type Request = NotifyReportRequest;
+    type Response = NotifyReportResponse;
+}
+
+/// 1.57.1. NotifyReportRequest
+/// This contains the field definition of the NotifyReportRequest PDU sent by the Charging Station to the CSMS.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyReportRequest {
+    /// Required. The id of the GetReportRequest or GetBaseReportRequest that requested this report.
+    pub request_id: i32,
+    /// Required. Timestamp of the moment this message was generated at the Charging Station.
+    pub generated_at: DateTime<Utc>,
+    /// Optional. "to be continued" indicator. Indicates whether another part of the report follows in an upcoming
+    /// notifyReportRequest message. Default value when omitted is false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tbc: Option<bool>,
+    /// Required. Sequence number of this message. First message starts at 0.
+    pub seq_no: i32,
+    /// Optional. List of ReportData.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub report_data: Option<Vec<ReportDataType>>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for NotifyReportRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_bounds("seq_no", 0, i32::MAX, self.seq_no);
+
+        if let Some(report_data) = &self.report_data {
+            b.check_cardinality("report_data", 1, usize::MAX, &report_data.iter());
+            b.check_iter_member("report_data", report_data.iter());
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("NotifyReportRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for NotifyReportRequest {
+    fn get_message_type(&self) -> String {
+        String::from("NotifyReport")
+    }
+}
+
+/// 1.57.2. NotifyReportResponse
+/// This contains the field definition of the NotifyReportResponse PDU sent by the CSMS to the Charging Station in response to
+/// NotifyReportRequest. No fields are defined.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyReportResponse {
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for NotifyReportResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut e = StructureValidationBuilder::new();
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("NotifyReportResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_notify_report() {
+        assert!(NotifyReport::request().validate().is_ok());
+        assert!(NotifyReport::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_notify_report_request_seq_no_negative() {
+        let req = NotifyReportRequest {
+            seq_no: -1,
+            ..Default::default()
+        };
+        assert_invalid_fields(&req.validate().unwrap_err(), &["seq_no"]);
+    }
+
+    #[test]
+    fn test_notify_report_request_report_data() {
+        let mut req = NotifyReportRequest {
+            report_data: Some(vec![Default::default(); 2]),
+            ..Default::default()
+        };
+        assert!(req.validate().is_ok());
+
+        req.report_data = Some(vec![]);
+        assert_invalid_fields(&req.validate().unwrap_err(), &["report_data"]);
+    }
+
+    #[test]
+    fn test_notify_report_request_serialize_deserialize() {
+        let req = NotifyReportRequest {
+            request_id: 7,
+            generated_at: Utc::now(),
+            tbc: Some(true),
+            seq_no: 1,
+            report_data: Some(vec![Default::default()]),
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("\"seqNo\":1"));
+        assert!(serialized.contains("\"tbc\":true"));
+        assert!(serialized.contains("generatedAt"));
+        let deserialized: NotifyReportRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+
+    #[test]
+    fn test_notify_report_response_serialize_deserialize() {
+        let resp = NotifyReport::response();
+        let serialized = serde_json::to_string(&resp).unwrap();
+        let deserialized: NotifyReportResponse = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
@@ -0,0 +1,209 @@
+use crate::enums::payment_status_enum_type::PaymentStatusEnumType;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::address_type::AddressType;
+use crate::structures::custom_data_type::CustomDataType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.58. NotifySettlement
+pub struct NotifySettlement;
+
+impl OcppMessage for NotifySettlement {
+    type Request = NotifySettlementRequest;
+    type Response = NotifySettlementResponse;
+}
+
+/// 1.58.1. NotifySettlementRequest
+/// (2.1) This contains the field definition of the NotifySettlementRequest PDU sent by the Charging Station to the CSMS.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifySettlementRequest {
+    /// Optional. The transactionId that the settlement belongs to. Can be empty if the payment transaction is canceled
+    /// prior to the start of the OCPP transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_id: Option<String>,
+    /// Required. The payment reference received from the payment terminal and is used as the value for idToken.
+    pub psp_ref: String,
+    /// Required. The status of the settlement attempt.
+    pub status: PaymentStatusEnumType,
+    /// Optional. Additional information from payment terminal/payment process.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_info: Option<String>,
+    /// Required. The amount that was settled, or attempted to be settled (in case of failure).
+    pub settlement_amount: f64,
+    /// Required. The time when the settlement was done.
+    pub settlement_time: DateTime<Utc>,
+    /// Optional. Receipt id, to be used if the receipt is generated by the payment terminal or the Charging Station.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receipt_id: Option<String>,
+    /// Optional. The receipt URL, to be used if the receipt is generated by the payment terminal or the Charging Station.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receipt_url: Option<String>,
+    /// Optional. The address of the company for an invoice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vat_company: Option<AddressType>,
+    /// Optional. VAT number for a company receipt.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vat_number: Option<String>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for NotifySettlementRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(transaction_id) = &self.transaction_id {
+            b.check_cardinality("transaction_id", 0, 36, &transaction_id.chars());
+        }
+
+        b.check_cardinality("psp_ref", 0, 255, &self.psp_ref.chars());
+
+        if let Some(status_info) = &self.status_info {
+            b.check_cardinality("status_info", 0, 500, &status_info.chars());
+        }
+
+        if let Some(receipt_id) = &self.receipt_id {
+            b.check_cardinality("receipt_id", 0, 50, &receipt_id.chars());
+        }
+
+        if let Some(receipt_url) = &self.receipt_url {
+            b.check_cardinality("receipt_url", 0, 2000, &receipt_url.chars());
+        }
+
+        if let Some(vat_company) = &self.vat_company {
+            b.check_member("vat_company", vat_company);
+        }
+
+        if let Some(vat_number) = &self.vat_number {
+            b.check_cardinality("vat_number", 0, 20, &vat_number.chars());
+        }
+
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("NotifySettlementRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for NotifySettlementRequest {
+    fn get_message_type(&self) -> String {
+        String::from("NotifySettlement")
+    }
+}
+
+/// 1.58.2. NotifySettlementResponse
+/// (2.1) This contains the field definition of the NotifySettlementResponse PDU sent by the CSMS to the Charging Station in
+/// response to NotifySettlementRequest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifySettlementResponse {
+    /// Optional. The receipt URL if receipt generated by CSMS. The Charging Station can QR encode it and show it to the EV
+    /// Driver.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receipt_url: Option<String>,
+    /// Optional. The receipt id if the receipt is generated by CSMS.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receipt_id: Option<String>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for NotifySettlementResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(receipt_url) = &self.receipt_url {
+            b.check_cardinality("receipt_url", 0, 2000, &receipt_url.chars());
+        }
+
+        if let Some(receipt_id) = &self.receipt_id {
+            b.check_cardinality("receipt_id", 0, 50, &receipt_id.chars());
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("NotifySettlementResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_notify_settlement() {
+        assert!(NotifySettlement::request().validate().is_ok());
+        assert!(NotifySettlement::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_notify_settlement_request_invalid() {
+        let req = NotifySettlementRequest {
+            transaction_id: Some("a".repeat(37)),
+            psp_ref: "a".repeat(256),
+            status_info: Some("a".repeat(501)),
+            receipt_id: Some("a".repeat(51)),
+            receipt_url: Some("a".repeat(2001)),
+            vat_number: Some("a".repeat(21)),
+            ..Default::default()
+        };
+        assert_invalid_fields(
+            &req.validate().unwrap_err(),
+            &[
+                "transaction_id",
+                "psp_ref",
+                "status_info",
+                "receipt_id",
+                "receipt_url",
+                "vat_number",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_notify_settlement_response_invalid() {
+        let resp = NotifySettlementResponse {
+            receipt_url: Some("a".repeat(2001)),
+            receipt_id: Some("a".repeat(51)),
+            custom_data: None,
+        };
+        assert_invalid_fields(
+            &resp.validate().unwrap_err(),
+            &["receipt_url", "receipt_id"],
+        );
+    }
+
+    #[test]
+    fn test_notify_settlement_serialize_deserialize() {
+        let req = NotifySettlementRequest {
+            transaction_id: Some("tx-1".to_string()),
+            psp_ref: "psp-123".to_string(),
+            status: PaymentStatusEnumType::Settled,
+            status_info: None,
+            settlement_amount: 12.5,
+            settlement_time: Utc::now(),
+            receipt_id: Some("r-1".to_string()),
+            receipt_url: None,
+            vat_company: None,
+            vat_number: None,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("\"pspRef\":\"psp-123\""));
+        assert!(serialized.contains("\"settlementAmount\":12.5"));
+        let deserialized: NotifySettlementRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+}
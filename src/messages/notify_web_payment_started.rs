@@ -0,0 +1,110 @@
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.59. NotifyWebPaymentStarted
+pub struct NotifyWebPaymentStarted;
+
+impl OcppMessage for NotifyWebPaymentStarted {
+    type Request = NotifyWebPaymentStartedRequest;
+    type Response = NotifyWebPaymentStartedResponse;
+}
+
+/// 1.59.1. NotifyWebPaymentStartedRequest
+/// (2.1) This contains the field definition of the NotifyWebPaymentStartedRequest PDU sent by the CSMS to the Charging
+/// Station.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyWebPaymentStartedRequest {
+    /// Required. EVSE id for which transaction is requested.
+    pub evse_id: i32,
+    /// Required. Timeout value in seconds after which no result of web payment process (e.g. QR code scanning) is to be
+    /// expected anymore.
+    pub timeout: i32,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for NotifyWebPaymentStartedRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_bounds("evse_id", 0, i32::MAX, self.evse_id);
+        b.check_bounds("timeout", 0, i32::MAX, self.timeout);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("NotifyWebPaymentStartedRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for NotifyWebPaymentStartedRequest {
+    fn get_message_type(&self) -> String {
+        String::from("NotifyWebPaymentStarted")
+    }
+}
+
+/// 1.59.2. NotifyWebPaymentStartedResponse
+/// (2.1) This contains the field definition of the NotifyWebPaymentStartedResponse PDU sent by the Charging Station to the
+/// CSMS in response to NotifyWebPaymentStartedRequest. No fields are defined.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyWebPaymentStartedResponse {
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for NotifyWebPaymentStartedResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut e = StructureValidationBuilder::new();
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("NotifyWebPaymentStartedResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_notify_web_payment_started() {
+        assert!(NotifyWebPaymentStarted::request().validate().is_ok());
+        assert!(NotifyWebPaymentStarted::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_notify_web_payment_started_invalid() {
+        let req = NotifyWebPaymentStartedRequest {
+            evse_id: -1,
+            timeout: -1,
+            custom_data: None,
+        };
+        assert_invalid_fields(&req.validate().unwrap_err(), &["evse_id", "timeout"]);
+    }
+
+    #[test]
+    fn test_notify_web_payment_started_serialize_deserialize() {
+        let req = NotifyWebPaymentStartedRequest {
+            evse_id: 1,
+            timeout: 60,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert_eq!(serialized, r#"{"evseId":1,"timeout":60}"#);
+        let deserialized: NotifyWebPaymentStartedRequest =
+            serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+}
@@ -0,0 +1,127 @@
+use crate::enums::generic_status_enum_type::GenericStatusEnumType;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::constant_stream_data_type::ConstantStreamDataType;
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::status_info_type::StatusInfoType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.60. OpenPeriodicEventStream
+pub struct OpenPeriodicEventStream;
+
+impl OcppMessage for OpenPeriodicEventStream {
+    type Request = OpenPeriodicEventStreamRequest;
+    type Response = OpenPeriodicEventStreamResponse;
+}
+
+/// 1.60.1. OpenPeriodicEventStreamRequest
+/// (2.1) This contains the field definition of the OpenPeriodicEventStreamRequest PDU sent by the Charging Station to the
+/// CSMS.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenPeriodicEventStreamRequest {
+    /// Required. The stream to open, with the monitor it reports on and its periodic parameters.
+    pub constant_stream_data: ConstantStreamDataType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for OpenPeriodicEventStreamRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_member("constant_stream_data", &self.constant_stream_data);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("OpenPeriodicEventStreamRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for OpenPeriodicEventStreamRequest {
+    fn get_message_type(&self) -> String {
+        String::from("OpenPeriodicEventStream")
+    }
+}
+
+/// 1.60.2. OpenPeriodicEventStreamResponse
+/// (2.1) This contains the field definition of the OpenPeriodicEventStreamResponse PDU sent by the CSMS to the Charging
+/// Station in response to OpenPeriodicEventStreamRequest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenPeriodicEventStreamResponse {
+    /// Required. Result of request.
+    pub status: GenericStatusEnumType,
+    /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for OpenPeriodicEventStreamResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(status_info) = &self.status_info {
+            b.check_member("status_info", status_info);
+        }
+
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("OpenPeriodicEventStreamResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_open_periodic_event_stream() {
+        assert!(OpenPeriodicEventStream::request().validate().is_ok());
+        assert!(OpenPeriodicEventStream::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_open_periodic_event_stream_invalid() {
+        let req = OpenPeriodicEventStreamRequest {
+            constant_stream_data: ConstantStreamDataType {
+                id: -1,
+                ..Default::default()
+            },
+            custom_data: None,
+        };
+        assert_invalid_fields(&req.validate().unwrap_err(), &["constant_stream_data"]);
+    }
+
+    #[test]
+    fn test_open_periodic_event_stream_serialize_deserialize() {
+        let req = OpenPeriodicEventStreamRequest {
+            constant_stream_data: ConstantStreamDataType {
+                id: 1,
+                variable_monitoring_id: 2,
+                params: Default::default(),
+                custom_data: None,
+            },
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("\"constantStreamData\""));
+        assert!(serialized.contains("\"variableMonitoringId\":2"));
+        let deserialized: OpenPeriodicEventStreamRequest =
+            serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+}
@@ -0,0 +1,157 @@
+use crate::enums::generic_status_enum_type::GenericStatusEnumType;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::status_info_type::StatusInfoType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.61. PublishFirmware
+pub struct PublishFirmware;
+
+impl OcppMessage for PublishFirmware {
+    type Request = PublishFirmwareRequest;
+    type Response = PublishFirmwareResponse;
+}
+
+/// 1.61.1. PublishFirmwareRequest
+/// This contains the field definition of the PublishFirmwareRequest PDU sent by the CSMS to the Local Controller.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PublishFirmwareRequest {
+    /// Required. This contains a string containing a URI pointing to a location from which to retrieve the firmware.
+    pub location: String,
+    /// Optional. This specifies how many times Charging Station must try to download the firmware before giving up.
+    /// If this field is not present, it is left to Charging Station to decide how many times it wants to retry.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retries: Option<i32>,
+    /// Required. The MD5 checksum over the entire firmware file as a hexadecimal string of length 32.
+    pub checksum: String,
+    /// Required. The Id of the request.
+    pub request_id: i32,
+    /// Optional. The interval in seconds after which a retry may be attempted. If this field is not present, it is left to
+    /// Charging Station to decide how long to wait between attempts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_interval: Option<i32>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for PublishFirmwareRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_cardinality("location", 0, 2000, &self.location.chars());
+
+        if let Some(retries) = self.retries {
+            b.check_bounds("retries", 0, i32::MAX, retries);
+        }
+
+        b.check_cardinality("checksum", 0, 32, &self.checksum.chars());
+
+        if let Some(retry_interval) = self.retry_interval {
+            b.check_bounds("retry_interval", 0, i32::MAX, retry_interval);
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("PublishFirmwareRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for PublishFirmwareRequest {
+    fn get_message_type(&self) -> String {
+        String::from("PublishFirmware")
+    }
+}
+
+/// 1.61.2. PublishFirmwareResponse
+/// This contains the field definition of the PublishFirmwareResponse PDU sent by the Local Controller to the CSMS in response to
+/// PublishFirmwareRequest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PublishFirmwareResponse {
+    /// Required. Indicates whether the request was accepted.
+    pub status: GenericStatusEnumType,
+    /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for PublishFirmwareResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(status_info) = &self.status_info {
+            b.check_member("status_info", status_info);
+        }
+
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("PublishFirmwareResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_publish_firmware() {
+        assert!(PublishFirmware::request().validate().is_ok());
+        assert!(PublishFirmware::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_publish_firmware_request_invalid() {
+        let req = PublishFirmwareRequest {
+            location: "a".repeat(2001),
+            retries: Some(-1),
+            checksum: "a".repeat(33),
+            request_id: 1,
+            retry_interval: Some(-1),
+            custom_data: None,
+        };
+        assert_invalid_fields(
+            &req.validate().unwrap_err(),
+            &["location", "retries", "checksum", "retry_interval"],
+        );
+    }
+
+    #[test]
+    fn test_publish_firmware_request_serialize_deserialize() {
+        let req = PublishFirmwareRequest {
+            location: "https://example.com/firmware.bin".to_string(),
+            retries: None,
+            checksum: "d41d8cd98f00b204e9800998ecf8427e".to_string(),
+            request_id: 1,
+            retry_interval: Some(30),
+            custom_data: None,
+        };
+        assert!(req.validate().is_ok());
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("\"retryInterval\":30"));
+        let deserialized: PublishFirmwareRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+
+    #[test]
+    fn test_publish_firmware_response_serialize_deserialize() {
+        let resp = PublishFirmware::response();
+        let serialized = serde_json::to_string(&resp).unwrap();
+        let deserialized: PublishFirmwareResponse = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
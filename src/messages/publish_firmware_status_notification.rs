@@ -0,0 +1,176 @@
+use crate::enums::published_firmware_status_enum_type::PublishFirmwareStatusEnumType;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::status_info_type::StatusInfoType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.62. PublishFirmwareStatusNotification
+pub struct PublishFirmwareStatusNotification;
+
+impl OcppMessage for PublishFirmwareStatusNotification {
+    type Request = PublishFirmwareStatusNotificationRequest;
+    type Response = PublishFirmwareStatusNotificationResponse;
+}
+
+/// 1.62.1. PublishFirmwareStatusNotificationRequest
+/// This contains the field definition of the PublishFirmwareStatusNotificationRequest PDU sent by the Local Controller to the
+/// CSMS.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PublishFirmwareStatusNotificationRequest {
+    /// Required. This contains the progress status of the publishfirmware installation.
+    pub status: PublishFirmwareStatusEnumType,
+    /// Optional. Required if status is Published. Can be multiple URI's, if the Local Controller supports e.g. HTTP, HTTPS,
+    /// and FTP.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<Vec<String>>,
+    /// Optional. The request id that was provided in the PublishFirmwareRequest which triggered this action.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<i32>,
+    /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for PublishFirmwareStatusNotificationRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        match &self.location {
+            Some(location) => {
+                b.check_cardinality("location", 1, usize::MAX, &location.iter());
+                for (i, uri) in location.iter().enumerate() {
+                    b.check_cardinality(&format!("location[{i}]"), 0, 2000, &uri.chars());
+                }
+            }
+            None if self.status == PublishFirmwareStatusEnumType::Published => {
+                b.push_relation_error(
+                    "location",
+                    "status",
+                    "location is required when status is Published",
+                );
+            }
+            None => {}
+        }
+
+        if let Some(request_id) = self.request_id {
+            b.check_bounds("request_id", 0, i32::MAX, request_id);
+        }
+
+        if let Some(status_info) = &self.status_info {
+            b.check_member("status_info", status_info);
+        }
+
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("PublishFirmwareStatusNotificationRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for PublishFirmwareStatusNotificationRequest {
+    fn get_message_type(&self) -> String {
+        String::from("PublishFirmwareStatusNotification")
+    }
+}
+
+/// 1.62.2. PublishFirmwareStatusNotificationResponse
+/// This contains the field definition of the PublishFirmwareStatusNotificationResponse PDU sent by the CSMS to the Local
+/// Controller in response to PublishFirmwareStatusNotificationRequest. No fields are defined.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PublishFirmwareStatusNotificationResponse {
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for PublishFirmwareStatusNotificationResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut e = StructureValidationBuilder::new();
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("PublishFirmwareStatusNotificationResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_publish_firmware_status_notification() {
+        assert!(
+            PublishFirmwareStatusNotification::request()
+                .validate()
+                .is_ok()
+        );
+        assert!(
+            PublishFirmwareStatusNotification::response()
+                .validate()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_publish_firmware_status_notification_request_published_requires_location() {
+        let mut req = PublishFirmwareStatusNotificationRequest {
+            status: PublishFirmwareStatusEnumType::Published,
+            ..Default::default()
+        };
+        assert_invalid_fields(&req.validate().unwrap_err(), &["location", "status"]);
+
+        req.location = Some(vec!["https://lc.local/firmware.bin".to_string()]);
+        assert!(req.validate().is_ok());
+    }
+
+    #[test]
+    fn test_publish_firmware_status_notification_request_invalid_location() {
+        let mut req = PublishFirmwareStatusNotificationRequest {
+            location: Some(vec![]),
+            ..Default::default()
+        };
+        assert_invalid_fields(&req.validate().unwrap_err(), &["location"]);
+
+        req.location = Some(vec!["a".repeat(2001)]);
+        assert_invalid_fields(&req.validate().unwrap_err(), &["location[0]"]);
+    }
+
+    #[test]
+    fn test_publish_firmware_status_notification_request_serialize_deserialize() {
+        let req = PublishFirmwareStatusNotificationRequest {
+            status: PublishFirmwareStatusEnumType::Published,
+            location: Some(vec!["https://lc.local/firmware.bin".to_string()]),
+            request_id: Some(2),
+            status_info: None,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("\"status\":\"Published\""));
+        let deserialized: PublishFirmwareStatusNotificationRequest =
+            serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+
+    #[test]
+    fn test_publish_firmware_status_notification_response_serialize_deserialize() {
+        let resp = PublishFirmwareStatusNotification::response();
+        let serialized = serde_json::to_string(&resp).unwrap();
+        let deserialized: PublishFirmwareStatusNotificationResponse =
+            serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
@@ -0,0 +1,160 @@
+use crate::enums::charging_profile_status_enum_type::ChargingProfileStatusEnumType;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::charging_schedule_update_type::ChargingScheduleUpdateType;
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::status_info_type::StatusInfoType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.63. PullDynamicScheduleUpdate
+pub struct PullDynamicScheduleUpdate;
+
+impl OcppMessage for PullDynamicScheduleUpdate {
+    type Request = PullDynamicScheduleUpdateRequest;
+    type Response = PullDynamicScheduleUpdateResponse;
+}
+
+/// 1.63.1. PullDynamicScheduleUpdateRequest
+/// (2.1) This contains the field definition of the PullDynamicScheduleUpdateRequest PDU sent by the Charging Station to the CSMS.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PullDynamicScheduleUpdateRequest {
+    /// Required. Id of charging profile to update.
+    pub charging_profile_id: i32,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for PullDynamicScheduleUpdateRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut e = StructureValidationBuilder::new();
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("PullDynamicScheduleUpdateRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for PullDynamicScheduleUpdateRequest {
+    fn get_message_type(&self) -> String {
+        String::from("PullDynamicScheduleUpdate")
+    }
+}
+
+/// 1.63.2. PullDynamicScheduleUpdateResponse
+/// (2.1) This contains the field definition of the PullDynamicScheduleUpdateResponse PDU sent by the CSMS to the Charging Station
+/// in response to PullDynamicScheduleUpdateRequest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PullDynamicScheduleUpdateResponse {
+    /// Optional. Will only be present when status is Accepted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule_update: Option<ChargingScheduleUpdateType>,
+    /// Required. Result of request.
+    pub status: ChargingProfileStatusEnumType,
+    /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for PullDynamicScheduleUpdateResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(schedule_update) = &self.schedule_update {
+            b.check_member("schedule_update", schedule_update);
+
+            if self.status != ChargingProfileStatusEnumType::Accepted {
+                b.push_relation_error(
+                    "schedule_update",
+                    "status",
+                    "schedule_update will only be present when status is Accepted",
+                );
+            }
+        }
+
+        if let Some(status_info) = &self.status_info {
+            b.check_member("status_info", status_info);
+        }
+
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("PullDynamicScheduleUpdateResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_pull_dynamic_schedule_update() {
+        assert!(PullDynamicScheduleUpdate::request().validate().is_ok());
+        assert!(PullDynamicScheduleUpdate::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_pull_dynamic_schedule_update_response_schedule_update() {
+        let mut resp = PullDynamicScheduleUpdateResponse {
+            schedule_update: Some(ChargingScheduleUpdateType {
+                limit: Some(11000.0),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert!(resp.validate().is_ok());
+
+        resp.status = ChargingProfileStatusEnumType::Rejected;
+        assert_invalid_fields(
+            &resp.validate().unwrap_err(),
+            &["schedule_update", "status"],
+        );
+    }
+
+    #[test]
+    fn test_pull_dynamic_schedule_update_response_invalid_update() {
+        let resp = PullDynamicScheduleUpdateResponse {
+            schedule_update: Some(ChargingScheduleUpdateType {
+                discharge_limit: Some(1.0),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_invalid_fields(&resp.validate().unwrap_err(), &["schedule_update"]);
+    }
+
+    #[test]
+    fn test_pull_dynamic_schedule_update_request_serialize_deserialize() {
+        let req = PullDynamicScheduleUpdateRequest {
+            charging_profile_id: 12,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert_eq!(serialized, "{\"chargingProfileId\":12}");
+        let deserialized: PullDynamicScheduleUpdateRequest =
+            serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+
+    #[test]
+    fn test_pull_dynamic_schedule_update_response_serialize_deserialize() {
+        let resp = PullDynamicScheduleUpdate::response();
+        let serialized = serde_json::to_string(&resp).unwrap();
+        let deserialized: PullDynamicScheduleUpdateResponse =
+            serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
@@ -0,0 +1,158 @@
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::charging_profile_type::ChargingProfileType;
+use crate::structures::custom_data_type::CustomDataType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.64. ReportChargingProfiles
+pub struct ReportChargingProfiles;
+
+impl OcppMessage for ReportChargingProfiles {
+    type Request = ReportChargingProfilesRequest;
+    type Response = ReportChargingProfilesResponse;
+}
+
+/// 1.64.1. ReportChargingProfilesRequest
+/// This contains the field definition of the ReportChargingProfilesRequest PDU sent by the Charging Station to the CSMS.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportChargingProfilesRequest {
+    /// Required. Id used to match the GetChargingProfilesRequest message with the resulting ReportChargingProfilesRequest
+    /// messages. When the CSMS provided a requestId in the GetChargingProfilesRequest, this field SHALL contain the same value.
+    pub request_id: i32,
+    /// Required. Source that has installed this charging profile.
+    /// Values defined in appendix as ChargingLimitSourceEnumStringType.
+    pub charging_limit_source: String,
+    /// Optional. To Be Continued. Default value when omitted: false.
+    /// false indicates that there are no further messages as part of this report.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tbc: Option<bool>,
+    /// Required. The evse to which the charging profile applies. If evseId = 0, the message contains an overall limit for the
+    /// Charging Station.
+    pub evse_id: i32,
+    /// Required. The charging profile as configured in the Charging Station.
+    pub charging_profile: Vec<ChargingProfileType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for ReportChargingProfilesRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_cardinality(
+            "charging_limit_source",
+            0,
+            20,
+            &self.charging_limit_source.chars(),
+        );
+        b.check_bounds("evse_id", 0, i32::MAX, self.evse_id);
+        b.check_cardinality(
+            "charging_profile",
+            1,
+            usize::MAX,
+            &self.charging_profile.iter(),
+        );
+        b.check_iter_member("charging_profile", self.charging_profile.iter());
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("ReportChargingProfilesRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for ReportChargingProfilesRequest {
+    fn get_message_type(&self) -> String {
+        String::from("ReportChargingProfiles")
+    }
+}
+
+impl Default for ReportChargingProfilesRequest {
+    fn default() -> ReportChargingProfilesRequest {
+        Self {
+            request_id: 0,
+            charging_limit_source: "CSO".to_string(),
+            tbc: None,
+            evse_id: 0,
+            charging_profile: vec![Default::default()],
+            custom_data: None,
+        }
+    }
+}
+
+/// 1.64.2. ReportChargingProfilesResponse
+/// This contains the field definition of the ReportChargingProfilesResponse PDU sent by the CSMS to the Charging Station in
+/// response to ReportChargingProfilesRequest. No fields are defined.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportChargingProfilesResponse {
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for ReportChargingProfilesResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut e = StructureValidationBuilder::new();
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("ReportChargingProfilesResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_report_charging_profiles() {
+        assert!(ReportChargingProfiles::request().validate().is_ok());
+        assert!(ReportChargingProfiles::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_report_charging_profiles_request_invalid() {
+        let req = ReportChargingProfilesRequest {
+            charging_limit_source: "a".repeat(21),
+            evse_id: -1,
+            charging_profile: vec![],
+            ..Default::default()
+        };
+        assert_invalid_fields(
+            &req.validate().unwrap_err(),
+            &["charging_limit_source", "evse_id", "charging_profile"],
+        );
+    }
+
+    #[test]
+    fn test_report_charging_profiles_request_serialize_deserialize() {
+        let req = ReportChargingProfilesRequest {
+            request_id: 4,
+            tbc: Some(true),
+            ..Default::default()
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("\"chargingLimitSource\":\"CSO\""));
+        let deserialized: ReportChargingProfilesRequest =
+            serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+
+    #[test]
+    fn test_report_charging_profiles_response_serialize_deserialize() {
+        let resp = ReportChargingProfiles::response();
+        let serialized = serde_json::to_string(&resp).unwrap();
+        let deserialized: ReportChargingProfilesResponse =
+            serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
@@ -0,0 +1,204 @@
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::der_curve_get_type::DERCurveGetType;
+use crate::structures::enter_service_get_type::EnterServiceGetType;
+use crate::structures::fixed_pf_get_type::FixedPFGetType;
+use crate::structures::fixed_var_get_type::FixedVarGetType;
+use crate::structures::freq_droop_get_type::FreqDroopGetType;
+use crate::structures::gradient_get_type::GradientGetType;
+use crate::structures::limit_max_discharge_get_type::LimitMaxDischargeGetType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.65. ReportDERControl
+pub struct ReportDERControl;
+
+impl OcppMessage for ReportDERControl {
+    type Request = ReportDERControlRequest;
+    type Response = ReportDERControlResponse;
+}
+
+/// 1.65.1. ReportDERControlRequest
+/// (2.1) This contains the field definition of the ReportDERControlRequest PDU sent by the Charging Station to the CSMS.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportDERControlRequest {
+    /// Required. RequestId from GetDERControlRequest.
+    pub request_id: i32,
+    /// Optional. To Be Continued. Default value when omitted: false. False indicates that there are no further messages as
+    /// part of this report.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tbc: Option<bool>,
+    /// Optional. Frequency Watt, Volt-Var and other curve-based controls.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub curve: Option<Vec<DERCurveGetType>>,
+    /// Optional. Enter service after trip settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enter_service: Option<Vec<EnterServiceGetType>>,
+    /// Optional. Fixed power factor setpoint when absorbing reactive power.
+    #[serde(rename = "fixedPFAbsorb", skip_serializing_if = "Option::is_none")]
+    pub fixed_pf_absorb: Option<Vec<FixedPFGetType>>,
+    /// Optional. Fixed power factor setpoint when injecting reactive power.
+    #[serde(rename = "fixedPFInject", skip_serializing_if = "Option::is_none")]
+    pub fixed_pf_inject: Option<Vec<FixedPFGetType>>,
+    /// Optional. Fixed reactive power setpoint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixed_var: Option<Vec<FixedVarGetType>>,
+    /// Optional. Frequency droop settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub freq_droop: Option<Vec<FreqDroopGetType>>,
+    /// Optional. Gradient settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gradient: Option<Vec<GradientGetType>>,
+    /// Optional. Limit maximum discharge as percentage of rated capability.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit_max_discharge: Option<Vec<LimitMaxDischargeGetType>>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+
+impl ReportDERControlRequest {
+    /// Returns true when further ReportDERControlRequest messages follow as part of the same report.
+    pub fn is_continued(&self) -> bool {
+        self.tbc.unwrap_or(false)
+    }
+}
+
+fn check_controls<T: OcppEntity>(
+    b: &mut StructureValidationBuilder,
+    field: &str,
+    controls: &Option<Vec<T>>,
+) {
+    if let Some(controls) = controls {
+        b.check_cardinality(field, 1, 24, &controls.iter());
+        b.check_iter_member(field, controls.iter());
+    }
+}
+
+#[typetag::serde]
+impl OcppEntity for ReportDERControlRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        check_controls(&mut b, "curve", &self.curve);
+        check_controls(&mut b, "enter_service", &self.enter_service);
+        check_controls(&mut b, "fixed_pf_absorb", &self.fixed_pf_absorb);
+        check_controls(&mut b, "fixed_pf_inject", &self.fixed_pf_inject);
+        check_controls(&mut b, "fixed_var", &self.fixed_var);
+        check_controls(&mut b, "freq_droop", &self.freq_droop);
+        check_controls(&mut b, "gradient", &self.gradient);
+        check_controls(&mut b, "limit_max_discharge", &self.limit_max_discharge);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("ReportDERControlRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for ReportDERControlRequest {
+    fn get_message_type(&self) -> String {
+        String::from("ReportDERControl")
+    }
+}
+
+/// 1.65.2. ReportDERControlResponse
+/// (2.1) This contains the field definition of the ReportDERControlResponse PDU sent by the CSMS to the Charging Station in
+/// response to ReportDERControlRequest. No fields are defined.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportDERControlResponse {
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for ReportDERControlResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut e = StructureValidationBuilder::new();
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("ReportDERControlResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+    use crate::structures::enter_service_type::EnterServiceType;
+    use crate::structures::gradient_type::GradientType;
+
+    #[test]
+    fn test_report_der_control() {
+        assert!(ReportDERControl::request().validate().is_ok());
+        assert!(ReportDERControl::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_report_der_control_tbc() {
+        let mut req = ReportDERControlRequest::default();
+        assert!(!req.is_continued());
+        req.tbc = Some(true);
+        assert!(req.is_continued());
+    }
+
+    #[test]
+    fn test_report_der_control_invalid() {
+        let req = ReportDERControlRequest {
+            enter_service: Some(vec![EnterServiceGetType {
+                id: "a".repeat(37),
+                enter_service: EnterServiceType::default(),
+                custom_data: None,
+            }]),
+            gradient: Some(vec![]),
+            ..Default::default()
+        };
+        assert_invalid_fields(
+            &req.validate().unwrap_err(),
+            &["enter_service[0]", "gradient"],
+        );
+    }
+
+    #[test]
+    fn test_report_der_control_too_many() {
+        let req = ReportDERControlRequest {
+            gradient: Some(vec![
+                GradientGetType {
+                    id: "g".to_string(),
+                    gradient: GradientType::default(),
+                    custom_data: None,
+                };
+                25
+            ]),
+            ..Default::default()
+        };
+        assert_invalid_fields(&req.validate().unwrap_err(), &["gradient"]);
+    }
+
+    #[test]
+    fn test_report_der_control_serialize_deserialize() {
+        let req = ReportDERControlRequest {
+            request_id: 3,
+            tbc: Some(true),
+            gradient: Some(vec![GradientGetType {
+                id: "g".to_string(),
+                gradient: GradientType::default(),
+                custom_data: None,
+            }]),
+            ..Default::default()
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("\"requestId\":3"));
+        assert!(serialized.contains("\"tbc\":true"));
+        let deserialized: ReportDERControlRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+}
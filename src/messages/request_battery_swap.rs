@@ -0,0 +1,123 @@
+use crate::enums::generic_status_enum_type::GenericStatusEnumType;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::id_token_type::IdTokenType;
+use crate::structures::status_info_type::StatusInfoType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.66. RequestBatterySwap
+pub struct RequestBatterySwap;
+
+impl OcppMessage for RequestBatterySwap {
+    type Request = RequestBatterySwapRequest;
+    type Response = RequestBatterySwapResponse;
+}
+
+/// 1.66.1. RequestBatterySwapRequest
+/// (2.1) This contains the field definition of the RequestBatterySwapRequest PDU sent by the CSMS to the Charging Station.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestBatterySwapRequest {
+    /// Required. Id token of EV driver.
+    pub id_token: IdTokenType,
+    /// Required. Request id to match with BatterySwapRequest.
+    pub request_id: i32,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for RequestBatterySwapRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_member("id_token", &self.id_token);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("RequestBatterySwapRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for RequestBatterySwapRequest {
+    fn get_message_type(&self) -> String {
+        String::from("RequestBatterySwap")
+    }
+}
+
+/// 1.66.2. RequestBatterySwapResponse
+/// (2.1) This contains the field definition of the RequestBatterySwapResponse PDU sent by the Charging Station to the CSMS in
+/// response to RequestBatterySwapRequest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestBatterySwapResponse {
+    /// Required. Accepted or rejected the request.
+    pub status: GenericStatusEnumType,
+    /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for RequestBatterySwapResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(status_info) = &self.status_info {
+            b.check_member("status_info", status_info);
+        }
+
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("RequestBatterySwapResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_request_battery_swap() {
+        assert!(RequestBatterySwap::request().validate().is_ok());
+        assert!(RequestBatterySwap::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_request_battery_swap_invalid() {
+        let req = RequestBatterySwapRequest {
+            id_token: IdTokenType {
+                id_token: "a".repeat(256),
+                ..Default::default()
+            },
+            request_id: 1,
+            custom_data: None,
+        };
+        assert_invalid_fields(&req.validate().unwrap_err(), &["id_token"]);
+    }
+
+    #[test]
+    fn test_request_battery_swap_serialize_deserialize() {
+        let req = RequestBatterySwapRequest {
+            id_token: IdTokenType::default(),
+            request_id: 3,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("\"requestId\":3"));
+        let deserialized: RequestBatterySwapRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+}
@@ -0,0 +1,210 @@
+use crate::enums::charging_profile_purpose_enum_type::ChargingProfilePurposeEnumType;
+use crate::enums::requested_start_stop_status_enum_type::RequestStartStopStatusEnumType;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::charging_profile_type::ChargingProfileType;
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::id_token_type::IdTokenType;
+use crate::structures::status_info_type::StatusInfoType;
+use crate::structures::transaction_limit_type::TransactionLimitType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.67. RequestStartTransaction
+pub struct RequestStartTransaction;
+
+impl OcppMessage for RequestStartTransaction {
+    type Request = RequestStartTransactionRequest;
+    type Response = RequestStartTransactionResponse;
+}
+
+/// 1.67.1. RequestStartTransactionRequest
+/// This contains the field definition of the RequestStartTransactionRequest PDU sent by the CSMS to the Charging Station.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestStartTransactionRequest {
+    /// Optional. Number of the EVSE on which to start the transaction. EvseId SHALL be > 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evse_id: Option<i32>,
+    /// Optional. The group identifier that the Charging Station must use to start a transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_id_token: Option<IdTokenType>,
+    /// Required. The identifier that the Charging Station must use to start a transaction.
+    pub id_token: IdTokenType,
+    /// Required. Id given by the server to this start request. The Charging Station will return this in the
+    /// TransactionEventRequest, letting the server know which transaction was started for this request.
+    pub remote_start_id: i32,
+    /// Optional. Charging Profile to be used by the Charging Station for the requested transaction.
+    /// ChargingProfilePurpose MUST be set to TxProfile.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charging_profile: Option<ChargingProfileType>,
+    /// Optional. (2.1) Maximum cost, energy, time or SoC allowed for this transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_limit: Option<TransactionLimitType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for RequestStartTransactionRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(evse_id) = self.evse_id {
+            b.check_bounds("evse_id", 1, i32::MAX, evse_id);
+        }
+
+        if let Some(group_id_token) = &self.group_id_token {
+            b.check_member("group_id_token", group_id_token);
+        }
+
+        b.check_member("id_token", &self.id_token);
+
+        if let Some(charging_profile) = &self.charging_profile {
+            b.check_member("charging_profile", charging_profile);
+
+            if charging_profile.charging_profile_purpose
+                != ChargingProfilePurposeEnumType::TxProfile
+            {
+                b.push_relation_error(
+                    "charging_profile",
+                    "remote_start_id",
+                    "the charging_profile of a RequestStartTransactionRequest SHALL be a TxProfile",
+                );
+            }
+        }
+
+        if let Some(transaction_limit) = &self.transaction_limit {
+            b.check_member("transaction_limit", transaction_limit);
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("RequestStartTransactionRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for RequestStartTransactionRequest {
+    fn get_message_type(&self) -> String {
+        String::from("RequestStartTransaction")
+    }
+}
+
+/// 1.67.2. RequestStartTransactionResponse
+/// This contains the field definition of the RequestStartTransactionResponse PDU sent by the Charging Station to the CSMS in
+/// response to RequestStartTransactionRequest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestStartTransactionResponse {
+    /// Required. Status indicating whether the Charging Station accepts the request to start a transaction.
+    pub status: RequestStartStopStatusEnumType,
+    /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_info: Option<StatusInfoType>,
+    /// Optional. When the transaction was already started by the Charging Station before the RequestStartTransactionRequest
+    /// was received, for example: cable plugged in first. This contains the transactionId of the already started transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_id: Option<String>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for RequestStartTransactionResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(status_info) = &self.status_info {
+            b.check_member("status_info", status_info);
+        }
+
+        if let Some(transaction_id) = &self.transaction_id {
+            b.check_cardinality("transaction_id", 0, 36, &transaction_id.chars());
+        }
+
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("RequestStartTransactionResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_request_start_transaction() {
+        assert!(RequestStartTransaction::request().validate().is_ok());
+        assert!(RequestStartTransaction::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_request_start_transaction_evse_id_zero() {
+        let req = RequestStartTransactionRequest {
+            evse_id: Some(0),
+            ..Default::default()
+        };
+        assert_invalid_fields(&req.validate().unwrap_err(), &["evse_id"]);
+    }
+
+    #[test]
+    fn test_request_start_transaction_charging_profile_purpose() {
+        let mut req = RequestStartTransactionRequest {
+            evse_id: Some(1),
+            charging_profile: Some(ChargingProfileType::default()),
+            ..Default::default()
+        };
+        assert_invalid_fields(
+            &req.validate().unwrap_err(),
+            &["charging_profile", "remote_start_id"],
+        );
+
+        req.charging_profile = Some(ChargingProfileType {
+            charging_profile_purpose: ChargingProfilePurposeEnumType::TxProfile,
+            ..Default::default()
+        });
+        assert!(req.validate().is_ok());
+    }
+
+    #[test]
+    fn test_request_start_transaction_response_transaction_id_long() {
+        let resp = RequestStartTransactionResponse {
+            transaction_id: Some("a".repeat(37)),
+            ..Default::default()
+        };
+        assert_invalid_fields(&resp.validate().unwrap_err(), &["transaction_id"]);
+    }
+
+    #[test]
+    fn test_request_start_transaction_serialize_deserialize() {
+        let req = RequestStartTransactionRequest {
+            evse_id: Some(1),
+            remote_start_id: 42,
+            ..Default::default()
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("\"remoteStartId\":42"));
+        let deserialized: RequestStartTransactionRequest =
+            serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+
+        let resp = RequestStartTransactionResponse {
+            status: RequestStartStopStatusEnumType::Accepted,
+            status_info: None,
+            transaction_id: Some("tx-1".to_string()),
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&resp).unwrap();
+        let deserialized: RequestStartTransactionResponse =
+            serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::enums::requested_start_stop_status_enum_type::RequestStartStopStatusEnumType;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::status_info_type::StatusInfoType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.68. RequestStopTransaction
+pub struct RequestStopTransaction;
+
+impl OcppMessage for RequestStopTransaction {
+    type Request = RequestStopTransactionRequest;
+    type Response = RequestStopTransactionResponse;
+}
+
+/// 1.68.1. RequestStopTransactionRequest
+/// This contains the field definition of the RequestStopTransactionRequest PDU sent by the CSMS to the Charging Station.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestStopTransactionRequest {
+    /// Required. The identifier of the transaction which the Charging Station is requested to stop.
+    pub transaction_id: String,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for RequestStopTransactionRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_cardinality("transaction_id", 0, 36, &self.transaction_id.chars());
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("RequestStopTransactionRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for RequestStopTransactionRequest {
+    fn get_message_type(&self) -> String {
+        String::from("RequestStopTransaction")
+    }
+}
+
+/// 1.68.2. RequestStopTransactionResponse
+/// This contains the field definition of the RequestStopTransactionResponse PDU sent by the Charging Station to the CSMS in
+/// response to RequestStopTransactionRequest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestStopTransactionResponse {
+    /// Required. Status indicating whether Charging Station accepts the request to stop a transaction.
+    pub status: RequestStartStopStatusEnumType,
+    /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for RequestStopTransactionResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(status_info) = &self.status_info {
+            b.check_member("status_info", status_info);
+        }
+
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("RequestStopTransactionResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_request_stop_transaction() {
+        assert!(RequestStopTransaction::request().validate().is_ok());
+        assert!(RequestStopTransaction::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_request_stop_transaction_transaction_id_long() {
+        let req = RequestStopTransactionRequest {
+            transaction_id: "a".repeat(37),
+            custom_data: None,
+        };
+        assert_invalid_fields(&req.validate().unwrap_err(), &["transaction_id"]);
+    }
+
+    #[test]
+    fn test_request_stop_transaction_serialize_deserialize() {
+        let req = RequestStopTransactionRequest {
+            transaction_id: "tx-1".to_string(),
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert_eq!(serialized, r#"{"transactionId":"tx-1"}"#);
+        let deserialized: RequestStopTransactionRequest =
+            serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+
+        let resp = RequestStopTransactionResponse {
+            status: RequestStartStopStatusEnumType::Rejected,
+            status_info: None,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&resp).unwrap();
+        let deserialized: RequestStopTransactionResponse =
+            serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
@@ -0,0 +1,100 @@
+use crate::enums::reservation_update_status_enum_type::ReservationUpdateStatusEnumType;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.69. ReservationStatusUpdate
+pub struct ReservationStatusUpdate;
+
+impl OcppMessage for ReservationStatusUpdate {
+    type Request = ReservationStatusUpdateRequest;
+    type Response = ReservationStatusUpdateResponse;
+}
+
+/// 1.69.1. ReservationStatusUpdateRequest
+/// This contains the field definition of the ReservationStatusUpdateRequest PDU sent by the Charging Station to the CSMS.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReservationStatusUpdateRequest {
+    /// Required. The ID of the reservation.
+    pub reservation_id: i32,
+    /// Required. The updated reservation status.
+    pub reservation_update_status: ReservationUpdateStatusEnumType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for ReservationStatusUpdateRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut e = StructureValidationBuilder::new();
+
+        e.check_enum("reservation_update_status", &self.reservation_update_status);
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("ReservationStatusUpdateRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for ReservationStatusUpdateRequest {
+    fn get_message_type(&self) -> String {
+        String::from("ReservationStatusUpdate")
+    }
+}
+
+/// 1.69.2. ReservationStatusUpdateResponse
+/// This contains the field definition of the ReservationStatusUpdateResponse PDU sent by the CSMS to the Charging Station in
+/// response to ReservationStatusUpdateRequest. No fields are defined.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReservationStatusUpdateResponse {
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for ReservationStatusUpdateResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut e = StructureValidationBuilder::new();
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("ReservationStatusUpdateResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reservation_status_update() {
+        assert!(ReservationStatusUpdate::request().validate().is_ok());
+        assert!(ReservationStatusUpdate::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_reservation_status_update_serialize_deserialize() {
+        let req = ReservationStatusUpdateRequest {
+            reservation_id: 12,
+            reservation_update_status: ReservationUpdateStatusEnumType::NoTransaction,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert_eq!(
+            serialized,
+            r#"{"reservationId":12,"reservationUpdateStatus":"NoTransaction"}"#
+        );
+        let deserialized: ReservationStatusUpdateRequest =
+            serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+}
@@ -0,0 +1,158 @@
+use crate::enums::reserve_now_status_enum_type::ReserveNowStatusEnumType;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::id_token_type::IdTokenType;
+use crate::structures::status_info_type::StatusInfoType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.70. ReserveNow
+pub struct ReserveNow;
+
+impl OcppMessage for ReserveNow {
+    type Request = ReserveNowRequest;
+    type Response = ReserveNowResponse;
+}
+
+/// 1.70.1. ReserveNowRequest
+/// This contains the field definition of the ReserveNowRequest PDU sent by the CSMS to the Charging Station.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReserveNowRequest {
+    /// Required. Id of reservation.
+    pub id: i32,
+    /// Required. Date and time at which the reservation expires.
+    pub expiry_date_time: DateTime<Utc>,
+    /// Optional. (2.1) This field specifies the connector type. Values defined in Appendix as ConnectorEnumStringType.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connector_type: Option<String>,
+    /// Required. The identifier for which the reservation is made.
+    pub id_token: IdTokenType,
+    /// Optional. This contains ID of the evse to be reserved.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evse_id: Option<i32>,
+    /// Optional. The group identifier for which the reservation is made.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_id_token: Option<IdTokenType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for ReserveNowRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(connector_type) = &self.connector_type {
+            b.check_cardinality("connector_type", 0, 20, &connector_type.chars());
+        }
+
+        b.check_member("id_token", &self.id_token);
+
+        if let Some(evse_id) = self.evse_id {
+            b.check_bounds("evse_id", 0, i32::MAX, evse_id);
+        }
+
+        if let Some(group_id_token) = &self.group_id_token {
+            b.check_member("group_id_token", group_id_token);
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("ReserveNowRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for ReserveNowRequest {
+    fn get_message_type(&self) -> String {
+        String::from("ReserveNow")
+    }
+}
+
+/// 1.70.2. ReserveNowResponse
+/// This contains the field definition of the ReserveNowResponse PDU sent by the Charging Station to the CSMS in response to
+/// ReserveNowRequest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReserveNowResponse {
+    /// Required. This indicates the success or failure of the reservation.
+    pub status: ReserveNowStatusEnumType,
+    /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for ReserveNowResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(status_info) = &self.status_info {
+            b.check_member("status_info", status_info);
+        }
+
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("ReserveNowResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_reserve_now() {
+        assert!(ReserveNow::request().validate().is_ok());
+        assert!(ReserveNow::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_reserve_now_request_invalid() {
+        let req = ReserveNowRequest {
+            connector_type: Some("a".repeat(21)),
+            evse_id: Some(-1),
+            ..Default::default()
+        };
+        assert_invalid_fields(&req.validate().unwrap_err(), &["connector_type", "evse_id"]);
+    }
+
+    #[test]
+    fn test_reserve_now_serialize_deserialize() {
+        let req = ReserveNowRequest {
+            id: 7,
+            expiry_date_time: Utc::now(),
+            connector_type: Some("cType2".to_string()),
+            id_token: IdTokenType::default(),
+            evse_id: Some(1),
+            group_id_token: None,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("\"expiryDateTime\""));
+        assert!(serialized.contains("\"connectorType\":\"cType2\""));
+        let deserialized: ReserveNowRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+
+        let resp = ReserveNowResponse {
+            status: ReserveNowStatusEnumType::Occupied,
+            status_info: None,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&resp).unwrap();
+        let deserialized: ReserveNowResponse = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
@@ -0,0 +1,135 @@
+use crate::enums::reset_enum_type::ResetEnumType;
+use crate::enums::reset_status_enum_type::ResetStatusEnumType;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::status_info_type::StatusInfoType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.71. Reset
+pub struct Reset;
+
+impl OcppMessage for Reset {
+    type Request = ResetRequest;
+    type Response = ResetResponse;
+}
+
+/// 1.71.1. ResetRequest
+/// This contains the field definition of the ResetRequest PDU sent by the CSMS to the Charging Station.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ResetRequest {
+    /// Required. This contains the type of reset that the Charging Station or EVSE should perform.
+    pub r#type: ResetEnumType,
+    /// Optional. This contains the ID of a specific EVSE that needs to be reset, instead of the entire Charging Station.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evse_id: Option<i32>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for ResetRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(evse_id) = self.evse_id {
+            b.check_bounds("evse_id", 0, i32::MAX, evse_id);
+        }
+
+        b.check_enum("type", &self.r#type);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("ResetRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for ResetRequest {
+    fn get_message_type(&self) -> String {
+        String::from("Reset")
+    }
+}
+
+/// 1.71.2. ResetResponse
+/// This contains the field definition of the ResetResponse PDU sent by the Charging Station to the CSMS in response to a
+/// ResetRequest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ResetResponse {
+    /// Required. This indicates whether the Charging Station is able to perform the reset.
+    pub status: ResetStatusEnumType,
+    /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for ResetResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(status_info) = &self.status_info {
+            b.check_member("status_info", status_info);
+        }
+
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("ResetResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reset() {
+        assert!(Reset::request().validate().is_ok());
+        assert!(Reset::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_reset_request_negative_evse_id() {
+        let req = ResetRequest {
+            evse_id: Some(-1),
+            ..Default::default()
+        };
+        assert!(req.validate().is_err());
+    }
+
+    #[test]
+    fn test_reset_request_serialize_deserialize() {
+        let req = ResetRequest {
+            r#type: ResetEnumType::OnIdle,
+            evse_id: Some(1),
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("\"type\":\"OnIdle\""));
+        let deserialized: ResetRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+
+    #[test]
+    fn test_reset_response_serialize_deserialize() {
+        let resp = ResetResponse {
+            status: ResetStatusEnumType::Scheduled,
+            status_info: None,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&resp).unwrap();
+        let deserialized: ResetResponse = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
@@ -0,0 +1,127 @@
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.72. SecurityEventNotification
+pub struct SecurityEventNotification;
+
+impl OcppMessage for SecurityEventNotification {
+    type Request = SecurityEventNotificationRequest;
+    type Response = SecurityEventNotificationResponse;
+}
+
+/// 1.72.1. SecurityEventNotificationRequest
+/// This contains the field definition of the SecurityEventNotificationRequest PDU sent by the Charging Station to the CSMS.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityEventNotificationRequest {
+    /// Required. Type of the security event. This value should be taken from the Security events list.
+    pub r#type: String,
+    /// Required. Date and time at which the event occurred.
+    pub timestamp: DateTime<Utc>,
+    /// Optional. Additional information about the occurred security event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tech_info: Option<String>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for SecurityEventNotificationRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_cardinality("type", 0, 50, &self.r#type.chars());
+
+        if let Some(tech_info) = &self.tech_info {
+            b.check_cardinality("tech_info", 0, 255, &tech_info.chars());
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("SecurityEventNotificationRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for SecurityEventNotificationRequest {
+    fn get_message_type(&self) -> String {
+        String::from("SecurityEventNotification")
+    }
+}
+
+/// 1.72.2. SecurityEventNotificationResponse
+/// This contains the field definition of the SecurityEventNotificationResponse PDU sent by the CSMS to the Charging Station in
+/// response to SecurityEventNotificationRequest. No fields are defined.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityEventNotificationResponse {
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for SecurityEventNotificationResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut e = StructureValidationBuilder::new();
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("SecurityEventNotificationResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_security_event_notification() {
+        assert!(SecurityEventNotification::request().validate().is_ok());
+        assert!(SecurityEventNotification::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_security_event_notification_request_invalid() {
+        let req = SecurityEventNotificationRequest {
+            r#type: "a".repeat(51),
+            timestamp: Utc::now(),
+            tech_info: Some("a".repeat(256)),
+            custom_data: None,
+        };
+        assert_invalid_fields(&req.validate().unwrap_err(), &["type", "tech_info"]);
+    }
+
+    #[test]
+    fn test_security_event_notification_request_serialize_deserialize() {
+        let req = SecurityEventNotificationRequest {
+            r#type: "FirmwareUpdated".to_string(),
+            timestamp: Utc::now(),
+            tech_info: Some("1.2.3".to_string()),
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("\"type\":\"FirmwareUpdated\""));
+        assert!(serialized.contains("\"techInfo\":\"1.2.3\""));
+        let deserialized: SecurityEventNotificationRequest =
+            serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+
+    #[test]
+    fn test_security_event_notification_response_serialize_deserialize() {
+        let resp = SecurityEventNotification::response();
+        let serialized = serde_json::to_string(&resp).unwrap();
+        let deserialized: SecurityEventNotificationResponse =
+            serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
@@ -0,0 +1,210 @@
+use crate::enums::send_local_list_status_enum_type::SendLocalListStatusEnumType;
+use crate::enums::update_enum_type::UpdateEnumType;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::authorization_data::AuthorizationData;
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::status_info_type::StatusInfoType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.73. SendLocalList
+pub struct SendLocalList;
+
+impl OcppMessage for SendLocalList {
+    type Request = SendLocalListRequest;
+    type Response = SendLocalListResponse;
+}
+
+/// 1.73.1. SendLocalListRequest
+/// This contains the field definition of the SendLocalListRequest PDU sent by the CSMS to the Charging Station.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SendLocalListRequest {
+    /// Required. In case of a full update this is the version number of the full list. In case of a differential update it is
+    /// the version number of the list after the update has been applied.
+    pub version_number: i32,
+    /// Required. This contains the type of update (full or differential) of this request.
+    pub update_type: UpdateEnumType,
+    /// Optional. This contains the Local Authorization List entries. Required when update_type is Full.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub local_authorization_list: Option<Vec<AuthorizationData>>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for SendLocalListRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(local_authorization_list) = &self.local_authorization_list {
+            b.check_cardinality(
+                "local_authorization_list",
+                1,
+                usize::MAX,
+                &local_authorization_list.iter(),
+            );
+            b.check_iter_member("local_authorization_list", local_authorization_list.iter());
+        }
+
+        if self.update_type == UpdateEnumType::Full {
+            match &self.local_authorization_list {
+                None => b.push_relation_error(
+                    "local_authorization_list",
+                    "update_type",
+                    "local_authorization_list SHALL be present when update_type is Full",
+                ),
+                Some(list) if list.iter().any(|d| d.id_token_info.is_none()) => b
+                    .push_relation_error(
+                        "local_authorization_list",
+                        "update_type",
+                        "every entry SHALL contain an id_token_info when update_type is Full",
+                    ),
+                _ => {}
+            }
+        }
+
+        b.check_enum("update_type", &self.update_type);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("SendLocalListRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for SendLocalListRequest {
+    fn get_message_type(&self) -> String {
+        String::from("SendLocalList")
+    }
+}
+
+/// 1.73.2. SendLocalListResponse
+/// This contains the field definition of the SendLocalListResponse PDU sent by the Charging Station to the CSMS in response to
+/// SendLocalListRequest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SendLocalListResponse {
+    /// Required. This indicates whether the Charging Station has successfully received and applied the update of the Local
+    /// Authorization List.
+    pub status: SendLocalListStatusEnumType,
+    /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for SendLocalListResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(status_info) = &self.status_info {
+            b.check_member("status_info", status_info);
+        }
+
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("SendLocalListResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+    use crate::structures::id_token_info_type::IdTokenInfoType;
+
+    #[test]
+    fn test_send_local_list() {
+        assert!(SendLocalList::request().validate().is_ok());
+        assert!(SendLocalList::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_send_local_list_full_requires_list() {
+        let req = SendLocalListRequest {
+            update_type: UpdateEnumType::Full,
+            ..Default::default()
+        };
+        assert_invalid_fields(
+            &req.validate().unwrap_err(),
+            &["local_authorization_list", "update_type"],
+        );
+    }
+
+    #[test]
+    fn test_send_local_list_full_requires_id_token_info() {
+        let mut req = SendLocalListRequest {
+            version_number: 2,
+            update_type: UpdateEnumType::Full,
+            local_authorization_list: Some(vec![AuthorizationData::default()]),
+            custom_data: None,
+        };
+        assert_invalid_fields(
+            &req.validate().unwrap_err(),
+            &["local_authorization_list", "update_type"],
+        );
+
+        req.local_authorization_list = Some(vec![AuthorizationData {
+            id_token_info: Some(IdTokenInfoType::default()),
+            ..Default::default()
+        }]);
+        assert!(req.validate().is_ok());
+    }
+
+    #[test]
+    fn test_send_local_list_differential_allows_removal() {
+        let req = SendLocalListRequest {
+            version_number: 3,
+            update_type: UpdateEnumType::Differential,
+            local_authorization_list: Some(vec![AuthorizationData::default()]),
+            custom_data: None,
+        };
+        assert!(req.validate().is_ok());
+    }
+
+    #[test]
+    fn test_send_local_list_empty_list() {
+        let req = SendLocalListRequest {
+            local_authorization_list: Some(vec![]),
+            ..Default::default()
+        };
+        assert_invalid_fields(&req.validate().unwrap_err(), &["local_authorization_list"]);
+    }
+
+    #[test]
+    fn test_send_local_list_serialize_deserialize() {
+        let req = SendLocalListRequest {
+            version_number: 1,
+            update_type: UpdateEnumType::Full,
+            local_authorization_list: Some(vec![AuthorizationData {
+                id_token_info: Some(IdTokenInfoType::default()),
+                ..Default::default()
+            }]),
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("\"updateType\":\"Full\""));
+        assert!(serialized.contains("\"localAuthorizationList\""));
+        let deserialized: SendLocalListRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+
+        let resp = SendLocalListResponse {
+            status: SendLocalListStatusEnumType::VersionMismatch,
+            status_info: None,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&resp).unwrap();
+        let deserialized: SendLocalListResponse = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
@@ -0,0 +1,182 @@
+use crate::enums::charging_profile_purpose_enum_type::ChargingProfilePurposeEnumType;
+use crate::enums::charging_profile_status_enum_type::ChargingProfileStatusEnumType;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::charging_profile_type::ChargingProfileType;
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::status_info_type::StatusInfoType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.74. SetChargingProfile
+pub struct SetChargingProfile;
+
+impl OcppMessage for SetChargingProfile {
+    type Request = SetChargingProfileRequest;
+    type Response = SetChargingProfileResponse;
+}
+
+/// 1.74.1. SetChargingProfileRequest
+/// This contains the field definition of the SetChargingProfileRequest PDU sent by the CSMS to the Charging Station.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SetChargingProfileRequest {
+    /// Required. For TxDefaultProfile an evseId=0 applies the profile to each individual evse.
+    /// For ChargingStationMaxProfile and ChargingStationExternalConstraints an evseId=0 contains an overal limit for the
+    /// whole Charging Station.
+    pub evse_id: i32,
+    /// Required. The charging profile to be set at the Charging Station.
+    pub charging_profile: ChargingProfileType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for SetChargingProfileRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_bounds("evse_id", 0, i32::MAX, self.evse_id);
+        b.check_member("charging_profile", &self.charging_profile);
+
+        match self.charging_profile.charging_profile_purpose {
+            ChargingProfilePurposeEnumType::TxProfile if self.evse_id == 0 => {
+                b.push_relation_error(
+                    "evse_id",
+                    "charging_profile",
+                    "a TxProfile SHALL only be set on an EVSE with evse_id > 0",
+                );
+            }
+            ChargingProfilePurposeEnumType::ChargingStationMaxProfile if self.evse_id != 0 => {
+                b.push_relation_error(
+                    "evse_id",
+                    "charging_profile",
+                    "a ChargingStationMaxProfile SHALL only be set with evse_id = 0",
+                );
+            }
+            _ => {}
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("SetChargingProfileRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for SetChargingProfileRequest {
+    fn get_message_type(&self) -> String {
+        String::from("SetChargingProfile")
+    }
+}
+
+/// 1.74.2. SetChargingProfileResponse
+/// This contains the field definition of the SetChargingProfileResponse PDU sent by the Charging Station to the CSMS in response
+/// to SetChargingProfileRequest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SetChargingProfileResponse {
+    /// Required. Returns whether the Charging Station has been able to process the message successfully.
+    /// This does not guarantee the schedule will be followed to the letter. There might be other constraints the Charging
+    /// Station may need to take into account.
+    pub status: ChargingProfileStatusEnumType,
+    /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for SetChargingProfileResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(status_info) = &self.status_info {
+            b.check_member("status_info", status_info);
+        }
+
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("SetChargingProfileResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_set_charging_profile() {
+        assert!(SetChargingProfile::request().validate().is_ok());
+        assert!(SetChargingProfile::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_set_charging_profile_request_negative_evse_id() {
+        let req = SetChargingProfileRequest {
+            evse_id: -1,
+            ..Default::default()
+        };
+        assert_invalid_fields(&req.validate().unwrap_err(), &["evse_id"]);
+    }
+
+    #[test]
+    fn test_set_charging_profile_request_invalid_profile() {
+        let mut req = SetChargingProfile::request();
+        req.charging_profile.stack_level = -1;
+        assert_invalid_fields(&req.validate().unwrap_err(), &["charging_profile"]);
+    }
+
+    #[test]
+    fn test_set_charging_profile_request_purpose_and_evse() {
+        let mut req = SetChargingProfile::request();
+        req.charging_profile.charging_profile_purpose = ChargingProfilePurposeEnumType::TxProfile;
+        assert_invalid_fields(
+            &req.validate().unwrap_err(),
+            &["evse_id", "charging_profile"],
+        );
+        req.evse_id = 1;
+        assert!(req.validate().is_ok());
+
+        req.charging_profile.charging_profile_purpose =
+            ChargingProfilePurposeEnumType::ChargingStationMaxProfile;
+        assert_invalid_fields(
+            &req.validate().unwrap_err(),
+            &["evse_id", "charging_profile"],
+        );
+        req.evse_id = 0;
+        assert!(req.validate().is_ok());
+    }
+
+    #[test]
+    fn test_set_charging_profile_request_serialize_deserialize() {
+        let req = SetChargingProfileRequest {
+            evse_id: 1,
+            ..Default::default()
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("\"evseId\":1"));
+        let deserialized: SetChargingProfileRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+
+    #[test]
+    fn test_set_charging_profile_response_serialize_deserialize() {
+        let resp = SetChargingProfileResponse {
+            status: ChargingProfileStatusEnumType::Rejected,
+            status_info: None,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&resp).unwrap();
+        let deserialized: SetChargingProfileResponse = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
@@ -0,0 +1,133 @@
+use crate::enums::tariff_set_status_enum_type::TariffSetStatusEnumType;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::status_info_type::StatusInfoType;
+use crate::structures::tariff_type::TariffType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.75. SetDefaultTariff
+pub struct SetDefaultTariff;
+
+impl OcppMessage for SetDefaultTariff {
+    type Request = SetDefaultTariffRequest;
+    type Response = SetDefaultTariffResponse;
+}
+
+/// 1.75.1. SetDefaultTariffRequest
+/// (2.1) This contains the field definition of the SetDefaultTariffRequest PDU sent by the CSMS to the Charging Station.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SetDefaultTariffRequest {
+    /// Required. EVSE that tariff applies to. When evseId = 0, then tariff applies to all EVSEs.
+    pub evse_id: i32,
+    /// Required. The tariff to be used as default.
+    pub tariff: TariffType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for SetDefaultTariffRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_bounds("evse_id", 0, i32::MAX, self.evse_id);
+        b.check_member("tariff", &self.tariff);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("SetDefaultTariffRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for SetDefaultTariffRequest {
+    fn get_message_type(&self) -> String {
+        String::from("SetDefaultTariff")
+    }
+}
+
+/// 1.75.2. SetDefaultTariffResponse
+/// (2.1) This contains the field definition of the SetDefaultTariffResponse PDU sent by the Charging Station to the CSMS in
+/// response to SetDefaultTariffRequest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SetDefaultTariffResponse {
+    /// Required. Status of the operation.
+    pub status: TariffSetStatusEnumType,
+    /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for SetDefaultTariffResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(status_info) = &self.status_info {
+            b.check_member("status_info", status_info);
+        }
+
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("SetDefaultTariffResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_set_default_tariff() {
+        assert!(SetDefaultTariff::request().validate().is_ok());
+        assert!(SetDefaultTariff::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_set_default_tariff_invalid() {
+        let req = SetDefaultTariffRequest {
+            evse_id: -1,
+            tariff: TariffType {
+                tariff_id: "a".repeat(61),
+                ..Default::default()
+            },
+            custom_data: None,
+        };
+        assert_invalid_fields(&req.validate().unwrap_err(), &["evse_id", "tariff"]);
+    }
+
+    #[test]
+    fn test_set_default_tariff_serialize_deserialize() {
+        let req = SetDefaultTariffRequest {
+            evse_id: 0,
+            tariff: TariffType::default(),
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("\"evseId\":0"));
+        let deserialized: SetDefaultTariffRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+
+        let resp = SetDefaultTariffResponse {
+            status: TariffSetStatusEnumType::DuplicateTariffId,
+            status_info: None,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&resp).unwrap();
+        let deserialized: SetDefaultTariffResponse = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
@@ -0,0 +1,316 @@
+use crate::enums::der_control_enum_type::DERControlEnumType;
+use crate::enums::der_control_status_enum_type::DERControlStatusEnumType;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::der_curve_type::DERCurveType;
+use crate::structures::enter_service_type::EnterServiceType;
+use crate::structures::fixed_pf_type::FixedPFType;
+use crate::structures::fixed_var_type::FixedVarType;
+use crate::structures::freq_droop_type::FreqDroopType;
+use crate::structures::gradient_type::GradientType;
+use crate::structures::limit_max_discharge_type::LimitMaxDischargeType;
+use crate::structures::status_info_type::StatusInfoType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.76. SetDERControl
+pub struct SetDERControl;
+
+impl OcppMessage for SetDERControl {
+    type Request = SetDERControlRequest;
+    type Response = SetDERControlResponse;
+}
+
+/// 1.76.1. SetDERControlRequest
+/// (2.1) This contains the field definition of the SetDERControlRequest PDU sent by the CSMS to the Charging Station.
+/// Exactly one of the control fields SHALL be present, and it SHALL match `control_type`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SetDERControlRequest {
+    /// Required. True if this is a default DER control.
+    pub is_default: bool,
+    /// Required. Unique id of this control, e.g. UUID.
+    pub control_id: String,
+    /// Required. Type of control. Determines which setting field below is used.
+    pub control_type: DERControlEnumType,
+    /// Optional. Curve data. Used for all curve-based control types.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub curve: Option<DERCurveType>,
+    /// Optional. Enter service after trip settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enter_service: Option<EnterServiceType>,
+    /// Optional. Fixed power factor setpoint when absorbing reactive power.
+    #[serde(rename = "fixedPFAbsorb", skip_serializing_if = "Option::is_none")]
+    pub fixed_pf_absorb: Option<FixedPFType>,
+    /// Optional. Fixed power factor setpoint when injecting reactive power.
+    #[serde(rename = "fixedPFInject", skip_serializing_if = "Option::is_none")]
+    pub fixed_pf_inject: Option<FixedPFType>,
+    /// Optional. Fixed reactive power setpoint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixed_var: Option<FixedVarType>,
+    /// Optional. Frequency droop settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub freq_droop: Option<FreqDroopType>,
+    /// Optional. Gradient settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gradient: Option<GradientType>,
+    /// Optional. Limit maximum discharge as percentage of rated capability.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit_max_discharge: Option<LimitMaxDischargeType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+
+impl SetDERControlRequest {
+    /// Returns the name of the control field that SHALL be used for the given control type.
+    pub fn control_field(control_type: &DERControlEnumType) -> &'static str {
+        match control_type {
+            DERControlEnumType::EnterService => "enter_service",
+            DERControlEnumType::FixedPFAbsorb => "fixed_pf_absorb",
+            DERControlEnumType::FixedPFInject => "fixed_pf_inject",
+            DERControlEnumType::FixedVAr => "fixed_var",
+            DERControlEnumType::FreqDroop => "freq_droop",
+            DERControlEnumType::Gradients => "gradient",
+            DERControlEnumType::LimitMaxDischarge => "limit_max_discharge",
+            _ => "curve",
+        }
+    }
+}
+
+#[typetag::serde]
+impl OcppEntity for SetDERControlRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_cardinality("control_id", 0, 36, &self.control_id.chars());
+
+        let controls: [(&str, Option<&dyn OcppEntity>); 8] = [
+            ("curve", self.curve.as_ref().map(|c| c as &dyn OcppEntity)),
+            (
+                "enter_service",
+                self.enter_service.as_ref().map(|c| c as &dyn OcppEntity),
+            ),
+            (
+                "fixed_pf_absorb",
+                self.fixed_pf_absorb.as_ref().map(|c| c as &dyn OcppEntity),
+            ),
+            (
+                "fixed_pf_inject",
+                self.fixed_pf_inject.as_ref().map(|c| c as &dyn OcppEntity),
+            ),
+            (
+                "fixed_var",
+                self.fixed_var.as_ref().map(|c| c as &dyn OcppEntity),
+            ),
+            (
+                "freq_droop",
+                self.freq_droop.as_ref().map(|c| c as &dyn OcppEntity),
+            ),
+            (
+                "gradient",
+                self.gradient.as_ref().map(|c| c as &dyn OcppEntity),
+            ),
+            (
+                "limit_max_discharge",
+                self.limit_max_discharge
+                    .as_ref()
+                    .map(|c| c as &dyn OcppEntity),
+            ),
+        ];
+
+        let expected = Self::control_field(&self.control_type);
+        for (field, control) in controls {
+            match control {
+                Some(control) => {
+                    b.check_member(field, control);
+                    if field != expected {
+                        b.push_relation_error(
+                            field,
+                            "control_type",
+                            "only the control matching control_type SHALL be present",
+                        );
+                    }
+                }
+                None if field == expected => b.push_relation_error(
+                    field,
+                    "control_type",
+                    "the control matching control_type SHALL be present",
+                ),
+                None => {}
+            }
+        }
+
+        b.check_enum("control_type", &self.control_type);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("SetDERControlRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for SetDERControlRequest {
+    fn get_message_type(&self) -> String {
+        String::from("SetDERControl")
+    }
+}
+
+impl Default for SetDERControlRequest {
+    fn default() -> SetDERControlRequest {
+        Self {
+            is_default: false,
+            control_id: "".to_string(),
+            control_type: DERControlEnumType::EnterService,
+            curve: None,
+            enter_service: Some(Default::default()),
+            fixed_pf_absorb: None,
+            fixed_pf_inject: None,
+            fixed_var: None,
+            freq_droop: None,
+            gradient: None,
+            limit_max_discharge: None,
+            custom_data: None,
+        }
+    }
+}
+
+/// 1.76.2. SetDERControlResponse
+/// (2.1) This contains the field definition of the SetDERControlResponse PDU sent by the Charging Station to the CSMS in
+/// response to SetDERControlRequest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SetDERControlResponse {
+    /// Required. Result of operation.
+    pub status: DERControlStatusEnumType,
+    /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_info: Option<StatusInfoType>,
+    /// Optional. List of controlIds that are superseded as a result of setting this control.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub superseded_ids: Option<Vec<String>>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for SetDERControlResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(status_info) = &self.status_info {
+            b.check_member("status_info", status_info);
+        }
+
+        if let Some(superseded_ids) = &self.superseded_ids {
+            b.check_cardinality("superseded_ids", 1, 24, &superseded_ids.iter());
+            for (i, id) in superseded_ids.iter().enumerate() {
+                b.check_cardinality(&format!("superseded_ids[{i}]"), 0, 36, &id.chars());
+            }
+        }
+
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("SetDERControlResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_set_der_control() {
+        assert!(SetDERControl::request().validate().is_ok());
+        assert!(SetDERControl::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_set_der_control_curve_types() {
+        let req = SetDERControlRequest {
+            control_type: DERControlEnumType::VoltVar,
+            enter_service: None,
+            curve: Some(DERCurveType::default()),
+            ..Default::default()
+        };
+        assert!(req.validate().is_ok());
+    }
+
+    #[test]
+    fn test_set_der_control_missing_control() {
+        let req = SetDERControlRequest {
+            control_type: DERControlEnumType::Gradients,
+            enter_service: None,
+            ..Default::default()
+        };
+        assert_invalid_fields(&req.validate().unwrap_err(), &["gradient", "control_type"]);
+    }
+
+    #[test]
+    fn test_set_der_control_mismatched_control() {
+        let req = SetDERControlRequest {
+            control_type: DERControlEnumType::FreqDroop,
+            ..Default::default()
+        };
+        assert_invalid_fields(
+            &req.validate().unwrap_err(),
+            &["enter_service", "freq_droop", "control_type"],
+        );
+    }
+
+    #[test]
+    fn test_set_der_control_multiple_controls() {
+        let req = SetDERControlRequest {
+            gradient: Some(GradientType::default()),
+            ..Default::default()
+        };
+        assert_invalid_fields(&req.validate().unwrap_err(), &["gradient", "control_type"]);
+    }
+
+    #[test]
+    fn test_set_der_control_response_superseded_ids() {
+        let resp = SetDERControlResponse {
+            superseded_ids: Some(vec!["a".repeat(37)]),
+            ..Default::default()
+        };
+        assert_invalid_fields(&resp.validate().unwrap_err(), &["superseded_ids[0]"]);
+
+        let resp = SetDERControlResponse {
+            superseded_ids: Some(vec![]),
+            ..Default::default()
+        };
+        assert_invalid_fields(&resp.validate().unwrap_err(), &["superseded_ids"]);
+    }
+
+    #[test]
+    fn test_set_der_control_serialize_deserialize() {
+        let req = SetDERControlRequest {
+            control_id: "ctrl-1".to_string(),
+            control_type: DERControlEnumType::FixedPFAbsorb,
+            enter_service: None,
+            fixed_pf_absorb: Some(FixedPFType {
+                priority: 1,
+                displacement: 0.9,
+                excitation: true,
+                start_time: None,
+                duration: None,
+                custom_data: None,
+            }),
+            ..Default::default()
+        };
+        assert!(req.validate().is_ok());
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("\"controlType\":\"FixedPFAbsorb\""));
+        assert!(serialized.contains("\"fixedPFAbsorb\""));
+        let deserialized: SetDERControlRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+}
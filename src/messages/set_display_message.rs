@@ -0,0 +1,131 @@
+use crate::enums::display_message_status_enum_type::DisplayMessageStatusEnumType;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::message_info_type::MessageInfoType;
+use crate::structures::status_info_type::StatusInfoType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.77. SetDisplayMessage
+pub struct SetDisplayMessage;
+
+impl OcppMessage for SetDisplayMessage {
+    type Request = SetDisplayMessageRequest;
+    type Response = SetDisplayMessageResponse;
+}
+
+/// 1.77.1. SetDisplayMessageRequest
+/// This contains the field definition of the SetDisplayMessageRequest PDU sent by the CSMS to the Charging Station.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SetDisplayMessageRequest {
+    /// Required. Message to be configured in the Charging Station, to be displayed.
+    pub message: MessageInfoType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for SetDisplayMessageRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_member("message", &self.message);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("SetDisplayMessageRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for SetDisplayMessageRequest {
+    fn get_message_type(&self) -> String {
+        String::from("SetDisplayMessage")
+    }
+}
+
+/// 1.77.2. SetDisplayMessageResponse
+/// This contains the field definition of the SetDisplayMessageResponse PDU sent by the Charging Station to the CSMS in response
+/// to SetDisplayMessageRequest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SetDisplayMessageResponse {
+    /// Required. This indicates whether the Charging Station is able to display the message.
+    pub status: DisplayMessageStatusEnumType,
+    /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for SetDisplayMessageResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(status_info) = &self.status_info {
+            b.check_member("status_info", status_info);
+        }
+
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("SetDisplayMessageResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_set_display_message() {
+        assert!(SetDisplayMessage::request().validate().is_ok());
+        assert!(SetDisplayMessage::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_set_display_message_invalid() {
+        let req = SetDisplayMessageRequest {
+            message: MessageInfoType {
+                id: -1,
+                ..Default::default()
+            },
+            custom_data: None,
+        };
+        assert_invalid_fields(&req.validate().unwrap_err(), &["message"]);
+    }
+
+    #[test]
+    fn test_set_display_message_serialize_deserialize() {
+        let req = SetDisplayMessageRequest {
+            message: MessageInfoType {
+                id: 5,
+                ..Default::default()
+            },
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("\"priority\":\"NormalCycle\""));
+        let deserialized: SetDisplayMessageRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+
+        let resp = SetDisplayMessageResponse {
+            status: DisplayMessageStatusEnumType::NotSupportedPriority,
+            status_info: None,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&resp).unwrap();
+        let deserialized: SetDisplayMessageResponse = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
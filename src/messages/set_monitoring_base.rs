@@ -0,0 +1,118 @@
+use crate::enums::generic_device_model_status::GenericDeviceModelStatusEnumType;
+use crate::enums::monitoring_base_enum_type::MonitoringBaseEnumType;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::status_info_type::StatusInfoType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.78. SetMonitoringBase
+pub struct SetMonitoringBase;
+
+impl OcppMessage for SetMonitoringBase {
+    type Request = SetMonitoringBaseRequest;
+    type Response = SetMonitoringBaseResponse;
+}
+
+/// 1.78.1. SetMonitoringBaseRequest
+/// This contains the field definition of the SetMonitoringBaseRequest PDU sent by the CSMS to the Charging Station.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SetMonitoringBaseRequest {
+    /// Required. Specify which monitoring base will be set.
+    pub monitoring_base: MonitoringBaseEnumType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for SetMonitoringBaseRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut e = StructureValidationBuilder::new();
+
+        e.check_enum("monitoring_base", &self.monitoring_base);
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("SetMonitoringBaseRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for SetMonitoringBaseRequest {
+    fn get_message_type(&self) -> String {
+        String::from("SetMonitoringBase")
+    }
+}
+
+/// 1.78.2. SetMonitoringBaseResponse
+/// This contains the field definition of the SetMonitoringBaseResponse PDU sent by the Charging Station to the CSMS in response
+/// to SetMonitoringBaseRequest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SetMonitoringBaseResponse {
+    /// Required. Indicates whether the Charging Station was able to accept the request.
+    pub status: GenericDeviceModelStatusEnumType,
+    /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for SetMonitoringBaseResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(status_info) = &self.status_info {
+            b.check_member("status_info", status_info);
+        }
+
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("SetMonitoringBaseResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_monitoring_base() {
+        assert!(SetMonitoringBase::request().validate().is_ok());
+        assert!(SetMonitoringBase::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_set_monitoring_base_request_serialize_deserialize() {
+        let req = SetMonitoringBaseRequest {
+            monitoring_base: MonitoringBaseEnumType::FactoryDefault,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("\"monitoringBase\":\"FactoryDefault\""));
+        let deserialized: SetMonitoringBaseRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+
+    #[test]
+    fn test_set_monitoring_base_response_serialize_deserialize() {
+        let resp = SetMonitoringBaseResponse {
+            status: GenericDeviceModelStatusEnumType::NotSupported,
+            status_info: None,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&resp).unwrap();
+        let deserialized: SetMonitoringBaseResponse = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
@@ -0,0 +1,130 @@
+use crate::enums::generic_status_enum_type::GenericStatusEnumType;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::status_info_type::StatusInfoType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.79. SetMonitoringLevel
+pub struct SetMonitoringLevel;
+
+impl OcppMessage for SetMonitoringLevel {
+    type Request = SetMonitoringLevelRequest;
+    type Response = SetMonitoringLevelResponse;
+}
+
+/// 1.79.1. SetMonitoringLevelRequest
+/// This contains the field definition of the SetMonitoringLevelRequest PDU sent by the CSMS to the Charging Station.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SetMonitoringLevelRequest {
+    /// Required. The Charging Station SHALL only report events with a severity number lower than or equal to this severity.
+    /// The severity range is 0-9, with 0 as the highest and 9 as the lowest severity level.
+    pub severity: i32,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for SetMonitoringLevelRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_bounds("severity", 0, 9, self.severity);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("SetMonitoringLevelRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for SetMonitoringLevelRequest {
+    fn get_message_type(&self) -> String {
+        String::from("SetMonitoringLevel")
+    }
+}
+
+/// 1.79.2. SetMonitoringLevelResponse
+/// This contains the field definition of the SetMonitoringLevelResponse PDU sent by the Charging Station to the CSMS in response
+/// to SetMonitoringLevelRequest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SetMonitoringLevelResponse {
+    /// Required. Indicates whether the Charging Station was able to accept the request.
+    pub status: GenericStatusEnumType,
+    /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for SetMonitoringLevelResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(status_info) = &self.status_info {
+            b.check_member("status_info", status_info);
+        }
+
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("SetMonitoringLevelResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_monitoring_level() {
+        assert!(SetMonitoringLevel::request().validate().is_ok());
+        assert!(SetMonitoringLevel::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_set_monitoring_level_request_severity() {
+        let mut req = SetMonitoringLevelRequest {
+            severity: 9,
+            custom_data: None,
+        };
+        assert!(req.validate().is_ok());
+        req.severity = 10;
+        assert!(req.validate().is_err());
+        req.severity = -1;
+        assert!(req.validate().is_err());
+    }
+
+    #[test]
+    fn test_set_monitoring_level_request_serialize_deserialize() {
+        let req = SetMonitoringLevelRequest {
+            severity: 5,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        let deserialized: SetMonitoringLevelRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+
+    #[test]
+    fn test_set_monitoring_level_response_serialize_deserialize() {
+        let resp = SetMonitoringLevelResponse {
+            status: GenericStatusEnumType::Rejected,
+            status_info: None,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&resp).unwrap();
+        let deserialized: SetMonitoringLevelResponse = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
@@ -0,0 +1,137 @@
+use crate::enums::set_network_profile_status_enum_type::SetNetworkProfileStatusEnumType;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::network_connection_profile_type::NetworkConnectionProfileType;
+use crate::structures::status_info_type::StatusInfoType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.80. SetNetworkProfile
+pub struct SetNetworkProfile;
+
+impl OcppMessage for SetNetworkProfile {
+    type Request = SetNetworkProfileRequest;
+    type Response = SetNetworkProfileResponse;
+}
+
+/// 1.80.1. SetNetworkProfileRequest
+/// This contains the field definition of the SetNetworkProfileRequest PDU sent by the CSMS to the Charging Station.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SetNetworkProfileRequest {
+    /// Required. Slot in which the configuration should be stored.
+    pub configuration_slot: i32,
+    /// Required. Connection details.
+    pub connection_data: NetworkConnectionProfileType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for SetNetworkProfileRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_member("connection_data", &self.connection_data);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("SetNetworkProfileRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for SetNetworkProfileRequest {
+    fn get_message_type(&self) -> String {
+        String::from("SetNetworkProfile")
+    }
+}
+
+/// 1.80.2. SetNetworkProfileResponse
+/// This contains the field definition of the SetNetworkProfileResponse PDU sent by the Charging Station to the CSMS in response
+/// to SetNetworkProfileRequest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SetNetworkProfileResponse {
+    /// Required. Result of operation.
+    pub status: SetNetworkProfileStatusEnumType,
+    /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for SetNetworkProfileResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(status_info) = &self.status_info {
+            b.check_member("status_info", status_info);
+        }
+
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("SetNetworkProfileResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_set_network_profile() {
+        assert!(SetNetworkProfile::request().validate().is_ok());
+        assert!(SetNetworkProfile::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_set_network_profile_invalid() {
+        let req = SetNetworkProfileRequest {
+            configuration_slot: 1,
+            connection_data: NetworkConnectionProfileType {
+                message_timeout: -1,
+                ..Default::default()
+            },
+            custom_data: None,
+        };
+        assert_invalid_fields(&req.validate().unwrap_err(), &["connection_data"]);
+    }
+
+    #[test]
+    fn test_set_network_profile_serialize_deserialize() {
+        let req = SetNetworkProfileRequest {
+            configuration_slot: 2,
+            connection_data: NetworkConnectionProfileType {
+                ocpp_csms_url: "wss://csms.example.com/ocpp".to_string(),
+                security_profile: 2,
+                ..Default::default()
+            },
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("\"configurationSlot\":2"));
+        assert!(serialized.contains("\"connectionData\""));
+        let deserialized: SetNetworkProfileRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+
+        let resp = SetNetworkProfileResponse {
+            status: SetNetworkProfileStatusEnumType::Failed,
+            status_info: None,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&resp).unwrap();
+        let deserialized: SetNetworkProfileResponse = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
@@ -0,0 +1,157 @@
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::set_monitoring_data_type::SetMonitoringDataType;
+use crate::structures::set_monitoring_result_type::SetMonitoringResultType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.81. SetVariableMonitoring
+pub struct SetVariableMonitoring;
+
+impl OcppMessage for SetVariableMonitoring {
+    type Request = SetVariableMonitoringRequest;
+    type Response = SetVariableMonitoringResponse;
+}
+
+/// 1.81.1. SetVariableMonitoringRequest
+/// This contains the field definition of the SetVariableMonitoringRequest PDU sent by the CSMS to the Charging Station.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SetVariableMonitoringRequest {
+    /// Required. List of MonitoringData containing monitoring settings.
+    pub set_monitoring_data: Vec<SetMonitoringDataType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for SetVariableMonitoringRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_cardinality(
+            "set_monitoring_data",
+            1,
+            usize::MAX,
+            &self.set_monitoring_data.iter(),
+        );
+        b.check_iter_member("set_monitoring_data", self.set_monitoring_data.iter());
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("SetVariableMonitoringRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for SetVariableMonitoringRequest {
+    fn get_message_type(&self) -> String {
+        String::from("SetVariableMonitoring")
+    }
+}
+
+impl Default for SetVariableMonitoringRequest {
+    fn default() -> SetVariableMonitoringRequest {
+        Self {
+            set_monitoring_data: vec![Default::default()],
+            custom_data: None,
+        }
+    }
+}
+
+/// 1.81.2. SetVariableMonitoringResponse
+/// This contains the field definition of the SetVariableMonitoringResponse PDU sent by the Charging Station to the CSMS in
+/// response to SetVariableMonitoringRequest.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SetVariableMonitoringResponse {
+    /// Required. List of result statuses per monitor.
+    pub set_monitoring_result: Vec<SetMonitoringResultType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for SetVariableMonitoringResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_cardinality(
+            "set_monitoring_result",
+            1,
+            usize::MAX,
+            &self.set_monitoring_result.iter(),
+        );
+        b.check_iter_member("set_monitoring_result", self.set_monitoring_result.iter());
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("SetVariableMonitoringResponse")
+    }
+}
+
+impl Default for SetVariableMonitoringResponse {
+    fn default() -> SetVariableMonitoringResponse {
+        Self {
+            set_monitoring_result: vec![Default::default()],
+            custom_data: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_set_variable_monitoring() {
+        assert!(SetVariableMonitoring::request().validate().is_ok());
+        assert!(SetVariableMonitoring::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_set_variable_monitoring_empty() {
+        let req = SetVariableMonitoringRequest {
+            set_monitoring_data: vec![],
+            custom_data: None,
+        };
+        assert_invalid_fields(&req.validate().unwrap_err(), &["set_monitoring_data"]);
+
+        let resp = SetVariableMonitoringResponse {
+            set_monitoring_result: vec![],
+            custom_data: None,
+        };
+        assert_invalid_fields(&resp.validate().unwrap_err(), &["set_monitoring_result"]);
+    }
+
+    #[test]
+    fn test_set_variable_monitoring_request_invalid_severity() {
+        let mut req = SetVariableMonitoring::request();
+        req.set_monitoring_data[0].severity = 10;
+        assert_invalid_fields(&req.validate().unwrap_err(), &["set_monitoring_data[0]"]);
+    }
+
+    #[test]
+    fn test_set_variable_monitoring_request_serialize_deserialize() {
+        let req = SetVariableMonitoring::request();
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("setMonitoringData"));
+        let deserialized: SetVariableMonitoringRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+
+    #[test]
+    fn test_set_variable_monitoring_response_serialize_deserialize() {
+        let resp = SetVariableMonitoring::response();
+        let serialized = serde_json::to_string(&resp).unwrap();
+        let deserialized: SetVariableMonitoringResponse =
+            serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
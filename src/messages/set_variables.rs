@@ -0,0 +1,156 @@
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::set_variable_data_type::SetVariableDataType;
+use crate::structures::set_variable_result_type::SetVariableResultType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.82. SetVariables
+pub struct SetVariables;
+
+impl OcppMessage for SetVariables {
+    type Request = SetVariablesRequest;
+    type Response = SetVariablesResponse;
+}
+
+/// 1.82.1. SetVariablesRequest
+/// This contains the field definition of the SetVariablesRequest PDU sent by the CSMS to the Charging Station.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SetVariablesRequest {
+    /// Required. List of Component-Variable pairs and attribute values to set.
+    pub set_variable_data: Vec<SetVariableDataType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for SetVariablesRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_cardinality(
+            "set_variable_data",
+            1,
+            usize::MAX,
+            &self.set_variable_data.iter(),
+        );
+        b.check_iter_member("set_variable_data", self.set_variable_data.iter());
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("SetVariablesRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for SetVariablesRequest {
+    fn get_message_type(&self) -> String {
+        String::from("SetVariables")
+    }
+}
+
+impl Default for SetVariablesRequest {
+    fn default() -> SetVariablesRequest {
+        Self {
+            set_variable_data: vec![Default::default()],
+            custom_data: None,
+        }
+    }
+}
+
+/// 1.82.2. SetVariablesResponse
+/// This contains the field definition of the SetVariablesResponse PDU sent by the Charging Station to the CSMS in response to
+/// SetVariablesRequest.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SetVariablesResponse {
+    /// Required. List of result statuses per Component-Variable.
+    pub set_variable_result: Vec<SetVariableResultType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for SetVariablesResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_cardinality(
+            "set_variable_result",
+            1,
+            usize::MAX,
+            &self.set_variable_result.iter(),
+        );
+        b.check_iter_member("set_variable_result", self.set_variable_result.iter());
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("SetVariablesResponse")
+    }
+}
+
+impl Default for SetVariablesResponse {
+    fn default() -> SetVariablesResponse {
+        Self {
+            set_variable_result: vec![Default::default()],
+            custom_data: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_set_variables() {
+        assert!(SetVariables::request().validate().is_ok());
+        assert!(SetVariables::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_set_variables_empty() {
+        let req = SetVariablesRequest {
+            set_variable_data: vec![],
+            custom_data: None,
+        };
+        assert_invalid_fields(&req.validate().unwrap_err(), &["set_variable_data"]);
+
+        let resp = SetVariablesResponse {
+            set_variable_result: vec![],
+            custom_data: None,
+        };
+        assert_invalid_fields(&resp.validate().unwrap_err(), &["set_variable_result"]);
+    }
+
+    #[test]
+    fn test_set_variables_request_invalid_data() {
+        let mut req = SetVariables::request();
+        req.set_variable_data[0].attribute_value = "a".repeat(2501);
+        assert_invalid_fields(&req.validate().unwrap_err(), &["set_variable_data[0]"]);
+    }
+
+    #[test]
+    fn test_set_variables_request_serialize_deserialize() {
+        let req = SetVariables::request();
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("setVariableData"));
+        let deserialized: SetVariablesRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+
+    #[test]
+    fn test_set_variables_response_serialize_deserialize() {
+        let resp = SetVariables::response();
+        let serialized = serde_json::to_string(&resp).unwrap();
+        let deserialized: SetVariablesResponse = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
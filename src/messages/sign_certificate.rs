@@ -0,0 +1,151 @@
+use crate::enums::certificate_signing_use_enum_type::CertificateSigningUseEnumType;
+use crate::enums::generic_status_enum_type::GenericStatusEnumType;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::certificate_hash_data_type::CertificateHashDataType;
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::status_info_type::StatusInfoType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.83. SignCertificate
+pub struct SignCertificate;
+
+impl OcppMessage for SignCertificate {
+    type Request = SignCertificateRequest;
+    type Response = SignCertificateResponse;
+}
+
+/// 1.83.1. SignCertificateRequest
+/// This contains the field definition of the SignCertificateRequest PDU sent by the Charging Station to the CSMS.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SignCertificateRequest {
+    /// Required. The Charging Station SHALL send the public key in form of a Certificate Signing Request (CSR) as described in
+    /// RFC 2986 and then PEM encoded.
+    pub csr: String,
+    /// Optional. Indicates the type of certificate that is to be signed. When omitted the certificate is to be used for both
+    /// the 15118 connection (if implemented) and the Charging Station to CSMS connection.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub certificate_type: Option<CertificateSigningUseEnumType>,
+    /// Optional. (2.1) The hash of the root certificate to which the requested certificate should chain.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash_root_certificate: Option<CertificateHashDataType>,
+    /// Optional. (2.1) RequestId to match this message with the CertificateSignedRequest.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<i32>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for SignCertificateRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_cardinality("csr", 0, 11000, &self.csr.chars());
+
+        if let Some(hash_root_certificate) = &self.hash_root_certificate {
+            b.check_member("hash_root_certificate", hash_root_certificate);
+        }
+
+        if let Some(certificate_type) = &self.certificate_type {
+            b.check_enum("certificate_type", certificate_type);
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("SignCertificateRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for SignCertificateRequest {
+    fn get_message_type(&self) -> String {
+        String::from("SignCertificate")
+    }
+}
+
+/// 1.83.2. SignCertificateResponse
+/// This contains the field definition of the SignCertificateResponse PDU sent by the CSMS to the Charging Station in response to
+/// SignCertificateRequest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SignCertificateResponse {
+    /// Required. Specifies whether the CSMS can process the request.
+    pub status: GenericStatusEnumType,
+    /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for SignCertificateResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(status_info) = &self.status_info {
+            b.check_member("status_info", status_info);
+        }
+
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("SignCertificateResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_sign_certificate() {
+        assert!(SignCertificate::request().validate().is_ok());
+        assert!(SignCertificate::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_sign_certificate_request_csr_long() {
+        let req = SignCertificateRequest {
+            csr: "a".repeat(11001),
+            ..Default::default()
+        };
+        assert_invalid_fields(&req.validate().unwrap_err(), &["csr"]);
+    }
+
+    #[test]
+    fn test_sign_certificate_request_serialize_deserialize() {
+        let req = SignCertificateRequest {
+            csr: "-----BEGIN CERTIFICATE REQUEST-----".to_string(),
+            certificate_type: Some(CertificateSigningUseEnumType::ChargingStationCertificate),
+            hash_root_certificate: None,
+            request_id: Some(1),
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("\"certificateType\":\"ChargingStationCertificate\""));
+        let deserialized: SignCertificateRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+
+    #[test]
+    fn test_sign_certificate_response_serialize_deserialize() {
+        let resp = SignCertificateResponse {
+            status: GenericStatusEnumType::Rejected,
+            status_info: None,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&resp).unwrap();
+        let deserialized: SignCertificateResponse = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
@@ -0,0 +1,154 @@
+use crate::enums::connector_status_enum_type::ConnectorStatusEnumType;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.84. StatusNotification
+pub struct StatusNotification;
+
+impl OcppMessage for StatusNotification {
+    type Request = StatusNotificationRequest;
+    type Response = StatusNotificationResponse;
+}
+
+/// 1.84.1. StatusNotificationRequest
+/// This contains the field definition of the StatusNotificationRequest PDU sent by the Charging Station to the CSMS.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusNotificationRequest {
+    /// Required. The time for which the status is reported.
+    pub timestamp: DateTime<Utc>,
+    /// Required. This contains the current status of the Connector.
+    pub connector_status: ConnectorStatusEnumType,
+    /// Required. The id of the EVSE to which the connector belongs for which the the status is reported.
+    pub evse_id: i32,
+    /// Required. The id of the connector within the EVSE for which the status is reported.
+    pub connector_id: i32,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for StatusNotificationRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_bounds("evse_id", 0, i32::MAX, self.evse_id);
+        b.check_bounds("connector_id", 0, i32::MAX, self.connector_id);
+
+        b.check_enum("connector_status", &self.connector_status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("StatusNotificationRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for StatusNotificationRequest {
+    fn get_message_type(&self) -> String {
+        String::from("StatusNotification")
+    }
+}
+
+/// 1.84.2. StatusNotificationResponse
+/// This contains the field definition of the StatusNotificationResponse PDU sent by the CSMS to the Charging Station in response
+/// to a StatusNotificationRequest. No fields are defined.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusNotificationResponse {
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for StatusNotificationResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut e = StructureValidationBuilder::new();
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("StatusNotificationResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_status_notification() {
+        assert!(StatusNotification::request().validate().is_ok());
+        assert!(StatusNotification::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_status_notification_request_negative_ids() {
+        let req = StatusNotificationRequest {
+            evse_id: -1,
+            connector_id: -1,
+            ..Default::default()
+        };
+        assert_invalid_fields(&req.validate().unwrap_err(), &["evse_id", "connector_id"]);
+    }
+
+    #[test]
+    fn test_status_notification_request_serialize_deserialize() {
+        let req = StatusNotificationRequest {
+            timestamp: Utc::now(),
+            connector_status: ConnectorStatusEnumType::Occupied,
+            evse_id: 1,
+            connector_id: 2,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("\"connectorStatus\":\"Occupied\""));
+        let deserialized: StatusNotificationRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+
+    #[test]
+    fn test_status_notification_response_serialize_deserialize() {
+        let resp = StatusNotification::response();
+        let serialized = serde_json::to_string(&resp).unwrap();
+        let deserialized: StatusNotificationResponse = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+
+    const CHARGING_STATUS: &str = r#"{"timestamp":"2025-01-01T00:00:00Z","connectorStatus":"Charging","evseId":1,"connectorId":1}"#;
+
+    #[test]
+    #[cfg(not(feature = "lenient-enums"))]
+    fn test_status_notification_request_unknown_enum_value_is_rejected() {
+        assert!(serde_json::from_str::<StatusNotificationRequest>(CHARGING_STATUS).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "lenient-enums")]
+    fn test_status_notification_request_unknown_enum_value_round_trips() {
+        use crate::traits::OcppEnum;
+
+        let req: StatusNotificationRequest = serde_json::from_str(CHARGING_STATUS).unwrap();
+        assert_eq!(
+            req.connector_status,
+            ConnectorStatusEnumType::Unknown("Charging".to_string())
+        );
+        assert_eq!(req.connector_status.unknown_value(), Some("Charging"));
+        assert_eq!(
+            ConnectorStatusEnumType::try_from("Charging".to_string()).unwrap(),
+            req.connector_status
+        );
+        assert_eq!(String::from(req.connector_status.clone()), "Charging");
+        assert_eq!(serde_json::to_string(&req).unwrap(), CHARGING_STATUS);
+
+        assert_invalid_fields(&req.validate().unwrap_err(), &["connector_status"]);
+    }
+}
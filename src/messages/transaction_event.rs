@@ -0,0 +1,377 @@
+use crate::enums::preconditioning_status_enum_type::PreconditioningStatusEnumType;
+use crate::enums::transaction_event_enum_type::TransactionEventEnumType;
+use crate::enums::trigger_reason_enum_type::TriggerReasonEnumType;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::cost_details_type::CostDetailsType;
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::evse_type::EVSEType;
+use crate::structures::id_token_info_type::IdTokenInfoType;
+use crate::structures::id_token_type::IdTokenType;
+use crate::structures::message_content_type::MessageContentType;
+use crate::structures::meter_value_type::MeterValueType;
+use crate::structures::transaction_limit_type::TransactionLimitType;
+use crate::structures::transaction_type::TransactionType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.85. TransactionEvent
+pub struct TransactionEvent;
+
+impl OcppMessage for TransactionEvent {
+    type Request = TransactionEventRequest;
+    type Response = TransactionEventResponse;
+}
+
+/// 1.85.1. TransactionEventRequest
+/// This contains the field definition of the TransactionEventRequest PDU sent by the Charging Station to the CSMS.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionEventRequest {
+    /// Required. This contains the type of this event.
+    /// The first TransactionEvent of a transaction SHALL contain: "Started". The last TransactionEvent of a transaction
+    /// SHALL contain: "Ended". All others SHALL contain: "Updated".
+    pub event_type: TransactionEventEnumType,
+    /// Required. The date and time at which this transaction event occurred.
+    pub timestamp: DateTime<Utc>,
+    /// Required. Reason the Charging Station sends this message to the CSMS.
+    pub trigger_reason: TriggerReasonEnumType,
+    /// Required. Incremental sequence number, helps with determining if all messages of a transaction have been received.
+    pub seq_no: i32,
+    /// Optional. Indication that this transaction event happened when the Charging Station was offline.
+    /// Default = false, meaning: the event occurred when the Charging Station was online.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offline: Option<bool>,
+    /// Optional. If the Charging Station is able to report the number of phases used, then it SHALL provide it.
+    /// When omitted the CSMS may be able to determine the number of phases used as follows:
+    /// 1: The numberPhases in the currently used ChargingSchedule.
+    /// 2: The number of phases provided via device management.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number_of_phases_used: Option<i32>,
+    /// Optional. The maximum current of the connected cable in Ampere (A).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cable_max_current: Option<i32>,
+    /// Optional. This contains the Id of the reservation that terminates as a result of this transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reservation_id: Option<i32>,
+    /// Optional. (2.1) The current preconditioning status of the BMS in the EV. Default value is Unknown.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preconditioning_status: Option<PreconditioningStatusEnumType>,
+    /// Optional. (2.1) True when EVSE electronics are in sleep mode for this transaction. Default value (when absent) is false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evse_sleep: Option<bool>,
+    /// Required. Contains transaction specific information.
+    pub transaction_info: TransactionType,
+    /// Optional. This identifies which evse (and connector) of the Charging Station is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evse: Option<EVSEType>,
+    /// Optional. This contains the identifier for which a transaction is (or will be) started or stopped.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id_token: Option<IdTokenType>,
+    /// Optional. This contains the relevant meter values.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meter_value: Option<Vec<MeterValueType>>,
+    /// Optional. (2.1) Cost details of transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost_details: Option<CostDetailsType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for TransactionEventRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_bounds("seq_no", 0, i32::MAX, self.seq_no);
+
+        if let Some(number_of_phases_used) = self.number_of_phases_used {
+            b.check_bounds("number_of_phases_used", 0, 3, number_of_phases_used);
+        }
+
+        if let Some(reservation_id) = self.reservation_id {
+            b.check_bounds("reservation_id", 0, i32::MAX, reservation_id);
+        }
+
+        b.check_member("transaction_info", &self.transaction_info);
+
+        if let Some(evse) = &self.evse {
+            b.check_member("evse", evse);
+        }
+
+        if let Some(id_token) = &self.id_token {
+            b.check_member("id_token", id_token);
+        }
+
+        if let Some(meter_value) = &self.meter_value {
+            b.check_cardinality("meter_value", 1, usize::MAX, &meter_value.iter());
+            b.check_iter_member("meter_value", meter_value.iter());
+        }
+
+        if let Some(cost_details) = &self.cost_details {
+            b.check_member("cost_details", cost_details);
+        }
+
+        b.check_enum("event_type", &self.event_type);
+
+        b.check_enum("trigger_reason", &self.trigger_reason);
+
+        if let Some(preconditioning_status) = &self.preconditioning_status {
+            b.check_enum("preconditioning_status", preconditioning_status);
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("TransactionEventRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for TransactionEventRequest {
+    fn get_message_type(&self) -> String {
+        String::from("TransactionEvent")
+    }
+}
+
+/// 1.85.2. TransactionEventResponse
+/// This contains the field definition of the TransactionEventResponse PDU sent by the CSMS to the Charging Station in response to
+/// a TransactionEventRequest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionEventResponse {
+    /// Optional. SHALL only be sent when charging has ended. Final total cost of this transaction, including taxes.
+    /// In the currency configured with the Configuration Variable: Currency.
+    /// When omitted, the transaction was NOT free. To indicate a free transaction, the CSMS SHALL send 0.00.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_cost: Option<f64>,
+    /// Optional. Priority from a business point of view. Default priority is 0, The range is from -9 to 9.
+    /// Higher values indicate a higher priority. The chargingPriority in TransactionEventResponse is temporarily,
+    /// so it may not be set in the IdTokenInfoType afterwards.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charging_priority: Option<i32>,
+    /// Optional. Is required when the transactionEventRequest contained an idToken.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id_token_info: Option<IdTokenInfoType>,
+    /// Optional. (2.1) Maximum cost/energy/time limit allowed for this transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_limit: Option<TransactionLimitType>,
+    /// Optional. This can contain updated personal message that can be shown to the EV Driver.
+    /// This can be used to provide updated tariff information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_personal_message: Option<MessageContentType>,
+    /// Optional. (2.1) Additional languages for the updated personal message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_personal_message_extra: Option<Vec<MessageContentType>>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+
+impl TransactionEventResponse {
+    /// Validate this response as the answer to `request`.
+    /// In addition to the rules checked by `validate`, this requires an idTokenInfo exactly when the request contained
+    /// an idToken.
+    pub fn validate_for(&self, request: &TransactionEventRequest) -> Result<(), OcppError> {
+        let mut b = self.validation_builder();
+
+        match (&self.id_token_info, &request.id_token) {
+            (Some(_), None) => b.push_relation_error(
+                "id_token_info",
+                "id_token",
+                "id_token_info may only be sent when the TransactionEventRequest contained an id_token",
+            ),
+            (None, Some(_)) => b.push_relation_error(
+                "id_token_info",
+                "id_token",
+                "id_token_info is required when the TransactionEventRequest contained an id_token",
+            ),
+            _ => {}
+        }
+
+        b.build("TransactionEventResponse")
+    }
+
+    fn validation_builder(&self) -> StructureValidationBuilder {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(charging_priority) = self.charging_priority {
+            b.check_bounds("charging_priority", -9, 9, charging_priority);
+        }
+
+        if let Some(id_token_info) = &self.id_token_info {
+            b.check_member("id_token_info", id_token_info);
+        }
+
+        if let Some(transaction_limit) = &self.transaction_limit {
+            b.check_member("transaction_limit", transaction_limit);
+        }
+
+        if let Some(updated_personal_message) = &self.updated_personal_message {
+            b.check_member("updated_personal_message", updated_personal_message);
+        }
+
+        if let Some(updated_personal_message_extra) = &self.updated_personal_message_extra {
+            b.check_cardinality(
+                "updated_personal_message_extra",
+                1,
+                4,
+                &updated_personal_message_extra.iter(),
+            );
+            b.check_iter_member(
+                "updated_personal_message_extra",
+                updated_personal_message_extra.iter(),
+            );
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b
+    }
+}
+#[typetag::serde]
+impl OcppEntity for TransactionEventResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        self.validation_builder().build("TransactionEventResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    fn started_request() -> TransactionEventRequest {
+        TransactionEventRequest {
+            event_type: TransactionEventEnumType::Started,
+            trigger_reason: TriggerReasonEnumType::Authorized,
+            transaction_info: TransactionType {
+                transaction_id: "tx-1".to_string(),
+                ..Default::default()
+            },
+            evse: Some(EVSEType::default()),
+            id_token: Some(IdTokenType::default()),
+            meter_value: Some(vec![MeterValueType {
+                timestamp: Utc::now(),
+                sampled_value: vec![Default::default()],
+                custom_data: None,
+            }]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_transaction_event() {
+        let req = TransactionEvent::request();
+        let resp = TransactionEvent::response();
+
+        assert!(req.validate().is_ok());
+        assert!(resp.validate().is_ok());
+        assert!(started_request().validate().is_ok());
+    }
+
+    #[test]
+    fn test_transaction_event_request_seq_no_negative() {
+        let mut req = started_request();
+        req.seq_no = -1;
+        assert_invalid_fields(&req.validate().unwrap_err(), &["seq_no"]);
+    }
+
+    #[test]
+    fn test_transaction_event_request_number_of_phases_used() {
+        let mut req = started_request();
+        req.number_of_phases_used = Some(3);
+        assert!(req.validate().is_ok());
+        req.number_of_phases_used = Some(4);
+        assert_invalid_fields(&req.validate().unwrap_err(), &["number_of_phases_used"]);
+    }
+
+    #[test]
+    fn test_transaction_event_request_empty_meter_value() {
+        let mut req = started_request();
+        req.meter_value = Some(vec![]);
+        assert_invalid_fields(&req.validate().unwrap_err(), &["meter_value"]);
+    }
+
+    #[test]
+    fn test_transaction_event_request_transaction_id_long() {
+        let mut req = started_request();
+        req.transaction_info.transaction_id = "a".repeat(37);
+        assert_invalid_fields(&req.validate().unwrap_err(), &["transaction_info"]);
+    }
+
+    #[test]
+    fn test_transaction_event_request_serialize_deserialize() {
+        let mut req = started_request();
+        req.offline = Some(true);
+        req.seq_no = 3;
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("\"seqNo\":3"));
+        assert!(serialized.contains("\"triggerReason\":\"Authorized\""));
+        assert!(serialized.contains("\"offline\":true"));
+        let deserialized: TransactionEventRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+
+    #[test]
+    fn test_transaction_event_response_charging_priority() {
+        let mut resp = TransactionEvent::response();
+        resp.charging_priority = Some(10);
+        assert_invalid_fields(&resp.validate().unwrap_err(), &["charging_priority"]);
+    }
+
+    #[test]
+    fn test_transaction_event_response_personal_message_extra_long() {
+        let mut resp = TransactionEvent::response();
+        resp.updated_personal_message_extra = Some(vec![Default::default(); 5]);
+        assert_invalid_fields(
+            &resp.validate().unwrap_err(),
+            &["updated_personal_message_extra"],
+        );
+    }
+
+    #[test]
+    fn test_transaction_event_response_id_token_info_requires_id_token() {
+        let resp = TransactionEventResponse {
+            id_token_info: Some(IdTokenInfoType::default()),
+            ..Default::default()
+        };
+        assert!(resp.validate().is_ok());
+        assert!(resp.validate_for(&started_request()).is_ok());
+
+        let mut req = started_request();
+        req.id_token = None;
+        assert_invalid_fields(
+            &resp.validate_for(&req).unwrap_err(),
+            &["id_token_info", "id_token"],
+        );
+        assert!(TransactionEvent::response().validate_for(&req).is_ok());
+    }
+
+    #[test]
+    fn test_transaction_event_response_id_token_requires_id_token_info() {
+        let resp = TransactionEvent::response();
+        assert!(resp.validate().is_ok());
+        assert_invalid_fields(
+            &resp.validate_for(&started_request()).unwrap_err(),
+            &["id_token_info", "id_token"],
+        );
+    }
+
+    #[test]
+    fn test_transaction_event_response_serialize_deserialize() {
+        let resp = TransactionEventResponse {
+            total_cost: Some(12.5),
+            id_token_info: Some(IdTokenInfoType::default()),
+            ..Default::default()
+        };
+        let serialized = serde_json::to_string(&resp).unwrap();
+        let deserialized: TransactionEventResponse = serde_json::from_str(&serialized).unwrap();
+        assert!(resp.validate().is_ok());
+        assert_eq!(resp, deserialized);
+    }
+}
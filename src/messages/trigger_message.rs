@@ -0,0 +1,177 @@
+use crate::enums::message_trigger_enum_type::MessageTriggerEnumType;
+use crate::enums::trigger_message_status_enum_type::TriggerMessageStatusEnumType;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::evse_type::EVSEType;
+use crate::structures::status_info_type::StatusInfoType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.86. TriggerMessage
+pub struct TriggerMessage;
+
+impl OcppMessage for TriggerMessage {
+    type Request = TriggerMessageRequest;
+    type Response = TriggerMessageResponse;
+}
+
+/// 1.86.1. TriggerMessageRequest
+/// This contains the field definition of the TriggerMessageRequest PDU sent by the CSMS to the Charging Station.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TriggerMessageRequest {
+    /// Required. Type of message to be triggered.
+    pub requested_message: MessageTriggerEnumType,
+    /// Optional. Can be used to specifiy the EVSE and Connector if required for the message which needs to be sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evse: Option<EVSEType>,
+    /// Optional. (2.1) When requestedMessage = CustomTrigger this will trigger sending the corresponding message in field
+    /// customTrigger, if supported by Charging Station.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_trigger: Option<String>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for TriggerMessageRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(evse) = &self.evse {
+            b.check_member("evse", evse);
+        }
+
+        if let Some(custom_trigger) = &self.custom_trigger {
+            b.check_cardinality("custom_trigger", 0, 50, &custom_trigger.chars());
+        }
+
+        let is_custom = self.requested_message == MessageTriggerEnumType::CustomTrigger;
+        if is_custom != self.custom_trigger.is_some() {
+            b.push_relation_error(
+                "custom_trigger",
+                "requested_message",
+                "custom_trigger must be set if and only if requested_message is CustomTrigger",
+            );
+        }
+
+        b.check_enum("requested_message", &self.requested_message);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("TriggerMessageRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for TriggerMessageRequest {
+    fn get_message_type(&self) -> String {
+        String::from("TriggerMessage")
+    }
+}
+
+/// 1.86.2. TriggerMessageResponse
+/// This contains the field definition of the TriggerMessageResponse PDU sent by the Charging Station to the CSMS in response to
+/// a TriggerMessageRequest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TriggerMessageResponse {
+    /// Required. Indicates whether the Charging Station will send the requested notification or not.
+    pub status: TriggerMessageStatusEnumType,
+    /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for TriggerMessageResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(status_info) = &self.status_info {
+            b.check_member("status_info", status_info);
+        }
+
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("TriggerMessageResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_trigger_message() {
+        assert!(TriggerMessage::request().validate().is_ok());
+        assert!(TriggerMessage::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_trigger_message_request_custom_trigger() {
+        let mut req = TriggerMessageRequest {
+            requested_message: MessageTriggerEnumType::CustomTrigger,
+            evse: None,
+            custom_trigger: Some("VendorSpecificMessage".to_string()),
+            custom_data: None,
+        };
+        assert!(req.validate().is_ok());
+
+        req.custom_trigger = Some("a".repeat(51));
+        assert_invalid_fields(&req.validate().unwrap_err(), &["custom_trigger"]);
+
+        req.custom_trigger = None;
+        assert_invalid_fields(
+            &req.validate().unwrap_err(),
+            &["custom_trigger", "requested_message"],
+        );
+
+        req.requested_message = MessageTriggerEnumType::Heartbeat;
+        req.custom_trigger = Some("VendorSpecificMessage".to_string());
+        assert_invalid_fields(
+            &req.validate().unwrap_err(),
+            &["custom_trigger", "requested_message"],
+        );
+    }
+
+    #[test]
+    fn test_trigger_message_request_serialize_deserialize() {
+        let req = TriggerMessageRequest {
+            requested_message: MessageTriggerEnumType::StatusNotification,
+            evse: Some(EVSEType {
+                id: 1,
+                connector_id: Some(1),
+                custom_data: None,
+            }),
+            custom_trigger: None,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("\"requestedMessage\":\"StatusNotification\""));
+        let deserialized: TriggerMessageRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+
+    #[test]
+    fn test_trigger_message_response_serialize_deserialize() {
+        let resp = TriggerMessageResponse {
+            status: TriggerMessageStatusEnumType::NotImplemented,
+            status_info: None,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&resp).unwrap();
+        let deserialized: TriggerMessageResponse = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
@@ -0,0 +1,131 @@
+use crate::enums::unlock_status_enum_type::UnlockStatusEnumType;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::status_info_type::StatusInfoType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.87. UnlockConnector
+pub struct UnlockConnector;
+
+impl OcppMessage for UnlockConnector {
+    type Request = UnlockConnectorRequest;
+    type Response = UnlockConnectorResponse;
+}
+
+/// 1.87.1. UnlockConnectorRequest
+/// This contains the field definition of the UnlockConnectorRequest PDU sent by the CSMS to the Charging Station.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UnlockConnectorRequest {
+    /// Required. This contains the identifier of the EVSE for which a connector needs to be unlocked.
+    pub evse_id: i32,
+    /// Required. This contains the identifier of the connector that needs to be unlocked.
+    pub connector_id: i32,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for UnlockConnectorRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_bounds("evse_id", 0, i32::MAX, self.evse_id);
+        b.check_bounds("connector_id", 0, i32::MAX, self.connector_id);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("UnlockConnectorRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for UnlockConnectorRequest {
+    fn get_message_type(&self) -> String {
+        String::from("UnlockConnector")
+    }
+}
+
+/// 1.87.2. UnlockConnectorResponse
+/// This contains the field definition of the UnlockConnectorResponse PDU sent by the Charging Station to the CSMS in response to
+/// an UnlockConnectorRequest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UnlockConnectorResponse {
+    /// Required. This indicates whether the Charging Station has unlocked the connector.
+    pub status: UnlockStatusEnumType,
+    /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for UnlockConnectorResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(status_info) = &self.status_info {
+            b.check_member("status_info", status_info);
+        }
+
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("UnlockConnectorResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_unlock_connector() {
+        assert!(UnlockConnector::request().validate().is_ok());
+        assert!(UnlockConnector::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_unlock_connector_request_negative_ids() {
+        let req = UnlockConnectorRequest {
+            evse_id: -1,
+            connector_id: -2,
+            custom_data: None,
+        };
+        assert_invalid_fields(&req.validate().unwrap_err(), &["evse_id", "connector_id"]);
+    }
+
+    #[test]
+    fn test_unlock_connector_request_serialize_deserialize() {
+        let req = UnlockConnectorRequest {
+            evse_id: 1,
+            connector_id: 1,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        let deserialized: UnlockConnectorRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+
+    #[test]
+    fn test_unlock_connector_response_serialize_deserialize() {
+        let resp = UnlockConnectorResponse {
+            status: UnlockStatusEnumType::UnknownConnector,
+            status_info: None,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&resp).unwrap();
+        let deserialized: UnlockConnectorResponse = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
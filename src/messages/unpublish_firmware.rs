@@ -0,0 +1,117 @@
+use crate::enums::unpublish_firmware_status_enum_type::UnpublishFirmwareStatusEnumType;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.88. UnpublishFirmware
+pub struct UnpublishFirmware;
+
+impl OcppMessage for UnpublishFirmware {
+    type Request = UnpublishFirmwareRequest;
+    type Response = UnpublishFirmwareResponse;
+}
+
+/// 1.88.1. UnpublishFirmwareRequest
+/// This contains the field definition of the UnpublishFirmwareRequest PDU sent by the CSMS to the Local Controller.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UnpublishFirmwareRequest {
+    /// Required. The MD5 checksum over the entire firmware file as a hexadecimal string of length 32.
+    pub checksum: String,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for UnpublishFirmwareRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_cardinality("checksum", 0, 32, &self.checksum.chars());
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("UnpublishFirmwareRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for UnpublishFirmwareRequest {
+    fn get_message_type(&self) -> String {
+        String::from("UnpublishFirmware")
+    }
+}
+
+/// 1.88.2. UnpublishFirmwareResponse
+/// This contains the field definition of the UnpublishFirmwareResponse PDU sent by the Local Controller to the CSMS in response to
+/// UnpublishFirmwareRequest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UnpublishFirmwareResponse {
+    /// Required. Indicates whether the Local Controller succeeded in unpublishing the firmware.
+    pub status: UnpublishFirmwareStatusEnumType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for UnpublishFirmwareResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut e = StructureValidationBuilder::new();
+
+        e.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("UnpublishFirmwareResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unpublish_firmware() {
+        assert!(UnpublishFirmware::request().validate().is_ok());
+        assert!(UnpublishFirmware::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_unpublish_firmware_request_checksum_long() {
+        let req = UnpublishFirmwareRequest {
+            checksum: "a".repeat(33),
+            custom_data: None,
+        };
+        assert!(req.validate().is_err());
+    }
+
+    #[test]
+    fn test_unpublish_firmware_request_serialize_deserialize() {
+        let req = UnpublishFirmwareRequest {
+            checksum: "d41d8cd98f00b204e9800998ecf8427e".to_string(),
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        let deserialized: UnpublishFirmwareRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+
+    #[test]
+    fn test_unpublish_firmware_response_serialize_deserialize() {
+        let resp = UnpublishFirmwareResponse {
+            status: UnpublishFirmwareStatusEnumType::NoFirmware,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&resp).unwrap();
+        assert_eq!(serialized, "{\"status\":\"NoFirmware\"}");
+        let deserialized: UnpublishFirmwareResponse = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
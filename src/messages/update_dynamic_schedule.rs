@@ -0,0 +1,139 @@
+use crate::enums::charging_profile_status_enum_type::ChargingProfileStatusEnumType;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::charging_schedule_update_type::ChargingScheduleUpdateType;
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::status_info_type::StatusInfoType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.89. UpdateDynamicSchedule
+pub struct UpdateDynamicSchedule;
+
+impl OcppMessage for UpdateDynamicSchedule {
+    type Request = UpdateDynamicScheduleRequest;
+    type Response = UpdateDynamicScheduleResponse;
+}
+
+/// 1.89.1. UpdateDynamicScheduleRequest
+/// (2.1) This contains the field definition of the UpdateDynamicScheduleRequest PDU sent by the CSMS to the Charging Station.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateDynamicScheduleRequest {
+    /// Required. Id of charging profile to update.
+    pub charging_profile_id: i32,
+    /// Required. Updates to a ChargingSchedulePeriodType for dynamic charging profiles.
+    pub schedule_update: ChargingScheduleUpdateType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for UpdateDynamicScheduleRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_member("schedule_update", &self.schedule_update);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("UpdateDynamicScheduleRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for UpdateDynamicScheduleRequest {
+    fn get_message_type(&self) -> String {
+        String::from("UpdateDynamicSchedule")
+    }
+}
+
+/// 1.89.2. UpdateDynamicScheduleResponse
+/// (2.1) This contains the field definition of the UpdateDynamicScheduleResponse PDU sent by the Charging Station to the CSMS in
+/// response to UpdateDynamicScheduleRequest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateDynamicScheduleResponse {
+    /// Required. Returns whether message was processed successfully.
+    pub status: ChargingProfileStatusEnumType,
+    /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for UpdateDynamicScheduleResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(status_info) = &self.status_info {
+            b.check_member("status_info", status_info);
+        }
+
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("UpdateDynamicScheduleResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_update_dynamic_schedule() {
+        assert!(UpdateDynamicSchedule::request().validate().is_ok());
+        assert!(UpdateDynamicSchedule::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_update_dynamic_schedule_request_invalid_update() {
+        let req = UpdateDynamicScheduleRequest {
+            charging_profile_id: 1,
+            schedule_update: ChargingScheduleUpdateType {
+                discharge_limit: Some(5.0),
+                ..Default::default()
+            },
+            custom_data: None,
+        };
+        assert_invalid_fields(&req.validate().unwrap_err(), &["schedule_update"]);
+    }
+
+    #[test]
+    fn test_update_dynamic_schedule_request_serialize_deserialize() {
+        let req = UpdateDynamicScheduleRequest {
+            charging_profile_id: 1,
+            schedule_update: ChargingScheduleUpdateType {
+                setpoint: Some(7400.0),
+                ..Default::default()
+            },
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("scheduleUpdate"));
+        let deserialized: UpdateDynamicScheduleRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+
+    #[test]
+    fn test_update_dynamic_schedule_response_serialize_deserialize() {
+        let resp = UpdateDynamicScheduleResponse {
+            status: ChargingProfileStatusEnumType::Rejected,
+            status_info: None,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&resp).unwrap();
+        let deserialized: UpdateDynamicScheduleResponse =
+            serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
@@ -0,0 +1,158 @@
+use crate::enums::update_firmware_status_enum_type::UpdateFirmwareStatusEnumType;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::firmware_type::FirmwareType;
+use crate::structures::status_info_type::StatusInfoType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.90. UpdateFirmware
+pub struct UpdateFirmware;
+
+impl OcppMessage for UpdateFirmware {
+    type Request = UpdateFirmwareRequest;
+    type Response = UpdateFirmwareResponse;
+}
+
+/// 1.90.1. UpdateFirmwareRequest
+/// This contains the field definition of the UpdateFirmwareRequest PDU sent by the CSMS to the Charging Station.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateFirmwareRequest {
+    /// Optional. This specifies how many times Charging Station must retry to download the firmware before giving up.
+    /// If this field is not present, it is left to Charging Station to decide how many times it wants to retry.
+    /// If the value is 0, it means: no retries.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retries: Option<i32>,
+    /// Optional. The interval in seconds after which a retry may be attempted. If this field is not present, it is left to
+    /// Charging Station to decide how long to wait between attempts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_interval: Option<i32>,
+    /// Required. The Id of this request.
+    pub request_id: i32,
+    /// Required. Specifies the firmware to be updated on the Charging Station.
+    pub firmware: FirmwareType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for UpdateFirmwareRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(retries) = self.retries {
+            b.check_bounds("retries", 0, i32::MAX, retries);
+        }
+
+        if let Some(retry_interval) = self.retry_interval {
+            b.check_bounds("retry_interval", 0, i32::MAX, retry_interval);
+        }
+
+        b.check_member("firmware", &self.firmware);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("UpdateFirmwareRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for UpdateFirmwareRequest {
+    fn get_message_type(&self) -> String {
+        String::from("UpdateFirmware")
+    }
+}
+
+/// 1.90.2. UpdateFirmwareResponse
+/// This contains the field definition of the UpdateFirmwareResponse PDU sent by the Charging Station to the CSMS in response to
+/// UpdateFirmwareRequest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateFirmwareResponse {
+    /// Required. This field indicates whether the Charging Station was able to accept the request.
+    pub status: UpdateFirmwareStatusEnumType,
+    /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for UpdateFirmwareResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(status_info) = &self.status_info {
+            b.check_member("status_info", status_info);
+        }
+
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("UpdateFirmwareResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_update_firmware() {
+        assert!(UpdateFirmware::request().validate().is_ok());
+        assert!(UpdateFirmware::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_update_firmware_request_negative_retries() {
+        let req = UpdateFirmwareRequest {
+            retries: Some(-1),
+            retry_interval: Some(-1),
+            ..Default::default()
+        };
+        assert_invalid_fields(&req.validate().unwrap_err(), &["retries", "retry_interval"]);
+    }
+
+    #[test]
+    fn test_update_firmware_request_signature_requires_certificate() {
+        let mut req = UpdateFirmware::request();
+        req.firmware.signature = Some("c2lnbmF0dXJl".to_string());
+        assert_invalid_fields(&req.validate().unwrap_err(), &["firmware"]);
+
+        req.firmware.signing_certificate = Some("-----BEGIN CERTIFICATE-----".to_string());
+        assert!(req.validate().is_ok());
+    }
+
+    #[test]
+    fn test_update_firmware_request_serialize_deserialize() {
+        let mut req = UpdateFirmware::request();
+        req.request_id = 3;
+        req.retries = Some(2);
+        req.firmware.location = "https://example.com/firmware.bin".to_string();
+        let serialized = serde_json::to_string(&req).unwrap();
+        assert!(serialized.contains("\"requestId\":3"));
+        let deserialized: UpdateFirmwareRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+
+    #[test]
+    fn test_update_firmware_response_serialize_deserialize() {
+        let resp = UpdateFirmwareResponse {
+            status: UpdateFirmwareStatusEnumType::InvalidCertificate,
+            status_info: None,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&resp).unwrap();
+        let deserialized: UpdateFirmwareResponse = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
@@ -0,0 +1,130 @@
+use crate::enums::priority_charging_status_enum_type::PriorityChargingStatusEnumType;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::status_info_type::StatusInfoType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.91. UsePriorityCharging
+pub struct UsePriorityCharging;
+
+impl OcppMessage for UsePriorityCharging {
+    type Request = UsePriorityChargingRequest;
+    type Response = UsePriorityChargingResponse;
+}
+
+/// 1.91.1. UsePriorityChargingRequest
+/// (2.1) This contains the field definition of the UsePriorityChargingRequest PDU sent by the CSMS to the Charging Station.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UsePriorityChargingRequest {
+    /// Required. The transaction for which priority charging is requested.
+    pub transaction_id: String,
+    /// Required. True to request priority charging. False to request stopping priority charging.
+    pub activate: bool,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for UsePriorityChargingRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_cardinality("transaction_id", 0, 36, &self.transaction_id.chars());
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("UsePriorityChargingRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for UsePriorityChargingRequest {
+    fn get_message_type(&self) -> String {
+        String::from("UsePriorityCharging")
+    }
+}
+
+/// 1.91.2. UsePriorityChargingResponse
+/// (2.1) This contains the field definition of the UsePriorityChargingResponse PDU sent by the Charging Station to the CSMS in
+/// response to UsePriorityChargingRequest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UsePriorityChargingResponse {
+    /// Required. Result of the request.
+    pub status: PriorityChargingStatusEnumType,
+    /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for UsePriorityChargingResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(status_info) = &self.status_info {
+            b.check_member("status_info", status_info);
+        }
+
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("UsePriorityChargingResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_use_priority_charging() {
+        assert!(UsePriorityCharging::request().validate().is_ok());
+        assert!(UsePriorityCharging::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_use_priority_charging_request_transaction_id_long() {
+        let req = UsePriorityChargingRequest {
+            transaction_id: "a".repeat(37),
+            activate: true,
+            custom_data: None,
+        };
+        assert!(req.validate().is_err());
+    }
+
+    #[test]
+    fn test_use_priority_charging_request_serialize_deserialize() {
+        let req = UsePriorityChargingRequest {
+            transaction_id: "tx-1".to_string(),
+            activate: true,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&req).unwrap();
+        let deserialized: UsePriorityChargingRequest = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+    }
+
+    #[test]
+    fn test_use_priority_charging_response_serialize_deserialize() {
+        let resp = UsePriorityChargingResponse {
+            status: PriorityChargingStatusEnumType::NoProfile,
+            status_info: None,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&resp).unwrap();
+        assert!(serialized.contains("\"status\":\"NoProfile\""));
+        let deserialized: UsePriorityChargingResponse = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
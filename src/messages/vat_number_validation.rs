@@ -0,0 +1,161 @@
+use crate::enums::generic_status_enum_type::GenericStatusEnumType;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::address_type::AddressType;
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::status_info_type::StatusInfoType;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest};
+use serde::{Deserialize, Serialize};
+use std::default::Default;
+
+/// 1.92. VatNumberValidation
+pub struct VatNumberValidation;
+
+impl OcppMessage for VatNumberValidation {
+    type Request = VatNumberValidationRequest;
+    type Response = VatNumberValidationResponse;
+}
+
+/// 1.92.1. VatNumberValidationRequest
+/// (2.1) This contains the field definition of the VatNumberValidationRequest PDU sent by the Charging Station to the CSMS.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct VatNumberValidationRequest {
+    /// Required. VAT number to check.
+    pub vat_number: String,
+    /// Optional. EVSE id for which check is done.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evse_id: Option<i32>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for VatNumberValidationRequest {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        b.check_cardinality("vat_number", 0, 20, &self.vat_number.chars());
+
+        if let Some(evse_id) = self.evse_id {
+            b.check_bounds("evse_id", 0, i32::MAX, evse_id);
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("VatNumberValidationRequest")
+    }
+}
+
+#[typetag::serde]
+impl OcppRequest for VatNumberValidationRequest {
+    fn get_message_type(&self) -> String {
+        String::from("VatNumberValidation")
+    }
+}
+
+/// 1.92.2. VatNumberValidationResponse
+/// (2.1) This contains the field definition of the VatNumberValidationResponse PDU sent by the CSMS to the Charging Station in
+/// response to VatNumberValidationRequest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct VatNumberValidationResponse {
+    /// Optional. Company address associated with vatNumber.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company: Option<AddressType>,
+    /// Optional. Detailed status information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_info: Option<StatusInfoType>,
+    /// Required. VAT number that was requested.
+    pub vat_number: String,
+    /// Optional. EVSE id for which check was requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evse_id: Option<i32>,
+    /// Required. Result of operation.
+    pub status: GenericStatusEnumType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+#[typetag::serde]
+impl OcppEntity for VatNumberValidationResponse {
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut b = StructureValidationBuilder::new();
+
+        if let Some(company) = &self.company {
+            b.check_member("company", company);
+        }
+
+        if let Some(status_info) = &self.status_info {
+            b.check_member("status_info", status_info);
+        }
+
+        b.check_cardinality("vat_number", 0, 20, &self.vat_number.chars());
+
+        if let Some(evse_id) = self.evse_id {
+            b.check_bounds("evse_id", 0, i32::MAX, evse_id);
+        }
+
+        b.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
+        }
+
+        b.build("VatNumberValidationResponse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_vat_number_validation() {
+        assert!(VatNumberValidation::request().validate().is_ok());
+        assert!(VatNumberValidation::response().validate().is_ok());
+    }
+
+    #[test]
+    fn test_vat_number_validation_invalid() {
+        let req = VatNumberValidationRequest {
+            vat_number: "a".repeat(21),
+            evse_id: Some(-1),
+            custom_data: None,
+        };
+        assert_invalid_fields(&req.validate().unwrap_err(), &["vat_number", "evse_id"]);
+
+        let resp = VatNumberValidationResponse {
+            vat_number: "a".repeat(21),
+            ..Default::default()
+        };
+        assert_invalid_fields(&resp.validate().unwrap_err(), &["vat_number"]);
+    }
+
+    #[test]
+    fn test_vat_number_validation_serialize_deserialize() {
+        let resp = VatNumberValidationResponse {
+            company: Some(AddressType {
+                name: "ACME".to_string(),
+                address1: "Main Street 1".to_string(),
+                address2: None,
+                city: "Springfield".to_string(),
+                postal_code: Some("1234".to_string()),
+                country: "NL".to_string(),
+                custom_data: None,
+            }),
+            status_info: None,
+            vat_number: "NL123".to_string(),
+            evse_id: Some(1),
+            status: GenericStatusEnumType::Accepted,
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&resp).unwrap();
+        assert!(serialized.contains("\"vatNumber\":\"NL123\""));
+        assert!(serialized.contains("\"postalCode\":\"1234\""));
+        let deserialized: VatNumberValidationResponse = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(resp, deserialized);
+    }
+}
@@ -1,10 +1,22 @@
-use crate::traits::{OcppEntity, OcppRequest};
-use serde::{Deserialize, Serialize};
-use serde_json::Value;
+pub mod decoder;
+pub mod message_id;
+pub mod pending;
+mod unknown_fields;
+
+use crate::errors::OcppError;
+use crate::messages::{for_each_action, for_each_unconfirmed_action};
+use crate::ocppj::message_id::validate_message_id;
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest, OcppUnconfirmedMessage};
+use miette::Diagnostic;
+use serde::de::Error as _;
+use serde::ser::SerializeTuple;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value, json};
 use serde_tuple::{Deserialize_tuple, Serialize_tuple};
+use std::fmt;
 
 #[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize, Default)]
-#[serde(into = "i32")]
+#[serde(into = "i32", try_from = "i32")]
 pub enum MessageTypeId {
     #[default]
     Call = 2,
@@ -15,7 +27,7 @@ pub enum MessageTypeId {
 }
 
 impl TryFrom<&str> for MessageTypeId {
-    type Error = ();
+    type Error = OcppError;
     fn try_from(s: &str) -> Result<Self, Self::Error> {
         match s {
             "CALL" => Ok(MessageTypeId::Call),
@@ -23,20 +35,23 @@ impl TryFrom<&str> for MessageTypeId {
             "CALLERROR" => Ok(MessageTypeId::CallError),
             "CALLRESULTERROR" => Ok(MessageTypeId::CallResultError),
             "SEND" => Ok(MessageTypeId::Send),
-            _ => Err(()),
+            _ => Err(OcppError::InvalidEnumValueError {
+                enum_name: "MessageTypeId".to_string(),
+                value: s.to_string(),
+            }),
         }
     }
 }
 
 impl TryFrom<String> for MessageTypeId {
-    type Error = ();
+    type Error = OcppError;
     fn try_from(s: String) -> Result<Self, Self::Error> {
         s.as_str().try_into()
     }
 }
 
 impl TryFrom<i32> for MessageTypeId {
-    type Error = ();
+    type Error = OcppError;
     fn try_from(i: i32) -> Result<Self, Self::Error> {
         match i {
             2 => Ok(MessageTypeId::Call),
@@ -44,7 +59,10 @@ impl TryFrom<i32> for MessageTypeId {
             4 => Ok(MessageTypeId::CallError),
             5 => Ok(MessageTypeId::CallResultError),
             6 => Ok(MessageTypeId::Send),
-            _ => Err(()),
+            _ => Err(OcppError::InvalidEnumValueError {
+                enum_name: "MessageTypeId".to_string(),
+                value: i.to_string(),
+            }),
         }
     }
 }
@@ -56,7 +74,7 @@ impl From<MessageTypeId> for String {
             MessageTypeId::CallResult => "CALLRESULT".to_string(),
             MessageTypeId::CallError => "CALLERROR".to_string(),
             MessageTypeId::Send => "SEND".to_string(),
-            MessageTypeId::CallResultError => "CallRESULTERROR".to_string(),
+            MessageTypeId::CallResultError => "CALLRESULTERROR".to_string(),
         }
     }
 }
@@ -73,9 +91,125 @@ impl From<MessageTypeId> for i32 {
     }
 }
 
+macro_rules! impl_payload_decoders {
+    ($($module:ident::$message:ident),* $(,)?) => {
+        /// Deserialize a CALL payload into the request type of the given action.
+        pub fn request_from_value(
+            action: &str,
+            payload: Value,
+        ) -> Result<Box<dyn OcppRequest>, serde_json::Error> {
+            match action {
+                $(
+                    stringify!($message) => Ok(Box::new(serde_json::from_value::<
+                        <crate::messages::$module::$message as OcppMessage>::Request,
+                    >(payload)?)),
+                )*
+                _ => Err(serde_json::Error::custom(format!("unknown action `{action}`"))),
+            }
+        }
+
+        /// Deserialize a CALLRESULT payload into the response type of the given action.
+        pub fn response_from_value(
+            action: &str,
+            payload: Value,
+        ) -> Result<Box<dyn OcppEntity>, serde_json::Error> {
+            match action {
+                $(
+                    stringify!($message) => Ok(Box::new(serde_json::from_value::<
+                        <crate::messages::$module::$message as OcppMessage>::Response,
+                    >(payload)?)),
+                )*
+                _ => Err(serde_json::Error::custom(format!("unknown action `{action}`"))),
+            }
+        }
+    };
+}
+
+for_each_action!(impl_payload_decoders);
+
+macro_rules! impl_unconfirmed_payload_decoders {
+    ($($module:ident::$message:ident),* $(,)?) => {
+        /// Whether the given action is an unconfirmed message, which must be sent with SEND
+        /// instead of CALL.
+        pub fn is_unconfirmed_action(action: &str) -> bool {
+            matches!(action, $(stringify!($message))|*)
+        }
+
+        /// Deserialize a SEND payload into the request type of the given action.
+        pub fn unconfirmed_request_from_value(
+            action: &str,
+            payload: Value,
+        ) -> Result<Box<dyn OcppRequest>, serde_json::Error> {
+            match action {
+                $(
+                    stringify!($message) => Ok(Box::new(serde_json::from_value::<
+                        <crate::messages::$module::$message as OcppUnconfirmedMessage>::Request,
+                    >(payload)?)),
+                )*
+                _ => Err(serde_json::Error::custom(format!(
+                    "`{action}` is not an unconfirmed action"
+                ))),
+            }
+        }
+    };
+}
+
+for_each_unconfirmed_action!(impl_unconfirmed_payload_decoders);
+
+/// Check that an action may be sent with the given message type: unconfirmed actions must use
+/// SEND, and every other action must use CALL.
+pub(crate) fn check_action_message_type(
+    action: &str,
+    message_type_id: MessageTypeId,
+) -> Result<(), OcppError> {
+    let expected = if is_unconfirmed_action(action) {
+        MessageTypeId::Send
+    } else {
+        MessageTypeId::Call
+    };
+
+    if message_type_id != expected {
+        return Err(OcppError::MessageTypeMismatchError {
+            action: action.to_string(),
+            message_type: String::from(message_type_id),
+        });
+    }
+
+    Ok(())
+}
+
+/// Serializes a trait-object payload as the bare JSON object required by OCPP-J, bypassing the
+/// `typetag` discriminator that `dyn OcppEntity` and `dyn OcppRequest` would otherwise carry.
+struct UntaggedPayload<'a, T: ?Sized>(&'a T);
+
+impl<T: ?Sized + erased_serde::Serialize> Serialize for UntaggedPayload<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        erased_serde::serialize(self.0, serializer)
+    }
+}
+
+/// Check that a decoded message type id matches the frame being deserialized.
+fn expect_message_type_id<E: serde::de::Error>(
+    message_type_id: MessageTypeId,
+    expected: MessageTypeId,
+) -> Result<(), E> {
+    if message_type_id != expected {
+        return Err(E::custom(format!(
+            "expected a {}, got a {}",
+            String::from(expected),
+            String::from(message_type_id)
+        )));
+    }
+
+    Ok(())
+}
+
 /// A struct containing all the info required to send an ocpp message in a way that complies with
 /// OCPP-J. Messages strictly adhere to RCP standards.
-#[derive(Clone, Debug, Serialize_tuple, Deserialize_tuple)]
+///
+/// Serializes to `[2, "<messageId>", "<action>", {payload}]`. On deserialization, the action
+/// determines which request type the payload is decoded into.
+#[derive(Clone, Debug)]
 pub struct RcpCall {
     pub message_type_id: MessageTypeId,
     pub message_id: String,
@@ -84,30 +218,624 @@ pub struct RcpCall {
 }
 
 impl RcpCall {
+    /// Create a new RCP-spec CALL. Fails if the message id is invalid or if the payload is an
+    /// unconfirmed message, which must be sent with `RcpSend` instead.
+    pub fn new(message_id: &str, payload: Box<dyn OcppRequest>) -> Result<Self, OcppError> {
+        validate_message_id(message_id)?;
+        let action = payload.get_message_type();
+        check_action_message_type(&action, MessageTypeId::Call)?;
 
-    /// Create a new RCP-spec CALL.
-    pub fn new(message_id: &str, payload: Box<dyn OcppRequest>) -> Self {
-        Self {
+        Ok(Self {
             message_type_id: MessageTypeId::Call,
             message_id: String::from(message_id),
-            action: payload.get_message_type(),
+            action,
             payload,
-        }
+        })
     }
 }
 
-#[derive(Clone, Debug, Serialize_tuple, Deserialize_tuple)]
+impl Serialize for RcpCall {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(4)?;
+        tuple.serialize_element(&self.message_type_id)?;
+        tuple.serialize_element(&self.message_id)?;
+        tuple.serialize_element(&self.action)?;
+        tuple.serialize_element(&UntaggedPayload(&*self.payload))?;
+        tuple.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for RcpCall {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (message_type_id, message_id, action, payload) =
+            <(MessageTypeId, String, String, Value)>::deserialize(deserializer)?;
+        expect_message_type_id(message_type_id, MessageTypeId::Call)?;
+        validate_message_id(&message_id).map_err(D::Error::custom)?;
+        check_action_message_type(&action, MessageTypeId::Call).map_err(D::Error::custom)?;
+        let payload = request_from_value(&action, payload).map_err(D::Error::custom)?;
+
+        Ok(Self {
+            message_type_id: MessageTypeId::Call,
+            message_id,
+            action,
+            payload,
+        })
+    }
+}
+
+/// An unconfirmed message, such as NotifyPeriodicEventStream. Serializes to
+/// `[6, "<messageId>", "<action>", {payload}]`. The receiver does not respond to a SEND.
+#[derive(Clone, Debug)]
+pub struct RcpSend {
+    pub message_type_id: MessageTypeId,
+    pub message_id: String,
+    pub action: String,
+    pub payload: Box<dyn OcppRequest>,
+}
+
+impl RcpSend {
+    /// Create a new RCP-spec SEND. Fails if the message id is invalid or if the payload is not an
+    /// unconfirmed message.
+    pub fn new(message_id: &str, payload: Box<dyn OcppRequest>) -> Result<Self, OcppError> {
+        validate_message_id(message_id)?;
+        let action = payload.get_message_type();
+        check_action_message_type(&action, MessageTypeId::Send)?;
+
+        Ok(Self {
+            message_type_id: MessageTypeId::Send,
+            message_id: String::from(message_id),
+            action,
+            payload,
+        })
+    }
+}
+
+impl Serialize for RcpSend {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(4)?;
+        tuple.serialize_element(&self.message_type_id)?;
+        tuple.serialize_element(&self.message_id)?;
+        tuple.serialize_element(&self.action)?;
+        tuple.serialize_element(&UntaggedPayload(&*self.payload))?;
+        tuple.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for RcpSend {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (message_type_id, message_id, action, payload) =
+            <(MessageTypeId, String, String, Value)>::deserialize(deserializer)?;
+        expect_message_type_id(message_type_id, MessageTypeId::Send)?;
+        validate_message_id(&message_id).map_err(D::Error::custom)?;
+        check_action_message_type(&action, MessageTypeId::Send).map_err(D::Error::custom)?;
+        let payload = unconfirmed_request_from_value(&action, payload).map_err(D::Error::custom)?;
+
+        Ok(Self {
+            message_type_id: MessageTypeId::Send,
+            message_id,
+            action,
+            payload,
+        })
+    }
+}
+
+/// The response to an `RcpCall`. Serializes to `[3, "<messageId>", {payload}]`.
+///
+/// A CALLRESULT does not carry its action on the wire, so it cannot implement `Deserialize` on its
+/// own. Use `RcpCallResult::deserialize_for` with the `RcpCall` it answers instead.
+#[derive(Clone, Debug)]
 pub struct RcpCallResult {
     pub message_type_id: MessageTypeId,
     pub message_id: String,
     pub payload: Box<dyn OcppEntity>,
 }
 
+impl RcpCallResult {
+    /// Create a new RCP-spec CALLRESULT answering the CALL with the given message id.
+    pub fn new(message_id: &str, payload: Box<dyn OcppEntity>) -> Self {
+        Self {
+            message_type_id: MessageTypeId::CallResult,
+            message_id: String::from(message_id),
+            payload,
+        }
+    }
+
+    /// Deserialize a CALLRESULT answering `call`, decoding its payload into the response type of
+    /// the call's action.
+    pub fn deserialize_for<'de, D: Deserializer<'de>>(
+        call: &RcpCall,
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let (message_type_id, message_id, payload) =
+            <(MessageTypeId, String, Value)>::deserialize(deserializer)?;
+        expect_message_type_id(message_type_id, MessageTypeId::CallResult)?;
+        if message_id != call.message_id {
+            return Err(D::Error::custom(format!(
+                "message id `{message_id}` does not match call `{}`",
+                call.message_id
+            )));
+        }
+        let payload = response_from_value(&call.action, payload).map_err(D::Error::custom)?;
+
+        Ok(Self {
+            message_type_id: MessageTypeId::CallResult,
+            message_id,
+            payload,
+        })
+    }
+}
+
+impl Serialize for RcpCallResult {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(3)?;
+        tuple.serialize_element(&self.message_type_id)?;
+        tuple.serialize_element(&self.message_id)?;
+        tuple.serialize_element(&UntaggedPayload(&*self.payload))?;
+        tuple.end()
+    }
+}
+
+/// The error codes defined by the OCPP-J RPC framework for use in a CALLERROR.
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub enum RpcErrorCode {
+    /// Payload for Action is syntactically incorrect.
+    FormatViolation,
+    /// Any other error not covered by the more specific error codes.
+    GenericError,
+    /// An internal error occurred and the receiver was not able to process the requested Action
+    /// successfully.
+    InternalError,
+    /// A message with a Message Type Number received that is not supported by this
+    /// implementation.
+    MessageTypeNotSupported,
+    /// Requested Action is not known by receiver.
+    NotImplemented,
+    /// Requested Action is recognized but not supported by the receiver.
+    NotSupported,
+    /// Payload for Action is syntactically correct but at least one of the fields violates
+    /// occurrence constraints.
+    OccurrenceConstraintViolation,
+    /// Payload is syntactically correct but at least one field contains an invalid value.
+    PropertyConstraintViolation,
+    /// Payload for Action is not conform the PDU structure.
+    ProtocolError,
+    /// Content of the call is not a valid RPC Request, for example: MessageId could not be read.
+    RpcFrameworkError,
+    /// During the processing of Action a security issue occurred preventing receiver from
+    /// completing the Action successfully.
+    SecurityError,
+    /// Payload for Action is syntactically correct but at least one of the fields violates data
+    /// type constraints (e.g. "somestring": 12).
+    TypeConstraintViolation,
+}
+
+impl fmt::Display for RpcErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl From<&OcppError> for RpcErrorCode {
+    fn from(error: &OcppError) -> Self {
+        match error {
+            // Wrapper errors take the code of the first underlying error.
+            OcppError::StructureValidationError { related, .. }
+            | OcppError::FieldValidationError { related, .. }
+            | OcppError::SchemaValidationError { related, .. } => related
+                .first()
+                .map_or(RpcErrorCode::GenericError, RpcErrorCode::from),
+            OcppError::FieldCardinalityError { .. } | OcppError::FieldRelationshipError { .. } => {
+                RpcErrorCode::OccurrenceConstraintViolation
+            }
+            OcppError::InvalidEnumValueError { .. }
+            | OcppError::FieldBoundsError { .. }
+            | OcppError::FieldValueError { .. }
+            | OcppError::FieldISOError { .. } => RpcErrorCode::PropertyConstraintViolation,
+            OcppError::SchemaViolationError { keyword, .. } => match keyword.as_str() {
+                "type" | "format" => RpcErrorCode::TypeConstraintViolation,
+                "required" | "minItems" | "maxItems" => RpcErrorCode::OccurrenceConstraintViolation,
+                "additionalProperties" => RpcErrorCode::FormatViolation,
+                _ => RpcErrorCode::PropertyConstraintViolation,
+            },
+            OcppError::MissingSchemaError { .. } => RpcErrorCode::GenericError,
+            OcppError::BuilderError { .. } => RpcErrorCode::InternalError,
+            OcppError::UnknownActionError { .. } => RpcErrorCode::NotImplemented,
+            OcppError::MalformedFrameError { .. } => RpcErrorCode::RpcFrameworkError,
+            OcppError::PayloadDeserializationError { .. } => RpcErrorCode::FormatViolation,
+            OcppError::UnmatchedMessageIdError { .. }
+            | OcppError::DuplicateMessageIdError { .. } => RpcErrorCode::RpcFrameworkError,
+            OcppError::CallTimeoutError { .. } => RpcErrorCode::GenericError,
+            OcppError::CallErrorReceived { error_code, .. } => *error_code,
+            OcppError::MessageTypeMismatchError { .. } => RpcErrorCode::MessageTypeNotSupported,
+            OcppError::TransportError { .. } => RpcErrorCode::GenericError,
+            OcppError::SubprotocolNegotiationError { .. } => RpcErrorCode::GenericError,
+            OcppError::UnhandledActionError { .. } => RpcErrorCode::NotSupported,
+            OcppError::StationNotConnectedError { .. } => RpcErrorCode::GenericError,
+            OcppError::AuthenticationError { .. } => RpcErrorCode::SecurityError,
+        }
+    }
+}
+
+impl From<OcppError> for RpcErrorCode {
+    fn from(error: OcppError) -> Self {
+        RpcErrorCode::from(&error)
+    }
+}
+
+/// Render a diagnostic and everything related to it as a JSON object, so it can travel in the
+/// `errorDetails` of a CALLERROR.
+fn diagnostic_details(diagnostic: &dyn Diagnostic) -> Value {
+    let mut details = Map::new();
+    details.insert("message".to_string(), json!(diagnostic.to_string()));
+    if let Some(help) = diagnostic.help() {
+        details.insert("help".to_string(), json!(help.to_string()));
+    }
+    if let Some(related) = diagnostic.related() {
+        let related: Vec<Value> = related.map(diagnostic_details).collect();
+        if !related.is_empty() {
+            details.insert("related".to_string(), Value::Array(related));
+        }
+    }
+
+    Value::Object(details)
+}
+
+/// The error response to an `RcpCall`. Serializes to
+/// `[4, "<messageId>", "<errorCode>", "<errorDescription>", {errorDetails}]`.
 #[derive(Clone, Debug, Serialize_tuple, Deserialize_tuple)]
 pub struct RcpCallError {
     pub message_type_id: MessageTypeId,
     pub message_id: String,
-    pub error_code: String,
+    pub error_code: RpcErrorCode,
+    pub error_description: String,
+    pub error_details: Value,
+}
+
+impl RcpCallError {
+    /// Create a new RCP-spec CALLERROR answering the CALL with the given message id.
+    pub fn new(
+        message_id: &str,
+        error_code: RpcErrorCode,
+        error_description: &str,
+        error_details: Value,
+    ) -> Self {
+        Self {
+            message_type_id: MessageTypeId::CallError,
+            message_id: String::from(message_id),
+            error_code,
+            error_description: String::from(error_description),
+            error_details,
+        }
+    }
+
+    /// Create a ready-to-send CALLERROR reporting `error` for the CALL with the given message id.
+    /// The error code is derived from the kind of error, and its nested diagnostics are rendered
+    /// into the error details.
+    pub fn from_ocpp_error(message_id: &str, error: &OcppError) -> Self {
+        Self::new(
+            message_id,
+            RpcErrorCode::from(error),
+            &error.to_string(),
+            diagnostic_details(error),
+        )
+    }
+}
+
+/// Sent by the receiver of a CALLRESULT that it could not process, for example because its payload
+/// is invalid. Serializes to
+/// `[5, "<messageId>", "<errorCode>", "<errorDescription>", {errorDetails}]`.
+#[derive(Clone, Debug, Serialize_tuple, Deserialize_tuple)]
+pub struct RcpCallResultError {
+    pub message_type_id: MessageTypeId,
+    pub message_id: String,
+    pub error_code: RpcErrorCode,
     pub error_description: String,
     pub error_details: Value,
-}
\ No newline at end of file
+}
+
+impl RcpCallResultError {
+    /// Create a new RCP-spec CALLRESULTERROR for the CALLRESULT with the given message id.
+    pub fn new(
+        message_id: &str,
+        error_code: RpcErrorCode,
+        error_description: &str,
+        error_details: Value,
+    ) -> Self {
+        Self {
+            message_type_id: MessageTypeId::CallResultError,
+            message_id: String::from(message_id),
+            error_code,
+            error_description: String::from(error_description),
+            error_details,
+        }
+    }
+
+    /// Create a ready-to-send CALLRESULTERROR reporting `error` for the CALLRESULT with the given
+    /// message id.
+    pub fn from_ocpp_error(message_id: &str, error: &OcppError) -> Self {
+        Self::new(
+            message_id,
+            RpcErrorCode::from(error),
+            &error.to_string(),
+            diagnostic_details(error),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::StructureValidationBuilder;
+    use crate::messages::boot_notification::{BootNotificationRequest, BootNotificationResponse};
+    use crate::messages::get_log::GetLogRequest;
+    use crate::messages::notify_periodic_event_stream::NotifyPeriodicEventStreamRequest;
+    use crate::structures::stream_data_element_type::StreamDataElementType;
+
+    #[test]
+    fn test_call_serializes_without_type_tag() {
+        let call = RcpCall::new("19223201", Box::new(BootNotificationRequest::default())).unwrap();
+        let value = serde_json::to_value(&call).unwrap();
+
+        assert_eq!(value[0], json!(2));
+        assert_eq!(value[1], json!("19223201"));
+        assert_eq!(value[2], json!("BootNotification"));
+        assert_eq!(
+            value[3],
+            serde_json::to_value(BootNotificationRequest::default()).unwrap()
+        );
+        assert!(value[3].get("type").is_none());
+    }
+
+    #[test]
+    fn test_call_serialize_deserialize() {
+        let call = RcpCall::new("abc", Box::new(GetLogRequest::default())).unwrap();
+        let json = serde_json::to_string(&call).unwrap();
+        let deserialized: RcpCall = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized.message_type_id, MessageTypeId::Call);
+        assert_eq!(deserialized.message_id, "abc");
+        assert_eq!(deserialized.action, "GetLog");
+        assert_eq!(serde_json::to_string(&deserialized).unwrap(), json);
+    }
+
+    #[test]
+    fn test_call_deserialize_unknown_action() {
+        let json = r#"[2, "abc", "NotAnAction", {}]"#;
+        assert!(serde_json::from_str::<RcpCall>(json).is_err());
+    }
+
+    #[test]
+    fn test_call_deserialize_wrong_message_type_id() {
+        let json = r#"[3, "abc", "ClearCache", {}]"#;
+        assert!(serde_json::from_str::<RcpCall>(json).is_err());
+    }
+
+    #[test]
+    fn test_call_result_serialize_deserialize() {
+        let call = RcpCall::new("abc", Box::new(BootNotificationRequest::default())).unwrap();
+        let result = RcpCallResult::new("abc", Box::new(BootNotificationResponse::default()));
+        let json = serde_json::to_string(&result).unwrap();
+        let value: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value[0], json!(3));
+        assert!(value[2].get("type").is_none());
+
+        let deserialized =
+            RcpCallResult::deserialize_for(&call, &mut serde_json::Deserializer::from_str(&json))
+                .unwrap();
+        assert_eq!(deserialized.message_id, "abc");
+        assert_eq!(serde_json::to_string(&deserialized).unwrap(), json);
+    }
+
+    #[test]
+    fn test_call_result_deserialize_mismatched_id() {
+        let call = RcpCall::new("abc", Box::new(BootNotificationRequest::default())).unwrap();
+        let json = serde_json::to_value(RcpCallResult::new(
+            "def",
+            Box::new(BootNotificationResponse::default()),
+        ))
+        .unwrap();
+
+        assert!(RcpCallResult::deserialize_for(&call, json).is_err());
+    }
+
+    #[test]
+    fn test_rpc_error_code_serialize() {
+        assert_eq!(
+            serde_json::to_value(RpcErrorCode::OccurrenceConstraintViolation).unwrap(),
+            json!("OccurrenceConstraintViolation")
+        );
+        assert_eq!(
+            serde_json::from_value::<RpcErrorCode>(json!("RpcFrameworkError")).unwrap(),
+            RpcErrorCode::RpcFrameworkError
+        );
+    }
+
+    #[test]
+    fn test_rpc_error_code_from_ocpp_error() {
+        let cardinality = OcppError::FieldCardinalityError {
+            cardinality: 5,
+            lower: 0,
+            upper: 4,
+        };
+        let bounds = OcppError::FieldBoundsError {
+            value: "-1".to_string(),
+            lower: "0".to_string(),
+            upper: "10".to_string(),
+        };
+
+        assert_eq!(
+            RpcErrorCode::from(&cardinality),
+            RpcErrorCode::OccurrenceConstraintViolation
+        );
+        assert_eq!(
+            RpcErrorCode::from(&bounds),
+            RpcErrorCode::PropertyConstraintViolation
+        );
+        assert_eq!(
+            RpcErrorCode::from(bounds.to_field_validation_error("stack_level")),
+            RpcErrorCode::PropertyConstraintViolation
+        );
+        assert_eq!(
+            RpcErrorCode::from(OcppError::UnknownActionError {
+                action: "Frobnicate".to_string()
+            }),
+            RpcErrorCode::NotImplemented
+        );
+    }
+
+    #[test]
+    fn test_call_error_from_ocpp_error() {
+        let mut b = StructureValidationBuilder::new();
+        b.check_bounds("retries", 0, i32::MAX, -1);
+        let error = b.build("GetLogRequest").unwrap_err();
+
+        let call_error = RcpCallError::from_ocpp_error("abc", &error);
+        assert_eq!(call_error.message_type_id, MessageTypeId::CallError);
+        assert_eq!(call_error.message_id, "abc");
+        assert_eq!(
+            call_error.error_code,
+            RpcErrorCode::PropertyConstraintViolation
+        );
+        assert_eq!(call_error.error_description, error.to_string());
+
+        let field = &call_error.error_details["related"][0];
+        assert_eq!(
+            field["message"],
+            json!("OCPP Field Validation Error: retries")
+        );
+        assert!(
+            field["related"][0]["message"]
+                .as_str()
+                .unwrap()
+                .starts_with("Field Bound Error")
+        );
+
+        let value = serde_json::to_value(&call_error).unwrap();
+        assert_eq!(value[0], json!(4));
+        assert_eq!(value[2], json!("PropertyConstraintViolation"));
+    }
+
+    #[test]
+    fn test_message_type_id_serialize_deserialize() {
+        for (message_type_id, value) in [
+            (MessageTypeId::Call, 2),
+            (MessageTypeId::CallResult, 3),
+            (MessageTypeId::CallError, 4),
+            (MessageTypeId::CallResultError, 5),
+            (MessageTypeId::Send, 6),
+        ] {
+            assert_eq!(
+                serde_json::to_value(message_type_id.clone()).unwrap(),
+                json!(value)
+            );
+            assert_eq!(
+                serde_json::from_value::<MessageTypeId>(json!(value)).unwrap(),
+                message_type_id
+            );
+        }
+
+        assert!(serde_json::from_value::<MessageTypeId>(json!(7)).is_err());
+        assert_eq!(
+            String::from(MessageTypeId::CallResultError),
+            "CALLRESULTERROR"
+        );
+        assert_eq!(
+            MessageTypeId::try_from("CALLRESULTERROR").unwrap(),
+            MessageTypeId::CallResultError
+        );
+    }
+
+    #[test]
+    fn test_call_error_serialize_deserialize() {
+        let error = RcpCallError::new("abc", RpcErrorCode::GenericError, "Oops", json!({}));
+        let json = serde_json::to_string(&error).unwrap();
+        assert_eq!(json, r#"[4,"abc","GenericError","Oops",{}]"#);
+
+        let deserialized: RcpCallError = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.message_type_id, MessageTypeId::CallError);
+        assert_eq!(deserialized.error_code, RpcErrorCode::GenericError);
+    }
+
+    #[test]
+    fn test_call_result_error_serialize_deserialize() {
+        let error = RcpCallResultError::from_ocpp_error(
+            "abc",
+            &OcppError::PayloadDeserializationError {
+                action: "GetLog".to_string(),
+                reason: "missing field `status`".to_string(),
+            },
+        );
+        let json = serde_json::to_string(&error).unwrap();
+        let value: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value[0], json!(5));
+        assert_eq!(value[2], json!("FormatViolation"));
+
+        let deserialized: RcpCallResultError = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.message_type_id, MessageTypeId::CallResultError);
+        assert_eq!(deserialized.message_id, "abc");
+    }
+
+    fn stream_request() -> NotifyPeriodicEventStreamRequest {
+        NotifyPeriodicEventStreamRequest {
+            id: 1,
+            pending: 0,
+            data: vec![StreamDataElementType {
+                t: 1.0,
+                v: "16.0".to_string(),
+                custom_data: None,
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_send_serialize_deserialize() {
+        let send = RcpSend::new("abc", Box::new(stream_request())).unwrap();
+        let json = serde_json::to_string(&send).unwrap();
+        let value: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value[0], json!(6));
+        assert_eq!(value[2], json!("NotifyPeriodicEventStream"));
+        assert!(value[3].get("type").is_none());
+
+        let deserialized: RcpSend = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.action, "NotifyPeriodicEventStream");
+        assert_eq!(serde_json::to_string(&deserialized).unwrap(), json);
+    }
+
+    #[test]
+    fn test_send_only_for_unconfirmed_actions() {
+        assert!(is_unconfirmed_action("NotifyPeriodicEventStream"));
+        assert!(!is_unconfirmed_action("BootNotification"));
+
+        let result = RcpSend::new("abc", Box::new(BootNotificationRequest::default()));
+        assert!(matches!(
+            result,
+            Err(OcppError::MessageTypeMismatchError { .. })
+        ));
+
+        let json = r#"[6, "abc", "ClearCache", {}]"#;
+        assert!(serde_json::from_str::<RcpSend>(json).is_err());
+
+        let call = json!([2, "abc", "NotifyPeriodicEventStream", stream_request()]);
+        assert!(serde_json::from_value::<RcpCall>(call).is_err());
+    }
+
+    #[test]
+    fn test_message_id_validation() {
+        let too_long = "a".repeat(37);
+        assert!(RcpCall::new(&too_long, Box::new(GetLogRequest::default())).is_err());
+        assert!(RcpCall::new("", Box::new(GetLogRequest::default())).is_err());
+        assert!(RcpSend::new("with space", Box::new(stream_request())).is_err());
+
+        let json = format!(r#"[2, "{too_long}", "ClearCache", {{}}]"#);
+        assert!(serde_json::from_str::<RcpCall>(&json).is_err());
+    }
+
+    #[test]
+    fn test_call_rejects_unconfirmed_action() {
+        assert!(matches!(
+            RcpCall::new("abc", Box::new(stream_request())),
+            Err(OcppError::MessageTypeMismatchError { .. })
+        ));
+    }
+}
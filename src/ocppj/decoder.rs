@@ -0,0 +1,934 @@
+use crate::errors::OcppError;
+use crate::messages::{for_each_action, for_each_unconfirmed_action};
+use crate::ocppj::message_id::validate_message_id;
+use crate::ocppj::unknown_fields;
+use crate::ocppj::{
+    MessageTypeId, RcpCallError, RcpCallResultError, RpcErrorCode, check_action_message_type,
+};
+use crate::traits::{OcppEntity, OcppMessage, OcppRequest, OcppUnconfirmedMessage};
+use serde::{Serialize, Serializer};
+use serde_json::Value;
+
+/// Generate an enum with one variant per OCPP message, each wrapping the `$kind` associated type
+/// of the message's `$message_trait` implementation.
+macro_rules! impl_message_enum {
+    (
+        $name:ident,
+        $message_trait:ident::$kind:ident,
+        $doc:literal;
+        $($module:ident::$message:ident),* $(,)?
+    ) => {
+        #[doc = $doc]
+        // Variants are kept unboxed so callers can match on and move out the payloads directly.
+        #[allow(clippy::large_enum_variant)]
+        #[derive(Debug, Clone)]
+        pub enum $name {
+            $(
+                $message(<crate::messages::$module::$message as $message_trait>::$kind),
+            )*
+        }
+
+        impl $name {
+            /// The OCPP-J action string of this message.
+            pub fn action(&self) -> &'static str {
+                match self {
+                    $(Self::$message(_) => stringify!($message),)*
+                }
+            }
+
+            /// Deserialize a payload into the variant of the given action.
+            pub fn from_payload(action: &str, payload: Value) -> Result<Self, OcppError> {
+                match action {
+                    $(
+                        stringify!($message) => serde_json::from_value(payload)
+                            .map(Self::$message)
+                            .map_err(|e| OcppError::PayloadDeserializationError {
+                                action: action.to_string(),
+                                reason: e.to_string(),
+                            }),
+                    )*
+                    _ => Err(OcppError::UnknownActionError {
+                        action: action.to_string(),
+                    }),
+                }
+            }
+
+            /// Deserialize a payload into the variant of the given action, then check it as per
+            /// `profile`.
+            pub fn from_payload_with_profile(
+                action: &str,
+                payload: Value,
+                profile: DecodingProfile,
+            ) -> Result<Decoded<Self>, OcppError> {
+                let (message, unknown) = match action {
+                    $(
+                        stringify!($message) => unknown_fields::from_value(payload)
+                            .map(|(payload, unknown)| (Self::$message(payload), unknown)),
+                    )*
+                    _ => {
+                        return Err(OcppError::UnknownActionError {
+                            action: action.to_string(),
+                        })
+                    }
+                }
+                .map_err(|e| OcppError::PayloadDeserializationError {
+                    action: action.to_string(),
+                    reason: e.to_string(),
+                })?;
+                let warnings = profile.check(
+                    &format!("{action}{}", stringify!($kind)),
+                    unknown,
+                    message.validate(),
+                )?;
+
+                Ok(Decoded {
+                    value: message,
+                    warnings,
+                })
+            }
+
+            /// Validate the wrapped payload.
+            pub fn validate(&self) -> Result<(), OcppError> {
+                match self {
+                    $(Self::$message(payload) => payload.validate(),)*
+                }
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                match self {
+                    $(Self::$message(payload) => payload.serialize(serializer),)*
+                }
+            }
+        }
+
+        $(
+            impl From<<crate::messages::$module::$message as $message_trait>::$kind> for $name {
+                fn from(payload: <crate::messages::$module::$message as $message_trait>::$kind) -> Self {
+                    Self::$message(payload)
+                }
+            }
+
+            impl TryFrom<$name> for <crate::messages::$module::$message as $message_trait>::$kind {
+                type Error = $name;
+
+                fn try_from(message: $name) -> Result<Self, Self::Error> {
+                    match message {
+                        $name::$message(payload) => Ok(payload),
+                        // Unreachable for enums that only have a single variant.
+                        #[allow(unreachable_patterns)]
+                        other => Err(other),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_request_message {
+    ($($path:tt)*) => {
+        impl_message_enum!(
+            RequestMessage,
+            OcppMessage::Request,
+            "A typed OCPP request, with one variant per action implemented in `crate::messages`.";
+            $($path)*
+        );
+    };
+}
+
+macro_rules! impl_response_message {
+    ($($path:tt)*) => {
+        impl_message_enum!(
+            ResponseMessage,
+            OcppMessage::Response,
+            "A typed OCPP response, with one variant per action implemented in `crate::messages`.";
+            $($path)*
+        );
+    };
+}
+
+macro_rules! impl_unconfirmed_message {
+    ($($path:tt)*) => {
+        impl_message_enum!(
+            UnconfirmedMessage,
+            OcppUnconfirmedMessage::Request,
+            "A typed unconfirmed OCPP request, with one variant per action sent with SEND.";
+            $($path)*
+        );
+    };
+}
+
+for_each_action!(impl_request_message);
+for_each_action!(impl_response_message);
+for_each_unconfirmed_action!(impl_unconfirmed_message);
+
+/// A request that is sent with a CALL, linked to the type of the response that answers it. This
+/// lets a runtime return the typed response of a CALL, e.g. a `BootNotificationResponse` for a
+/// `BootNotificationRequest`.
+pub trait CallRequest:
+    OcppEntity
+    + OcppRequest
+    + Into<RequestMessage>
+    + TryFrom<RequestMessage, Error = RequestMessage>
+    + Send
+    + 'static
+{
+    /// The OCPP-J action string of this request.
+    const ACTION: &'static str;
+
+    type Response: OcppEntity
+        + Into<ResponseMessage>
+        + TryFrom<ResponseMessage, Error = ResponseMessage>
+        + Send
+        + 'static;
+}
+
+macro_rules! impl_call_request {
+    ($($module:ident::$message:ident),* $(,)?) => {
+        $(
+            impl CallRequest for <crate::messages::$module::$message as OcppMessage>::Request {
+                const ACTION: &'static str = stringify!($message);
+
+                type Response = <crate::messages::$module::$message as OcppMessage>::Response;
+            }
+        )*
+    };
+}
+
+for_each_action!(impl_call_request);
+
+/// A CALL frame decoded into its typed request.
+#[derive(Debug, Clone)]
+pub struct IncomingCall {
+    pub message_id: String,
+    pub request: RequestMessage,
+}
+
+impl IncomingCall {
+    /// The OCPP-J action string of the decoded request.
+    pub fn action(&self) -> &'static str {
+        self.request.action()
+    }
+}
+
+/// A SEND frame decoded into its typed request.
+#[derive(Debug, Clone)]
+pub struct IncomingSend {
+    pub message_id: String,
+    pub request: UnconfirmedMessage,
+}
+
+impl IncomingSend {
+    /// The OCPP-J action string of the decoded request.
+    pub fn action(&self) -> &'static str {
+        self.request.action()
+    }
+}
+
+/// How strictly the payload of an incoming message is checked while decoding it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DecodingProfile {
+    /// Reject payloads with unknown fields, out-of-range values or bad string lengths.
+    #[default]
+    Strict,
+    /// Accept such payloads, reporting every deviation as a warning. This lets a receiver log the
+    /// bugs of its peer without refusing the message.
+    Lenient,
+}
+
+impl DecodingProfile {
+    /// Check a decoded payload, given the properties it had that its type does not declare and the
+    /// result of validating it. Returns the tolerated deviations.
+    fn check(
+        self,
+        schema: &str,
+        unknown: Vec<OcppError>,
+        validation: Result<(), OcppError>,
+    ) -> Result<Vec<OcppError>, OcppError> {
+        let mut deviations = unknown;
+
+        match self {
+            DecodingProfile::Strict => {
+                if !deviations.is_empty() {
+                    return Err(OcppError::SchemaValidationError {
+                        schema: schema.to_string(),
+                        related: deviations,
+                    });
+                }
+                validation.map(|()| deviations)
+            }
+            DecodingProfile::Lenient => {
+                if let Err(e) = validation {
+                    field_deviations(e, "", &mut deviations);
+                }
+                Ok(deviations)
+            }
+        }
+    }
+}
+
+/// A decoded message along with the deviations that a `Lenient` profile tolerated.
+#[derive(Debug, Clone)]
+pub struct Decoded<T> {
+    pub value: T,
+    pub warnings: Vec<OcppError>,
+}
+
+impl<T> Decoded<T> {
+    fn unchecked(value: T) -> Self {
+        Self {
+            value,
+            warnings: vec![],
+        }
+    }
+
+    /// Map the decoded value, keeping its warnings.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Decoded<U> {
+        Decoded {
+            value: f(self.value),
+            warnings: self.warnings,
+        }
+    }
+}
+
+/// Split a validation error into one `FieldValidationError` per invalid field. Fields of nested
+/// structures are named by their path, e.g. `charging_station.model`.
+fn field_deviations(error: OcppError, path: &str, out: &mut Vec<OcppError>) {
+    match error {
+        OcppError::StructureValidationError { related, .. } => {
+            for e in related {
+                field_deviations(e, path, out);
+            }
+        }
+        OcppError::FieldValidationError { field, related } => {
+            let path = if path.is_empty() {
+                field
+            } else {
+                format!("{path}.{field}")
+            };
+            let nested = related.iter().all(|e| {
+                matches!(
+                    e,
+                    OcppError::StructureValidationError { .. }
+                        | OcppError::FieldValidationError { .. }
+                )
+            });
+            if nested {
+                for e in related {
+                    field_deviations(e, &path, out);
+                }
+            } else {
+                out.push(OcppError::FieldValidationError {
+                    field: path,
+                    related,
+                });
+            }
+        }
+        other if path.is_empty() => out.push(other),
+        other => out.push(other.to_field_validation_error(path)),
+    }
+}
+
+fn malformed(reason: impl Into<String>) -> OcppError {
+    OcppError::MalformedFrameError {
+        reason: reason.into(),
+    }
+}
+
+/// Parse a raw WebSocket text frame into the elements of its OCPP-J array, checking that it is a
+/// JSON array whose first element is a known message type id.
+pub(crate) fn parse_frame(text: &str) -> Result<(MessageTypeId, Vec<Value>), OcppError> {
+    let value: Value =
+        serde_json::from_str(text).map_err(|e| malformed(format!("invalid JSON: {e}")))?;
+    let Value::Array(elements) = value else {
+        return Err(malformed("frame is not a JSON array"));
+    };
+
+    let message_type_id = elements
+        .first()
+        .and_then(Value::as_i64)
+        .and_then(|id| i32::try_from(id).ok())
+        .ok_or_else(|| malformed("messageTypeId is missing or not an integer"))?;
+    let message_type_id = MessageTypeId::try_from(message_type_id)
+        .map_err(|_| malformed(format!("unknown messageTypeId {message_type_id}")))?;
+
+    Ok((message_type_id, elements))
+}
+
+/// Read the string element at `index` of a frame.
+pub(crate) fn string_element(
+    elements: &[Value],
+    index: usize,
+    name: &str,
+) -> Result<String, OcppError> {
+    elements
+        .get(index)
+        .and_then(Value::as_str)
+        .map(String::from)
+        .ok_or_else(|| malformed(format!("{name} is missing or not a string")))
+}
+
+/// Read and validate the message id, the second element of every frame.
+pub(crate) fn message_id_element(elements: &[Value]) -> Result<String, OcppError> {
+    let message_id = string_element(elements, 1, "messageId")?;
+    validate_message_id(&message_id).map_err(|e| malformed(format!("invalid messageId: {e}")))?;
+
+    Ok(message_id)
+}
+
+/// Check that a frame has exactly the number of elements its message type requires.
+pub(crate) fn expect_len(elements: &[Value], len: usize) -> Result<(), OcppError> {
+    if elements.len() != len {
+        return Err(malformed(format!(
+            "expected {len} elements, got {}",
+            elements.len()
+        )));
+    }
+
+    Ok(())
+}
+
+/// Read the JSON object payload at the end of a frame.
+fn take_payload(elements: &mut Vec<Value>) -> Result<Value, OcppError> {
+    let payload = elements.pop().unwrap_or_default();
+    if !payload.is_object() {
+        return Err(malformed("payload is not a JSON object"));
+    }
+
+    Ok(payload)
+}
+
+/// Decode a CALL, checking its payload as per `profile` if one is given.
+fn call_from_elements(
+    mut elements: Vec<Value>,
+    profile: Option<DecodingProfile>,
+) -> Result<Decoded<IncomingCall>, OcppError> {
+    expect_len(&elements, 4)?;
+    let message_id = message_id_element(&elements)?;
+    let action = string_element(&elements, 2, "action")?;
+    let payload = take_payload(&mut elements)?;
+    check_action_message_type(&action, MessageTypeId::Call)?;
+
+    let request = match profile {
+        Some(profile) => RequestMessage::from_payload_with_profile(&action, payload, profile)?,
+        None => Decoded::unchecked(RequestMessage::from_payload(&action, payload)?),
+    };
+    Ok(request.map(|request| IncomingCall {
+        message_id,
+        request,
+    }))
+}
+
+/// Decode a SEND, checking its payload as per `profile` if one is given.
+fn send_from_elements(
+    mut elements: Vec<Value>,
+    profile: Option<DecodingProfile>,
+) -> Result<Decoded<IncomingSend>, OcppError> {
+    expect_len(&elements, 4)?;
+    let message_id = message_id_element(&elements)?;
+    let action = string_element(&elements, 2, "action")?;
+    let payload = take_payload(&mut elements)?;
+    check_action_message_type(&action, MessageTypeId::Send)?;
+
+    let request = match profile {
+        Some(profile) => UnconfirmedMessage::from_payload_with_profile(&action, payload, profile)?,
+        None => Decoded::unchecked(UnconfirmedMessage::from_payload(&action, payload)?),
+    };
+    Ok(request.map(|request| IncomingSend {
+        message_id,
+        request,
+    }))
+}
+
+fn call_result_from_elements(mut elements: Vec<Value>) -> Result<RawCallResult, OcppError> {
+    expect_len(&elements, 3)?;
+    let message_id = message_id_element(&elements)?;
+    let payload = take_payload(&mut elements)?;
+
+    Ok(RawCallResult {
+        message_id,
+        payload,
+    })
+}
+
+/// Read the `messageId`, `errorCode`, `errorDescription` and `errorDetails` shared by CALLERROR
+/// and CALLRESULTERROR frames.
+fn error_from_elements(
+    mut elements: Vec<Value>,
+) -> Result<(String, RpcErrorCode, String, Value), OcppError> {
+    expect_len(&elements, 5)?;
+    let message_id = message_id_element(&elements)?;
+    let error_code = string_element(&elements, 2, "errorCode")?;
+    let error_code = serde_json::from_value::<RpcErrorCode>(Value::String(error_code.clone()))
+        .map_err(|_| malformed(format!("unknown errorCode {error_code}")))?;
+    let error_description = string_element(&elements, 3, "errorDescription")?;
+    let error_details = take_payload(&mut elements)?;
+
+    Ok((message_id, error_code, error_description, error_details))
+}
+
+/// Parse a raw frame that must be a CALL into its elements.
+fn call_elements(text: &str) -> Result<Vec<Value>, OcppError> {
+    let (message_type_id, elements) = parse_frame(text)?;
+    if message_type_id != MessageTypeId::Call {
+        return Err(malformed(format!(
+            "expected a CALL, got {}",
+            String::from(message_type_id)
+        )));
+    }
+
+    Ok(elements)
+}
+
+/// Decode a raw `[2, "<messageId>", "<action>", {payload}]` frame into a typed request, dispatching
+/// on the action string. The payload is not validated, see `decode_call_with_profile`.
+pub fn decode_call(text: &str) -> Result<IncomingCall, OcppError> {
+    call_from_elements(call_elements(text)?, None).map(|call| call.value)
+}
+
+/// Decode a raw CALL frame like `decode_call`, then check its payload as per `profile`.
+pub fn decode_call_with_profile(
+    text: &str,
+    profile: DecodingProfile,
+) -> Result<Decoded<IncomingCall>, OcppError> {
+    call_from_elements(call_elements(text)?, Some(profile))
+}
+
+/// Best-effort extraction of the message id of a CALL that failed to decode, so that it can still
+/// be answered with a CALLERROR. Returns `None` if the frame is not a CALL or has no usable id.
+pub fn call_message_id(text: &str) -> Option<String> {
+    let (message_type_id, elements) = parse_frame(text).ok()?;
+    if message_type_id != MessageTypeId::Call {
+        return None;
+    }
+
+    message_id_element(&elements).ok()
+}
+
+/// A CALLRESULT whose payload has not been decoded yet. Its payload type depends on the CALL it
+/// answers, see `PendingCalls::resolve_result`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RawCallResult {
+    pub message_id: String,
+    pub payload: Value,
+}
+
+/// Any frame that can be received over an OCPP-J connection.
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone)]
+pub enum IncomingFrame {
+    Call(IncomingCall),
+    CallResult(RawCallResult),
+    CallError(RcpCallError),
+    CallResultError(RcpCallResultError),
+    Send(IncomingSend),
+}
+
+impl IncomingFrame {
+    /// The message id of the frame.
+    pub fn message_id(&self) -> &str {
+        match self {
+            IncomingFrame::Call(call) => &call.message_id,
+            IncomingFrame::CallResult(result) => &result.message_id,
+            IncomingFrame::CallError(error) => &error.message_id,
+            IncomingFrame::CallResultError(error) => &error.message_id,
+            IncomingFrame::Send(send) => &send.message_id,
+        }
+    }
+}
+
+/// Decode a raw WebSocket text frame of any message type. CALL and SEND payloads are decoded into
+/// their typed request, while CALLRESULT payloads are left raw until matched with their CALL. The
+/// payloads are not validated, see `decode_frame_with_profile`.
+pub fn decode_frame(text: &str) -> Result<IncomingFrame, OcppError> {
+    frame_from_text(text, None).map(|frame| frame.value)
+}
+
+/// Decode a raw WebSocket text frame like `decode_frame`, then check its CALL or SEND payload as
+/// per `profile`. CALLRESULT payloads are checked once matched with their CALL, see
+/// `PendingCalls::resolve_result_with_profile`.
+pub fn decode_frame_with_profile(
+    text: &str,
+    profile: DecodingProfile,
+) -> Result<Decoded<IncomingFrame>, OcppError> {
+    frame_from_text(text, Some(profile))
+}
+
+fn frame_from_text(
+    text: &str,
+    profile: Option<DecodingProfile>,
+) -> Result<Decoded<IncomingFrame>, OcppError> {
+    let (message_type_id, elements) = parse_frame(text)?;
+    match message_type_id {
+        MessageTypeId::Call => {
+            call_from_elements(elements, profile).map(|call| call.map(IncomingFrame::Call))
+        }
+        MessageTypeId::CallResult => call_result_from_elements(elements)
+            .map(|result| Decoded::unchecked(IncomingFrame::CallResult(result))),
+        MessageTypeId::CallError => {
+            let (message_id, error_code, error_description, error_details) =
+                error_from_elements(elements)?;
+            Ok(Decoded::unchecked(IncomingFrame::CallError(
+                RcpCallError::new(&message_id, error_code, &error_description, error_details),
+            )))
+        }
+        MessageTypeId::CallResultError => {
+            let (message_id, error_code, error_description, error_details) =
+                error_from_elements(elements)?;
+            Ok(Decoded::unchecked(IncomingFrame::CallResultError(
+                RcpCallResultError::new(&message_id, error_code, &error_description, error_details),
+            )))
+        }
+        MessageTypeId::Send => {
+            send_from_elements(elements, profile).map(|send| send.map(IncomingFrame::Send))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::log_enum_type::LogEnumType;
+    use crate::enums::log_status_enum_type::LogStatusEnumType;
+    use crate::messages::clear_cache::ClearCacheResponse;
+    use crate::messages::get_log::{GetLogRequest, GetLogResponse};
+    use serde_json::json;
+
+    #[test]
+    fn test_decode_call() {
+        let request = GetLogRequest {
+            log_type: LogEnumType::SecurityLog,
+            request_id: 7,
+            ..Default::default()
+        };
+        let text = format!(
+            r#"[2, "42", "GetLog", {}]"#,
+            serde_json::to_string(&request).unwrap()
+        );
+
+        let call = decode_call(&text).unwrap();
+        assert_eq!(call.message_id, "42");
+        assert_eq!(call.action(), "GetLog");
+        match call.request {
+            RequestMessage::GetLog(decoded) => assert_eq!(decoded, request),
+            other => panic!("Expected a GetLog request. Got {other:?} instead."),
+        }
+    }
+
+    #[test]
+    fn test_decode_call_round_trip() {
+        let request = RequestMessage::from(GetLogRequest::default());
+        let text = format!(
+            r#"[2,"1","{}",{}]"#,
+            request.action(),
+            serde_json::to_string(&request).unwrap()
+        );
+        assert!(decode_call(&text).is_ok());
+    }
+
+    #[test]
+    fn test_decode_call_unknown_action() {
+        let result = decode_call(r#"[2, "42", "Frobnicate", {}]"#);
+        assert!(matches!(
+            result,
+            Err(OcppError::UnknownActionError { action }) if action == "Frobnicate"
+        ));
+    }
+
+    #[test]
+    fn test_decode_call_malformed() {
+        for text in [
+            "not json",
+            r#"{"a": 1}"#,
+            r#"[]"#,
+            r#"["2", "42", "ClearCache", {}]"#,
+            r#"[9, "42", "ClearCache", {}]"#,
+            r#"[3, "42", {}]"#,
+            r#"[2, "42", "ClearCache"]"#,
+            r#"[2, 42, "ClearCache", {}]"#,
+            r#"[2, "42", "ClearCache", []]"#,
+        ] {
+            assert!(
+                matches!(
+                    decode_call(text),
+                    Err(OcppError::MalformedFrameError { .. })
+                ),
+                "Expected {text} to be malformed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_decode_call_payload_error() {
+        let result = decode_call(r#"[2, "42", "GetLog", {"requestId": "seven"}]"#);
+        assert!(matches!(
+            result,
+            Err(OcppError::PayloadDeserializationError { action, .. }) if action == "GetLog"
+        ));
+    }
+
+    #[test]
+    fn test_decode_frame() {
+        let frame = decode_frame(r#"[2, "1", "ClearCache", {}]"#).unwrap();
+        assert!(matches!(frame, IncomingFrame::Call(_)));
+
+        let frame = decode_frame(r#"[3, "2", {"status": "Accepted"}]"#).unwrap();
+        assert_eq!(frame.message_id(), "2");
+        assert!(matches!(frame, IncomingFrame::CallResult(_)));
+
+        let frame = decode_frame(r#"[4, "3", "NotImplemented", "Unknown action", {}]"#).unwrap();
+        match frame {
+            IncomingFrame::CallError(error) => {
+                assert_eq!(error.message_id, "3");
+                assert_eq!(error.error_code, RpcErrorCode::NotImplemented);
+                assert_eq!(error.error_description, "Unknown action");
+            }
+            other => panic!("Expected a CALLERROR. Got {other:?} instead."),
+        }
+    }
+
+    #[test]
+    fn test_decode_frame_malformed() {
+        for text in [
+            r#"[3, "2"]"#,
+            r#"[3, "2", "payload"]"#,
+            r#"[4, "3", "NotAnErrorCode", "", {}]"#,
+            r#"[4, "3", "GenericError", {}]"#,
+        ] {
+            assert!(
+                matches!(
+                    decode_frame(text),
+                    Err(OcppError::MalformedFrameError { .. })
+                ),
+                "Expected {text} to be malformed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_response_message_try_from() {
+        let response = ResponseMessage::from_payload("GetLog", json!({"status": "Accepted"}));
+        let response: GetLogResponse = response.unwrap().try_into().unwrap();
+        assert_eq!(response.status, LogStatusEnumType::Accepted);
+
+        let response = ResponseMessage::from(ClearCacheResponse::default());
+        assert!(GetLogResponse::try_from(response).is_err());
+    }
+
+    #[test]
+    fn test_decode_send_and_call_result_error() {
+        let frame = decode_frame(
+            r#"[6, "5", "NotifyPeriodicEventStream", {"id": 1, "pending": 0, "basetime": "2025-01-01T00:00:00Z", "data": [{"t": 0.0, "v": "1"}]}]"#,
+        )
+        .unwrap();
+        match frame {
+            IncomingFrame::Send(send) => {
+                assert_eq!(send.message_id, "5");
+                assert_eq!(send.action(), "NotifyPeriodicEventStream");
+            }
+            other => panic!("Expected a SEND. Got {other:?} instead."),
+        }
+
+        let frame = decode_frame(r#"[5, "6", "FormatViolation", "Bad payload", {}]"#).unwrap();
+        match frame {
+            IncomingFrame::CallResultError(error) => {
+                assert_eq!(error.message_type_id, MessageTypeId::CallResultError);
+                assert_eq!(error.error_code, RpcErrorCode::FormatViolation);
+            }
+            other => panic!("Expected a CALLRESULTERROR. Got {other:?} instead."),
+        }
+    }
+
+    #[test]
+    fn test_decode_message_type_mismatch() {
+        assert!(matches!(
+            decode_frame(r#"[6, "7", "ClearCache", {}]"#),
+            Err(OcppError::MessageTypeMismatchError { .. })
+        ));
+        assert!(matches!(
+            decode_frame(r#"[2, "8", "NotifyPeriodicEventStream", {}]"#),
+            Err(OcppError::MessageTypeMismatchError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_decode_frame_invalid_message_id() {
+        let too_long = "a".repeat(37);
+        for text in [
+            format!(r#"[2, "{too_long}", "ClearCache", {{}}]"#),
+            format!(r#"[3, "{too_long}", {{}}]"#),
+            r#"[4, "", "GenericError", "", {}]"#.to_string(),
+        ] {
+            assert!(
+                matches!(
+                    decode_frame(&text),
+                    Err(OcppError::MalformedFrameError { .. })
+                ),
+                "Expected {text} to be malformed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_call_message_id() {
+        assert_eq!(
+            call_message_id(r#"[2, "9", "NoSuchAction", {}]"#),
+            Some("9".to_string())
+        );
+        assert_eq!(
+            call_message_id(r#"[2, "9", "GetLog", "oops"]"#),
+            Some("9".to_string())
+        );
+        assert_eq!(call_message_id(r#"[3, "9", {}]"#), None);
+        assert_eq!(call_message_id(r#"[2, "", "ClearCache", {}]"#), None);
+        assert_eq!(call_message_id("not json"), None);
+    }
+
+    const BUGGY_BOOT: &str = r#"[2, "10", "BootNotification", {
+        "reason": "PowerUp",
+        "chargingStation": {"model": "SingleSocketChargerModelX", "vendorName": "V", "firmwareBuild": 7},
+        "customData": {"vendorId": "com.example", "bootCount": 3},
+        "debug": null,
+        "uptime": 3600
+    }]"#;
+
+    fn schema_violations(error: &OcppError) -> Vec<(String, String)> {
+        match error {
+            OcppError::SchemaValidationError { related, .. } => related
+                .iter()
+                .map(|e| match e {
+                    OcppError::SchemaViolationError {
+                        pointer, keyword, ..
+                    } => (pointer.clone(), keyword.clone()),
+                    other => panic!("Expected a SchemaViolationError. Got {other:?} instead."),
+                })
+                .collect(),
+            other => panic!("Expected a SchemaValidationError. Got {other:?} instead."),
+        }
+    }
+
+    #[test]
+    fn test_decode_call_with_strict_profile() {
+        let error = decode_call_with_profile(BUGGY_BOOT, DecodingProfile::Strict).unwrap_err();
+        assert_eq!(
+            schema_violations(&error),
+            vec![
+                ("/debug".to_string(), "additionalProperties".to_string()),
+                ("/uptime".to_string(), "additionalProperties".to_string()),
+                (
+                    "/chargingStation/firmwareBuild".to_string(),
+                    "additionalProperties".to_string()
+                ),
+            ]
+        );
+        assert_eq!(RpcErrorCode::from(&error), RpcErrorCode::FormatViolation);
+
+        let mut frame: Value = serde_json::from_str(BUGGY_BOOT).unwrap();
+        frame[3].as_object_mut().unwrap().remove("debug");
+        frame[3].as_object_mut().unwrap().remove("uptime");
+        frame[3]["chargingStation"]
+            .as_object_mut()
+            .unwrap()
+            .remove("firmwareBuild");
+        let error = decode_call_with_profile(&frame.to_string(), DecodingProfile::Strict);
+        assert!(matches!(
+            error,
+            Err(OcppError::StructureValidationError { structure, .. })
+                if structure == "BootNotificationRequest"
+        ));
+
+        let call = decode_call_with_profile(
+            r#"[2, "11", "GetLog", {"logType": "SecurityLog", "requestId": 7, "log": {"remoteLocation": "ftp://example.com"}}]"#,
+            DecodingProfile::Strict,
+        )
+        .unwrap();
+        assert_eq!(call.value.action(), "GetLog");
+        assert!(call.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_strict_profile_accepts_skipped_and_defaulted_fields() {
+        // `messageExtra` is not serialized when empty and defaults to empty when absent, so the
+        // decoded message serializes differently from either payload. Both are valid.
+        for message_extra in [r#", "messageExtra": []"#, ""] {
+            let frame = format!(
+                r#"[2, "14", "SetDisplayMessage", {{"message": {{"id": 1, "priority": "InFront", "message": {{"format": "UTF8", "content": "Welcome"}}{message_extra}}}}}]"#
+            );
+            let call = decode_call_with_profile(&frame, DecodingProfile::Strict).unwrap();
+            assert_eq!(call.value.action(), "SetDisplayMessage");
+            assert!(call.warnings.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_decode_call_with_lenient_profile() {
+        let call = decode_call_with_profile(BUGGY_BOOT, DecodingProfile::Lenient).unwrap();
+        assert_eq!(call.value.message_id, "10");
+        assert_eq!(call.value.action(), "BootNotification");
+
+        let [debug, uptime, firmware_build, model] = call.warnings.as_slice() else {
+            panic!("Expected 4 warnings. Got {:?} instead.", call.warnings);
+        };
+        assert!(matches!(
+            debug,
+            OcppError::SchemaViolationError { pointer, .. } if pointer == "/debug"
+        ));
+        assert!(matches!(
+            uptime,
+            OcppError::SchemaViolationError { pointer, .. } if pointer == "/uptime"
+        ));
+        assert!(matches!(
+            firmware_build,
+            OcppError::SchemaViolationError { pointer, .. } if pointer == "/chargingStation/firmwareBuild"
+        ));
+        match model {
+            OcppError::FieldValidationError { field, related } => {
+                assert_eq!(field, "charging_station.model");
+                assert!(matches!(
+                    related.as_slice(),
+                    [OcppError::FieldCardinalityError {
+                        cardinality: 25,
+                        ..
+                    }]
+                ));
+            }
+            other => panic!("Expected a FieldValidationError. Got {other:?} instead."),
+        }
+    }
+
+    #[test]
+    fn test_decode_frame_with_profile() {
+        let frame = decode_frame_with_profile(BUGGY_BOOT, DecodingProfile::Lenient).unwrap();
+        assert!(matches!(frame.value, IncomingFrame::Call(_)));
+        assert_eq!(frame.warnings.len(), 4);
+
+        let frame = decode_frame_with_profile(
+            r#"[6, "12", "NotifyPeriodicEventStream", {"id": -1, "pending": 0, "basetime": "2025-01-01T00:00:00Z", "data": [{"t": 0.0, "v": "1", "unit": "W"}]}]"#,
+            DecodingProfile::Lenient,
+        )
+        .unwrap();
+        assert!(matches!(frame.value, IncomingFrame::Send(_)));
+        assert_eq!(frame.warnings.len(), 2);
+
+        // CALLRESULT payloads are only checked once matched with their CALL.
+        let frame =
+            decode_frame_with_profile(r#"[3, "13", {"unknown": true}]"#, DecodingProfile::Strict)
+                .unwrap();
+        assert!(matches!(frame.value, IncomingFrame::CallResult(_)));
+        assert!(frame.warnings.is_empty());
+
+        // Unchecked decoding is unchanged.
+        assert!(decode_frame(BUGGY_BOOT).is_ok());
+    }
+
+    #[test]
+    fn test_call_request_links_response() {
+        use crate::messages::clear_cache::ClearCacheRequest;
+
+        assert_eq!(<GetLogRequest as CallRequest>::ACTION, "GetLog");
+        let response = ResponseMessage::from(ClearCacheResponse::default());
+        let response = <ClearCacheRequest as CallRequest>::Response::try_from(response);
+        assert!(response.is_ok());
+    }
+}
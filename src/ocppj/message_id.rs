@@ -0,0 +1,121 @@
+use crate::errors::{OcppError, validate_string_length};
+use std::sync::atomic::{AtomicU64, Ordering};
+use uuid::Uuid;
+
+/// The maximum length of an OCPP-J message id, chosen by the spec to fit a GUID.
+pub const MAX_MESSAGE_ID_LENGTH: usize = 36;
+
+/// Check that a message id is 1 to 36 characters long and only consists of visible ASCII
+/// characters.
+pub fn validate_message_id(message_id: &str) -> Result<(), OcppError> {
+    validate_string_length(message_id, 1, MAX_MESSAGE_ID_LENGTH)
+        .map_err(|e| e.to_field_validation_error("messageId"))?;
+
+    if !message_id.chars().all(|c| c.is_ascii_graphic()) {
+        return Err(OcppError::FieldValueError {
+            value: message_id.to_string(),
+        }
+        .to_field_validation_error("messageId"));
+    }
+
+    Ok(())
+}
+
+/// A source of message ids for outgoing CALL and SEND frames. Every id a generator yields must be
+/// valid and unique for the lifetime of the sender.
+pub trait MessageIdGenerator: Send + Sync {
+    fn next_id(&self) -> String;
+}
+
+/// Generates random UUIDv4 message ids, e.g. `"9f3c5b0a-3c5e-4f7a-9d5b-2f0c1e7b6a41"`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UuidMessageIdGenerator;
+
+impl MessageIdGenerator for UuidMessageIdGenerator {
+    fn next_id(&self) -> String {
+        Uuid::new_v4().to_string()
+    }
+}
+
+/// Generates monotonically increasing message ids of the form `"<prefix>-<counter>"`, e.g.
+/// `"CS001-42"`. The prefix is typically the identity of the Charging Station.
+#[derive(Debug)]
+pub struct CounterMessageIdGenerator {
+    prefix: String,
+    counter: AtomicU64,
+}
+
+impl CounterMessageIdGenerator {
+    /// The longest prefix that still leaves room for the separator and any `u64` counter value.
+    pub const MAX_PREFIX_LENGTH: usize = MAX_MESSAGE_ID_LENGTH - 21;
+
+    /// Create a generator whose first id is `"<prefix>-1"`.
+    pub fn new(prefix: &str) -> Result<Self, OcppError> {
+        Self::starting_at(prefix, 1)
+    }
+
+    /// Create a generator whose first id is `"<prefix>-<start>"`, e.g. to continue counting after
+    /// a restart.
+    pub fn starting_at(prefix: &str, start: u64) -> Result<Self, OcppError> {
+        validate_string_length(prefix, 1, Self::MAX_PREFIX_LENGTH)
+            .map_err(|e| e.to_field_validation_error("prefix"))?;
+        validate_message_id(prefix).map_err(|e| e.to_field_validation_error("prefix"))?;
+
+        Ok(Self {
+            prefix: prefix.to_string(),
+            counter: AtomicU64::new(start),
+        })
+    }
+}
+
+impl MessageIdGenerator for CounterMessageIdGenerator {
+    fn next_id(&self) -> String {
+        let count = self.counter.fetch_add(1, Ordering::Relaxed);
+        format!("{}-{count}", self.prefix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_validate_message_id() {
+        assert!(validate_message_id("19223201").is_ok());
+        assert!(validate_message_id(&"a".repeat(36)).is_ok());
+        assert!(validate_message_id("").is_err());
+        assert!(validate_message_id(&"a".repeat(37)).is_err());
+        assert!(validate_message_id("with space").is_err());
+        assert!(validate_message_id("naïve").is_err());
+        assert!(validate_message_id("tab\t").is_err());
+    }
+
+    #[test]
+    fn test_uuid_generator() {
+        let generator = UuidMessageIdGenerator;
+        let ids: HashSet<String> = (0..100).map(|_| generator.next_id()).collect();
+        assert_eq!(ids.len(), 100);
+        for id in ids {
+            assert!(validate_message_id(&id).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_counter_generator() {
+        let generator = CounterMessageIdGenerator::new("CS001").unwrap();
+        assert_eq!(generator.next_id(), "CS001-1");
+        assert_eq!(generator.next_id(), "CS001-2");
+
+        let generator = CounterMessageIdGenerator::starting_at("CS001", u64::MAX).unwrap();
+        let id = generator.next_id();
+        assert!(validate_message_id(&id).is_ok());
+    }
+
+    #[test]
+    fn test_counter_generator_invalid_prefix() {
+        assert!(CounterMessageIdGenerator::new("").is_err());
+        assert!(CounterMessageIdGenerator::new("a-prefix-that-is-too-long").is_err());
+        assert!(CounterMessageIdGenerator::new("CS 001").is_err());
+    }
+}
@@ -0,0 +1,342 @@
+use crate::errors::OcppError;
+use crate::ocppj::decoder::{Decoded, DecodingProfile, RawCallResult, ResponseMessage};
+use crate::ocppj::{RcpCall, RcpCallError};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// A CALL that has been sent and is awaiting its CALLRESULT or CALLERROR.
+#[derive(Debug, Clone)]
+struct PendingCall {
+    action: String,
+    deadline: Instant,
+}
+
+/// A CALL that has been answered with a CALLRESULT, with its payload decoded into the response
+/// type of the CALL's action.
+#[derive(Debug, Clone)]
+pub struct CompletedCall {
+    pub message_id: String,
+    pub action: String,
+    pub response: ResponseMessage,
+}
+
+/// A registry of outgoing CALLs keyed by message id. A CALLRESULT does not carry its action on the
+/// wire, so the registry remembers the action of every CALL it is given and uses it to decode the
+/// matching CALLRESULT.
+#[derive(Debug, Clone)]
+pub struct PendingCalls {
+    calls: HashMap<String, PendingCall>,
+    timeout: Duration,
+}
+
+impl Default for PendingCalls {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(30))
+    }
+}
+
+impl PendingCalls {
+    /// Create a registry that expires CALLs after the given timeout.
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            calls: HashMap::new(),
+            timeout,
+        }
+    }
+
+    /// The number of CALLs awaiting an answer.
+    pub fn len(&self) -> usize {
+        self.calls.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.calls.is_empty()
+    }
+
+    /// Whether a CALL with the given message id is awaiting an answer.
+    pub fn contains(&self, message_id: &str) -> bool {
+        self.calls.contains_key(message_id)
+    }
+
+    /// Register an outgoing CALL using the registry's default timeout.
+    pub fn register(&mut self, call: &RcpCall) -> Result<(), OcppError> {
+        self.register_with_timeout(call, self.timeout)
+    }
+
+    /// Register an outgoing CALL that expires after `timeout`. Fails if a CALL with the same
+    /// message id is already pending.
+    pub fn register_with_timeout(
+        &mut self,
+        call: &RcpCall,
+        timeout: Duration,
+    ) -> Result<(), OcppError> {
+        if self.calls.contains_key(&call.message_id) {
+            return Err(OcppError::DuplicateMessageIdError {
+                message_id: call.message_id.clone(),
+            });
+        }
+
+        self.calls.insert(
+            call.message_id.clone(),
+            PendingCall {
+                action: call.action.clone(),
+                deadline: Instant::now() + timeout,
+            },
+        );
+        Ok(())
+    }
+
+    /// Remove a pending CALL, reporting it as unmatched if it is unknown and as timed out if its
+    /// deadline has passed.
+    fn take(&mut self, message_id: &str) -> Result<PendingCall, OcppError> {
+        let call =
+            self.calls
+                .remove(message_id)
+                .ok_or_else(|| OcppError::UnmatchedMessageIdError {
+                    message_id: message_id.to_string(),
+                })?;
+
+        if Instant::now() > call.deadline {
+            return Err(OcppError::CallTimeoutError {
+                message_id: message_id.to_string(),
+                action: call.action,
+            });
+        }
+
+        Ok(call)
+    }
+
+    /// Forget a pending CALL, e.g. because it could not be sent. Returns whether it was pending.
+    pub fn cancel(&mut self, message_id: &str) -> bool {
+        self.calls.remove(message_id).is_some()
+    }
+
+    /// Match a CALLRESULT with its pending CALL and decode its payload into the CALL's response
+    /// type.
+    pub fn resolve_result(&mut self, result: RawCallResult) -> Result<CompletedCall, OcppError> {
+        let call = self.take(&result.message_id)?;
+        let response = ResponseMessage::from_payload(&call.action, result.payload)?;
+
+        Ok(CompletedCall {
+            message_id: result.message_id,
+            action: call.action,
+            response,
+        })
+    }
+
+    /// Match a CALLRESULT with its pending CALL like `resolve_result`, then check its payload as
+    /// per `profile`.
+    pub fn resolve_result_with_profile(
+        &mut self,
+        result: RawCallResult,
+        profile: DecodingProfile,
+    ) -> Result<Decoded<CompletedCall>, OcppError> {
+        let call = self.take(&result.message_id)?;
+        let response =
+            ResponseMessage::from_payload_with_profile(&call.action, result.payload, profile)?;
+
+        Ok(response.map(|response| CompletedCall {
+            message_id: result.message_id,
+            action: call.action,
+            response,
+        }))
+    }
+
+    /// Match a CALLERROR with its pending CALL. The CALL is removed from the registry and the
+    /// error is surfaced as an `OcppError::CallErrorReceived`.
+    pub fn resolve_error(&mut self, error: RcpCallError) -> OcppError {
+        match self.take(&error.message_id) {
+            Ok(call) => OcppError::CallErrorReceived {
+                message_id: error.message_id,
+                action: call.action,
+                error_code: error.error_code,
+                error_description: error.error_description,
+                error_details: error.error_details,
+            },
+            Err(e) => e,
+        }
+    }
+
+    /// Remove every CALL whose deadline has passed by `now`, returning a
+    /// `OcppError::CallTimeoutError` for each.
+    pub fn expire(&mut self, now: Instant) -> Vec<OcppError> {
+        let expired: Vec<String> = self
+            .calls
+            .iter()
+            .filter(|(_, call)| now > call.deadline)
+            .map(|(message_id, _)| message_id.clone())
+            .collect();
+
+        expired
+            .into_iter()
+            .filter_map(|message_id| {
+                self.calls
+                    .remove(&message_id)
+                    .map(|call| OcppError::CallTimeoutError {
+                        message_id,
+                        action: call.action,
+                    })
+            })
+            .collect()
+    }
+
+    /// The earliest deadline of all pending CALLs, if any.
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.calls.values().map(|call| call.deadline).min()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::registration_status_enum_type::RegistrationStatusEnumType;
+    use crate::messages::boot_notification::{BootNotificationRequest, BootNotificationResponse};
+    use crate::messages::clear_cache::ClearCacheRequest;
+    use crate::ocppj::RpcErrorCode;
+    use crate::ocppj::decoder::{IncomingFrame, decode_frame};
+    use serde_json::json;
+
+    fn boot_call(message_id: &str) -> RcpCall {
+        RcpCall::new(message_id, Box::new(BootNotificationRequest::default())).unwrap()
+    }
+
+    fn raw_result(text: &str) -> RawCallResult {
+        match decode_frame(text).unwrap() {
+            IncomingFrame::CallResult(result) => result,
+            other => panic!("Expected a CALLRESULT. Got {other:?} instead."),
+        }
+    }
+
+    #[test]
+    fn test_resolve_result() {
+        let mut pending = PendingCalls::default();
+        pending.register(&boot_call("1")).unwrap();
+        assert!(pending.contains("1"));
+
+        let result = raw_result(
+            r#"[3, "1", {"currentTime": "2025-01-01T00:00:00Z", "interval": 300, "status": "Accepted"}]"#,
+        );
+        let completed = pending.resolve_result(result).unwrap();
+        assert_eq!(completed.action, "BootNotification");
+        assert!(pending.is_empty());
+
+        let response = BootNotificationResponse::try_from(completed.response).unwrap();
+        assert_eq!(response.interval, 300);
+        assert_eq!(response.status, RegistrationStatusEnumType::Accepted);
+    }
+
+    #[test]
+    fn test_resolve_result_payload_error() {
+        let mut pending = PendingCalls::default();
+        pending.register(&boot_call("1")).unwrap();
+
+        let result = pending.resolve_result(raw_result(r#"[3, "1", {"interval": "soon"}]"#));
+        assert!(matches!(
+            result,
+            Err(OcppError::PayloadDeserializationError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_resolve_result_with_profile() {
+        let text = r#"[3, "1", {"currentTime": "2025-01-01T00:00:00Z", "interval": 300, "status": "Accepted", "statusInfo": {"reasonCode": "ThisReasonCodeIsTooLong"}}]"#;
+
+        let mut pending = PendingCalls::default();
+        pending.register(&boot_call("1")).unwrap();
+        let result = pending.resolve_result_with_profile(raw_result(text), DecodingProfile::Strict);
+        assert!(matches!(
+            result,
+            Err(OcppError::StructureValidationError { .. })
+        ));
+
+        pending.register(&boot_call("1")).unwrap();
+        let completed = pending
+            .resolve_result_with_profile(raw_result(text), DecodingProfile::Lenient)
+            .unwrap();
+        assert_eq!(completed.value.action, "BootNotification");
+        assert!(matches!(
+            completed.warnings.as_slice(),
+            [OcppError::FieldValidationError { field, .. }] if field == "status_info.reason_code"
+        ));
+    }
+
+    #[test]
+    fn test_resolve_unmatched() {
+        let mut pending = PendingCalls::default();
+        let result = pending.resolve_result(raw_result(r#"[3, "404", {}]"#));
+        assert!(matches!(
+            result,
+            Err(OcppError::UnmatchedMessageIdError { message_id }) if message_id == "404"
+        ));
+    }
+
+    #[test]
+    fn test_register_duplicate() {
+        let mut pending = PendingCalls::default();
+        pending.register(&boot_call("1")).unwrap();
+        let duplicate = RcpCall::new("1", Box::new(ClearCacheRequest::default())).unwrap();
+        assert!(matches!(
+            pending.register(&duplicate),
+            Err(OcppError::DuplicateMessageIdError { .. })
+        ));
+        assert_eq!(pending.len(), 1);
+    }
+
+    #[test]
+    fn test_cancel() {
+        let mut pending = PendingCalls::default();
+        pending.register(&boot_call("1")).unwrap();
+        assert!(pending.cancel("1"));
+        assert!(!pending.cancel("1"));
+        assert!(pending.is_empty());
+    }
+
+    #[test]
+    fn test_resolve_error() {
+        let mut pending = PendingCalls::default();
+        pending.register(&boot_call("1")).unwrap();
+
+        let error = RcpCallError::new("1", RpcErrorCode::NotSupported, "Nope", json!({}));
+        match pending.resolve_error(error) {
+            OcppError::CallErrorReceived {
+                action, error_code, ..
+            } => {
+                assert_eq!(action, "BootNotification");
+                assert_eq!(error_code, RpcErrorCode::NotSupported);
+            }
+            other => panic!("Expected a CallErrorReceived. Got {other} instead."),
+        }
+        assert!(pending.is_empty());
+    }
+
+    #[test]
+    fn test_timeout() {
+        let mut pending = PendingCalls::default();
+        pending
+            .register_with_timeout(&boot_call("1"), Duration::ZERO)
+            .unwrap();
+        pending
+            .register_with_timeout(&boot_call("2"), Duration::from_secs(60))
+            .unwrap();
+
+        let expired = pending.expire(Instant::now() + Duration::from_millis(1));
+        assert_eq!(expired.len(), 1);
+        assert!(matches!(
+            &expired[0],
+            OcppError::CallTimeoutError { message_id, .. } if message_id == "1"
+        ));
+        assert!(pending.contains("2"));
+    }
+
+    #[test]
+    fn test_resolve_after_deadline() {
+        let mut pending = PendingCalls::default();
+        pending
+            .register_with_timeout(&boot_call("1"), Duration::ZERO)
+            .unwrap();
+        std::thread::sleep(Duration::from_millis(1));
+
+        let result = pending.resolve_result(raw_result(r#"[3, "1", {}]"#));
+        assert!(matches!(result, Err(OcppError::CallTimeoutError { .. })));
+    }
+}
@@ -0,0 +1,254 @@
+//! Deserialization from a `serde_json::Value` that reports the object properties the target type
+//! does not declare, instead of silently ignoring them.
+
+use crate::errors::OcppError;
+use crate::schema::validator::child;
+use serde::de::value::StringDeserializer;
+use serde::de::{DeserializeOwned, DeserializeSeed, MapAccess, SeqAccess, Visitor};
+use serde::{Deserializer, forward_to_deserialize_any};
+use serde_json::{Map, Value};
+use std::cell::RefCell;
+
+/// Deserialize `value` into `T`, also returning a `SchemaViolationError` for every property of
+/// `value` that is not a field of the structure it is deserialized into. The field names are the
+/// ones the type declares to serde, so optional and defaulted fields are never reported, whatever
+/// their value.
+pub(crate) fn from_value<T: DeserializeOwned>(
+    value: Value,
+) -> Result<(T, Vec<OcppError>), serde_json::Error> {
+    let unknown = RefCell::new(vec![]);
+    let decoded = T::deserialize(Tracked {
+        value,
+        pointer: String::new(),
+        unknown: &unknown,
+    })?;
+
+    Ok((decoded, unknown.into_inner()))
+}
+
+/// A value at `pointer` whose unknown properties are pushed to `unknown` as it is deserialized.
+struct Tracked<'a> {
+    value: Value,
+    pointer: String,
+    unknown: &'a RefCell<Vec<OcppError>>,
+}
+
+impl<'de> Deserializer<'de> for Tracked<'_> {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.value {
+            Value::Object(map) => visitor.visit_map(TrackedMap {
+                entries: map.into_iter(),
+                next: None,
+                pointer: self.pointer,
+                unknown: self.unknown,
+            }),
+            Value::Array(items) => visitor.visit_seq(TrackedSeq {
+                items: items.into_iter().enumerate(),
+                pointer: self.pointer,
+                unknown: self.unknown,
+            }),
+            other => other.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.value {
+            Value::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        if let Value::Object(map) = &self.value {
+            let mut unknown = self.unknown.borrow_mut();
+            for name in map.keys().filter(|name| !fields.contains(&name.as_str())) {
+                unknown.push(OcppError::SchemaViolationError {
+                    pointer: child(&self.pointer, name),
+                    keyword: "additionalProperties".to_string(),
+                    reason: format!("unknown property `{name}`"),
+                });
+            }
+        }
+
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.value.deserialize_enum(name, variants, visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf unit
+        unit_struct seq tuple tuple_struct map identifier
+    }
+}
+
+struct TrackedMap<'a> {
+    entries: <Map<String, Value> as IntoIterator>::IntoIter,
+    next: Option<(String, Value)>,
+    pointer: String,
+    unknown: &'a RefCell<Vec<OcppError>>,
+}
+
+impl<'de> MapAccess<'de> for TrackedMap<'_> {
+    type Error = serde_json::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        let Some((name, value)) = self.entries.next() else {
+            return Ok(None);
+        };
+        let key = seed.deserialize(StringDeserializer::new(name.clone()))?;
+        self.next = Some((name, value));
+
+        Ok(Some(key))
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        let (name, value) = self
+            .next
+            .take()
+            .expect("next_value_seed is called after next_key_seed");
+        seed.deserialize(Tracked {
+            value,
+            pointer: child(&self.pointer, &name),
+            unknown: self.unknown,
+        })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.entries.len())
+    }
+}
+
+struct TrackedSeq<'a> {
+    items: std::iter::Enumerate<std::vec::IntoIter<Value>>,
+    pointer: String,
+    unknown: &'a RefCell<Vec<OcppError>>,
+}
+
+impl<'de> SeqAccess<'de> for TrackedSeq<'_> {
+    type Error = serde_json::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        self.items
+            .next()
+            .map(|(i, value)| {
+                seed.deserialize(Tracked {
+                    value,
+                    pointer: format!("{}/{i}", self.pointer),
+                    unknown: self.unknown,
+                })
+            })
+            .transpose()
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.items.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::boot_notification::BootNotificationRequest;
+    use crate::messages::set_display_message::SetDisplayMessageRequest;
+    use serde_json::json;
+
+    fn unknown_pointers(errors: &[OcppError]) -> Vec<&str> {
+        errors
+            .iter()
+            .map(|e| match e {
+                OcppError::SchemaViolationError { pointer, .. } => pointer.as_str(),
+                other => panic!("expected a SchemaViolationError, got {other:?}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_unknown_properties_are_reported_with_their_pointer() {
+        let (request, unknown) = from_value::<BootNotificationRequest>(json!({
+            "reason": "PowerUp",
+            "chargingStation": {"model": "M", "vendorName": "V", "firmwareBuild": "1"},
+            "uptime": 12,
+        }))
+        .unwrap();
+        assert_eq!(request.charging_station.model, "M");
+        assert_eq!(
+            unknown_pointers(&unknown),
+            ["/uptime", "/chargingStation/firmwareBuild"]
+        );
+    }
+
+    #[test]
+    fn test_declared_fields_are_not_reported() {
+        // `messageExtra` is skipped when serialized empty and defaulted when absent; `display` is
+        // sent as an explicit null. None of them is unknown.
+        let (request, unknown) = from_value::<SetDisplayMessageRequest>(json!({
+            "message": {
+                "id": 1,
+                "priority": "AlwaysFront",
+                "message": {"format": "ASCII", "content": "Hi"},
+                "messageExtra": [],
+                "display": null,
+            },
+        }))
+        .unwrap();
+        assert!(request.message.message_extra.is_empty());
+        assert!(unknown.is_empty(), "{unknown:?}");
+
+        let (_, unknown) = from_value::<SetDisplayMessageRequest>(json!({
+            "message": {
+                "id": 1,
+                "priority": "AlwaysFront",
+                "message": {"format": "ASCII", "content": "Hi"},
+            },
+        }))
+        .unwrap();
+        assert!(unknown.is_empty(), "{unknown:?}");
+    }
+
+    #[test]
+    fn test_custom_data_accepts_vendor_properties() {
+        let (request, unknown) = from_value::<BootNotificationRequest>(json!({
+            "reason": "PowerUp",
+            "chargingStation": {"model": "M", "vendorName": "V"},
+            "customData": {"vendorId": "com.example", "firmwareBuild": "1"},
+        }))
+        .unwrap();
+        assert!(request.custom_data.is_some());
+        assert!(unknown.is_empty(), "{unknown:?}");
+    }
+}
@@ -0,0 +1,500 @@
+//! Validation of raw OCPP-J payloads against the OCPP 2.1 JSON schemas.
+//!
+//! The schemas are embedded at compile time from the `schemas/` directory, one file per payload
+//! named as the published schema set names them (e.g. `BootNotificationRequest.json`): a request
+//! and a response for every CALL action, and a request for every SEND action.
+//! Use [`validate`] on a payload before it is deserialized to find out whether it conforms to the
+//! wire format, independently of the checks done by `OcppEntity::validate`.
+
+pub(crate) mod validator;
+
+use crate::errors::OcppError;
+use crate::schema::validator::Validator;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// Which payload of an action a schema describes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// The payload of a CALL or SEND.
+    Request,
+    /// The payload of a CALLRESULT.
+    Response,
+}
+
+impl Direction {
+    fn suffix(self) -> &'static str {
+        match self {
+            Direction::Request => "Request",
+            Direction::Response => "Response",
+        }
+    }
+}
+
+/// The source of every schema in `schemas/`, named after the payload it describes (e.g.
+/// `BootNotificationRequest`). The table is generated by `build.rs`, so vendoring a schema only
+/// takes copying its file from the published OCPP 2.1 schema set.
+const SOURCES: &[(&str, &str)] = include!(concat!(env!("OUT_DIR"), "/schemas.rs"));
+
+/// The embedded schemas, parsed on first use.
+fn schemas() -> &'static HashMap<&'static str, Value> {
+    static SCHEMAS: OnceLock<HashMap<&'static str, Value>> = OnceLock::new();
+    SCHEMAS.get_or_init(|| {
+        SOURCES
+            .iter()
+            .map(|(name, source)| {
+                let schema = serde_json::from_str(source)
+                    .unwrap_or_else(|e| panic!("embedded schema {name} is invalid: {e}"));
+                (*name, schema)
+            })
+            .collect()
+    })
+}
+
+/// Get the embedded JSON schema for the payload of an action, if there is one.
+pub fn schema(action: &str, direction: Direction) -> Option<&'static Value> {
+    schemas().get(format!("{action}{}", direction.suffix()).as_str())
+}
+
+/// Validate a payload against the JSON schema of the given action and direction.
+///
+/// Every violation is reported as a `SchemaViolationError` carrying the JSON pointer of the
+/// offending value, collected in a `SchemaValidationError` named after the schema. Actions without
+/// an embedded schema yield a `MissingSchemaError`.
+pub fn validate(action: &str, direction: Direction, payload: &Value) -> Result<(), OcppError> {
+    let name = format!("{action}{}", direction.suffix());
+    let Some(schema) = schema(action, direction) else {
+        return Err(OcppError::MissingSchemaError { schema: name });
+    };
+
+    let related = Validator::validate(schema, payload);
+    if related.is_empty() {
+        Ok(())
+    } else {
+        Err(OcppError::SchemaValidationError {
+            schema: name,
+            related,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::{for_each_action, for_each_unconfirmed_action};
+    use crate::traits::{OcppEntity, OcppMessage, OcppUnconfirmedMessage};
+    use serde::de::DeserializeOwned;
+    use serde_json::json;
+
+    macro_rules! call_payloads {
+        ($($module:ident::$message:ident),* $(,)?) => {
+            [$(
+                concat!(stringify!($message), "Request"),
+                concat!(stringify!($message), "Response"),
+            )*]
+        };
+    }
+
+    macro_rules! send_payloads {
+        ($($module:ident::$message:ident),* $(,)?) => {
+            [$(concat!(stringify!($message), "Request")),*]
+        };
+    }
+
+    /// The name of every payload of every action, which is also the name of its schema.
+    fn payloads() -> Vec<&'static str> {
+        let mut payloads = for_each_action!(call_payloads).to_vec();
+        payloads.extend(for_each_unconfirmed_action!(send_payloads));
+        payloads
+    }
+
+    #[test]
+    fn test_embedded_schemas_are_named_after_their_payload() {
+        for (name, _) in SOURCES {
+            let id = schemas()[name]["$id"].as_str().unwrap();
+            assert!(
+                id.ends_with(&format!(":{name}")),
+                "{id} is embedded as {name}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_missing_schema() {
+        assert!(schema("NotAnAction", Direction::Request).is_none());
+        assert!(schema("NotifyPeriodicEventStream", Direction::Response).is_none());
+        assert!(matches!(
+            validate("NotAnAction", Direction::Request, &json!({})),
+            Err(OcppError::MissingSchemaError { schema }) if schema == "NotAnActionRequest"
+        ));
+    }
+
+    #[test]
+    fn test_every_schema_file_is_embedded() {
+        let payloads = payloads();
+        let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("schemas");
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let file = entry.unwrap().file_name().into_string().unwrap();
+            let name = file
+                .strip_suffix(".json")
+                .unwrap_or_else(|| panic!("{file} is not a JSON schema"));
+            assert!(schemas().contains_key(name), "{file} is not embedded");
+            assert!(
+                payloads.contains(&name),
+                "{file} is not named after the payload of an action"
+            );
+        }
+    }
+
+    #[test]
+    #[ignore = "only part of the OCPP 2.1 schema set is vendored in schemas/ so far"]
+    fn test_every_payload_has_a_schema() {
+        let missing: Vec<_> = payloads()
+            .into_iter()
+            .filter(|name| !schemas().contains_key(name))
+            .collect();
+        assert!(missing.is_empty(), "no schema for {}", missing.join(", "));
+    }
+
+    #[test]
+    fn test_violations_carry_json_pointers() {
+        let payload = json!({
+            "reason": "Restarted",
+            "chargingStation": {"model": "a".repeat(21)},
+            "firmware": "1.0",
+        });
+
+        let Err(OcppError::SchemaValidationError { schema, related }) =
+            validate("BootNotification", Direction::Request, &payload)
+        else {
+            panic!("expected a SchemaValidationError");
+        };
+        assert_eq!(schema, "BootNotificationRequest");
+
+        let mut violations: Vec<(String, String)> = related
+            .into_iter()
+            .map(|e| match e {
+                OcppError::SchemaViolationError {
+                    pointer, keyword, ..
+                } => (pointer, keyword),
+                other => panic!("unexpected error {other:?}"),
+            })
+            .collect();
+        violations.sort();
+        assert_eq!(
+            violations,
+            [
+                ("/chargingStation/model", "maxLength"),
+                ("/chargingStation/vendorName", "required"),
+                ("/firmware", "additionalProperties"),
+                ("/reason", "enum"),
+            ]
+            .map(|(p, k)| (p.to_string(), k.to_string()))
+        );
+    }
+
+    /// Collect the pointer of every property `schema` declares that `value` leaves out, so that an
+    /// example payload exercises the whole schema. `customData` is only checked at the top level.
+    fn uncovered(
+        root: &Value,
+        schema: &Value,
+        value: &Value,
+        pointer: &str,
+        out: &mut Vec<String>,
+    ) {
+        let schema = resolved(root, schema);
+        match value {
+            Value::Object(map) => {
+                for (name, property) in schema["properties"].as_object().into_iter().flatten() {
+                    match map.get(name) {
+                        Some(child) => {
+                            uncovered(root, property, child, &format!("{pointer}/{name}"), out)
+                        }
+                        None if name == "customData" && !pointer.is_empty() => {}
+                        None => out.push(format!("{pointer}/{name}")),
+                    }
+                }
+            }
+            Value::Array(items) => {
+                for (i, item) in items.iter().enumerate() {
+                    uncovered(root, &schema["items"], item, &format!("{pointer}/{i}"), out);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    #[test]
+    fn test_example_payloads_conform_to_schemas() {
+        let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/payloads");
+        let mut found = vec![];
+        for name in payloads() {
+            let Some(root) = schemas().get(name) else {
+                continue;
+            };
+            let example: Value = serde_json::from_str(
+                &std::fs::read_to_string(dir.join(format!("{name}.json"))).unwrap(),
+            )
+            .unwrap();
+
+            let related = Validator::validate(root, &example);
+            found.extend(related.iter().map(|e| format!("{name}: {e:?}")));
+            let mut missing = vec![];
+            uncovered(root, root, &example, "", &mut missing);
+            found.extend(
+                missing
+                    .iter()
+                    .map(|pointer| format!("{name}: no example of {pointer}")),
+            );
+        }
+        assert!(found.is_empty(), "{}", found.join("\n"));
+    }
+
+    #[test]
+    fn test_example_structures_conform_to_schemas() {
+        let dir =
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/payloads/structures");
+        let mut checked = 0;
+        let mut found = vec![];
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            let name = path.file_stem().unwrap().to_str().unwrap();
+            let Some(root) = schemas()
+                .values()
+                .find(|schema| schema["definitions"].get(name).is_some())
+            else {
+                continue;
+            };
+            let schema = json!({
+                "definitions": root["definitions"],
+                "$ref": format!("#/definitions/{name}"),
+            });
+            let example: Value =
+                serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+
+            checked += 1;
+            let related = Validator::validate(&schema, &example);
+            found.extend(related.iter().map(|e| format!("{name}: {e:?}")));
+        }
+        assert!(checked > 0, "no structure is defined by a vendored schema");
+        assert!(found.is_empty(), "{}", found.join("\n"));
+    }
+
+    /// A copy of a sample with one value changed or removed, and a description of the change.
+    struct Mutation {
+        description: String,
+        payload: Value,
+    }
+
+    /// Follow `$ref` until a schema that is not a reference is found.
+    fn resolved<'a>(root: &'a Value, schema: &'a Value) -> &'a Value {
+        match schema["$ref"].as_str() {
+            Some(reference) => resolved(root, root.pointer(&reference[1..]).unwrap()),
+            None => schema,
+        }
+    }
+
+    fn mutated(sample: &Value, pointer: &str, value: Option<Value>) -> Value {
+        let mut payload = sample.clone();
+        let (parent, name) = pointer.rsplit_once('/').unwrap();
+        let parent = payload.pointer_mut(parent).unwrap();
+        match (parent, value) {
+            (Value::Object(map), Some(value)) => {
+                map.insert(name.to_string(), value);
+            }
+            (Value::Object(map), None) => {
+                map.remove(name);
+            }
+            (Value::Array(items), Some(value)) => items[name.parse::<usize>().unwrap()] = value,
+            _ => unreachable!(),
+        }
+        payload
+    }
+
+    /// Generate samples that each break one constraint the schema puts on a value present in
+    /// `sample`: a too long string, an out of range number, an unknown enum value, an item count
+    /// out of range, a wrong type, a malformed date-time or a missing required property.
+    fn mutations(
+        root: &Value,
+        schema: &Value,
+        sample: &Value,
+        value: &Value,
+        pointer: &str,
+    ) -> Vec<Mutation> {
+        let schema = resolved(root, schema);
+        let mut out = vec![];
+        let mut push = |description: String, new: Option<Value>| {
+            out.push(Mutation {
+                description: format!("{pointer}: {description}"),
+                payload: mutated(sample, pointer, new),
+            })
+        };
+
+        if !pointer.is_empty() {
+            if let Some(max) = schema["maxLength"].as_u64() {
+                push(
+                    format!("length {}", max + 1),
+                    Some(json!("x".repeat(max as usize + 1))),
+                );
+            }
+            if let Some(min) = schema["minimum"].as_f64() {
+                push(format!("value {}", min - 1.0), Some(json!(min as i64 - 1)));
+            }
+            if let Some(max) = schema["maximum"].as_f64() {
+                push(format!("value {}", max + 1.0), Some(json!(max as i64 + 1)));
+            }
+            if schema["enum"].is_array() {
+                push("unknown enum value".to_string(), Some(json!("NotAValue")));
+            }
+            if schema["format"] == "date-time" {
+                push("malformed date-time".to_string(), Some(json!("yesterday")));
+            }
+            if let Some(min) = schema["minItems"].as_u64().filter(|min| *min > 0) {
+                let items = value.as_array().unwrap()[..min as usize - 1].to_vec();
+                push(format!("{} items", min - 1), Some(Value::Array(items)));
+            }
+            if let Some(max) = schema["maxItems"].as_u64() {
+                let items = vec![value[0].clone(); max as usize + 1];
+                push(format!("{} items", max + 1), Some(Value::Array(items)));
+            }
+            match schema["type"].as_str() {
+                Some("string") => push("wrong type".to_string(), Some(json!(1))),
+                Some(_) => push("wrong type".to_string(), Some(json!("x"))),
+                None => {}
+            }
+        }
+
+        match value {
+            Value::Object(map) => {
+                for name in schema["required"].as_array().into_iter().flatten() {
+                    let name = name.as_str().unwrap();
+                    if map.contains_key(name) {
+                        out.push(Mutation {
+                            description: format!("{pointer}/{name}: removed"),
+                            payload: mutated(sample, &format!("{pointer}/{name}"), None),
+                        });
+                    }
+                }
+                for (name, child) in map {
+                    let property = &schema["properties"][name];
+                    out.extend(mutations(
+                        root,
+                        property,
+                        sample,
+                        child,
+                        &format!("{pointer}/{name}"),
+                    ));
+                }
+            }
+            Value::Array(items) => {
+                if let Some(first) = items.first() {
+                    out.extend(mutations(
+                        root,
+                        &schema["items"],
+                        sample,
+                        first,
+                        &format!("{pointer}/0"),
+                    ));
+                }
+            }
+            _ => {}
+        }
+
+        out
+    }
+
+    /// Whether the Rust type accepts a payload: it deserializes and passes `validate()`.
+    fn accepts<T: DeserializeOwned + OcppEntity>(payload: &Value) -> bool {
+        serde_json::from_value::<T>(payload.clone()).is_ok_and(|entity| entity.validate().is_ok())
+    }
+
+    /// Check that the schema and the Rust type agree on `sample` and on every mutation of it. A
+    /// payload without a vendored schema has nothing to agree with.
+    fn disagreements(
+        action: &str,
+        direction: Direction,
+        sample: &str,
+        accepts: fn(&Value) -> bool,
+    ) -> Vec<String> {
+        let name = format!("{action}{}", direction.suffix());
+        let sample: Value = serde_json::from_str(sample).unwrap();
+        let Some(root) = schema(action, direction) else {
+            return vec![];
+        };
+
+        let mut samples = vec![Mutation {
+            description: "example payload".to_string(),
+            payload: sample.clone(),
+        }];
+        samples.extend(mutations(root, root, &sample, &sample, ""));
+
+        samples
+            .into_iter()
+            .filter_map(|Mutation { description, payload }| {
+                let by_schema = validate(action, direction, &payload).is_ok();
+                let by_rust = accepts(&payload);
+                (by_schema != by_rust).then(|| {
+                    format!("{name} {description}: schema accepts {by_schema}, validate() accepts {by_rust}")
+                })
+            })
+            .collect()
+    }
+
+    macro_rules! check_agreement {
+        ($($module:ident::$message:ident),* $(,)?) => {{
+            let mut found = vec![];
+            $(
+                found.extend(disagreements(
+                    stringify!($message),
+                    Direction::Request,
+                    include_str!(concat!(
+                        "../tests/payloads/",
+                        stringify!($message),
+                        "Request.json"
+                    )),
+                    accepts::<<crate::messages::$module::$message as OcppMessage>::Request>,
+                ));
+                found.extend(disagreements(
+                    stringify!($message),
+                    Direction::Response,
+                    include_str!(concat!(
+                        "../tests/payloads/",
+                        stringify!($message),
+                        "Response.json"
+                    )),
+                    accepts::<<crate::messages::$module::$message as OcppMessage>::Response>,
+                ));
+            )*
+            found
+        }};
+    }
+
+    macro_rules! check_send_agreement {
+        ($($module:ident::$message:ident),* $(,)?) => {{
+            let mut found = vec![];
+            $(
+                found.extend(disagreements(
+                    stringify!($message),
+                    Direction::Request,
+                    include_str!(concat!(
+                        "../tests/payloads/",
+                        stringify!($message),
+                        "Request.json"
+                    )),
+                    accepts::<
+                        <crate::messages::$module::$message as OcppUnconfirmedMessage>::Request,
+                    >,
+                ));
+            )*
+            found
+        }};
+    }
+
+    #[test]
+    fn test_validate_agrees_with_schemas() {
+        let mut found = for_each_action!(check_agreement);
+        found.extend(for_each_unconfirmed_action!(check_send_agreement));
+        assert!(found.is_empty(), "{}", found.join("\n"));
+    }
+}
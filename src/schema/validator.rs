@@ -0,0 +1,373 @@
+use crate::errors::OcppError;
+use chrono::DateTime;
+use serde_json::{Map, Value};
+
+/// Evaluates a JSON value against a draft-06 JSON schema, restricted to the keywords used by the
+/// OCPP JSON schemas. Unsupported keywords (`description`, `javaType`, ...) are ignored.
+pub(super) struct Validator<'a> {
+    root: &'a Value,
+    errors: Vec<OcppError>,
+}
+
+impl<'a> Validator<'a> {
+    /// Validate `value` against the schema `root`, returning one `SchemaViolationError` per
+    /// violated keyword.
+    pub(super) fn validate(root: &'a Value, value: &Value) -> Vec<OcppError> {
+        let mut validator = Self {
+            root,
+            errors: vec![],
+        };
+        validator.check(root, value, "");
+        validator.errors
+    }
+
+    fn push(&mut self, pointer: &str, keyword: &str, reason: String) {
+        self.errors.push(OcppError::SchemaViolationError {
+            pointer: pointer.to_string(),
+            keyword: keyword.to_string(),
+            reason,
+        });
+    }
+
+    fn check(&mut self, schema: &'a Value, value: &Value, pointer: &str) {
+        // `{}` and `true` accept any value, e.g. DataTransferRequest.data
+        let Some(schema) = schema.as_object() else {
+            return;
+        };
+
+        // In draft-06 the siblings of `$ref` are ignored.
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            match resolve(self.root, reference) {
+                Some(target) => self.check(target, value, pointer),
+                None => self.push(
+                    pointer,
+                    "$ref",
+                    format!("cannot resolve reference `{reference}`"),
+                ),
+            }
+            return;
+        }
+
+        if let Some(expected) = schema.get("type")
+            && !type_matches(expected, value)
+        {
+            self.push(
+                pointer,
+                "type",
+                format!("expected {expected}, got {}", kind(value)),
+            );
+            return;
+        }
+
+        if let Some(values) = schema.get("enum").and_then(Value::as_array)
+            && !values.contains(value)
+        {
+            self.push(
+                pointer,
+                "enum",
+                format!("{value} is not one of {}", Value::from(values.clone())),
+            );
+        }
+
+        match value {
+            Value::String(s) => self.check_string(schema, s, pointer),
+            Value::Number(n) => self.check_number(schema, n.as_f64().unwrap_or(f64::NAN), pointer),
+            Value::Array(items) => self.check_array(schema, items, pointer),
+            Value::Object(map) => self.check_object(schema, map, pointer),
+            _ => {}
+        }
+    }
+
+    fn check_string(&mut self, schema: &Map<String, Value>, s: &str, pointer: &str) {
+        let length = s.chars().count() as u64;
+
+        if let Some(min) = schema.get("minLength").and_then(Value::as_u64)
+            && length < min
+        {
+            self.push(
+                pointer,
+                "minLength",
+                format!("length {length} is shorter than {min}"),
+            );
+        }
+
+        if let Some(max) = schema.get("maxLength").and_then(Value::as_u64)
+            && length > max
+        {
+            self.push(
+                pointer,
+                "maxLength",
+                format!("length {length} is longer than {max}"),
+            );
+        }
+
+        if schema.get("format").and_then(Value::as_str) == Some("date-time")
+            && DateTime::parse_from_rfc3339(s).is_err()
+        {
+            self.push(
+                pointer,
+                "format",
+                format!("\"{s}\" is not an RFC 3339 date-time"),
+            );
+        }
+    }
+
+    fn check_number(&mut self, schema: &Map<String, Value>, n: f64, pointer: &str) {
+        if let Some(min) = schema.get("minimum").and_then(Value::as_f64)
+            && n < min
+        {
+            self.push(pointer, "minimum", format!("{n} is less than {min}"));
+        }
+
+        if let Some(max) = schema.get("maximum").and_then(Value::as_f64)
+            && n > max
+        {
+            self.push(pointer, "maximum", format!("{n} is greater than {max}"));
+        }
+
+        if let Some(min) = schema.get("exclusiveMinimum").and_then(Value::as_f64)
+            && n <= min
+        {
+            self.push(
+                pointer,
+                "exclusiveMinimum",
+                format!("{n} is not greater than {min}"),
+            );
+        }
+
+        if let Some(max) = schema.get("exclusiveMaximum").and_then(Value::as_f64)
+            && n >= max
+        {
+            self.push(
+                pointer,
+                "exclusiveMaximum",
+                format!("{n} is not less than {max}"),
+            );
+        }
+    }
+
+    fn check_array(&mut self, schema: &'a Map<String, Value>, items: &[Value], pointer: &str) {
+        let count = items.len() as u64;
+
+        if let Some(min) = schema.get("minItems").and_then(Value::as_u64)
+            && count < min
+        {
+            self.push(
+                pointer,
+                "minItems",
+                format!("{count} items is fewer than {min}"),
+            );
+        }
+
+        if let Some(max) = schema.get("maxItems").and_then(Value::as_u64)
+            && count > max
+        {
+            self.push(
+                pointer,
+                "maxItems",
+                format!("{count} items is more than {max}"),
+            );
+        }
+
+        if let Some(item_schema) = schema.get("items")
+            && item_schema.is_object()
+        {
+            for (i, item) in items.iter().enumerate() {
+                self.check(item_schema, item, &format!("{pointer}/{i}"));
+            }
+        }
+    }
+
+    fn check_object(
+        &mut self,
+        schema: &'a Map<String, Value>,
+        map: &Map<String, Value>,
+        pointer: &str,
+    ) {
+        if let Some(required) = schema.get("required").and_then(Value::as_array) {
+            for name in required.iter().filter_map(Value::as_str) {
+                if !map.contains_key(name) {
+                    self.push(
+                        &child(pointer, name),
+                        "required",
+                        format!("missing required property `{name}`"),
+                    );
+                }
+            }
+        }
+
+        let properties = schema.get("properties").and_then(Value::as_object);
+        for (name, value) in map {
+            match properties.and_then(|p| p.get(name)) {
+                Some(property) => self.check(property, value, &child(pointer, name)),
+                None => match schema.get("additionalProperties") {
+                    Some(Value::Bool(false)) => self.push(
+                        &child(pointer, name),
+                        "additionalProperties",
+                        format!("unknown property `{name}`"),
+                    ),
+                    Some(additional) => self.check(additional, value, &child(pointer, name)),
+                    None => {}
+                },
+            }
+        }
+    }
+}
+
+/// Resolve a local reference such as `#/definitions/StatusInfoType`.
+fn resolve<'a>(root: &'a Value, reference: &str) -> Option<&'a Value> {
+    root.pointer(reference.strip_prefix('#')?)
+}
+
+/// Append `name` to a JSON pointer, escaping it as per RFC 6901.
+pub(crate) fn child(pointer: &str, name: &str) -> String {
+    format!("{pointer}/{}", name.replace('~', "~0").replace('/', "~1"))
+}
+
+fn type_matches(expected: &Value, value: &Value) -> bool {
+    match expected {
+        Value::String(name) => match name.as_str() {
+            "null" => value.is_null(),
+            "boolean" => value.is_boolean(),
+            "integer" => kind(value) == "integer",
+            "number" => value.is_number(),
+            "string" => value.is_string(),
+            "array" => value.is_array(),
+            "object" => value.is_object(),
+            _ => true,
+        },
+        Value::Array(names) => names.iter().any(|name| type_matches(name, value)),
+        _ => true,
+    }
+}
+
+/// The JSON schema type name of a value. Numbers without a fractional part are integers.
+fn kind(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_i64() || n.is_u64() => "integer",
+        Value::Number(n) if n.as_f64().is_some_and(|f| f.fract() == 0.0) => "integer",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn violations(schema: Value, value: Value) -> Vec<(String, String)> {
+        Validator::validate(&schema, &value)
+            .into_iter()
+            .map(|e| match e {
+                OcppError::SchemaViolationError {
+                    pointer, keyword, ..
+                } => (pointer, keyword),
+                other => panic!("unexpected error {other:?}"),
+            })
+            .collect()
+    }
+
+    fn pair(pointer: &str, keyword: &str) -> (String, String) {
+        (pointer.to_string(), keyword.to_string())
+    }
+
+    #[test]
+    fn test_scalar_keywords() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "name": {"type": "string", "maxLength": 3},
+                "count": {"type": "integer", "minimum": 0.0, "maximum": 9.0},
+                "kind": {"type": "string", "enum": ["A", "B"]},
+                "at": {"type": "string", "format": "date-time"},
+            },
+        });
+
+        assert!(
+            violations(
+                schema.clone(),
+                json!({"name": "abc", "count": 9, "kind": "A", "at": "2025-01-01T12:00:00Z"})
+            )
+            .is_empty()
+        );
+        assert_eq!(
+            violations(
+                schema.clone(),
+                json!({"name": "abcd", "count": -1, "kind": "C", "at": "yesterday"})
+            ),
+            vec![
+                pair("/at", "format"),
+                pair("/count", "minimum"),
+                pair("/kind", "enum"),
+                pair("/name", "maxLength"),
+            ]
+        );
+        assert_eq!(
+            violations(schema, json!({"name": 1, "count": 1.5})),
+            vec![pair("/count", "type"), pair("/name", "type")]
+        );
+    }
+
+    #[test]
+    fn test_structural_keywords() {
+        let schema = json!({
+            "definitions": {
+                "ItemType": {
+                    "type": "object",
+                    "additionalProperties": false,
+                    "properties": {"id": {"type": "integer"}},
+                    "required": ["id"],
+                },
+            },
+            "type": "object",
+            "additionalProperties": false,
+            "properties": {
+                "items": {
+                    "type": "array",
+                    "items": {"$ref": "#/definitions/ItemType"},
+                    "minItems": 1,
+                    "maxItems": 2,
+                },
+            },
+            "required": ["items"],
+        });
+
+        assert!(violations(schema.clone(), json!({"items": [{"id": 1}]})).is_empty());
+        assert_eq!(
+            violations(schema.clone(), json!({"extra~/": true})),
+            vec![
+                pair("/items", "required"),
+                pair("/extra~0~1", "additionalProperties"),
+            ]
+        );
+        assert_eq!(
+            violations(schema.clone(), json!({"items": []})),
+            vec![pair("/items", "minItems")]
+        );
+        assert_eq!(
+            violations(
+                schema,
+                json!({"items": [{"id": 1}, {"name": "x"}, {"id": "2"}]})
+            ),
+            vec![
+                pair("/items", "maxItems"),
+                pair("/items/1/id", "required"),
+                pair("/items/1/name", "additionalProperties"),
+                pair("/items/2/id", "type"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unresolvable_reference() {
+        assert_eq!(
+            violations(json!({"$ref": "#/definitions/Missing"}), json!({})),
+            vec![pair("", "$ref")]
+        );
+    }
+}
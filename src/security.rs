@@ -0,0 +1,352 @@
+use crate::errors::{OcppError, validate_string_length};
+use crate::structures::network_connection_profile_type::NetworkConnectionProfileType;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use std::fmt;
+
+/// The OCPP security profiles, which define how a Charging Station and the CSMS authenticate each
+/// other.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum SecurityProfile {
+    /// Profile 1. HTTP Basic authentication of the Charging Station over an unsecured `ws://`
+    /// connection.
+    UnsecuredTransportWithBasicAuth,
+    /// Profile 2. HTTP Basic authentication of the Charging Station over a TLS `wss://` connection
+    /// that authenticates the CSMS with its server certificate.
+    TlsWithBasicAuth,
+    /// Profile 3. Mutual TLS over `wss://`, authenticating the Charging Station with a client
+    /// certificate.
+    TlsWithClientSideCertificates,
+}
+
+impl SecurityProfile {
+    /// Whether the connection runs over TLS.
+    pub fn uses_tls(&self) -> bool {
+        !matches!(self, SecurityProfile::UnsecuredTransportWithBasicAuth)
+    }
+
+    /// Whether the Charging Station authenticates with HTTP Basic authentication.
+    pub fn uses_basic_auth(&self) -> bool {
+        !matches!(self, SecurityProfile::TlsWithClientSideCertificates)
+    }
+}
+
+impl TryFrom<i32> for SecurityProfile {
+    type Error = OcppError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(SecurityProfile::UnsecuredTransportWithBasicAuth),
+            2 => Ok(SecurityProfile::TlsWithBasicAuth),
+            3 => Ok(SecurityProfile::TlsWithClientSideCertificates),
+            _ => Err(OcppError::InvalidEnumValueError {
+                enum_name: "SecurityProfile".to_string(),
+                value: value.to_string(),
+            }),
+        }
+    }
+}
+
+impl From<SecurityProfile> for i32 {
+    fn from(value: SecurityProfile) -> Self {
+        match value {
+            SecurityProfile::UnsecuredTransportWithBasicAuth => 1,
+            SecurityProfile::TlsWithBasicAuth => 2,
+            SecurityProfile::TlsWithClientSideCertificates => 3,
+        }
+    }
+}
+
+/// The maximum length of a Charging Station identity.
+pub const MAX_IDENTITY_LENGTH: usize = 48;
+
+/// The minimum length of a basic authentication password.
+pub const MIN_PASSWORD_LENGTH: usize = 16;
+
+/// The maximum length of a basic authentication password.
+pub const MAX_PASSWORD_LENGTH: usize = 40;
+
+/// Check that a Charging Station identity is at most 48 characters long and only consists of the
+/// characters of an identifierString, except for `:`, which cannot appear in a basic
+/// authentication username.
+pub fn validate_identity(identity: &str) -> Result<(), OcppError> {
+    validate_string_length(identity, 1, MAX_IDENTITY_LENGTH)
+        .map_err(|e| e.to_field_validation_error("identity"))?;
+
+    if !identity
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || "*-_=+|@.".contains(c))
+    {
+        return Err(OcppError::FieldValueError {
+            value: identity.to_string(),
+        }
+        .to_field_validation_error("identity"));
+    }
+
+    Ok(())
+}
+
+/// Check that a basic authentication password is 16 to 40 characters long and only consists of
+/// visible ASCII characters. The password itself is left out of the error.
+pub fn validate_password(password: &str) -> Result<(), OcppError> {
+    let length = password.chars().count();
+    if !(MIN_PASSWORD_LENGTH..=MAX_PASSWORD_LENGTH).contains(&length) {
+        return Err(OcppError::FieldBoundsError {
+            value: format!("{length} characters"),
+            lower: MIN_PASSWORD_LENGTH.to_string(),
+            upper: MAX_PASSWORD_LENGTH.to_string(),
+        }
+        .to_field_validation_error("basic_auth_password"));
+    }
+
+    if !password.chars().all(|c| c.is_ascii_graphic()) {
+        return Err(OcppError::FieldValueError {
+            value: "<redacted>".to_string(),
+        }
+        .to_field_validation_error("basic_auth_password"));
+    }
+
+    Ok(())
+}
+
+/// The credentials a Charging Station uses for HTTP Basic authentication in security profiles 1
+/// and 2. The username is the identity of the Charging Station.
+#[derive(Clone, Eq, PartialEq)]
+pub struct BasicAuthCredentials {
+    identity: String,
+    password: String,
+}
+
+impl fmt::Debug for BasicAuthCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("BasicAuthCredentials")
+            .field("identity", &self.identity)
+            .field("password", &"<redacted>")
+            .finish()
+    }
+}
+
+impl BasicAuthCredentials {
+    /// Create credentials, checking the identity and password rules.
+    pub fn new(identity: &str, password: &str) -> Result<Self, OcppError> {
+        validate_identity(identity)?;
+        validate_password(password)?;
+
+        Ok(Self {
+            identity: identity.to_string(),
+            password: password.to_string(),
+        })
+    }
+
+    /// Take the credentials from a network connection profile. Returns `None` for profiles that
+    /// do not use basic authentication.
+    pub fn from_profile(profile: &NetworkConnectionProfileType) -> Result<Option<Self>, OcppError> {
+        if !SecurityProfile::try_from(profile.security_profile)?.uses_basic_auth() {
+            return Ok(None);
+        }
+
+        let identity = profile.identity.as_deref().ok_or_else(|| {
+            OcppError::FieldRelationshipError {
+                this: "identity".to_string(),
+                other: "security_profile".to_string(),
+                help: "Security profiles 1 and 2 require basic authentication".to_string(),
+            }
+            .to_field_validation_error("identity")
+        })?;
+        let password = profile.basic_auth_password.as_deref().ok_or_else(|| {
+            OcppError::FieldRelationshipError {
+                this: "basic_auth_password".to_string(),
+                other: "security_profile".to_string(),
+                help: "Security profiles 1 and 2 require basic authentication".to_string(),
+            }
+            .to_field_validation_error("basic_auth_password")
+        })?;
+
+        Self::new(identity, password).map(Some)
+    }
+
+    pub fn identity(&self) -> &str {
+        &self.identity
+    }
+
+    pub fn password(&self) -> &str {
+        &self.password
+    }
+
+    /// The value of the `Authorization` header, e.g. `Basic Q1MwMDE6...`.
+    pub fn authorization_header(&self) -> String {
+        let token = STANDARD.encode(format!("{}:{}", self.identity, self.password));
+        format!("Basic {token}")
+    }
+
+    /// Parse the value of an `Authorization` header. The credentials are not checked against the
+    /// identity and password rules, so that a server can still report who failed to log in.
+    pub fn from_authorization_header(header: &str) -> Result<Self, OcppError> {
+        let invalid = |reason: &str| OcppError::AuthenticationError {
+            reason: reason.to_string(),
+        };
+
+        let (scheme, token) = header.trim().split_once(' ').ok_or_else(|| {
+            invalid("the Authorization header is not of the form `Basic <token>`")
+        })?;
+        if !scheme.eq_ignore_ascii_case("Basic") {
+            return Err(invalid(
+                "the Authorization header does not use the Basic scheme",
+            ));
+        }
+
+        let decoded = STANDARD
+            .decode(token.trim())
+            .map_err(|_| invalid("the Authorization token is not valid base64"))?;
+        let decoded = String::from_utf8(decoded)
+            .map_err(|_| invalid("the Authorization token is not valid UTF-8"))?;
+        let (identity, password) = decoded
+            .split_once(':')
+            .ok_or_else(|| invalid("the Authorization token has no `:` separator"))?;
+
+        Ok(Self {
+            identity: identity.to_string(),
+            password: password.to_string(),
+        })
+    }
+
+    /// Verify an `Authorization` header received from the Charging Station with the given
+    /// identity against these credentials.
+    pub fn verify(&self, identity: &str, header: Option<&str>) -> Result<(), OcppError> {
+        let header = header.ok_or_else(|| OcppError::AuthenticationError {
+            reason: "the Authorization header is missing".to_string(),
+        })?;
+        let received = Self::from_authorization_header(header)?;
+
+        let matches = received.identity == identity
+            && self.identity == identity
+            && constant_time_eq(received.password.as_bytes(), self.password.as_bytes());
+        if !matches {
+            return Err(OcppError::AuthenticationError {
+                reason: format!("invalid credentials for {identity}"),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Compare two byte strings in time that only depends on their lengths, so that a password can't
+/// be guessed byte by byte.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::ocpp_interface_enum_type::OCPPInterfaceEnumType;
+    use crate::enums::ocpp_transport_enum_type::OCPPTransportEnumType;
+
+    const PASSWORD: &str = "0123456789abcdef";
+
+    fn profile(security_profile: i32) -> NetworkConnectionProfileType {
+        NetworkConnectionProfileType {
+            ocpp_version: None,
+            ocpp_interface: OCPPInterfaceEnumType::Wired0,
+            ocpp_transport: OCPPTransportEnumType::JSON,
+            message_timeout: 30,
+            ocpp_csms_url: "wss://csms.example.com/ocpp".to_string(),
+            security_profile,
+            identity: Some("CS001".to_string()),
+            basic_auth_password: Some(PASSWORD.to_string()),
+            vpn: None,
+            apn: None,
+            custom_data: None,
+        }
+    }
+
+    #[test]
+    fn test_security_profile() {
+        for value in 1..=3 {
+            let profile = SecurityProfile::try_from(value).unwrap();
+            assert_eq!(i32::from(profile), value);
+        }
+        assert!(SecurityProfile::try_from(0).is_err());
+        assert!(!SecurityProfile::UnsecuredTransportWithBasicAuth.uses_tls());
+        assert!(!SecurityProfile::TlsWithClientSideCertificates.uses_basic_auth());
+    }
+
+    #[test]
+    fn test_validate_identity() {
+        assert!(validate_identity("CS-001_a.b@c").is_ok());
+        assert!(validate_identity("").is_err());
+        assert!(validate_identity("CS:001").is_err());
+        assert!(validate_identity("CS 001").is_err());
+        assert!(validate_identity(&"a".repeat(49)).is_err());
+    }
+
+    #[test]
+    fn test_validate_password() {
+        assert!(validate_password(PASSWORD).is_ok());
+        assert!(validate_password(&"a".repeat(40)).is_ok());
+        assert!(validate_password(&"a".repeat(15)).is_err());
+        assert!(validate_password(&"a".repeat(41)).is_err());
+        assert!(validate_password("0123456789 abcdef").is_err());
+        assert!(validate_password("0123456789abcdeé").is_err());
+
+        let error = validate_password("short-secret").unwrap_err();
+        assert!(!format!("{error:?}").contains("short-secret"));
+    }
+
+    #[test]
+    fn test_authorization_header_round_trip() {
+        let credentials = BasicAuthCredentials::new("CS001", PASSWORD).unwrap();
+        let header = credentials.authorization_header();
+        assert_eq!(header, "Basic Q1MwMDE6MDEyMzQ1Njc4OWFiY2RlZg==");
+        assert_eq!(
+            BasicAuthCredentials::from_authorization_header(&header).unwrap(),
+            credentials
+        );
+        assert!(!format!("{credentials:?}").contains(PASSWORD));
+    }
+
+    #[test]
+    fn test_from_authorization_header_malformed() {
+        for header in ["", "Basic", "Bearer abc", "Basic !!!", "Basic Q1MwMDE="] {
+            assert!(
+                matches!(
+                    BasicAuthCredentials::from_authorization_header(header),
+                    Err(OcppError::AuthenticationError { .. })
+                ),
+                "Expected {header:?} to be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_verify() {
+        let credentials = BasicAuthCredentials::new("CS001", PASSWORD).unwrap();
+        let header = credentials.authorization_header();
+        assert!(credentials.verify("CS001", Some(&header)).is_ok());
+        assert!(credentials.verify("CS002", Some(&header)).is_err());
+        assert!(credentials.verify("CS001", None).is_err());
+
+        let wrong = BasicAuthCredentials::new("CS001", "fedcba9876543210").unwrap();
+        assert!(
+            credentials
+                .verify("CS001", Some(&wrong.authorization_header()))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_from_profile() {
+        let credentials = BasicAuthCredentials::from_profile(&profile(2)).unwrap();
+        assert_eq!(credentials.unwrap().identity(), "CS001");
+        assert!(
+            BasicAuthCredentials::from_profile(&profile(3))
+                .unwrap()
+                .is_none()
+        );
+
+        let mut missing_password = profile(1);
+        missing_password.basic_auth_password = None;
+        assert!(BasicAuthCredentials::from_profile(&missing_password).is_err());
+    }
+}
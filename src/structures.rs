@@ -30,6 +30,7 @@ pub mod consumption_cost_type;
 pub mod cost_details_type;
 pub mod cost_dimension_type;
 pub mod cost_type;
+pub mod custom_data_type;
 pub mod dc_charging_parameters_type;
 pub mod der_charging_parameters_type;
 pub mod der_curve_get_type;
@@ -119,3 +120,160 @@ pub mod variable_monitoring_type;
 pub mod variable_type;
 pub mod voltage_params_type;
 pub mod vpn_type;
+
+#[cfg(test)]
+mod tests {
+    use crate::messages::tests::round_trip;
+
+    /// Round-trips the example payload `tests/payloads/structures/<name>.json` of every structure.
+    /// The examples are loaded at compile time, so that a structure without one fails to build.
+    macro_rules! round_trip_structures {
+        ($($module:ident::$structure:ident),* $(,)?) => {
+            vec![$(
+                (
+                    stringify!($structure),
+                    round_trip::<super::$module::$structure>(include_str!(concat!(
+                        env!("CARGO_MANIFEST_DIR"),
+                        "/tests/payloads/structures/",
+                        stringify!($structure),
+                        ".json"
+                    ))),
+                ),
+            )*]
+        };
+    }
+
+    #[test]
+    fn test_every_structure_round_trips_example_payloads() {
+        let results = round_trip_structures![
+            absolute_price_schedule_type::AbsolutePriceScheduleType,
+            ac_charging_parameters_type::ACChargingParametersType,
+            additional_info_type::AdditionalInfoType,
+            additional_selected_services_type::AdditionalSelectedServicesType,
+            address_type::AddressType,
+            apn_type::APNType,
+            authorization_data::AuthorizationData,
+            battery_data_type::BatteryDataType,
+            certificate_hash_data_chain_type::CertificateHashDataChainType,
+            certificate_hash_data_type::CertificateHashDataType,
+            certificate_status_request_info_type::CertificateStatusRequestInfoType,
+            certificate_status_type::CertificateStatusType,
+            charging_limit_type::ChargingLimitType,
+            charging_needs_type::ChargingNeedsType,
+            charging_period_type::ChargingPeriodType,
+            charging_profile_criterion_type::ChargingProfileCriterionType,
+            charging_profile_type::ChargingProfileType,
+            charging_schedule_period_type::ChargingSchedulePeriodType,
+            charging_schedule_type::ChargingScheduleType,
+            charging_schedule_update_type::ChargingScheduleUpdateType,
+            charging_station_type::ChargingStationType,
+            clear_charging_profile_type::ClearChargingProfileType,
+            clear_monitoring_result_type::ClearMonitoringResultType,
+            clear_tarrifs_result_type::ClearTariffsResultType,
+            component_type::ComponentType,
+            component_variable_type::ComponentVariableType,
+            composite_schedule_type::CompositeScheduleType,
+            constant_stream_data_type::ConstantStreamDataType,
+            consumption_cost_type::ConsumptionCostType,
+            cost_details_type::CostDetailsType,
+            cost_dimension_type::CostDimensionType,
+            cost_type::CostType,
+            custom_data_type::CustomDataType,
+            dc_charging_parameters_type::DCChargingParametersType,
+            der_charging_parameters_type::DERChargingParametersType,
+            der_curve_get_type::DERCurveGetType,
+            der_curve_points_type::DERCurvePointsType,
+            der_curve_type::DERCurveType,
+            enter_service_get_type::EnterServiceGetType,
+            enter_service_type::EnterServiceType,
+            ev_absolute_price_schedule_entry_type::EVAbsolutePriceScheduleEntryType,
+            ev_absolute_price_schedule_type::EVAbsolutePriceScheduleType,
+            ev_energy_offer_type::EVEnergyOfferType,
+            ev_power_schedule_entry_type::EVPowerScheduleEntryType,
+            ev_power_schedule_type::EVPowerScheduleType,
+            ev_price_rule_type::EVPriceRuleType,
+            event_data_type::EventDataType,
+            evse_type::EVSEType,
+            firmware_type::FirmwareType,
+            fixed_pf_get_type::FixedPFGetType,
+            fixed_pf_type::FixedPFType,
+            fixed_var_get_type::FixedVarGetType,
+            fixed_var_type::FixedVarType,
+            freq_droop_get_type::FreqDroopGetType,
+            freq_droop_type::FreqDroopType,
+            get_variable_data_type::GetVariableDataType,
+            get_variable_result_type::GetVariableResultType,
+            gradient_get_type::GradientGetType,
+            gradient_type::GradientType,
+            hysteresis_type::HysteresisType,
+            id_token_info_type::IdTokenInfoType,
+            id_token_type::IdTokenType,
+            limit_at_soc_type::LimitAtSOCType,
+            limit_max_discharge_get_type::LimitMaxDischargeGetType,
+            limit_max_discharge_type::LimitMaxDischargeType,
+            log_parameters_type::LogParametersType,
+            message_content_type::MessageContentType,
+            message_info_type::MessageInfoType,
+            meter_value_type::MeterValueType,
+            modem_type::ModemType,
+            monitoring_data_type::MonitoringDataType,
+            network_connection_profile_type::NetworkConnectionProfileType,
+            ocsp_request_data_type::OCSPRequestDataType,
+            overstay_rule_list_type::OverstayRuleListType,
+            overstay_rule_type::OverstayRuleType,
+            periodic_event_stream_params_type::PeriodicEventStreamParamsType,
+            price_level_schedule_entry_type::PriceLevelScheduleEntryType,
+            price_level_schedule_type::PriceLevelScheduleType,
+            price_rule_stack_type::PriceRuleStackType,
+            price_rule_type::PriceRuleType,
+            price_type::PriceType,
+            rational_number_type::RationalNumberType,
+            reactive_power_params_type::ReactivePowerParamsType,
+            relative_time_interval_type::RelativeTimeIntervalType,
+            report_data_type::ReportDataType,
+            sales_tariff_entry_type::SalesTariffEntryType,
+            sales_tariff_type::SalesTariffType,
+            sampled_meter_value_type::SampledValueType,
+            set_monitoring_data_type::SetMonitoringDataType,
+            set_monitoring_result_type::SetMonitoringResultType,
+            set_variable_data_type::SetVariableDataType,
+            set_variable_result_type::SetVariableResultType,
+            signed_meter_value_type::SignedMeterValueType,
+            status_info_type::StatusInfoType,
+            stream_data_element_type::StreamDataElementType,
+            tariff_assignment_type::TariffAssignmentType,
+            tariff_conditions_fixed_type::TariffConditionsFixedType,
+            tariff_conditions_type::TariffConditionsType,
+            tariff_energy_price_type::TariffEnergyPriceType,
+            tariff_energy_type::TariffEnergyType,
+            tariff_fixed_price_type::TariffFixedPriceType,
+            tariff_fixed_type::TariffFixedType,
+            tariff_time_price_type::TariffTimePriceType,
+            tariff_time_type::TariffTimeType,
+            tariff_type::TariffType,
+            tax_rate_type::TaxRateType,
+            tax_rule_type::TaxRuleType,
+            total_cost_type::TotalCostType,
+            total_price_type::TotalPriceType,
+            total_usage_type::TotalUsageType,
+            transaction_limit_type::TransactionLimitType,
+            transaction_type::TransactionType,
+            unit_of_measure_type::UnitOfMeasureType,
+            v2x_charging_parameters_type::V2XChargingParametersType,
+            v2x_freq_watt_point_type::V2XFreqWattPointType,
+            v2x_signal_watt_point_type::V2XSignalWattPointType,
+            variable_attribute_type::VariableAttributeType,
+            variable_characteristics_type::VariableCharacteristicsType,
+            variable_monitoring_type::VariableMonitoringType,
+            variable_type::VariableType,
+            voltage_params_type::VoltageParamsType,
+            vpn_type::VPNType,
+        ];
+
+        let failures: Vec<String> = results
+            .into_iter()
+            .filter_map(|(name, result)| result.err().map(|e| format!("{name}: {e}")))
+            .collect();
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}
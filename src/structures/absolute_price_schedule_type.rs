@@ -1,8 +1,10 @@
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::iso::iso_4217::CurrencyRegistry;
 use crate::structures::additional_selected_services_type::AdditionalSelectedServicesType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::overstay_rule_list_type::OverstayRuleListType;
 use crate::structures::price_rule_stack_type::PriceRuleStackType;
+use crate::structures::rational_number_type::RationalNumberType;
 use crate::structures::tax_rule_type::TaxRuleType;
 use crate::traits::OcppEntity;
 use chrono::{DateTime, Utc};
@@ -16,6 +18,7 @@ pub struct AbsolutePriceScheduleType {
     pub time_anchor: DateTime<Utc>,
 
     /// Required. Unique ID of price schedule
+    #[serde(rename = "priceScheduleID")]
     pub price_schedule_id: i32, // integer, 0 <= val
 
     /// Optional. Description of the price schedule.
@@ -36,24 +39,30 @@ pub struct AbsolutePriceScheduleType {
     pub price_algorithm: String, // string[0..2000]
 
     /// Required. A set of pricing rules for parking and energy costs.
-    pub price_rule_stacks: PriceRuleStackType,
+    pub price_rule_stacks: Vec<PriceRuleStackType>, // 1..1024
 
-    /// Optional. Optional. Describes the applicable tax rule(s) for this price schedule.
+    /// Optional. Describes the applicable tax rule(s) for this price schedule.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tax_rules: Option<TaxRuleType>,
+    pub tax_rules: Option<Vec<TaxRuleType>>, // 1..10
 
-    ///  Optional. A set of prices for optional services (e.g. valet, carwash).
+    /// Optional. A set of overstay rules that allows for escalation of charges after the overstay is triggered.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub additional_selected_services: Option<AdditionalSelectedServicesType>,
+    pub overstay_rule_list: Option<OverstayRuleListType>,
 
-    /// A set of overstay rules that allows for escalation of charges after the overstay is triggered.
-    overstay_rule_list: Option<OverstayRuleListType>,
+    /// Optional. A set of prices for optional services (e.g. valet, carwash).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_selected_services: Option<Vec<AdditionalSelectedServicesType>>, // 1..5
 
     /// Optional. Minimum amount to be billed for the overall charging session (e.g. including energy, parking, and overstay).
-    minimum_cost: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum_cost: Option<RationalNumberType>,
 
-    ///  Optional. Maximum amount to be billed for the overall charging session (e.g. including energy, parking, and overstay).
-    maximum_cost: Option<u32>,
+    /// Optional. Maximum amount to be billed for the overall charging session (e.g. including energy, parking, and overstay).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum_cost: Option<RationalNumberType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for AbsolutePriceScheduleType {
@@ -86,14 +95,41 @@ impl OcppEntity for AbsolutePriceScheduleType {
 
         e.check_cardinality("language", 0, 8, &self.language.chars());
         e.check_cardinality("price_algorithm", 0, 2000, &self.price_algorithm.chars());
-        e.check_member("price_rule_stacks", &self.price_rule_stacks);
+        e.check_cardinality("price_rule_stacks", 1, 1024, &self.price_rule_stacks.iter());
+        e.check_iter_member("price_rule_stacks", self.price_rule_stacks.iter());
 
         if let Some(tax_rules) = &self.tax_rules {
-            e.check_member("tax_rules", tax_rules);
+            e.check_cardinality("tax_rules", 1, 10, &tax_rules.iter());
+            e.check_iter_member("tax_rules", tax_rules.iter());
+        }
+
+        if let Some(overstay_rule_list) = &self.overstay_rule_list {
+            e.check_member("overstay_rule_list", overstay_rule_list);
         }
 
         if let Some(additional_selected_services) = &self.additional_selected_services {
-            e.check_member("additional_selected_services", additional_selected_services);
+            e.check_cardinality(
+                "additional_selected_services",
+                1,
+                5,
+                &additional_selected_services.iter(),
+            );
+            e.check_iter_member(
+                "additional_selected_services",
+                additional_selected_services.iter(),
+            );
+        }
+
+        if let Some(minimum_cost) = &self.minimum_cost {
+            e.check_member("minimum_cost", minimum_cost);
+        }
+
+        if let Some(maximum_cost) = &self.maximum_cost {
+            e.check_member("maximum_cost", maximum_cost);
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
         }
 
         e.build("AbsolutePriceScheduleType")
@@ -1,10 +1,12 @@
-use crate::errors::OcppError;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
 
 /// Represents AC charging parameters for ISO 15118-2.
 /// Used by: Common::ChargingNeedsType
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct ACChargingParametersType {
     /// Required. Amount of energy requested (in Wh). This includes energy required for preconditioning.
     /// Relates to: ISO 15118-2: AC_EVChargeParameterType: EAmount
@@ -19,6 +21,9 @@ pub struct ACChargingParametersType {
     /// Required. Maximum voltage supported by vehicle.
     /// Relates to: ISO 15118-2: AC_EVChargeParameterType: EVMaxVoltage
     pub ev_max_voltage: f64,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for ACChargingParametersType {
@@ -28,12 +33,19 @@ impl Default for ACChargingParametersType {
             ev_min_current: 0.0,
             ev_max_current: 0.0,
             ev_max_voltage: 0.0,
+            custom_data: None,
         }
     }
 }
 #[typetag::serde]
 impl OcppEntity for ACChargingParametersType {
     fn validate(&self) -> Result<(), OcppError> {
-        Ok(())
+        let mut e = StructureValidationBuilder::new();
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("ACChargingParametersType")
     }
 }
@@ -1,4 +1,5 @@
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
 
@@ -6,6 +7,7 @@ use serde::{Deserialize, Serialize};
 /// and the type of authorization to support multiple forms of identifiers.
 /// Used by: Common::IdTokenType
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct AdditionalInfoType {
     /// Required. This field specifies the additional IdToken.
     /// String length: 0..255
@@ -15,6 +17,9 @@ pub struct AdditionalInfoType {
     /// parties involved. When the type is not supported, the CSMS/Charging Station MAY ignore the additionalInfo.
     /// String length: 0..50
     pub r#type: String, // 'type' is a Rust keyword, so we use r#type to escape it
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for AdditionalInfoType {
@@ -22,6 +27,7 @@ impl Default for AdditionalInfoType {
         Self {
             additional_id_token: "".to_string(),
             r#type: "".to_string(),
+            custom_data: None,
         }
     }
 }
@@ -40,6 +46,10 @@ impl OcppEntity for AdditionalInfoType {
         );
         e.check_cardinality("type", 0, 50, &self.r#type.chars());
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("AdditionalInfoType")
     }
 }
@@ -1,17 +1,22 @@
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
+use crate::structures::rational_number_type::RationalNumberType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
 
 /// Represents additional selected services as part of the ISO 15118-20 price schedule.
 /// Used by: Common::AbsolutePriceScheduleType
-#[derive(Debug, PartialEq, Serialize, Deserialize, Clone, Eq)]
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct AdditionalSelectedServicesType {
     /// Required. Human-readable string to identify this service.
     /// String length: 0..80
     pub service_name: String,
     /// Required. Cost of the service.
-    /// Adapted from RationalNumberType, considered as u32.
-    pub service_fee: u32,
+    pub service_fee: RationalNumberType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for AdditionalSelectedServicesType {
@@ -20,6 +25,11 @@ impl OcppEntity for AdditionalSelectedServicesType {
     fn validate(&self) -> Result<(), OcppError> {
         let mut e = StructureValidationBuilder::new();
         e.check_cardinality("service_name", 0, 80, &self.service_name.chars());
+        e.check_member("service_fee", &self.service_fee);
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("AdditionalSelectedServicesType")
     }
 }
@@ -32,7 +42,12 @@ mod tests {
     fn test_serialization_deserialization() {
         let service = AdditionalSelectedServicesType {
             service_name: "Charging Service".to_string(),
-            service_fee: 1500, // Example fee
+            service_fee: RationalNumberType {
+                exponent: 0,
+                value: 1500,
+                custom_data: None,
+            },
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&service).unwrap();
@@ -47,13 +62,23 @@ mod tests {
     fn test_validation_valid() {
         let service = AdditionalSelectedServicesType {
             service_name: "Short service name".to_string(),
-            service_fee: 100,
+            service_fee: RationalNumberType {
+                exponent: 0,
+                value: 100,
+                custom_data: None,
+            },
+            custom_data: None,
         };
         assert!(service.validate().is_ok());
 
         let service_max_len = AdditionalSelectedServicesType {
             service_name: "a".repeat(80),
-            service_fee: 0, // Valid fee
+            service_fee: RationalNumberType {
+                exponent: 0,
+                value: 0,
+                custom_data: None,
+            },
+            custom_data: None,
         };
         assert!(service_max_len.validate().is_ok());
     }
@@ -62,7 +87,12 @@ mod tests {
     fn test_validation_service_name_too_long() {
         let service = AdditionalSelectedServicesType {
             service_name: "a".repeat(81), // Too long
-            service_fee: 500,
+            service_fee: RationalNumberType {
+                exponent: 0,
+                value: 500,
+                custom_data: None,
+            },
+            custom_data: None,
         };
         assert!(service.validate().is_err());
     }
@@ -1,10 +1,12 @@
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
 
 /// A generic address format.
 /// Used by: NotifySettlementRequest, VatNumberValidationResponse
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct AddressType {
     /// Required. Name of person/company.
     /// String length: 0..50
@@ -14,16 +16,21 @@ pub struct AddressType {
     pub address1: String,
     /// Optional. Address line 2.
     /// String length: 0..100
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub address2: Option<String>,
     /// Required. City.
     /// String length: 0..100
     pub city: String,
     /// Optional. Postal code.
     /// String length: 0..20
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub postal_code: Option<String>,
     /// Required. Country name.
     /// String length: 0..50
     pub country: String,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for AddressType {
@@ -45,6 +52,10 @@ impl OcppEntity for AddressType {
             e.check_cardinality("postal_code", 0, 20, &postal_code.chars());
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("AddressType")
     }
 }
@@ -62,6 +73,7 @@ mod tests {
             city: "Anytown".to_string(),
             postal_code: Some("12345".to_string()),
             country: "USA".to_string(),
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&address).unwrap();
@@ -80,6 +92,7 @@ mod tests {
             city: "Someville".to_string(),
             postal_code: None,
             country: "Canada".to_string(),
+            custom_data: None,
         };
         assert!(address.validate().is_ok());
 
@@ -90,6 +103,7 @@ mod tests {
             city: "Max City".repeat(10),                           // 100 chars
             postal_code: Some("12345678901234567890".to_string()), // 20 chars
             country: "Max Country".repeat(4),                      // 44 chars
+            custom_data: None,
         };
         assert!(address_with_all_fields.validate().is_ok());
     }
@@ -103,6 +117,7 @@ mod tests {
             city: "Anytown".to_string(),
             postal_code: None,
             country: "USA".to_string(),
+            custom_data: None,
         };
         assert!(address.validate().is_err());
     }
@@ -116,6 +131,7 @@ mod tests {
             city: "Anytown".to_string(),
             postal_code: None,
             country: "USA".to_string(),
+            custom_data: None,
         };
         assert!(address.validate().is_err());
     }
@@ -129,6 +145,7 @@ mod tests {
             city: "Anytown".to_string(),
             postal_code: None,
             country: "USA".to_string(),
+            custom_data: None,
         };
         assert!(address.validate().is_err());
     }
@@ -142,6 +159,7 @@ mod tests {
             city: "a".repeat(101), // Too long
             postal_code: None,
             country: "USA".to_string(),
+            custom_data: None,
         };
         assert!(address.validate().is_err());
     }
@@ -155,6 +173,7 @@ mod tests {
             city: "Anytown".to_string(),
             postal_code: Some("a".repeat(21)), // Too long
             country: "USA".to_string(),
+            custom_data: None,
         };
         assert!(address.validate().is_err());
     }
@@ -168,6 +187,7 @@ mod tests {
             city: "Anytown".to_string(),
             postal_code: None,
             country: "a".repeat(51), // Too long
+            custom_data: None,
         };
         assert!(address.validate().is_err());
     }
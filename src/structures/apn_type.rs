@@ -1,30 +1,40 @@
 use crate::enums::apn_authentication_enum_type::APNAuthenticationEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
 
 /// Collection of configuration data needed to make a data-connection over a cellular network.
 /// Used by: SetNetworkProfileRequest.NetworkConnectionProfileType
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct APNType {
     /// Required. The Access Point Name as a URL.
     /// String length: 0..2000
     pub apn: String,
     /// Optional. APN username.
     /// String length: 0..50
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub apn_user_name: Option<String>,
     /// Optional. APN Password.
     /// String length: 0..64
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub apn_password: Option<String>,
     /// Optional. SIM card pin code.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub sim_pin: Option<i32>, // Assuming integer can be i32 or similar
     /// Optional. Preferred network, written as MCC and MNC concatenated.
     /// String length: 0..6
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub preferred_network: Option<String>,
     /// Optional. Default: false. Use only the preferred Network, do not dial in when not available.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub use_only_preferred_network: Option<bool>,
     /// Required. Authentication method.
     pub apn_authentication: APNAuthenticationEnumType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for APNType {
@@ -37,6 +47,7 @@ impl Default for APNType {
             preferred_network: None,
             use_only_preferred_network: None,
             apn_authentication: APNAuthenticationEnumType::Pap,
+            custom_data: None,
         }
     }
 }
@@ -58,6 +69,11 @@ impl OcppEntity for APNType {
         if let Some(preferred_net) = &self.preferred_network {
             e.check_cardinality("preferred_network", 0, 6, &preferred_net.chars());
         }
+        e.check_enum("apn_authentication", &self.apn_authentication);
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
 
         e.build("ApnType")
     }
@@ -77,6 +93,7 @@ mod tests {
             preferred_network: Some("20404".to_string()),
             use_only_preferred_network: Some(true),
             apn_authentication: APNAuthenticationEnumType::try_from("CHAP".to_string()).unwrap(), // Example value
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&apn_config).unwrap();
@@ -96,6 +113,7 @@ mod tests {
             preferred_network: None,
             use_only_preferred_network: None,
             apn_authentication: APNAuthenticationEnumType::try_from("NONE".to_string()).unwrap(),
+            custom_data: None,
         };
         assert!(apn_config.validate().is_ok());
 
@@ -107,6 +125,7 @@ mod tests {
             preferred_network: Some("123456".to_string()),
             use_only_preferred_network: Some(false),
             apn_authentication: APNAuthenticationEnumType::try_from("PAP".to_string()).unwrap(),
+            custom_data: None,
         };
         assert!(apn_config_full.validate().is_ok());
     }
@@ -121,6 +140,7 @@ mod tests {
             preferred_network: None,
             use_only_preferred_network: None,
             apn_authentication: APNAuthenticationEnumType::try_from("CHAP".to_string()).unwrap(),
+            custom_data: None,
         };
         assert!(apn_config.validate().is_err());
     }
@@ -135,6 +155,7 @@ mod tests {
             preferred_network: None,
             use_only_preferred_network: None,
             apn_authentication: APNAuthenticationEnumType::try_from("CHAP".to_string()).unwrap(),
+            custom_data: None,
         };
         assert!(apn_config.validate().is_err());
     }
@@ -149,6 +170,7 @@ mod tests {
             preferred_network: None,
             use_only_preferred_network: None,
             apn_authentication: APNAuthenticationEnumType::try_from("CHAP".to_string()).unwrap(),
+            custom_data: None,
         };
         assert!(apn_config.validate().is_err());
     }
@@ -163,6 +185,7 @@ mod tests {
             preferred_network: Some("1234567".to_string()), // Too long
             use_only_preferred_network: None,
             apn_authentication: APNAuthenticationEnumType::try_from("CHAP".to_string()).unwrap(),
+            custom_data: None,
         };
         assert!(apn_config.validate().is_err());
     }
@@ -1,4 +1,5 @@
-use crate::errors::OcppError;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::id_token_info_type::IdTokenInfoType;
 use crate::structures::id_token_type::IdTokenType;
 use crate::traits::OcppEntity;
@@ -6,29 +7,74 @@ use serde::{Deserialize, Serialize};
 
 /// Contains the identifier to use for authorization.
 /// Used by: SendLocalListRequest
-#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
 pub struct AuthorizationData {
     /// Optional. Required when UpdateType is Full. This contains information about authorization status,
     /// expiry and group id. For a Differential update the following applies: If this element is present,
     /// then this entry SHALL be added or updated in the Local Authorization List. If this element is absent,
     /// the entry for this IdToken in the Local Authorization List SHALL be deleted.
-    pub id_token_info: IdTokenInfoType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id_token_info: Option<IdTokenInfoType>,
     /// Required. This contains the identifier which needs to be stored for authorization.
     pub id_token: IdTokenType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for AuthorizationData {
     fn validate(&self) -> Result<(), OcppError> {
-        let mut errors: Vec<OcppError> = Vec::new();
+        let mut b = StructureValidationBuilder::new();
 
-        if let Err(e) = self.id_token_info.validate() {
-            errors.push(e.to_field_validation_error("id_token_info"));
+        if let Some(id_token_info) = &self.id_token_info {
+            b.check_member("id_token_info", id_token_info);
         }
 
-        if let Err(e) = self.id_token.validate() {
-            errors.push(e.to_field_validation_error("id_token"));
+        b.check_member("id_token", &self.id_token);
+
+        if let Some(custom_data) = &self.custom_data {
+            b.check_member("custom_data", custom_data);
         }
 
-        Ok(())
+        b.build("AuthorizationData")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_validate_success() {
+        assert!(AuthorizationData::default().validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_failure() {
+        let data = AuthorizationData {
+            id_token_info: None,
+            id_token: IdTokenType {
+                id_token: "a".repeat(256),
+                ..Default::default()
+            },
+            custom_data: None,
+        };
+        assert_invalid_fields(&data.validate().unwrap_err(), &["id_token"]);
+    }
+
+    #[test]
+    fn test_serialization_deserialization() {
+        let data = AuthorizationData {
+            id_token_info: Some(IdTokenInfoType::default()),
+            id_token: IdTokenType::default(),
+            custom_data: None,
+        };
+        let serialized = serde_json::to_string(&data).unwrap();
+        assert!(serialized.contains("\"idTokenInfo\""));
+        assert!(serialized.contains("\"idToken\""));
+        let deserialized: AuthorizationData = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(data, deserialized);
     }
 }
@@ -1,4 +1,5 @@
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -6,6 +7,7 @@ use serde::{Deserialize, Serialize};
 /// Represents battery data.
 /// Used by: BatterySwapRequest
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct BatteryDataType {
     /// Required. Slot number where battery is inserted or removed.
     /// Constraints: 0 <= val
@@ -15,15 +17,22 @@ pub struct BatteryDataType {
     pub serial_number: String,
     /// Required. State of charge.
     /// Constraints: 0 <= val <= 100
+    #[serde(rename = "soC")]
     pub soc: f64,
     /// Required. State of health.
     /// Constraints: 0 <= val <= 100
+    #[serde(rename = "soH")]
     pub soh: f64,
     /// Optional. Production date of battery.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub production_date: Option<DateTime<Utc>>,
     /// Optional. Vendor-specific info from battery in undefined format.
     /// String length: 0..500
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub vendor_info: Option<String>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for BatteryDataType {
@@ -35,6 +44,7 @@ impl Default for BatteryDataType {
             soh: 0.0,
             production_date: None,
             vendor_info: None,
+            custom_data: None,
         }
     }
 }
@@ -55,6 +65,10 @@ impl OcppEntity for BatteryDataType {
             e.check_cardinality("vendor_info", 0, 500, &info.chars());
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("BatteryDataType")
     }
 }
@@ -73,6 +87,7 @@ mod tests {
             soh: 92.1,
             production_date: Some(Utc.with_ymd_and_hms(2023, 1, 15, 10, 0, 0).unwrap()),
             vendor_info: Some("Vendor specific data here.".to_string()),
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&battery_data).unwrap();
@@ -91,6 +106,7 @@ mod tests {
             soh: 75.0,
             production_date: None,
             vendor_info: None,
+            custom_data: None,
         };
         assert!(battery_data.validate().is_ok());
 
@@ -101,6 +117,7 @@ mod tests {
             soh: 100.0,
             production_date: Some(Utc.with_ymd_and_hms(2025, 7, 31, 16, 0, 0).unwrap()),
             vendor_info: Some("b".repeat(500)),
+            custom_data: None,
         };
         assert!(battery_data_max_values.validate().is_ok());
     }
@@ -114,6 +131,7 @@ mod tests {
             soh: 75.0,
             production_date: None,
             vendor_info: None,
+            custom_data: None,
         };
         assert!(battery_data.validate().is_err());
     }
@@ -127,6 +145,7 @@ mod tests {
             soh: 75.0,
             production_date: None,
             vendor_info: None,
+            custom_data: None,
         };
         assert!(battery_data_low.validate().is_err());
 
@@ -137,6 +156,7 @@ mod tests {
             soh: 75.0,
             production_date: None,
             vendor_info: None,
+            custom_data: None,
         };
         assert!(battery_data_high.validate().is_err());
     }
@@ -150,6 +170,7 @@ mod tests {
             soh: -0.1, // Out of range
             production_date: None,
             vendor_info: None,
+            custom_data: None,
         };
         assert!(battery_data_low.validate().is_err());
 
@@ -160,6 +181,7 @@ mod tests {
             soh: 100.1, // Out of range
             production_date: None,
             vendor_info: None,
+            custom_data: None,
         };
         assert!(battery_data_high.validate().is_err());
     }
@@ -173,6 +195,7 @@ mod tests {
             soh: 75.0,
             production_date: None,
             vendor_info: Some("a".repeat(501)), // Too long
+            custom_data: None,
         };
         assert!(battery_data.validate().is_err());
     }
@@ -1,19 +1,25 @@
 use crate::enums::get_certificate_id_use_enum_type::GetCertificateIdUseEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::certificate_hash_data_type::CertificateHashDataType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
 
 /// Represents a chain of certificate hash data.
 /// Used by: GetInstalledCertificateIdsResponse
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct CertificateHashDataChainType {
     /// Required. Indicates the type of the requested certificate(s).
     pub certificate_type: GetCertificateIdUseEnumType,
     /// Required. Information to identify a certificate.
     pub certificate_hash_data: CertificateHashDataType,
     /// Optional. Information to identify the child certificate(s).
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub child_certificate_hash_data: Option<Vec<CertificateHashDataType>>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for CertificateHashDataChainType {
@@ -26,6 +32,12 @@ impl OcppEntity for CertificateHashDataChainType {
             e.check_iter_member("certificate_hash_data", child_certificate_hash_data.iter());
         }
 
+        e.check_enum("certificate_type", &self.certificate_type);
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("CertificateHashDataChainType")
     }
 }
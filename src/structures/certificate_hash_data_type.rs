@@ -1,5 +1,6 @@
 use crate::enums::hash_algorithm_enum_type::HashAlgorithmEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
 
@@ -7,6 +8,7 @@ use serde::{Deserialize, Serialize};
 /// Used by: Common::CertificateHashDataChainType, Common::CertificateStatusRequestInfoType,
 /// Common::CertificateStatusType, SignCertificateRequest, DeleteCertificateRequest, CustomerInformationRequest
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct CertificateHashDataType {
     /// Required. Used algorithms for the hashes provided.
     pub hash_algorithm: HashAlgorithmEnumType,
@@ -22,6 +24,9 @@ pub struct CertificateHashDataType {
     /// without the prefix "0x" and without leading zeroes.
     /// String length: 0..40
     pub serial_number: String,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for CertificateHashDataType {
@@ -31,6 +36,7 @@ impl Default for CertificateHashDataType {
             issuer_name_hash: "SomeOCPPAuthHash".to_string(),
             issuer_key_hash: "0".to_string(),
             serial_number: "0".to_string(),
+            custom_data: None,
         }
     }
 }
@@ -45,6 +51,12 @@ impl OcppEntity for CertificateHashDataType {
         e.check_cardinality("issuer_key_hash", 0, 128, &self.issuer_key_hash.chars());
         e.check_cardinality("serial_number", 0, 40, &self.serial_number.chars());
 
+        e.check_enum("hash_algorithm", &self.hash_algorithm);
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("CertificateHashDataType")
     }
 }
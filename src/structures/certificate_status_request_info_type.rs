@@ -1,12 +1,14 @@
 use crate::enums::certificate_status_source_enum_type::CertificateStatusSourceEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::certificate_hash_data_type::CertificateHashDataType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
 
 /// Data necessary to request the revocation status of a certificate.
 /// Used by: GetCertificateChainStatusRequest
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct CertificateStatusRequestInfoType {
     /// Required. Source of status: OCSP, CRL
     pub source: CertificateStatusSourceEnumType,
@@ -16,6 +18,9 @@ pub struct CertificateStatusRequestInfoType {
     pub urls: Vec<String>,
     /// Required. Hash data of certificate.
     pub certificate_hash_data: CertificateHashDataType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for CertificateStatusRequestInfoType {
@@ -24,6 +29,7 @@ impl Default for CertificateStatusRequestInfoType {
             source: CertificateStatusSourceEnumType::Crl,
             urls: vec![Default::default()],
             certificate_hash_data: Default::default(),
+            custom_data: None,
         }
     }
 }
@@ -41,6 +47,11 @@ impl OcppEntity for CertificateStatusRequestInfoType {
         }
 
         e.check_member("certificate_hash_data", &self.certificate_hash_data);
+        e.check_enum("source", &self.source);
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
         e.build("CertificateRequestInfoType")
     }
 }
@@ -58,6 +69,7 @@ mod tests {
                 "http://example.com/ocsp2".to_string(),
             ],
             certificate_hash_data: CertificateHashDataType::default(), // Placeholder
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&cert_status_req).unwrap();
@@ -74,6 +86,7 @@ mod tests {
             source: CertificateStatusSourceEnumType::Crl,
             urls: vec!["http://example.com/crl".to_string()],
             certificate_hash_data: CertificateHashDataType::default(),
+            custom_data: None,
         };
         assert!(cert_status_req.validate().is_ok());
 
@@ -87,6 +100,7 @@ mod tests {
                 "e".repeat(2000),
             ],
             certificate_hash_data: CertificateHashDataType::default(),
+            custom_data: None,
         };
         assert!(cert_status_req_max_urls.validate().is_ok());
     }
@@ -97,6 +111,7 @@ mod tests {
             source: CertificateStatusSourceEnumType::Ocsp,
             urls: vec![], // No URLs
             certificate_hash_data: CertificateHashDataType::default(),
+            custom_data: None,
         };
         assert!(cert_status_req.validate().is_err());
     }
@@ -114,6 +129,7 @@ mod tests {
                 "u6".to_string(), // Too many
             ],
             certificate_hash_data: CertificateHashDataType::default(),
+            custom_data: None,
         };
         assert!(cert_status_req.validate().is_err());
     }
@@ -124,6 +140,7 @@ mod tests {
             source: CertificateStatusSourceEnumType::Ocsp,
             urls: vec!["a".repeat(2001)], // URL too long
             certificate_hash_data: CertificateHashDataType::default(),
+            custom_data: None,
         };
         assert!(cert_status_req.validate().is_err());
     }
@@ -1,7 +1,8 @@
 use crate::enums::certificate_status_enum_type::CertificateStatusEnumType;
 use crate::enums::certificate_status_source_enum_type::CertificateStatusSourceEnumType;
-use crate::errors::OcppError;
+use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::certificate_hash_data_type::CertificateHashDataType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -9,6 +10,7 @@ use serde::{Deserialize, Serialize};
 /// Revocation status of certificate
 /// Used by: GetCertificateChainStatusResponse
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct CertificateStatusType {
     /// Required. Source of status: OCSP, CRL
     pub source: CertificateStatusSourceEnumType,
@@ -18,6 +20,9 @@ pub struct CertificateStatusType {
     pub next_update: DateTime<Utc>,
     /// Required. Hash data of the certificate.
     pub certificate_hash_data: CertificateHashDataType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for CertificateStatusType {
@@ -27,13 +32,24 @@ impl Default for CertificateStatusType {
             status: CertificateStatusEnumType::Good,
             next_update: Default::default(),
             certificate_hash_data: Default::default(),
+            custom_data: None,
         }
     }
 }
 #[typetag::serde]
 impl OcppEntity for CertificateStatusType {
     fn validate(&self) -> Result<(), OcppError> {
-        self.certificate_hash_data.validate()
+        let mut e = StructureValidationBuilder::new();
+
+        e.check_member("certificate_hash_data", &self.certificate_hash_data);
+        e.check_enum("source", &self.source);
+        e.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("CertificateStatusType")
     }
 }
 
@@ -49,6 +65,7 @@ mod tests {
             status: CertificateStatusEnumType::Good,       // Placeholder
             next_update: Utc.with_ymd_and_hms(2025, 8, 1, 0, 0, 0).unwrap(),
             certificate_hash_data: CertificateHashDataType::default(), // Placeholder
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&cert_status).unwrap();
@@ -65,6 +82,7 @@ mod tests {
             status: CertificateStatusEnumType::Revoked,
             next_update: Utc.with_ymd_and_hms(2025, 9, 1, 0, 0, 0).unwrap(),
             certificate_hash_data: CertificateHashDataType::default(),
+            custom_data: None,
         };
         assert!(cert_status.validate().is_ok());
     }
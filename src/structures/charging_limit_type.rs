@@ -1,10 +1,12 @@
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
 
 /// Represents a charging limit.
 /// Used by: NotifyChargingLimitRequest
-#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
 pub struct ChargingLimitType {
     /// Required. Represents the source of the charging limit.
     /// Values defined in appendix as ChargingLimitSourceEnumStringType.
@@ -12,9 +14,14 @@ pub struct ChargingLimitType {
     pub charging_limit_source: String,
     /// Optional. True when the reported limit concerns local generation that is providing extra capacity,
     /// instead of a limitation.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_local_generation: Option<bool>,
     /// Optional. Indicates whether the charging limit is critical for the grid.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_grid_critical: Option<bool>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for ChargingLimitType {
@@ -26,6 +33,10 @@ impl OcppEntity for ChargingLimitType {
             20,
             &self.charging_limit_source.chars(),
         );
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("ChargingLimitType")
     }
 }
@@ -40,6 +51,7 @@ mod tests {
             charging_limit_source: "EMS".to_string(),
             is_local_generation: Some(true),
             is_grid_critical: Some(false),
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&charging_limit).unwrap();
@@ -55,6 +67,7 @@ mod tests {
             charging_limit_source: "CSO".to_string(),
             is_local_generation: None,
             is_grid_critical: None,
+            custom_data: None,
         };
         assert!(charging_limit_minimal.validate().is_ok());
 
@@ -62,6 +75,7 @@ mod tests {
             charging_limit_source: "a".repeat(20),
             is_local_generation: Some(true),
             is_grid_critical: Some(true),
+            custom_data: None,
         };
         assert!(charging_limit_full.validate().is_ok());
     }
@@ -72,6 +86,7 @@ mod tests {
             charging_limit_source: "a".repeat(21), // Too long
             is_local_generation: None,
             is_grid_critical: None,
+            custom_data: None,
         };
         assert!(charging_limit.validate().is_err());
     }
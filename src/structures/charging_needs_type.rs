@@ -3,6 +3,7 @@ use crate::enums::energy_transfer_mode_enum_type::EnergyTransferModeEnumType;
 use crate::enums::mobility_needs_mode_enum_type::MobilityNeedsModeEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::ac_charging_parameters_type::ACChargingParametersType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::dc_charging_parameters_type::DCChargingParametersType;
 use crate::structures::der_charging_parameters_type::DERChargingParametersType;
 use crate::structures::ev_energy_offer_type::EVEnergyOfferType;
@@ -14,6 +15,7 @@ use serde::{Deserialize, Serialize};
 /// Represents the charging needs of an EV.
 /// Used by: NotifyEVChargingNeedsRequest
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct ChargingNeedsType {
     /// Required. Mode of energy transfer requested by the EV.
     pub requested_energy_transfer: EnergyTransferModeEnumType,
@@ -53,6 +55,26 @@ pub struct ChargingNeedsType {
     /// Optional. Additional charging parameters for ISO 15118-20 AC bidirectional sessions with DER control (AC_BPT_DER).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub der_charging_parameters: Option<DERChargingParametersType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+impl Default for ChargingNeedsType {
+    fn default() -> Self {
+        Self {
+            requested_energy_transfer: EnergyTransferModeEnumType::DC,
+            available_energy_transfer: None,
+            control_mode: None,
+            mobility_needs_mode: None,
+            departure_time: None,
+            v2x_charging_parameters: None,
+            dc_charging_parameters: None,
+            ac_charging_parameters: None,
+            ev_energy_offer: None,
+            der_charging_parameters: None,
+            custom_data: None,
+        }
+    }
 }
 #[typetag::serde]
 impl OcppEntity for ChargingNeedsType {
@@ -79,6 +101,27 @@ impl OcppEntity for ChargingNeedsType {
             e.check_member("der_charging_parameters", der_charging_parameters);
         }
 
+        e.check_enum("requested_energy_transfer", &self.requested_energy_transfer);
+
+        if let Some(available_energy_transfer) = &self.available_energy_transfer {
+            e.check_iter_enum(
+                "available_energy_transfer",
+                available_energy_transfer.iter(),
+            );
+        }
+
+        if let Some(control_mode) = &self.control_mode {
+            e.check_enum("control_mode", control_mode);
+        }
+
+        if let Some(mobility_needs_mode) = &self.mobility_needs_mode {
+            e.check_enum("mobility_needs_mode", mobility_needs_mode);
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("ChargingNeedsType")
     }
 }
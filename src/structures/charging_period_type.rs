@@ -1,5 +1,6 @@
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::cost_dimension_type::CostDimensionType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -8,17 +9,23 @@ use serde::{Deserialize, Serialize};
 /// for example: amount of energy charged this period, maximum current during this period etc.
 /// Used by: Common::CostDetailsType
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct ChargingPeriodType {
     /// Optional. Unique identifier of the Tariff that was used to calculate cost.
     /// If not provided, then cost was calculated by some other means.
     /// String length: 0..60
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tariff_id: Option<String>,
     /// Required. Start timestamp of charging period. A period ends when the next period starts.
     /// The last period ends when the session ends.
     pub start_period: DateTime<Utc>,
     /// Optional. List of volume per cost dimension for this charging period.
     /// Cardinality 0..*, so represented as a Vec.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub dimensions: Option<Vec<CostDimensionType>>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for ChargingPeriodType {
@@ -27,6 +34,7 @@ impl Default for ChargingPeriodType {
             tariff_id: None,
             start_period: Utc::now(),
             dimensions: None,
+            custom_data: None,
         }
     }
 }
@@ -44,6 +52,10 @@ impl OcppEntity for ChargingPeriodType {
             e.check_iter_member("dimensions", dimensions.iter());
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("ChargingPeriodType")
     }
 }
@@ -59,6 +71,7 @@ mod tests {
             tariff_id: Some("TARIFF_ABC_123".to_string()),
             start_period: Utc.with_ymd_and_hms(2025, 7, 31, 17, 0, 0).unwrap(),
             dimensions: Some(vec![]),
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&charging_period).unwrap();
@@ -74,6 +87,7 @@ mod tests {
             tariff_id: None,
             start_period: Utc.with_ymd_and_hms(2025, 7, 31, 17, 0, 0).unwrap(),
             dimensions: None,
+            custom_data: None,
         };
         assert!(charging_period_minimal.validate().is_ok());
 
@@ -81,6 +95,7 @@ mod tests {
             tariff_id: Some("a".repeat(60)),
             start_period: Utc.with_ymd_and_hms(2025, 7, 31, 18, 0, 0).unwrap(),
             dimensions: Some(vec![]),
+            custom_data: None,
         };
         assert!(charging_period_full.validate().is_ok());
     }
@@ -91,6 +106,7 @@ mod tests {
             tariff_id: Some("a".repeat(61)), // Too long
             start_period: Utc.with_ymd_and_hms(2025, 7, 31, 17, 0, 0).unwrap(),
             dimensions: None,
+            custom_data: None,
         };
         assert!(charging_period.validate().is_err());
     }
@@ -1,29 +1,38 @@
 use crate::enums::charging_profile_purpose_enum_type::ChargingProfilePurposeEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
 
 /// A ChargingProfileCriterionType is a filter for charging profiles to be selected by a GetChargingProfilesRequest.
 /// Used by: GetChargingProfilesRequest
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
 pub struct ChargingProfileCriterionType {
     /// Optional. Defines the purpose of the schedule transferred by this profile.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub charging_profile_purpose: Option<ChargingProfilePurposeEnumType>,
     /// Optional. Value determining level in hierarchy stack of profiles.
     /// Higher values have precedence over lower values. Lowest level is 0.
     /// Constraints: 0 <= val
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub stack_level: Option<i32>,
     /// Optional. List of all the chargingProfileIds requested. Any ChargingProfile that matches one of these profiles will be reported.
     /// If omitted, the Charging Station SHALL NOT filter on chargingProfileId.
     /// This field SHALL NOT contain more ids than set in ChargingProfileEntries.maxLimit.
     /// Cardinality 0..*
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub charging_profile_id: Option<Vec<i32>>,
     /// Optional. For which charging limit sources, charging profiles SHALL be reported.
     /// If omitted, the Charging Station SHALL NOT filter on chargingLimitSource.
     /// Values defined in Appendix as ChargingLimitSourceEnumStringType.
     /// String length: 0..20
     /// Cardinality 0..4
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub charging_limit_source: Option<Vec<String>>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for ChargingProfileCriterionType {
@@ -47,6 +56,14 @@ impl OcppEntity for ChargingProfileCriterionType {
             }
         }
 
+        if let Some(charging_profile_purpose) = &self.charging_profile_purpose {
+            e.check_enum("charging_profile_purpose", charging_profile_purpose);
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("ChargingProfileCriterionType")
     }
 }
@@ -62,6 +79,7 @@ mod tests {
             stack_level: Some(1),
             charging_profile_id: Some(vec![101, 102]),
             charging_limit_source: Some(vec!["EMS".to_string(), "CSO".to_string()]),
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&criterion).unwrap();
@@ -78,6 +96,7 @@ mod tests {
             stack_level: None,
             charging_profile_id: None,
             charging_limit_source: None,
+            custom_data: None,
         };
         assert!(criterion_minimal.validate().is_ok());
 
@@ -91,6 +110,7 @@ mod tests {
                 "c".repeat(20),
                 "d".repeat(20),
             ]),
+            custom_data: None,
         };
         assert!(criterion_full.validate().is_ok());
     }
@@ -108,6 +128,7 @@ mod tests {
                 "s4".to_string(),
                 "s5".to_string(), // Too many
             ]),
+            custom_data: None,
         };
         assert!(criterion.validate().is_err());
     }
@@ -119,6 +140,7 @@ mod tests {
             stack_level: None,
             charging_profile_id: None,
             charging_limit_source: Some(vec!["a".repeat(21)]), // Too long
+            custom_data: None,
         };
         assert!(criterion.validate().is_err());
     }
@@ -3,12 +3,14 @@ use crate::enums::charging_profile_purpose_enum_type::ChargingProfilePurposeEnum
 use crate::enums::recurrency_kind_enum_type::RecurrencyKindEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::charging_schedule_type::ChargingScheduleType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 /// Represents a charging profile.
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct ChargingProfileType {
     /// Required. Id of ChargingProfile. Unique within Charging Station.
     /// Id can have a negative value. This is used to reference charging profiles from an external actor
@@ -25,17 +27,21 @@ pub struct ChargingProfileType {
     /// Required. Indicates the kind of schedule.
     pub charging_profile_kind: ChargingProfileKindEnumType,
     /// Optional. Indicates start point of a recurrence.
+    #[serde(rename = "recurrencyKind", skip_serializing_if = "Option::is_none")]
     pub recurrence_kind: Option<RecurrencyKindEnumType>,
     /// Optional. Point in time at which the profile starts to be valid.
     /// If absent, the profile is valid as soon as it is received by the Charging Station.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub valid_from: Option<DateTime<Utc>>,
     /// Optional. Point in time at which the profile stops to be valid.
     /// If absent, the profile is valid until it is replaced by another profile.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub valid_to: Option<DateTime<Utc>>,
 
     /// Optional. SHALL only be included if ChargingProfilePurpose is set to TxProfile in a SetChargingProfileRequest.
     /// The IdTokenId is used to match the profile to a specific transaction.
     /// String length: 0..36
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub transaction_id: Option<String>,
 
     /// Optional. Period in seconds that this charging profile remains valid after the Charging Station has gone offline.
@@ -43,18 +49,22 @@ pub struct ChargingProfileType {
     /// reverts back to a valid profile with a lower stack level. If the Charging Station is online again,
     /// the charging profile will become permanently invalid. A value of 0 means that the charging profile
     /// remains valid while offline. When the profile is absent, then no timeout applies and the charging profile remains valid when offline.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub max_offline_duration: Option<i32>,
 
     /// Optional. When set to true this charging profile will not be valid anymore after being offline for more than maxOfflineDuration.
     /// When absent defaults to false.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub invalid_after_offline_duration: Option<bool>,
 
     /// Optional. Interval in seconds after receipt of last update, when to request a profile update by sending a PullDynamicScheduleUpdateRequest message.
     /// A value of 0 or less means that no update interval applies. Only relevant in a dynamic charging profile.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub dyn_update_interval: Option<i32>,
 
     /// Optional. Time at which limits or setpoints in this charging profile are to be updated by a PullDynamicScheduleUpdateRequest or
     /// UpdateDynamicScheduleRequest by an external actor. Only relevant in a dynamic charging profile.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub dyn_update_time: Option<DateTime<Utc>>,
 
     /// Optional. ISO 15118-20 signature for all price schedules or ChargingSchedules.
@@ -62,13 +72,39 @@ pub struct ChargingProfileType {
     /// The value of the signature (like secp256k1) the ECDSA e.g. signature is 612 bits (64 bytes) and for 521 is 132 bytes.
     /// This equals 131 bytes, which can be encoded as base64 in 176 bytes.
     /// String length: 0..256
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub price_schedule_signature: Option<String>,
 
     /// Required. Schedule that contains limits for the available power or current over time.
     /// In order to support ISO 15118 schedules, the Charging Station SHALL support these schedules with associated tariff to choose from.
     /// Having multiple ChargingSchedules is only allowed for ChargingProfiles of type TxProfile, i.e. in the context of an ISO 15118 charging session.
     /// For ISO 15118 Dynamic Control Mode (AC_EVSECC), only one ChargingSchedule is allowed.
-    pub charging_schedule: ChargingScheduleType,
+    /// Cardinality 1..3
+    pub charging_schedule: Vec<ChargingScheduleType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+impl Default for ChargingProfileType {
+    fn default() -> Self {
+        Self {
+            id: 0,
+            stack_level: 0,
+            charging_profile_purpose: ChargingProfilePurposeEnumType::TxDefaultProfile,
+            charging_profile_kind: ChargingProfileKindEnumType::Absolute,
+            recurrence_kind: None,
+            valid_from: None,
+            valid_to: None,
+            transaction_id: None,
+            max_offline_duration: None,
+            invalid_after_offline_duration: None,
+            dyn_update_interval: None,
+            dyn_update_time: None,
+            price_schedule_signature: None,
+            charging_schedule: vec![Default::default()],
+            custom_data: None,
+        }
+    }
 }
 #[typetag::serde]
 impl OcppEntity for ChargingProfileType {
@@ -101,7 +137,30 @@ impl OcppEntity for ChargingProfileType {
             );
         }
 
-        e.check_member("charging_schedule", &self.charging_schedule);
+        e.check_cardinality("charging_schedule", 1, 3, &self.charging_schedule.iter());
+        e.check_iter_member("charging_schedule", self.charging_schedule.iter());
+
+        if self.charging_schedule.len() > 1
+            && self.charging_profile_purpose != ChargingProfilePurposeEnumType::TxProfile
+        {
+            e.push_relation_error(
+                "charging_schedule",
+                "charging_profile_purpose",
+                "Having multiple ChargingSchedules is only allowed for ChargingProfiles of type TxProfile.",
+            );
+        }
+
+        e.check_enum("charging_profile_purpose", &self.charging_profile_purpose);
+
+        e.check_enum("charging_profile_kind", &self.charging_profile_kind);
+
+        if let Some(recurrence_kind) = &self.recurrence_kind {
+            e.check_enum("recurrence_kind", recurrence_kind);
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
 
         e.build("ChargingProfileType")
     }
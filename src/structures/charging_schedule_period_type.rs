@@ -1,5 +1,6 @@
 use crate::enums::operation_mode_enum_type::OperationModeEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::v2x_freq_watt_point_type::V2XFreqWattPointType;
 use crate::structures::v2x_signal_watt_point_type::V2XSignalWattPointType;
 use crate::traits::OcppEntity;
@@ -10,6 +11,7 @@ use serde::{Deserialize, Serialize};
 /// When used in a NotifyEVChargingScheduleRequest only startPeriod, limit, limit_L2, limit_L3 are relevant.
 /// Used by: Common::ChargingScheduleType, Common::CompositeScheduleType
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
 pub struct ChargingSchedulePeriodType {
     /// Required. Start of the period, in seconds from the start of schedule.
     /// The value of StartPeriod also defines the stop time of the previous period.
@@ -24,11 +26,11 @@ pub struct ChargingSchedulePeriodType {
     pub limit: Option<f64>,
 
     /// Optional. Charging rate limit on phase L2 in the applicable ChargingRateUnit.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "limit_L2", skip_serializing_if = "Option::is_none")]
     pub limit_l2: Option<f64>,
 
     /// Optional. Charging rate limit on phase L3 in the applicable ChargingRateUnit.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "limit_L3", skip_serializing_if = "Option::is_none")]
     pub limit_l3: Option<f64>,
 
     /// Optional. The number of phases that can be used for charging.
@@ -56,12 +58,12 @@ pub struct ChargingSchedulePeriodType {
 
     /// Optional. Limit in ChargingRateUnit on phase L2 that the EV is allowed to discharge with.
     /// Constraints: val <= 0
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "dischargeLimit_L2", skip_serializing_if = "Option::is_none")]
     pub discharge_limit_l2: Option<f64>,
 
     /// Optional. Limit in ChargingRateUnit on phase L3 that the EV is allowed to discharge with.
     /// Constraints: val <= 0
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "dischargeLimit_L3", skip_serializing_if = "Option::is_none")]
     pub discharge_limit_l3: Option<f64>,
 
     /// Optional. Setpoint in ChargingRateUnit that the EV is allowed to discharge with.
@@ -71,11 +73,11 @@ pub struct ChargingSchedulePeriodType {
     pub setpoint: Option<f64>,
 
     /// Optional. Setpoint in ChargingRateUnit that the EV should follow on phase L2 as closely as possible.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "setpoint_L2", skip_serializing_if = "Option::is_none")]
     pub setpoint_l2: Option<f64>,
 
     /// Optional. Setpoint in ChargingRateUnit that the EV should follow on phase L3 as closely as possible.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "setpoint_L3", skip_serializing_if = "Option::is_none")]
     pub setpoint_l3: Option<f64>,
 
     /// Optional. Setpoint for reactive power (or current) in ChargingRateUnit that the EV should follow.
@@ -84,19 +86,25 @@ pub struct ChargingSchedulePeriodType {
     pub setpoint_reactive: Option<f64>,
 
     /// Optional. Setpoint for reactive power (or current) in ChargingRateUnit that the EV should follow on phase L2 as closely as possible.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "setpointReactive_L2",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub setpoint_reactive_l2: Option<f64>,
 
     /// Optional. (2.1) Setpoint for reactive power (or current) in
     /// chargingRateUnit that the EV should follow on phase L3
     /// as closely as possible
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "setpointReactive_L3",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub setpoint_reactive_l3: Option<f64>,
 
     /// Optional. (2.1) If true, the EV should attempt to keep the
     /// BMS preconditioned for this time interval.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub precondition_request: Option<bool>,
+    pub preconditioning_request: Option<bool>,
 
     /// Optional. (2.1) If true, the EVSE must turn off power
     /// electronics/modules associated with this transaction.
@@ -131,8 +139,11 @@ pub struct ChargingSchedulePeriodType {
     /// determines the value of setpoint power for a given signal.
     /// chargingRateUnit must be W for LocalFrequency
     /// control.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "v2xSignalWattCurve", skip_serializing_if = "Option::is_none")]
     pub v2x_signal_watt_point_type: Option<Vec<V2XSignalWattPointType>>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 #[typetag::serde]
@@ -173,6 +184,14 @@ impl OcppEntity for ChargingSchedulePeriodType {
             );
         }
 
+        if let Some(operation_mode) = &self.operation_mode {
+            e.check_enum("operation_mode", operation_mode);
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("ChargingSchedulePeriodType")
     }
 }
@@ -199,12 +218,13 @@ mod tests {
             setpoint_reactive: Some(2.0),
             setpoint_reactive_l2: Some(1.0),
             setpoint_reactive_l3: None,
-            precondition_request: None,
+            preconditioning_request: None,
             evse_sleep: None,
             v2x_baseline: None,
             operation_mode: None,
             v2x_freq_watt_curve: None,
             v2x_signal_watt_point_type: None,
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&period).unwrap();
@@ -232,12 +252,13 @@ mod tests {
             setpoint_reactive: None,
             setpoint_reactive_l2: None,
             setpoint_reactive_l3: None,
-            precondition_request: None,
+            preconditioning_request: None,
             evse_sleep: None,
             v2x_baseline: None,
             operation_mode: None,
             v2x_freq_watt_curve: None,
             v2x_signal_watt_point_type: None,
+            custom_data: None,
         };
         assert!(period_minimal.validate().is_ok());
 
@@ -257,12 +278,13 @@ mod tests {
             setpoint_reactive: Some(3.0),
             setpoint_reactive_l2: Some(1.5),
             setpoint_reactive_l3: None,
-            precondition_request: None,
+            preconditioning_request: None,
             evse_sleep: None,
             v2x_baseline: None,
             operation_mode: None,
             v2x_freq_watt_curve: None,
             v2x_signal_watt_point_type: None,
+            custom_data: None,
         };
         assert!(period_with_limits.validate().is_ok());
     }
@@ -285,12 +307,13 @@ mod tests {
             setpoint_reactive: None,
             setpoint_reactive_l2: None,
             setpoint_reactive_l3: None,
-            precondition_request: None,
+            preconditioning_request: None,
             evse_sleep: None,
             v2x_baseline: None,
             operation_mode: None,
             v2x_freq_watt_curve: None,
             v2x_signal_watt_point_type: None,
+            custom_data: None,
         };
         assert!(period.validate().is_err());
     }
@@ -313,12 +336,13 @@ mod tests {
             setpoint_reactive: None,
             setpoint_reactive_l2: None,
             setpoint_reactive_l3: None,
-            precondition_request: None,
+            preconditioning_request: None,
             evse_sleep: None,
             v2x_baseline: None,
             operation_mode: None,
             v2x_freq_watt_curve: None,
             v2x_signal_watt_point_type: None,
+            custom_data: None,
         };
         assert!(period_low.validate().is_err());
 
@@ -338,12 +362,13 @@ mod tests {
             setpoint_reactive: None,
             setpoint_reactive_l2: None,
             setpoint_reactive_l3: None,
-            precondition_request: None,
+            preconditioning_request: None,
             evse_sleep: None,
             v2x_baseline: None,
             operation_mode: None,
             v2x_freq_watt_curve: None,
             v2x_signal_watt_point_type: None,
+            custom_data: None,
         };
         assert!(period_high.validate().is_err());
     }
@@ -366,12 +391,13 @@ mod tests {
             setpoint_reactive: None,
             setpoint_reactive_l2: None,
             setpoint_reactive_l3: None,
-            precondition_request: None,
+            preconditioning_request: None,
             evse_sleep: None,
             v2x_baseline: None,
             operation_mode: None,
             v2x_freq_watt_curve: None,
             v2x_signal_watt_point_type: None,
+            custom_data: None,
         };
         assert!(period.validate().is_err());
     }
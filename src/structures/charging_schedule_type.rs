@@ -2,7 +2,9 @@ use crate::enums::charging_rate_unit_enum_type::ChargingRateUnitEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::absolute_price_schedule_type::AbsolutePriceScheduleType;
 use crate::structures::charging_schedule_period_type::ChargingSchedulePeriodType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::limit_at_soc_type::LimitAtSOCType;
+use crate::structures::price_level_schedule_type::PriceLevelScheduleType;
 use crate::structures::sales_tariff_type::SalesTariffType;
 use crate::traits::OcppEntity;
 use chrono::{DateTime, Utc};
@@ -10,6 +12,7 @@ use serde::{Deserialize, Serialize};
 
 /// Represents a charging schedule.
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct ChargingScheduleType {
     /// Required.
     pub id: i32,
@@ -66,10 +69,35 @@ pub struct ChargingScheduleType {
     pub absolute_price_schedule: Option<AbsolutePriceScheduleType>,
     /// Optional. The ISO 15118-20 price level schedule.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub price_level_schedule: Option<String>,
+    pub price_level_schedule: Option<PriceLevelScheduleType>,
     /// Optional. When present and SoC of EV is greater than or equal to soc, then charging limit or setpoint will be capped to the value of limit.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "limitAtSoC", skip_serializing_if = "Option::is_none")]
     pub limit_at_soc: Option<LimitAtSOCType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+impl Default for ChargingScheduleType {
+    fn default() -> Self {
+        Self {
+            id: 0,
+            start_schedule: None,
+            duration: None,
+            charging_rate_unit: ChargingRateUnitEnumType::W,
+            min_charging_rate: None,
+            power_tolerance: None,
+            signature_id: None,
+            digest_value: None,
+            use_local_time: None,
+            randomized_delay: None,
+            sales_tariff: None,
+            charging_schedule_period: vec![Default::default()],
+            absolute_price_schedule: None,
+            price_level_schedule: None,
+            limit_at_soc: None,
+            custom_data: None,
+        }
+    }
 }
 #[typetag::serde]
 impl OcppEntity for ChargingScheduleType {
@@ -98,6 +126,10 @@ impl OcppEntity for ChargingScheduleType {
             e.check_member("absolute_price_schedule", absolute_price_schedule);
         }
 
+        if let Some(price_level_schedule) = &self.price_level_schedule {
+            e.check_member("price_level_schedule", price_level_schedule);
+        }
+
         if let Some(limit_at_soc) = &self.limit_at_soc {
             e.check_member("limit_at_soc", limit_at_soc);
         }
@@ -113,6 +145,12 @@ impl OcppEntity for ChargingScheduleType {
             self.charging_schedule_period.iter(),
         );
 
+        e.check_enum("charging_rate_unit", &self.charging_rate_unit);
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("ChargingScheduleType")
     }
 }
@@ -139,8 +177,9 @@ mod tests {
             sales_tariff: Some(Default::default()),
             charging_schedule_period: vec![Default::default()],
             absolute_price_schedule: None,
-            price_level_schedule: Some("price_level_schedule_placeholder".to_string()),
+            price_level_schedule: None,
             limit_at_soc: Some(Default::default()),
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&schedule).unwrap();
@@ -168,6 +207,7 @@ mod tests {
             absolute_price_schedule: None,
             price_level_schedule: None,
             limit_at_soc: None,
+            custom_data: None,
         };
         if let Err(error) = schedule.validate() {
             println!("{:#?}", error)
@@ -193,6 +233,7 @@ mod tests {
             absolute_price_schedule: None,
             price_level_schedule: None,
             limit_at_soc: None,
+            custom_data: None,
         };
         let err = schedule.validate().unwrap_err();
         assert_num_field_errors(&err, 1);
@@ -217,6 +258,7 @@ mod tests {
             absolute_price_schedule: None,
             price_level_schedule: None,
             limit_at_soc: None,
+            custom_data: None,
         };
         let err = schedule.validate().unwrap_err();
         assert_num_field_errors(&err, 1);
@@ -241,6 +283,7 @@ mod tests {
             absolute_price_schedule: None,
             price_level_schedule: None,
             limit_at_soc: None,
+            custom_data: None,
         };
         let err = schedule.validate().unwrap_err();
         assert_num_field_errors(&err, 1);
@@ -266,6 +309,7 @@ mod tests {
             absolute_price_schedule: None,
             price_level_schedule: None,
             limit_at_soc: None,
+            custom_data: None,
         };
         let err_too_few = schedule_too_few.validate().unwrap_err();
         assert_num_field_errors(&err_too_few, 1);
@@ -288,6 +332,7 @@ mod tests {
             absolute_price_schedule: None,
             price_level_schedule: None,
             limit_at_soc: None,
+            custom_data: None,
         };
         let err_too_many = schedule_too_many.validate().unwrap_err();
         assert_num_field_errors(&err_too_many, 1);
@@ -312,6 +357,7 @@ mod tests {
             absolute_price_schedule: None,
             price_level_schedule: None,
             limit_at_soc: None,
+            custom_data: None,
         };
         let err = schedule.validate().unwrap_err();
         assert_invalid_fields(
@@ -1,10 +1,12 @@
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
 
 /// Updates to a ChargingSchedulePeriodType for dynamic charging profiles.
 /// Used by: PullDynamicScheduleUpdateResponse, UpdateDynamicScheduleRequest
-#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
 pub struct ChargingScheduleUpdateType {
     /// Optional. Only when not required by the ChargingRateUnit or ChargingRateUnit.Setpoint.
     /// Internal.evse.LocalFrequency, Local.GridBalancing, Local.LoadBalancing.
@@ -17,11 +19,11 @@ pub struct ChargingScheduleUpdateType {
     pub limit: Option<f64>,
 
     /// Optional. Charging rate limit on phase L2 in the applicable ChargingRateUnit.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "limit_L2", skip_serializing_if = "Option::is_none")]
     pub limit_l2: Option<f64>,
 
     /// Optional. Charging rate limit on phase L3 in the applicable ChargingRateUnit.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "limit_L3", skip_serializing_if = "Option::is_none")]
     pub limit_l3: Option<f64>,
 
     /// Optional. Limit in ChargingRateUnit that the EV is allowed to discharge with.
@@ -34,12 +36,12 @@ pub struct ChargingScheduleUpdateType {
 
     /// Optional. Limit in ChargingRateUnit on phase L2 that the EV is allowed to discharge with.
     /// Constraints: val <= 0
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "dischargeLimit_L2", skip_serializing_if = "Option::is_none")]
     pub discharge_limit_l2: Option<f64>,
 
     /// Optional. Limit in ChargingRateUnit on phase L3 that the EV is allowed to discharge with.
     /// Constraints: val <= 0
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "dischargeLimit_L3", skip_serializing_if = "Option::is_none")]
     pub discharge_limit_l3: Option<f64>,
 
     /// Optional. Setpoint in ChargingRateUnit that the EV is allowed to discharge with.
@@ -50,11 +52,11 @@ pub struct ChargingScheduleUpdateType {
     pub setpoint: Option<f64>,
 
     /// Optional. Setpoint in ChargingRateUnit that the EV should follow on phase L2 as close as possible.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "setpoint_L2", skip_serializing_if = "Option::is_none")]
     pub setpoint_l2: Option<f64>,
 
     /// Optional. Setpoint in ChargingRateUnit that the EV should follow on phase L3 as close as possible.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "setpoint_L3", skip_serializing_if = "Option::is_none")]
     pub setpoint_l3: Option<f64>,
 
     /// Optional. Setpoint for reactive power (or current) in ChargingRateUnit that the EV should follow.
@@ -65,12 +67,21 @@ pub struct ChargingScheduleUpdateType {
     pub setpoint_reactive: Option<f64>,
 
     /// Optional. Setpoint for reactive power (or current) in ChargingRateUnit that the EV should follow on phase L2 as closely as possible.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "setpointReactive_L2",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub setpoint_reactive_l2: Option<f64>,
 
     /// Optional. Setpoint for reactive power (or current) in ChargingRateUnit that the EV should follow on phase L3 as closely as possible.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "setpointReactive_L3",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub setpoint_reactive_l3: Option<f64>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for ChargingScheduleUpdateType {
@@ -94,6 +105,10 @@ impl OcppEntity for ChargingScheduleUpdateType {
             e.check_bounds("discharge_limit_l3", f64::MIN, 0.0, discharge_limit_l3);
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("ChargingScheduleUpdateType")
     }
 }
@@ -118,6 +133,7 @@ mod tests {
             setpoint_reactive: Some(1.0),
             setpoint_reactive_l2: Some(0.5),
             setpoint_reactive_l3: Some(0.5),
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&update).unwrap();
@@ -142,6 +158,7 @@ mod tests {
             setpoint_reactive: None,
             setpoint_reactive_l2: None,
             setpoint_reactive_l3: None,
+            custom_data: None,
         };
         assert!(update_minimal.validate().is_ok());
 
@@ -158,6 +175,7 @@ mod tests {
             setpoint_reactive: Some(3.0),
             setpoint_reactive_l2: Some(1.5),
             setpoint_reactive_l3: Some(1.5),
+            custom_data: None,
         };
         assert!(update_full.validate().is_ok());
     }
@@ -177,6 +195,7 @@ mod tests {
             setpoint_reactive: None,
             setpoint_reactive_l2: None,
             setpoint_reactive_l3: None,
+            custom_data: None,
         };
         let err = update.validate().unwrap_err();
         assert_num_field_errors(&err, 1);
@@ -198,6 +217,7 @@ mod tests {
             setpoint_reactive: None,
             setpoint_reactive_l2: None,
             setpoint_reactive_l3: None,
+            custom_data: None,
         };
         let err = update.validate().unwrap_err();
         assert_num_field_errors(&err, 1);
@@ -219,6 +239,7 @@ mod tests {
             setpoint_reactive: None,
             setpoint_reactive_l2: None,
             setpoint_reactive_l3: None,
+            custom_data: None,
         };
         let err = update.validate().unwrap_err();
         assert_num_field_errors(&err, 1);
@@ -240,6 +261,7 @@ mod tests {
             setpoint_reactive: None,
             setpoint_reactive_l2: None,
             setpoint_reactive_l3: None,
+            custom_data: None,
         };
         let err = update.validate().unwrap_err();
         assert_invalid_fields(
@@ -1,4 +1,5 @@
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::modem_type::ModemType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
@@ -6,6 +7,7 @@ use serde::{Deserialize, Serialize};
 /// The physical system where an Electrical Vehicle (EV) can be charged.
 /// Used by: BootNotificationRequest
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct ChargingStationType {
     /// Optional. Vendor-specific device identifier.
     /// String length: 0..25
@@ -24,6 +26,9 @@ pub struct ChargingStationType {
     /// Optional. Defines the functional parameters of a communication link.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub modem: Option<ModemType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for ChargingStationType {
@@ -34,6 +39,7 @@ impl Default for ChargingStationType {
             vendor_name: "".to_string(),
             firmware_version: None,
             modem: None,
+            custom_data: None,
         }
     }
 }
@@ -59,6 +65,10 @@ impl OcppEntity for ChargingStationType {
             e.check_member("modem", modem);
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("ChargingStationType")
     }
 }
@@ -76,6 +86,7 @@ mod tests {
             vendor_name: "VendorCorp".to_string(),
             firmware_version: Some("1.0.0".to_string()),
             modem: Some(Default::default()), // Placeholder
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&cs).unwrap();
@@ -93,6 +104,7 @@ mod tests {
             vendor_name: "MyVendor".to_string(),
             firmware_version: None,
             modem: None,
+            custom_data: None,
         };
         assert!(cs_minimal.validate().is_ok());
 
@@ -102,6 +114,7 @@ mod tests {
             vendor_name: "c".repeat(50),
             firmware_version: Some("d".repeat(50)),
             modem: None,
+            custom_data: None,
         };
         assert!(cs_full_lengths.validate().is_ok());
     }
@@ -114,6 +127,7 @@ mod tests {
             vendor_name: "VendorCorp".to_string(),
             firmware_version: None,
             modem: None,
+            custom_data: None,
         };
         let err = cs.validate().unwrap_err();
         assert_num_field_errors(&err, 1);
@@ -128,6 +142,7 @@ mod tests {
             vendor_name: "VendorCorp".to_string(),
             firmware_version: None,
             modem: None,
+            custom_data: None,
         };
         let err = cs.validate().unwrap_err();
         assert_num_field_errors(&err, 1);
@@ -142,6 +157,7 @@ mod tests {
             vendor_name: "a".repeat(51), // Invalid
             firmware_version: None,
             modem: None,
+            custom_data: None,
         };
         let err = cs.validate().unwrap_err();
         assert_num_field_errors(&err, 1);
@@ -156,6 +172,7 @@ mod tests {
             vendor_name: "VendorCorp".to_string(),
             firmware_version: Some("a".repeat(51)), // Invalid
             modem: None,
+            custom_data: None,
         };
         let err = cs.validate().unwrap_err();
         assert_num_field_errors(&err, 1);
@@ -170,6 +187,7 @@ mod tests {
             vendor_name: "c".repeat(51),            // Invalid 3
             firmware_version: Some("d".repeat(51)), // Invalid 4
             modem: None,
+            custom_data: None,
         };
         let err = cs.validate().unwrap_err();
         assert_invalid_fields(
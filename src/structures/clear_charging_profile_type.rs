@@ -1,11 +1,13 @@
 use crate::enums::charging_profile_purpose_enum_type::ChargingProfilePurposeEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
 
 /// A ClearChargingProfileType is a filter for charging profiles to be cleared by ClearChargingProfileRequest.
 /// Used by: ClearChargingProfileRequest
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct ClearChargingProfileType {
     /// Optional. Specifies the id of the EVSE for which to clear charging profiles.
     /// An evseId of zero (0) specifies the charging profile for the overall Charging Station.
@@ -23,6 +25,9 @@ pub struct ClearChargingProfileType {
     /// Constraints: 0 <= val
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stack_level: Option<i32>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for ClearChargingProfileType {
@@ -39,6 +44,14 @@ impl OcppEntity for ClearChargingProfileType {
             e.check_bounds("stack_level", 0, i32::MAX, stack_level);
         }
 
+        if let Some(charging_profile_purpose) = &self.charging_profile_purpose {
+            e.check_enum("charging_profile_purpose", charging_profile_purpose);
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("ClearChargingProfileType")
     }
 }
@@ -54,6 +67,7 @@ mod tests {
             evse_id: Some(1),
             charging_profile_purpose: Some(ChargingProfilePurposeEnumType::TxProfile),
             stack_level: Some(0),
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&clear_profile).unwrap();
@@ -69,6 +83,7 @@ mod tests {
             evse_id: None,
             charging_profile_purpose: None,
             stack_level: None,
+            custom_data: None,
         };
         assert!(clear_profile_minimal.validate().is_ok());
 
@@ -78,6 +93,7 @@ mod tests {
                 ChargingProfilePurposeEnumType::ChargingStationMaxProfile,
             ),
             stack_level: Some(10), // Valid
+            custom_data: None,
         };
         assert!(clear_profile_full.validate().is_ok());
     }
@@ -88,6 +104,7 @@ mod tests {
             evse_id: Some(-1), // Invalid
             charging_profile_purpose: None,
             stack_level: None,
+            custom_data: None,
         };
         let err = clear_profile.validate().unwrap_err();
         assert_num_field_errors(&err, 1);
@@ -100,6 +117,7 @@ mod tests {
             evse_id: None,
             charging_profile_purpose: None,
             stack_level: Some(-1), // Invalid
+            custom_data: None,
         };
         let err = clear_profile.validate().unwrap_err();
         assert_num_field_errors(&err, 1);
@@ -112,6 +130,7 @@ mod tests {
             evse_id: Some(-5), // Invalid 1
             charging_profile_purpose: Some(ChargingProfilePurposeEnumType::TxProfile),
             stack_level: Some(-2), // Invalid 2
+            custom_data: None,
         };
         let err = clear_profile.validate().unwrap_err();
         assert_invalid_fields(&err, &["evse_id", "stack_level"]);
@@ -1,5 +1,6 @@
 use crate::enums::clear_monitoring_status_enum_type::ClearMonitoringStatusEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::status_info_type::StatusInfoType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
@@ -7,6 +8,7 @@ use serde::{Deserialize, Serialize};
 /// Result of a clear monitoring request.
 /// Used by: ClearVariableMonitoringResponse
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct ClearMonitoringResultType {
     /// Required. Result of the clear request for this monitor, identified by its id.
     pub status: ClearMonitoringStatusEnumType,
@@ -16,6 +18,9 @@ pub struct ClearMonitoringResultType {
     /// Optional. Element providing more information about the status.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for ClearMonitoringResultType {
@@ -30,6 +35,12 @@ impl OcppEntity for ClearMonitoringResultType {
             e.check_member("status_info", status_info);
         }
 
+        e.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("ClearMonitoringResultType")
     }
 }
@@ -40,6 +51,7 @@ impl Default for ClearMonitoringResultType {
             status: ClearMonitoringStatusEnumType::Accepted,
             id: 0,
             status_info: None,
+            custom_data: None,
         }
     }
 }
@@ -55,6 +67,7 @@ mod tests {
             status: ClearMonitoringStatusEnumType::Accepted,
             id: 123,
             status_info: Some(Default::default()),
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&result).unwrap();
@@ -70,6 +83,7 @@ mod tests {
             status: ClearMonitoringStatusEnumType::Rejected,
             id: 0, // Valid
             status_info: None,
+            custom_data: None,
         };
         assert!(result_minimal.validate().is_ok());
 
@@ -77,6 +91,7 @@ mod tests {
             status: ClearMonitoringStatusEnumType::Accepted,
             id: 456, // Valid
             status_info: Some(Default::default()),
+            custom_data: None,
         };
         assert!(result_positive_id.validate().is_ok());
     }
@@ -87,6 +102,7 @@ mod tests {
             status: ClearMonitoringStatusEnumType::Rejected,
             id: -1, // Invalid
             status_info: None,
+            custom_data: None,
         };
         let err = result.validate().unwrap_err();
         assert_num_field_errors(&err, 1);
@@ -1,5 +1,6 @@
 use crate::enums::tariff_clear_status_enum_type::TariffClearStatusEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::status_info_type::StatusInfoType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
@@ -7,6 +8,7 @@ use serde::{Deserialize, Serialize};
 /// Result of a clear tariffs request.
 /// Used by: ClearTariffsResponse
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct ClearTariffsResultType {
     /// Optional. Id of tariff for which status is reported.
     /// If no tariffs were found, then this field is absent, and status will be NoTariff.
@@ -18,6 +20,9 @@ pub struct ClearTariffsResultType {
     /// Optional. Additional info on status.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for ClearTariffsResultType {
@@ -26,6 +31,7 @@ impl Default for ClearTariffsResultType {
             tariff_id: None,
             status: TariffClearStatusEnumType::Accepted,
             status_info: None,
+            custom_data: None,
         }
     }
 }
@@ -45,6 +51,12 @@ impl OcppEntity for ClearTariffsResultType {
             e.check_member("status_info", status_info);
         }
 
+        e.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("ClearTariffsResultType")
     }
 }
@@ -60,6 +72,7 @@ mod tests {
             tariff_id: Some("TARIFF_XYZ_789".to_string()),
             status: TariffClearStatusEnumType::Accepted,
             status_info: Some(Default::default()), // Placeholder
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&result).unwrap();
@@ -75,6 +88,7 @@ mod tests {
             tariff_id: None,
             status: TariffClearStatusEnumType::Accepted,
             status_info: None,
+            custom_data: None,
         };
         assert!(result_minimal.validate().is_ok());
 
@@ -82,6 +96,7 @@ mod tests {
             tariff_id: Some("a".repeat(60)), // Valid length
             status: TariffClearStatusEnumType::Accepted,
             status_info: Some(Default::default()),
+            custom_data: None,
         };
         assert!(result_with_id.validate().is_ok());
     }
@@ -92,6 +107,7 @@ mod tests {
             tariff_id: Some("a".repeat(61)), // Invalid
             status: TariffClearStatusEnumType::Accepted,
             status_info: None,
+            custom_data: None,
         };
         let err = result.validate().unwrap_err();
         assert_num_field_errors(&err, 1);
@@ -1,4 +1,5 @@
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::evse_type::EVSEType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
@@ -10,6 +11,7 @@ use serde::{Deserialize, Serialize};
 /// SetVariableMonitoringRequest.SetMonitoringDataType, SetVariableMonitoringResponse.SetMonitoringResultType,
 /// SetVariablesRequest.SetVariableDataType, SetVariablesResponse.SetVariableResultType, NotifyEventRequest.EventDataType
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct ComponentType {
     /// Required. Name of the component. Name should be taken from the list of standardized component names
     /// whenever possible. Case Insensitive. strongly advised to use Camel Case.
@@ -24,6 +26,9 @@ pub struct ComponentType {
     /// component is located at Connector level.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub evse: Option<EVSEType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for ComponentType {
@@ -32,6 +37,7 @@ impl Default for ComponentType {
             name: "".to_string(),
             instance: None,
             evse: None,
+            custom_data: None,
         }
     }
 }
@@ -53,6 +59,10 @@ impl OcppEntity for ComponentType {
             e.check_member("evse", evse);
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("ComponentType")
     }
 }
@@ -68,6 +78,7 @@ mod tests {
             name: "Meter".to_string(),
             instance: Some("Main".to_string()),
             evse: Some(Default::default()), // Placeholder
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&component).unwrap();
@@ -83,6 +94,7 @@ mod tests {
             name: "Core".to_string(),
             instance: None,
             evse: None,
+            custom_data: None,
         };
         assert!(component_minimal.validate().is_ok());
 
@@ -90,6 +102,7 @@ mod tests {
             name: "a".repeat(50),           // Valid length
             instance: Some("b".repeat(50)), // Valid length
             evse: Some(Default::default()),
+            custom_data: None,
         };
         assert!(component_full_lengths.validate().is_ok());
     }
@@ -100,6 +113,7 @@ mod tests {
             name: "a".repeat(51), // Invalid
             instance: None,
             evse: None,
+            custom_data: None,
         };
         let err = component.validate().unwrap_err();
         assert_num_field_errors(&err, 1);
@@ -112,6 +126,7 @@ mod tests {
             name: "ValidName".to_string(),
             instance: Some("a".repeat(51)), // Invalid
             evse: None,
+            custom_data: None,
         };
         let err = component.validate().unwrap_err();
         assert_num_field_errors(&err, 1);
@@ -124,6 +139,7 @@ mod tests {
             name: "a".repeat(51),           // Invalid 1
             instance: Some("b".repeat(51)), // Invalid 2
             evse: None,
+            custom_data: None,
         };
         let err = component.validate().unwrap_err();
         assert_invalid_fields(&err, &["name", "instance"]);
@@ -1,14 +1,19 @@
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::component_type::ComponentType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::variable_type::VariableType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct ComponentVariableType {
     pub component: ComponentType,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub variable: Option<VariableType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for ComponentVariableType {
@@ -19,6 +24,10 @@ impl OcppEntity for ComponentVariableType {
             e.check_member("variable", variable);
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("ComponentVariableType")
     }
 }
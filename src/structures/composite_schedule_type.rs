@@ -1,12 +1,14 @@
 use crate::enums::charging_rate_unit_enum_type::ChargingRateUnitEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::charging_schedule_period_type::ChargingSchedulePeriodType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 /// CompositeScheduleType is used by: GetCompositeScheduleResponse
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct CompositeScheduleType {
     /// Required.
     /// Constraints: 0 <= val
@@ -20,6 +22,9 @@ pub struct CompositeScheduleType {
     /// Required. List of ChargingSchedulePeriod elements defining maximum power or current over time.
     /// Cardinality 1..*
     pub charging_schedule_period: Vec<ChargingSchedulePeriodType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for CompositeScheduleType {
@@ -39,6 +44,11 @@ impl OcppEntity for CompositeScheduleType {
             "charging_schedule_period",
             self.charging_schedule_period.iter(),
         );
+        e.check_enum("charging_rate_unit", &self.charging_rate_unit);
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
         e.build("CompositeScheduletype")
     }
 }
@@ -57,6 +67,7 @@ mod tests {
             schedule_start: Utc.with_ymd_and_hms(2025, 8, 1, 10, 0, 0).unwrap(),
             charging_rate_unit: ChargingRateUnitEnumType::A, // Placeholder
             charging_schedule_period: vec![Default::default(), Default::default()], // Placeholder
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&schedule).unwrap();
@@ -74,6 +85,7 @@ mod tests {
             schedule_start: Utc.with_ymd_and_hms(2025, 8, 1, 10, 0, 0).unwrap(),
             charging_rate_unit: ChargingRateUnitEnumType::W,
             charging_schedule_period: vec![Default::default()], // Valid cardinality
+            custom_data: None,
         };
         assert!(schedule.validate().is_ok());
 
@@ -87,6 +99,7 @@ mod tests {
                 Default::default(),
                 Default::default(),
             ],
+            custom_data: None,
         };
         assert!(schedule_multiple_periods.validate().is_ok());
     }
@@ -99,6 +112,7 @@ mod tests {
             schedule_start: Utc.with_ymd_and_hms(2025, 8, 1, 10, 0, 0).unwrap(),
             charging_rate_unit: ChargingRateUnitEnumType::W,
             charging_schedule_period: vec![Default::default()],
+            custom_data: None,
         };
         let err = schedule.validate().unwrap_err();
         assert_num_field_errors(&err, 1);
@@ -113,6 +127,7 @@ mod tests {
             schedule_start: Utc.with_ymd_and_hms(2025, 8, 1, 10, 0, 0).unwrap(),
             charging_rate_unit: ChargingRateUnitEnumType::W,
             charging_schedule_period: vec![], // Invalid cardinality
+            custom_data: None,
         };
         let err = schedule.validate().unwrap_err();
         assert_num_field_errors(&err, 1);
@@ -1,10 +1,12 @@
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::periodic_event_stream_params_type::PeriodicEventStreamParamsType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
 
 /// ConstantStreamDataType is used by: OpenPeriodicEventStreamRequest, GetPeriodicEventStreamResponse
-#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
 pub struct ConstantStreamDataType {
     /// Required. Uniquely identifies the stream
     /// Constraints: 0 <= val
@@ -14,6 +16,9 @@ pub struct ConstantStreamDataType {
     pub variable_monitoring_id: i32,
     /// Required. Max time and items parameters
     pub params: PeriodicEventStreamParamsType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for ConstantStreamDataType {
@@ -29,6 +34,10 @@ impl OcppEntity for ConstantStreamDataType {
             self.variable_monitoring_id,
         );
         e.check_member("params", &self.params);
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("ConstantStreamDataType")
     }
 }
@@ -44,6 +53,7 @@ mod tests {
             id: 1,
             variable_monitoring_id: 101,
             params: Default::default(),
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&stream_data).unwrap();
@@ -59,6 +69,7 @@ mod tests {
             id: 0,                     // Valid
             variable_monitoring_id: 0, // Valid
             params: Default::default(),
+            custom_data: None,
         };
         assert!(stream_data.validate().is_ok());
 
@@ -66,6 +77,7 @@ mod tests {
             id: 123,
             variable_monitoring_id: 456,
             params: Default::default(),
+            custom_data: None,
         };
         assert!(stream_data_positive.validate().is_ok());
     }
@@ -76,6 +88,7 @@ mod tests {
             id: -1, // Invalid
             variable_monitoring_id: 101,
             params: Default::default(),
+            custom_data: None,
         };
         let err = stream_data.validate().unwrap_err();
         assert_num_field_errors(&err, 1);
@@ -88,6 +101,7 @@ mod tests {
             id: 1,
             variable_monitoring_id: -101, // Invalid
             params: Default::default(),
+            custom_data: None,
         };
         let err = stream_data.validate().unwrap_err();
         assert_num_field_errors(&err, 1);
@@ -100,6 +114,7 @@ mod tests {
             id: -1,                       // Invalid 1
             variable_monitoring_id: -101, // Invalid 2
             params: Default::default(),
+            custom_data: None,
         };
         let err = stream_data.validate().unwrap_err();
         assert_invalid_fields(&err, &["id", "variable_monitoring_id"]);
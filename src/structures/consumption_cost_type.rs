@@ -1,10 +1,12 @@
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::cost_type::CostType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
 
 /// ConsumptionCostType is used by: Common::SalesTariffEntryType
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct ConsumptionCostType {
     /// Required. The lowest level of consumption that defines the starting point of this consumption block.
     /// The block interval extends to the start of the next interval.
@@ -12,6 +14,9 @@ pub struct ConsumptionCostType {
     /// Required. This field contains the cost details.
     /// Cardinality 1..3
     pub cost: Vec<CostType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for ConsumptionCostType {
@@ -19,6 +24,7 @@ impl Default for ConsumptionCostType {
         Self {
             start_value: 0.0,
             cost: vec![CostType::default()],
+            custom_data: None,
         }
     }
 }
@@ -30,6 +36,10 @@ impl OcppEntity for ConsumptionCostType {
         let mut e = StructureValidationBuilder::new();
         e.check_cardinality("cost", 1, 3, &self.cost.iter());
         e.check_iter_member("cost", self.cost.iter());
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("ConsumptionCostType")
     }
 }
@@ -44,6 +54,7 @@ mod tests {
         let consumption_cost = ConsumptionCostType {
             start_value: 0.0,
             cost: vec![CostType::default()], // Placeholder
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&consumption_cost).unwrap();
@@ -58,12 +69,14 @@ mod tests {
         let consumption_cost_single = ConsumptionCostType {
             start_value: 10.5,
             cost: vec![CostType::default()],
+            custom_data: None,
         };
         assert!(consumption_cost_single.validate().is_ok());
 
         let consumption_cost_max_cardinality = ConsumptionCostType {
             start_value: 100.0,
             cost: vec![CostType::default(); 3],
+            custom_data: None,
         };
         assert!(consumption_cost_max_cardinality.validate().is_ok());
     }
@@ -73,6 +86,7 @@ mod tests {
         let consumption_cost = ConsumptionCostType {
             start_value: 0.0,
             cost: vec![], // Invalid cardinality
+            custom_data: None,
         };
         let err = consumption_cost.validate().unwrap_err();
         assert_num_field_errors(&err, 1);
@@ -84,6 +98,7 @@ mod tests {
         let consumption_cost = ConsumptionCostType {
             start_value: 0.0,
             cost: vec![CostType::default(); 4], // Invalid cardinality
+            custom_data: None,
         };
         let err = consumption_cost.validate().unwrap_err();
         assert_num_field_errors(&err, 1);
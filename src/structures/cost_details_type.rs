@@ -1,5 +1,6 @@
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::charging_period_type::ChargingPeriodType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::total_cost_type::TotalCostType;
 use crate::structures::total_usage_type::TotalUsageType;
 use crate::traits::OcppEntity;
@@ -8,6 +9,7 @@ use serde::{Deserialize, Serialize};
 /// CostDetailsType contains the cost as calculated by Charging Station based on provided TariffType.
 /// Used by: TransactionEventRequest
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct CostDetailsType {
     /// Optional. If set to true, then Charging Station has failed to calculate the cost.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -27,6 +29,9 @@ pub struct CostDetailsType {
     pub total_cost: TotalCostType,
     /// Required. Total usage of energy and time.
     pub total_usage: TotalUsageType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for CostDetailsType {
@@ -47,6 +52,10 @@ impl OcppEntity for CostDetailsType {
         e.check_member("total_cost", &self.total_cost);
         e.check_member("total_usage", &self.total_usage);
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("CostDetailsType")
     }
 }
@@ -64,6 +73,7 @@ mod tests {
             charging_periods: Some(vec![Default::default()]),
             total_cost: Default::default(),
             total_usage: Default::default(),
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&cost_details).unwrap();
@@ -81,6 +91,7 @@ mod tests {
             charging_periods: None,
             total_cost: Default::default(),
             total_usage: Default::default(),
+            custom_data: None,
         };
         assert!(cost_details_minimal.validate().is_ok());
 
@@ -90,6 +101,7 @@ mod tests {
             charging_periods: Some(vec![Default::default(), Default::default()]),
             total_cost: Default::default(),
             total_usage: Default::default(),
+            custom_data: None,
         };
         assert!(cost_details_full_lengths.validate().is_ok());
     }
@@ -102,6 +114,7 @@ mod tests {
             charging_periods: None,
             total_cost: Default::default(),
             total_usage: Default::default(),
+            custom_data: None,
         };
         let err = cost_details.validate().unwrap_err();
         assert_num_field_errors(&err, 1);
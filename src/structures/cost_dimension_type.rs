@@ -1,21 +1,34 @@
 use crate::enums::cost_dimension_enum_type::CostDimensionEnumType;
-use crate::errors::OcppError;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
 
 /// Volume consumed of cost dimension.
 /// Used by: Common::ChargingPeriodType
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct CostDimensionType {
     /// Required. Type of cost dimension: energy, power, time, etc.
     pub r#type: CostDimensionEnumType,
     /// Required. Volume of the dimension consumed, measured according to the dimension type.
     pub volume: f64, // decimal
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for CostDimensionType {
     fn validate(&self) -> Result<(), OcppError> {
-        Ok(())
+        let mut e = StructureValidationBuilder::new();
+
+        e.check_enum("type", &self.r#type);
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("CostDimensionType")
     }
 }
 
@@ -28,6 +41,7 @@ mod tests {
         let cost_dimension = CostDimensionType {
             r#type: CostDimensionEnumType::Energy,
             volume: 123.45,
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&cost_dimension).unwrap();
@@ -42,12 +56,14 @@ mod tests {
         let cost_dimension = CostDimensionType {
             r#type: CostDimensionEnumType::MaxPower,
             volume: 50.0,
+            custom_data: None,
         };
         assert!(cost_dimension.validate().is_ok());
 
         let cost_dimension_zero_volume = CostDimensionType {
             r#type: CostDimensionEnumType::ChargingTime,
             volume: 0.0,
+            custom_data: None,
         };
         assert!(cost_dimension_zero_volume.validate().is_ok());
     }
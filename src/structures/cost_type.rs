@@ -1,10 +1,12 @@
 use crate::enums::cost_kind_enum_type::CostKindEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
 
 /// CostType is used by: Common::ConsumptionCostType
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct CostType {
     /// Required. The kind of cost referred to in the message element amount.
     pub cost_kind: CostKindEnumType,
@@ -14,6 +16,9 @@ pub struct CostType {
     /// The final value is determined by: amount * 10 ^ amountMultiplier.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub amount_multiplier: Option<i32>, // integer
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for CostType {
@@ -22,6 +27,7 @@ impl Default for CostType {
             cost_kind: CostKindEnumType::CarbonDioxideEmission,
             amount: 0,
             amount_multiplier: None,
+            custom_data: None,
         }
     }
 }
@@ -37,6 +43,12 @@ impl OcppEntity for CostType {
             e.check_bounds("amount_multiplier", -3, 3, amount_multiplier);
         }
 
+        e.check_enum("cost_kind", &self.cost_kind);
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("CostType")
     }
 }
@@ -52,6 +64,7 @@ mod tests {
             cost_kind: CostKindEnumType::RelativePricePercentage,
             amount: 1500,
             amount_multiplier: Some(-2),
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&cost).unwrap();
@@ -67,6 +80,7 @@ mod tests {
             cost_kind: CostKindEnumType::RenewableGenerationPercentage,
             amount: 100,
             amount_multiplier: None,
+            custom_data: None,
         };
         assert!(cost_minimal.validate().is_ok());
 
@@ -74,6 +88,7 @@ mod tests {
             cost_kind: CostKindEnumType::CarbonDioxideEmission,
             amount: 250,
             amount_multiplier: Some(3), // Valid
+            custom_data: None,
         };
         assert!(cost_with_multiplier.validate().is_ok());
 
@@ -81,6 +96,7 @@ mod tests {
             cost_kind: CostKindEnumType::CarbonDioxideEmission,
             amount: 250,
             amount_multiplier: Some(-3), // Valid
+            custom_data: None,
         };
         assert!(cost_with_min_multiplier.validate().is_ok());
     }
@@ -91,6 +107,7 @@ mod tests {
             cost_kind: CostKindEnumType::RelativePricePercentage,
             amount: 100,
             amount_multiplier: Some(-4), // Invalid
+            custom_data: None,
         };
         let err = cost.validate().unwrap_err();
         assert_num_field_errors(&err, 1);
@@ -103,6 +120,7 @@ mod tests {
             cost_kind: CostKindEnumType::RelativePricePercentage,
             amount: 100,
             amount_multiplier: Some(4), // Invalid
+            custom_data: None,
         };
         let err = cost.validate().unwrap_err();
         assert_num_field_errors(&err, 1);
@@ -0,0 +1,160 @@
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::traits::OcppEntity;
+use serde::de::DeserializeOwned;
+use serde::ser::Error as _;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// A vendor-specific extension that can be carried in the `customData` of any message or
+/// structure. Implement this for a serde type to read and write it with
+/// [`CustomDataType::extension`] and [`CustomDataType::from_extension`].
+pub trait VendorExtension: Serialize + DeserializeOwned {
+    /// The vendorId that identifies this extension.
+    const VENDOR_ID: &'static str;
+}
+
+/// This class does not get 'AdditionalProperties = false' in the schema generation, so it can be
+/// extended with arbitrary JSON properties to allow adding custom data.
+/// Used by: every message and datatype
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomDataType {
+    /// Required. Identifies the vendor that defined the custom data.
+    /// String length: 0..255
+    pub vendor_id: String,
+    /// Vendor-specific properties, kept as-is so that they round-trip losslessly.
+    #[serde(flatten)]
+    pub properties: Map<String, Value>,
+}
+
+impl CustomDataType {
+    /// Create an empty CustomDataType for the given vendor.
+    pub fn new(vendor_id: &str) -> Self {
+        Self {
+            vendor_id: vendor_id.to_string(),
+            properties: Map::new(),
+        }
+    }
+
+    /// Get a vendor-specific property as `T`, or `None` if the property is absent.
+    pub fn get<T: DeserializeOwned>(&self, name: &str) -> Option<Result<T, serde_json::Error>> {
+        self.properties
+            .get(name)
+            .map(|value| T::deserialize(value.clone()))
+    }
+
+    /// Set a vendor-specific property, replacing any previous value.
+    pub fn set<T: Serialize>(&mut self, name: &str, value: T) -> Result<(), serde_json::Error> {
+        self.properties
+            .insert(name.to_string(), serde_json::to_value(value)?);
+        Ok(())
+    }
+
+    /// Read all vendor-specific properties as the extension `T`, or `None` if this custom data
+    /// belongs to another vendor.
+    pub fn extension<T: VendorExtension>(&self) -> Option<Result<T, serde_json::Error>> {
+        (self.vendor_id == T::VENDOR_ID)
+            .then(|| T::deserialize(Value::Object(self.properties.clone())))
+    }
+
+    /// Build the custom data carrying the extension `T`.
+    pub fn from_extension<T: VendorExtension>(extension: &T) -> Result<Self, serde_json::Error> {
+        match serde_json::to_value(extension)? {
+            Value::Object(mut properties) => {
+                properties.remove("vendorId");
+                Ok(Self {
+                    vendor_id: T::VENDOR_ID.to_string(),
+                    properties,
+                })
+            }
+            other => Err(serde_json::Error::custom(format!(
+                "a vendor extension must serialize to an object, got {other}"
+            ))),
+        }
+    }
+}
+
+#[typetag::serde]
+impl OcppEntity for CustomDataType {
+    /// Validates the fields of CustomDataType based on specified constraints.
+    /// Returns `Ok(())` if all values are valid, or `Err(OcppError::StructureValidationError)` if validation fails.
+    fn validate(&self) -> Result<(), OcppError> {
+        let mut e = StructureValidationBuilder::new();
+
+        e.check_cardinality("vendor_id", 0, 255, &self.vendor_id.chars());
+
+        e.build("CustomDataType")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct ParkingSensor {
+        occupied: bool,
+        last_change: String,
+    }
+
+    impl VendorExtension for ParkingSensor {
+        const VENDOR_ID: &'static str = "com.example.parking";
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(CustomDataType::new("com.example").validate().is_ok());
+        assert!(CustomDataType::new(&"a".repeat(256)).validate().is_err());
+    }
+
+    #[test]
+    fn test_serialization_keeps_vendor_properties() {
+        let payload = json!({
+            "vendorId": "com.example",
+            "firmwareChannel": "beta",
+            "sensors": [{"id": 1, "temperature": 21.5}],
+        });
+
+        let custom_data: CustomDataType = serde_json::from_value(payload.clone()).unwrap();
+        assert_eq!(custom_data.vendor_id, "com.example");
+        assert_eq!(custom_data.properties.len(), 2);
+        assert_eq!(serde_json::to_value(&custom_data).unwrap(), payload);
+    }
+
+    #[test]
+    fn test_get_and_set() {
+        let mut custom_data = CustomDataType::new("com.example");
+        custom_data.set("retries", 3).unwrap();
+
+        assert_eq!(custom_data.get::<i32>("retries").unwrap().unwrap(), 3);
+        assert!(custom_data.get::<String>("retries").unwrap().is_err());
+        assert!(custom_data.get::<i32>("missing").is_none());
+    }
+
+    #[test]
+    fn test_extension() {
+        let sensor = ParkingSensor {
+            occupied: true,
+            last_change: "2025-01-01T12:00:00Z".to_string(),
+        };
+
+        let custom_data = CustomDataType::from_extension(&sensor).unwrap();
+        assert_eq!(
+            serde_json::to_value(&custom_data).unwrap(),
+            json!({
+                "vendorId": "com.example.parking",
+                "occupied": true,
+                "lastChange": "2025-01-01T12:00:00Z",
+            })
+        );
+        assert_eq!(
+            custom_data.extension::<ParkingSensor>().unwrap().unwrap(),
+            sensor
+        );
+
+        let other = CustomDataType::new("com.example.other");
+        assert!(other.extension::<ParkingSensor>().is_none());
+    }
+}
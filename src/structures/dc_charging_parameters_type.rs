@@ -1,10 +1,12 @@
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
 
 /// EV DC charging parameters for ISO 15118-2
 /// Used by: Common::ChargingNeedsType
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct DCChargingParametersType {
     /// Required. Maximum current (in A) supported by the electric vehicle. Includes cable capacity.
     /// Relates to: ISO 15118-2: DC_EVChargeParameterType:EVMaximumCurrentLimit
@@ -32,13 +34,16 @@ pub struct DCChargingParametersType {
     /// Optional. Percentage of SoC at which the EV considers the battery fully charged. (possible values: 0 - 100)
     /// Relates to: ISO 15118-2: DC_EVChargeParameterType: FullSOC
     /// Constraints: 0 <= val <= 100
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "fullSoC", skip_serializing_if = "Option::is_none")]
     pub full_soc: Option<i32>, // integer
     /// Optional. Percentage of SoC at which the EV considers a fast charging process to end. (possible values: 0 - 100)
     /// Relates to: ISO 15118-2: DC_EVChargeParameterType: BulkSOC
     /// Constraints: 0 <= val <= 100
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "bulkSoC", skip_serializing_if = "Option::is_none")]
     pub bulk_soc: Option<i32>, // integer
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for DCChargingParametersType {
@@ -91,6 +96,10 @@ impl OcppEntity for DCChargingParametersType {
             e.check_bounds("bulk_soc", 0, 100, bulk_soc);
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("DCChargingParametersType")
     }
 }
@@ -111,6 +120,7 @@ mod tests {
             state_of_charge: Some(80),
             full_soc: Some(95),
             bulk_soc: Some(80),
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&dc_params).unwrap();
@@ -131,6 +141,7 @@ mod tests {
             state_of_charge: None,
             full_soc: None,
             bulk_soc: None,
+            custom_data: None,
         };
         assert!(dc_params_minimal.validate().is_ok());
 
@@ -143,6 +154,7 @@ mod tests {
             state_of_charge: Some(50),
             full_soc: Some(100),
             bulk_soc: Some(0),
+            custom_data: None,
         };
         assert!(dc_params_full.validate().is_ok());
     }
@@ -158,6 +170,7 @@ mod tests {
             state_of_charge: None,
             full_soc: None,
             bulk_soc: None,
+            custom_data: None,
         };
         let err = dc_params.validate().unwrap_err();
         assert_num_field_errors(&err, 1);
@@ -175,6 +188,7 @@ mod tests {
             energy_amount: None,
             full_soc: None,
             bulk_soc: None,
+            custom_data: None,
         };
         let err_low = dc_params_low.validate().unwrap_err();
         assert_num_field_errors(&err_low, 1);
@@ -189,6 +203,7 @@ mod tests {
             energy_amount: None,
             full_soc: None,
             bulk_soc: None,
+            custom_data: None,
         };
         let err_high = dc_params_high.validate().unwrap_err();
         assert_num_field_errors(&err_high, 1);
@@ -206,6 +221,7 @@ mod tests {
             state_of_charge: Some(101),     // Invalid 6
             full_soc: Some(-5),             // Invalid 7
             bulk_soc: Some(105),            // Invalid 8
+            custom_data: None,
         };
         let err = dc_params.validate().unwrap_err();
         assert_invalid_fields(
@@ -1,16 +1,21 @@
 use crate::enums::der_control_enum_type::DERControlEnumType;
 use crate::enums::islanding_detection_enum_type::IslandingDetectionEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
 
 /// DER DC charging parameters for ISO 15118-2
 /// Used by: Common::ChargingNeedsType
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
 pub struct DERChargingParametersType {
     /// Optional. DER control functions supported by EV.
     /// ISO 15118-20: DER_BPT_AC_CPDReqEnergyTransferModeType:DERControlFunctions (bitmap)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "evSupportedDERControl",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub ev_supported_der_control: Option<Vec<DERControlEnumType>>,
 
     /// Optional. Rated maximum injected active power by EV, at specified over-excited power factor (overExcitedPowerFactor)
@@ -60,13 +65,19 @@ pub struct DERChargingParametersType {
     /// Optional. Rated maximum absorbed apparent power on phase L2, defined by min(EV, EVSE) in va.
     /// Corresponds to the ChAMaxRtg in IEC 61850.
     /// ISO 15118-20: DER_BPT_AC_CPDReqEnergyTransferModeType: EVMaximumChargeApparentPower_L2
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "maxChargeApparentPower_L2",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub max_charge_apparent_power_l2: Option<f64>, // decimal
 
     /// Optional. Rated maximum absorbed apparent power on phase L3, defined by min(EV, EVSE) in va.
     /// Corresponds to the ChAMaxRtg in IEC 61850.
     /// ISO 15118-20: DER_BPT_AC_CPDReqEnergyTransferModeType: EVMaximumChargeApparentPower_L3
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "maxChargeApparentPower_L3",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub max_charge_apparent_power_l3: Option<f64>, // decimal
 
     /// Optional. Rated maximum injected apparent power, defined by min(EV, EVSE) in va.
@@ -80,13 +91,19 @@ pub struct DERChargingParametersType {
     /// Optional. Rated maximum injected apparent power on phase L2, defined by min(EV, EVSE) in va.
     /// Corresponds to the DisVAMaxRtg in IEC 61850.
     /// ISO 15118-20: DER_BPT_AC_CPDReqEnergyTransferModeType: EVMaximumDischargeApparentPower_L2
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "maxDischargeApparentPower_L2",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub max_discharge_apparent_power_l2: Option<f64>, // decimal
 
     /// Optional. Rated maximum injected apparent power on phase L3, defined by min(EV, EVSE) in va.
     /// Corresponds to the DisVAMaxRtg in IEC 61850.
     /// ISO 15118-20: DER_BPT_AC_CPDReqEnergyTransferModeType: EVMaximumDischargeApparentPower_L3
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "maxDischargeApparentPower_L3",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub max_discharge_apparent_power_l3: Option<f64>, // decimal
 
     /// Optional. Rated maximum absorbed reactive power, defined by min(EV, EVSE), in vars.
@@ -100,13 +117,19 @@ pub struct DERChargingParametersType {
     /// Optional. Rated maximum absorbed reactive power, defined by min(EV, EVSE), in vars on phase L2.
     /// Corresponds to the AvarMax attribute in the IEC 61850.
     /// ISO 15118-20: DER_BPT_AC_CPDReqEnergyTransferModeType: EVMaximumChargeReactivePower_L2
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "maxChargeReactivePower_L2",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub max_charge_reactive_power_l2: Option<f64>, // decimal
 
     /// Optional. Rated maximum absorbed reactive power, defined by min(EV, EVSE), in vars on phase L3.
     /// Corresponds to the AvarMax attribute in the IEC 61850.
     /// ISO 15118-20: DER_BPT_AC_CPDReqEnergyTransferModeType: EVMaximumChargeReactivePower_L3
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "maxChargeReactivePower_L3",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub max_charge_reactive_power_l3: Option<f64>, // decimal
 
     /// Optional. Rated minimum absorbed reactive power, defined by max(EV, EVSE), in vars.
@@ -120,13 +143,19 @@ pub struct DERChargingParametersType {
     /// Optional. Rated minimum absorbed reactive power, defined by max(EV, EVSE), in vars on phase L2.
     /// Corresponds to the AvarMin attribute in the IEC 61850.
     /// ISO 15118-20: DER_BPT_AC_CPDReqEnergyTransferModeType: EVMinimumChargeReactivePower_L2
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "minChargeReactivePower_L2",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub min_charge_reactive_power_l2: Option<f64>, // decimal
 
     /// Optional. Rated minimum absorbed reactive power, defined by max(EV, EVSE), in vars on phase L3.
     /// Corresponds to the AvarMin attribute in the IEC 61850.
     /// ISO 15118-20: DER_BPT_AC_CPDReqEnergyTransferModeType: EVMinimumChargeReactivePower_L3
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "minChargeReactivePower_L3",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub min_charge_reactive_power_l3: Option<f64>, // decimal
 
     /// Optional. Rated maximum injected reactive power, defined by min(EV, EVSE), in vars.
@@ -140,13 +169,19 @@ pub struct DERChargingParametersType {
     /// Optional. Rated maximum injected reactive power, defined by min(EV, EVSE), in vars on phase L2.
     /// Corresponds to the IvarMax attribute in the IEC 61850.
     /// ISO 15118-20: DER_BPT_AC_CPDReqEnergyTransferModeType: EVMaximumDischargeReactivePower_L2
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "maxDischargeReactivePower_L2",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub max_discharge_reactive_power_l2: Option<f64>, // decimal
 
     /// Optional. Rated maximum injected reactive power, defined by min(EV, EVSE), in vars on phase L3.
     /// Corresponds to the IvarMax attribute in the IEC 61850.
     /// ISO 15118-20: DER_BPT_AC_CPDReqEnergyTransferModeType: EVMaximumDischargeReactivePower_L3
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "maxDischargeReactivePower_L3",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub max_discharge_reactive_power_l3: Option<f64>, // decimal
 
     /// Optional. Rated minimum injected reactive power, defined by max(EV, EVSE), in vars.
@@ -160,13 +195,19 @@ pub struct DERChargingParametersType {
     /// Optional. Rated minimum injected reactive power, defined by max(EV, EVSE), in vars on phase L2.
     /// Corresponds to the IvarMin attribute in the IEC 61850.
     /// ISO 15118-20: DER_BPT_AC_CPDReqEnergyTransferModeType: EVMinimumDischargeReactivePower_L2
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "minDischargeReactivePower_L2",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub min_discharge_reactive_power_l2: Option<f64>, // decimal
 
     /// Optional. Rated minimum injected reactive power, defined by max(EV, EVSE), in vars on phase L3.
     /// Corresponds to the IvarMin attribute in the IEC 61850.
     /// ISO 15118-20: DER_BPT_AC_CPDReqEnergyTransferModeType: EVMinimumDischargeReactivePower_L3
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "minDischargeReactivePower_L3",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub min_discharge_reactive_power_l3: Option<f64>, // decimal
 
     /// Optional. Line voltage supported by EVSE and EV.
@@ -234,22 +275,34 @@ pub struct DERChargingParametersType {
 
     /// Optional. Maximum injected DC current allowed at level 1 charging.
     /// ISO 15118-20: DER_BPT_AC_CPDReqEnergyTransferModeType: EVMaximumLevel1DCInjection
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "evMaximumLevel1DCInjection",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub ev_maximum_level1_dc_injection: Option<f64>, // decimal
 
     /// Optional. Maximum allowed duration of DC injection at level 1 charging.
     /// ISO 15118-20: DER_BPT_AC_CPDReqEnergyTransferModeType: EVDurationLevel1DCInjection
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "evDurationLevel1DCInjection",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub ev_duration_level1_dc_injection: Option<f64>, // decimal
 
     /// Optional. Maximum injected DC current allowed at level 2 charging.
     /// ISO 15118-20: DER_BPT_AC_CPDReqEnergyTransferModeType: EVMaximumLevel2DCInjection
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "evMaximumLevel2DCInjection",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub ev_maximum_level2_dc_injection: Option<f64>, // decimal
 
     /// Optional. Maximum allowed duration of DC injection at level 2 charging.
     /// ISO 15118-20: DER_BPT_AC_CPDReqEnergyTransferModeType: EVDurationLevel2DCInjection
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "evDurationLevel2DCInjection",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub ev_duration_level2_dc_injection: Option<f64>, // decimal
 
     /// Optional. Measure of the susceptibility of the circuit to reactance, in Siemens (S).
@@ -263,6 +316,9 @@ pub struct DERChargingParametersType {
     /// ISO 15118-20: DER_BPT_AC_CPDReqEnergyTransferModeType: EVSessionTotalDischargeEnergyAvailable
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ev_session_total_discharge_energy_available: Option<f64>, // decimal
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for DERChargingParametersType {
@@ -310,6 +366,21 @@ impl OcppEntity for DERChargingParametersType {
             );
         }
 
+        if let Some(ev_supported_der_control) = &self.ev_supported_der_control {
+            e.check_iter_enum("ev_supported_der_control", ev_supported_der_control.iter());
+        }
+
+        if let Some(ev_islanding_detection_method) = &self.ev_islanding_detection_method {
+            e.check_iter_enum(
+                "ev_islanding_detection_method",
+                ev_islanding_detection_method.iter(),
+            );
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("DERChargingParametersType")
     }
 }
@@ -363,6 +434,7 @@ mod tests {
             ev_duration_level2_dc_injection: Some(600.0),
             ev_reactive_susceptance: Some(0.01),
             ev_session_total_discharge_energy_available: Some(5000.0),
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&der_params).unwrap();
@@ -419,6 +491,7 @@ mod tests {
             ev_duration_level2_dc_injection: None,
             ev_reactive_susceptance: None,
             ev_session_total_discharge_energy_available: None,
+            custom_data: None,
         };
         assert!(der_params_max_lengths.validate().is_ok());
     }
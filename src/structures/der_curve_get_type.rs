@@ -1,11 +1,13 @@
 use crate::enums::der_control_enum_type::DERControlEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::der_curve_type::DERCurveType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
 
 /// DERCurveGetType is used by: ReportDERControlRequest
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct DERCurveGetType {
     /// Required. Id of DER curve
     /// String length: 0..36
@@ -18,6 +20,9 @@ pub struct DERCurveGetType {
     pub is_superseded: bool,
     /// Required. Parameters defining the DER curve
     pub curve: DERCurveType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for DERCurveGetType {
@@ -27,6 +32,12 @@ impl OcppEntity for DERCurveGetType {
         let mut e = StructureValidationBuilder::new();
         e.check_cardinality("id", 0, 36, &self.id.chars());
         e.check_member("curve", &self.curve);
+        e.check_enum("curve_type", &self.curve_type);
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("DERCurveGetType")
     }
 }
@@ -44,6 +55,7 @@ mod tests {
             is_default: false,
             is_superseded: false,
             curve: DERCurveType::default(), // Placeholder
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&der_curve_get).unwrap();
@@ -61,6 +73,7 @@ mod tests {
             is_default: true,
             is_superseded: false,
             curve: DERCurveType::default(),
+            custom_data: None,
         };
         assert!(der_curve_get.validate().is_ok());
 
@@ -70,6 +83,7 @@ mod tests {
             is_default: false,
             is_superseded: true,
             curve: DERCurveType::default(),
+            custom_data: None,
         };
         assert!(der_curve_get_max_id_len.validate().is_ok());
     }
@@ -82,6 +96,7 @@ mod tests {
             is_default: false,
             is_superseded: false,
             curve: DERCurveType::default(),
+            custom_data: None,
         };
         let err = der_curve_get.validate().unwrap_err();
         assert_invalid_fields(&err, &["id"]);
@@ -1,10 +1,12 @@
-use crate::errors::OcppError;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 
 /// DERCurvePointsType is used by: Common::DERCurveType
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct DERCurvePointsType {
     /// Required. The data value of the X-axis (independent) variable, depending on the curve type.
     pub x: f64, // decimal
@@ -12,13 +14,22 @@ pub struct DERCurvePointsType {
     /// If y is power factor, then a positive value means DER is absorbing reactive power (under-excited),
     /// a negative value when DER is injecting reactive power (over-excited).
     pub y: f64, // decimal
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for DERCurvePointsType {
     /// Validates the fields of DERCurvePointsType.
     /// Returns `Ok(())` if all values are valid, or `Err(OcppError::StructureValidationError)` if validation fails.
     fn validate(&self) -> Result<(), OcppError> {
-        Ok(())
+        let mut e = StructureValidationBuilder::new();
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("DERCurvePointsType")
     }
 }
 
@@ -36,7 +47,11 @@ impl Display for DERCurvePointsType {
 
 impl Default for DERCurvePointsType {
     fn default() -> Self {
-        Self { x: 0.0, y: 0.0 }
+        Self {
+            x: 0.0,
+            y: 0.0,
+            custom_data: None,
+        }
     }
 }
 
@@ -46,7 +61,11 @@ mod tests {
 
     #[test]
     fn test_serialization_deserialization() {
-        let curve_point = DERCurvePointsType { x: 10.5, y: 20.7 };
+        let curve_point = DERCurvePointsType {
+            x: 10.5,
+            y: 20.7,
+            custom_data: None,
+        };
 
         let serialized = serde_json::to_string(&curve_point).unwrap();
         println!("Serialized: {}", serialized);
@@ -57,16 +76,25 @@ mod tests {
 
     #[test]
     fn test_validation_valid() {
-        let curve_point_positive = DERCurvePointsType { x: 1.0, y: 2.0 };
+        let curve_point_positive = DERCurvePointsType {
+            x: 1.0,
+            y: 2.0,
+            custom_data: None,
+        };
         assert!(curve_point_positive.validate().is_ok());
 
         let curve_point_negative_y = DERCurvePointsType {
             x: 5.0,
             y: -0.8, // Valid for power factor
+            custom_data: None,
         };
         assert!(curve_point_negative_y.validate().is_ok());
 
-        let curve_point_zero = DERCurvePointsType { x: 0.0, y: 0.0 };
+        let curve_point_zero = DERCurvePointsType {
+            x: 0.0,
+            y: 0.0,
+            custom_data: None,
+        };
         assert!(curve_point_zero.validate().is_ok());
     }
 }
@@ -1,5 +1,6 @@
 use crate::enums::der_unit_enum_type::DERUnitEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::der_curve_points_type::DERCurvePointsType;
 use crate::structures::hysteresis_type::HysteresisType;
 use crate::structures::reactive_power_params_type::ReactivePowerParamsType;
@@ -10,6 +11,7 @@ use serde::{Deserialize, Serialize};
 
 /// DERCurveType is used by: Common::DERCurveGetType, Common::LimitMaxDischargeType, SetDERControlRequest
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct DERCurveType {
     /// Required. Priority of curve (0=highest).
     /// Constraints: 0 <= val
@@ -38,6 +40,9 @@ pub struct DERCurveType {
     /// Required. Coordinates of the DER curve. X-axis is determined by curveType. Y-axis is determined by yUnit.
     /// Cardinality 1..10
     pub curve_data: Vec<DERCurvePointsType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for DERCurveType {
@@ -52,6 +57,7 @@ impl Default for DERCurveType {
             voltage_params: None,
             reactive_power_params: None,
             curve_data: vec![Default::default(); 1],
+            custom_data: None,
         }
     }
 }
@@ -75,6 +81,12 @@ impl OcppEntity for DERCurveType {
         if let Some(reactive_power_params) = &self.reactive_power_params {
             e.check_member("reactive_power_params", reactive_power_params);
         }
+        e.check_enum("y_unit", &self.y_unit);
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("DERCurveType")
     }
 }
@@ -97,6 +109,7 @@ mod tests {
             voltage_params: Some(Default::default()),
             reactive_power_params: Some(Default::default()),
             curve_data: vec![Default::default(), Default::default()],
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&der_curve).unwrap();
@@ -118,6 +131,7 @@ mod tests {
             voltage_params: None,
             reactive_power_params: None,
             curve_data: vec![Default::default()], // Valid cardinality
+            custom_data: None,
         };
         assert!(der_curve_minimal.validate().is_ok());
 
@@ -131,6 +145,7 @@ mod tests {
             voltage_params: Some(Default::default()),
             reactive_power_params: Some(Default::default()),
             curve_data: vec![Default::default(); 10], // Max cardinality
+            custom_data: None,
         };
         assert!(der_curve_full.validate().is_ok());
     }
@@ -147,6 +162,7 @@ mod tests {
             voltage_params: None,
             reactive_power_params: None,
             curve_data: vec![Default::default()],
+            custom_data: None,
         };
         let err = der_curve.validate().unwrap_err();
         assert_num_field_errors(&err, 1);
@@ -165,6 +181,7 @@ mod tests {
             voltage_params: None,
             reactive_power_params: None,
             curve_data: vec![], // Invalid cardinality
+            custom_data: None,
         };
         let err = der_curve.validate().unwrap_err();
         assert_num_field_errors(&err, 1);
@@ -183,6 +200,7 @@ mod tests {
             voltage_params: None,
             reactive_power_params: None,
             curve_data: vec![Default::default(); 11], // Invalid cardinality
+            custom_data: None,
         };
         let err = der_curve.validate().unwrap_err();
         if let OcppError::StructureValidationError {
@@ -1,24 +1,35 @@
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::enter_service_type::EnterServiceType;
+use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
 
 /// EnterServiceGetType is used by: ReportDERControlRequest
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct EnterServiceGetType {
     /// Required. Id of setting
     /// String length: 0..36
     pub id: String,
     /// Required. Enter Service settings
     pub enter_service: EnterServiceType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
-impl EnterServiceGetType {
+#[typetag::serde]
+impl OcppEntity for EnterServiceGetType {
     /// Validates the fields of EnterServiceGetType based on specified constraints.
     /// Returns `Ok(())` if all values are valid, or `Err(OcppError::StructureValidationError)` if validation fails.
-    pub fn validate(&self) -> Result<(), OcppError> {
+    fn validate(&self) -> Result<(), OcppError> {
         let mut e = StructureValidationBuilder::new();
         e.check_cardinality("id", 0, 36, &self.id.chars());
         e.check_member("enter_service", &self.enter_service);
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("EnterServiceGetType")
     }
 }
@@ -33,6 +44,7 @@ mod tests {
         let enter_service_get = EnterServiceGetType {
             id: "SERVICE_SETTING_001".to_string(),
             enter_service: EnterServiceType::default(), // Placeholder
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&enter_service_get).unwrap();
@@ -47,12 +59,14 @@ mod tests {
         let enter_service_get = EnterServiceGetType {
             id: "valid_id".to_string(),
             enter_service: EnterServiceType::default(),
+            custom_data: None,
         };
         assert!(enter_service_get.validate().is_ok());
 
         let enter_service_get_max_id_len = EnterServiceGetType {
             id: "a".repeat(36), // Valid length
             enter_service: EnterServiceType::default(),
+            custom_data: None,
         };
         assert!(enter_service_get_max_id_len.validate().is_ok());
     }
@@ -62,6 +76,7 @@ mod tests {
         let enter_service_get = EnterServiceGetType {
             id: "a".repeat(37), // Invalid
             enter_service: EnterServiceType::default(),
+            custom_data: None,
         };
         let err = enter_service_get.validate().unwrap_err();
         assert_num_field_errors(&err, 1);
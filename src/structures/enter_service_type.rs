@@ -1,9 +1,11 @@
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
 
 /// EnterServiceType is used by: Common::EnterServiceGetType, SetDERControlRequest
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct EnterServiceType {
     /// Required. Priority of setting (0=highest).
     /// Constraints: 0 <= val
@@ -25,6 +27,9 @@ pub struct EnterServiceType {
     /// Optional. Enter service ramp rate in seconds.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ramp_rate: Option<f64>, // decimal
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for EnterServiceType {
@@ -38,6 +43,7 @@ impl Default for EnterServiceType {
             delay: None,
             random_delay: None,
             ramp_rate: None,
+            custom_data: None,
         }
     }
 }
@@ -81,6 +87,10 @@ impl OcppEntity for EnterServiceType {
             e.check_bounds("ramp_rate", 0.0, f64::MAX, ramp_rate);
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("EnterServiceType")
     }
 }
@@ -101,6 +111,7 @@ mod tests {
             delay: Some(5.0),
             random_delay: Some(1.0),
             ramp_rate: Some(0.1),
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&enter_service).unwrap();
@@ -121,6 +132,7 @@ mod tests {
             delay: None,
             random_delay: None,
             ramp_rate: None,
+            custom_data: None,
         };
         assert!(enter_service.validate().is_ok());
 
@@ -133,6 +145,7 @@ mod tests {
             delay: Some(10.0),
             random_delay: Some(2.5),
             ramp_rate: Some(0.5),
+            custom_data: None,
         };
         assert!(enter_service_full.validate().is_ok());
     }
@@ -148,6 +161,7 @@ mod tests {
             delay: None,
             random_delay: None,
             ramp_rate: None,
+            custom_data: None,
         };
         let err = enter_service.validate().unwrap_err();
         assert_num_field_errors(&err, 2);
@@ -165,6 +179,7 @@ mod tests {
             delay: None,
             random_delay: None,
             ramp_rate: None,
+            custom_data: None,
         };
         if let Err(err) = enter_service.validate() {
             assert_invalid_fields(&err, &["high_freq", "low_freq"]);
@@ -182,6 +197,7 @@ mod tests {
             delay: Some(-1.0), // Invalid
             random_delay: None,
             ramp_rate: None,
+            custom_data: None,
         };
         let err = enter_service.validate().unwrap_err();
         if let OcppError::StructureValidationError {
@@ -210,6 +226,7 @@ mod tests {
             delay: Some(-1.0),        // Invalid 3
             random_delay: Some(-2.0), // Invalid 4
             ramp_rate: Some(-0.5),    // Invalid 5
+            custom_data: None,
         };
         assert!(enter_service.validate().is_err());
         if let Err(err) = enter_service.validate() {
@@ -1,4 +1,5 @@
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::ev_price_rule_type::EVPriceRuleType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
@@ -6,12 +7,16 @@ use serde::{Deserialize, Serialize};
 /// An entry in price schedule over time for which EV is willing to discharge.
 /// Used by: Common::EVAbsolutePriceScheduleType
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct EVAbsolutePriceScheduleEntryType {
     /// Required. The amount of seconds of this entry.
     pub duration: i32, // integer
     /// Required. A set of pricing rules for energy costs.
     /// Cardinality 1..8
     pub ev_price_rule: Vec<EVPriceRuleType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for EVAbsolutePriceScheduleEntryType {
@@ -19,6 +24,7 @@ impl Default for EVAbsolutePriceScheduleEntryType {
         Self {
             duration: 0,
             ev_price_rule: vec![Default::default()],
+            custom_data: None,
         }
     }
 }
@@ -31,6 +37,10 @@ impl OcppEntity for EVAbsolutePriceScheduleEntryType {
 
         e.check_cardinality("ev_price_rule", 1, 8, &self.ev_price_rule.iter());
         e.check_iter_member("ev_price_rule", self.ev_price_rule.iter());
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("EVAbsolutePriceScheduleEntryType")
     }
 }
@@ -45,6 +55,7 @@ mod tests {
         let entry = EVAbsolutePriceScheduleEntryType {
             duration: 3600,
             ev_price_rule: vec![Default::default(); 2], // Placeholder
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&entry).unwrap();
@@ -60,12 +71,14 @@ mod tests {
         let entry_single_rule = EVAbsolutePriceScheduleEntryType {
             duration: 1800,
             ev_price_rule: vec![Default::default()],
+            custom_data: None,
         };
         assert!(entry_single_rule.validate().is_ok());
 
         let entry_max_rules = EVAbsolutePriceScheduleEntryType {
             duration: 7200,
             ev_price_rule: vec![Default::default(); 8], // Max cardinality
+            custom_data: None,
         };
         assert!(entry_max_rules.validate().is_ok());
     }
@@ -75,6 +88,7 @@ mod tests {
         let entry = EVAbsolutePriceScheduleEntryType {
             duration: 3600,
             ev_price_rule: vec![], // Invalid cardinality
+            custom_data: None,
         };
         let err = entry.validate().unwrap_err();
         assert_invalid_fields(&err, &["ev_price_rule"]);
@@ -86,6 +100,7 @@ mod tests {
         let entry = EVAbsolutePriceScheduleEntryType {
             duration: 3600,
             ev_price_rule: vec![Default::default(); 9], // Invalid cardinality
+            custom_data: None,
         };
         let err = entry.validate().unwrap_err();
         assert_invalid_fields(&err, &["ev_price_rule"]);
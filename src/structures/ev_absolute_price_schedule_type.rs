@@ -1,6 +1,7 @@
 use crate::errors::OcppError::FieldISOError;
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::iso;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::ev_absolute_price_schedule_entry_type::EVAbsolutePriceScheduleEntryType;
 use crate::traits::OcppEntity;
 use chrono::{DateTime, Utc};
@@ -9,6 +10,7 @@ use serde::{Deserialize, Serialize};
 /// Price schedule of EV energy offer.
 /// Used by: Common::EVEnergyOfferType
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct EVAbsolutePriceScheduleType {
     /// Required. Starting point in time of the EVEnergyOffer.
     pub time_anchor: DateTime<Utc>,
@@ -21,6 +23,9 @@ pub struct EVAbsolutePriceScheduleType {
     /// Required. Schedule of prices for which EV is willing to discharge.
     /// Cardinality 1..1024
     pub ev_absolute_price_schedule_entries: Vec<EVAbsolutePriceScheduleEntryType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for EVAbsolutePriceScheduleType {
@@ -30,6 +35,7 @@ impl Default for EVAbsolutePriceScheduleType {
             currency: "".to_string(),
             price_algorithm: "".to_string(),
             ev_absolute_price_schedule_entries: vec![Default::default()],
+            custom_data: None,
         }
     }
 }
@@ -64,6 +70,10 @@ impl OcppEntity for EVAbsolutePriceScheduleType {
             self.ev_absolute_price_schedule_entries.iter(),
         );
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("EVAbsolutePriceScheduleType")
     }
 }
@@ -81,6 +91,7 @@ mod tests {
             currency: "EUR".to_string(),
             price_algorithm: "urn:iso:15118:20:2022:Power".to_string(),
             ev_absolute_price_schedule_entries: vec![Default::default()],
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&schedule).unwrap();
@@ -97,6 +108,7 @@ mod tests {
             currency: "USD".to_string(),
             price_algorithm: "urn:iso:15118:20:2022:PeakPower".to_string(),
             ev_absolute_price_schedule_entries: vec![Default::default()],
+            custom_data: None,
         };
         assert!(schedule.validate().is_ok());
 
@@ -105,6 +117,7 @@ mod tests {
             currency: "USD".to_string(),       // Max length
             price_algorithm: "a".repeat(2000), // Max length
             ev_absolute_price_schedule_entries: vec![Default::default(); 1024], // Max cardinality
+            custom_data: None,
         };
         println!("{:#?}", schedule_max_lengths_and_cardinality.validate());
         assert!(schedule_max_lengths_and_cardinality.validate().is_ok());
@@ -117,6 +130,7 @@ mod tests {
             currency: "USD".to_string(),
             price_algorithm: "a".repeat(2001), // Invalid
             ev_absolute_price_schedule_entries: vec![Default::default(); 33],
+            custom_data: None,
         };
         let err = schedule.validate().unwrap_err();
         if let OcppError::StructureValidationError {
@@ -141,6 +155,7 @@ mod tests {
             currency: "USD".to_string(),
             price_algorithm: "urn:iso:15118:20:2022:Power".to_string(),
             ev_absolute_price_schedule_entries: vec![], // Invalid cardinality
+            custom_data: None,
         };
         let err = schedule.validate().unwrap_err();
         assert_num_field_errors(&err, 1);
@@ -154,6 +169,7 @@ mod tests {
             currency: "USD".to_string(),
             price_algorithm: "urn:iso:15118:20:2022:Power".to_string(),
             ev_absolute_price_schedule_entries: vec![Default::default(); 1025], // Invalid cardinality
+            custom_data: None,
         };
         let err = schedule.validate().unwrap_err();
         assert_num_field_errors(&err, 1);
@@ -167,6 +183,7 @@ mod tests {
             currency: "ABCD".to_string(),               // Invalid 1
             price_algorithm: "a".repeat(2001),          // Invalid 2
             ev_absolute_price_schedule_entries: vec![], // Invalid 3
+            custom_data: None,
         };
         let err = schedule.validate().unwrap_err();
         assert_num_field_errors(&err, 3);
@@ -1,4 +1,5 @@
 use crate::errors::OcppError;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::ev_absolute_price_schedule_type::EVAbsolutePriceScheduleType;
 use crate::structures::ev_power_schedule_type::EVPowerScheduleType;
 use crate::traits::OcppEntity;
@@ -9,12 +10,16 @@ use serde::{Deserialize, Serialize};
 /// a positive value indicates that the EV currently is not able to offer energy to discharge.
 /// Used by: Common::ChargingNeedsType
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct EVEnergyOfferType {
     /// Required. Power schedule offered for discharging.
     pub ev_power_schedule: EVPowerScheduleType,
     /// Optional. Price schedule for which EV is willing to discharge.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ev_absolute_price_schedule: Option<EVAbsolutePriceScheduleType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for EVEnergyOfferType {
@@ -32,6 +37,11 @@ impl OcppEntity for EVEnergyOfferType {
         {
             errors.push(e);
         }
+        if let Some(custom_data) = &self.custom_data
+            && let Err(e) = custom_data.validate()
+        {
+            errors.push(e);
+        }
 
         // Check if any errors occurred
         if errors.is_empty() {
@@ -58,7 +68,9 @@ mod tests {
                 currency: "USD".to_string(),
                 price_algorithm: "".to_string(),
                 ev_absolute_price_schedule_entries: vec![],
+                custom_data: None,
             }),
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&energy_offer).unwrap();
@@ -73,6 +85,7 @@ mod tests {
         let energy_offer_minimal = EVEnergyOfferType {
             ev_power_schedule: EVPowerScheduleType::default(),
             ev_absolute_price_schedule: None,
+            custom_data: None,
         };
 
         if let Err(e) = energy_offer_minimal.validate() {
@@ -88,7 +101,9 @@ mod tests {
                 currency: "USD".to_string(),
                 price_algorithm: "".to_string(),
                 ev_absolute_price_schedule_entries: vec![Default::default()],
+                custom_data: None,
             }),
+            custom_data: None,
         };
         println!("{:#?}", energy_offer_full.validate());
         assert!(energy_offer_full.validate().is_ok());
@@ -1,4 +1,5 @@
-use crate::errors::OcppError;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
 
@@ -7,19 +8,29 @@ use serde::{Deserialize, Serialize};
 /// a positive value indicates that the EV currently is not able to offer energy to discharge.
 /// Used by: Common::EVPowerScheduleType
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct EVPowerScheduleEntryType {
     /// Required. The duration of this entry.
     pub duration: i32, // integer
     /// Required. Defines maximum amount of power for the duration of this EVPowerScheduleEntry to be discharged
     /// from the EV battery through EVSE power outlet. Negative values are used for discharging.
     pub power: f64, // decimal
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for EVPowerScheduleEntryType {
     /// Validates the fields of EVPowerScheduleEntryType based on specified constraints.
     /// Returns `Ok(())` if all values are valid, or `Err(OcppError::StructureValidationError)` if validation fails.
     fn validate(&self) -> Result<(), OcppError> {
-        Ok(())
+        let mut e = StructureValidationBuilder::new();
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("EVPowerScheduleEntryType")
     }
 }
 
@@ -28,6 +39,7 @@ impl Default for EVPowerScheduleEntryType {
         EVPowerScheduleEntryType {
             duration: 0,
             power: 0.0,
+            custom_data: None,
         }
     }
 }
@@ -41,6 +53,7 @@ mod tests {
         let entry = EVPowerScheduleEntryType {
             duration: 3600,
             power: -5000.0, // Example for discharging
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&entry).unwrap();
@@ -55,18 +68,21 @@ mod tests {
         let entry_discharging = EVPowerScheduleEntryType {
             duration: 1800,
             power: -2500.0,
+            custom_data: None,
         };
         assert!(entry_discharging.validate().is_ok());
 
         let entry_positive_power = EVPowerScheduleEntryType {
             duration: 60,
             power: 100.0, // EV not able to offer energy to discharge
+            custom_data: None,
         };
         assert!(entry_positive_power.validate().is_ok());
 
         let entry_zero_power = EVPowerScheduleEntryType {
             duration: 0,
             power: 0.0,
+            custom_data: None,
         };
         assert!(entry_zero_power.validate().is_ok());
     }
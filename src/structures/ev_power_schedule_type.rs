@@ -1,4 +1,5 @@
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::ev_power_schedule_entry_type::EVPowerScheduleEntryType;
 use crate::traits::OcppEntity;
 use chrono::{DateTime, Utc};
@@ -7,12 +8,16 @@ use serde::{Deserialize, Serialize};
 /// Schedule of EV energy offer.
 /// Used by: Common::EVEnergyOfferType
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct EVPowerScheduleType {
     /// Required. The time that defines the starting point for the EVEnergyOffer.
     pub time_anchor: DateTime<Utc>,
     /// Required. List of EVPowerScheduleEntries.
     /// Cardinality 1..1024
     pub ev_power_schedule_entries: Vec<EVPowerScheduleEntryType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for EVPowerScheduleType {
@@ -20,6 +25,7 @@ impl Default for EVPowerScheduleType {
         Self {
             time_anchor: Default::default(),
             ev_power_schedule_entries: vec![Default::default()],
+            custom_data: None,
         }
     }
 }
@@ -39,6 +45,10 @@ impl OcppEntity for EVPowerScheduleType {
             "ev_power_schedule_entries",
             self.ev_power_schedule_entries.iter(),
         );
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("EVPowerScheduleType")
     }
 }
@@ -54,6 +64,7 @@ mod tests {
         let schedule = EVPowerScheduleType {
             time_anchor: Utc.with_ymd_and_hms(2025, 8, 1, 10, 0, 0).unwrap(),
             ev_power_schedule_entries: vec![Default::default()],
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&schedule).unwrap();
@@ -68,12 +79,14 @@ mod tests {
         let schedule = EVPowerScheduleType {
             time_anchor: Utc.with_ymd_and_hms(2025, 8, 1, 10, 0, 0).unwrap(),
             ev_power_schedule_entries: vec![Default::default()],
+            custom_data: None,
         };
         assert!(schedule.validate().is_ok());
 
         let schedule_max_entries = EVPowerScheduleType {
             time_anchor: Utc.with_ymd_and_hms(2025, 8, 1, 0, 0, 0).unwrap(),
             ev_power_schedule_entries: vec![Default::default(); 1024], // Max cardinality
+            custom_data: None,
         };
         assert!(schedule_max_entries.validate().is_ok());
     }
@@ -83,6 +96,7 @@ mod tests {
         let schedule = EVPowerScheduleType {
             time_anchor: Utc.with_ymd_and_hms(2025, 8, 1, 10, 0, 0).unwrap(),
             ev_power_schedule_entries: vec![], // Invalid cardinality
+            custom_data: None,
         };
         let err = schedule.validate().unwrap_err();
         assert_invalid_fields(&err, &["ev_power_schedule_entries"]);
@@ -93,6 +107,7 @@ mod tests {
         let schedule = EVPowerScheduleType {
             time_anchor: Utc.with_ymd_and_hms(2025, 8, 1, 10, 0, 0).unwrap(),
             ev_power_schedule_entries: vec![Default::default(); 1025], // Invalid cardinality
+            custom_data: None,
         };
         let err = schedule.validate().unwrap_err();
         assert_invalid_fields(&err, &["ev_power_schedule_entries"]);
@@ -2,6 +2,7 @@ use crate::enums::event_notification_enum_type::EventNotificationEnumType;
 use crate::enums::event_trigger_enum_type::EventTriggerEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::component_type::ComponentType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::variable_type::VariableType;
 use crate::traits::OcppEntity;
 use chrono::{DateTime, Utc};
@@ -9,7 +10,8 @@ use serde::{Deserialize, Serialize};
 
 /// Class to report an event notification for a component-variable.
 /// Used by: NotifyEventRequest
-#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
 pub struct EventDataType {
     /// Required. Identifies the event. This field can be referred to as a cause by other events.
     /// Constraints: 0 <= val
@@ -55,6 +57,9 @@ pub struct EventDataType {
     pub component: ComponentType,
     /// Required. Variable for which event is notified.
     pub variable: VariableType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for EventDataType {
@@ -105,6 +110,16 @@ impl OcppEntity for EventDataType {
         e.check_member("component", &self.component);
         e.check_member("variable", &self.variable);
 
+        e.check_enum("trigger", &self.trigger);
+
+        if let Some(event_notification_type) = &self.event_notification_type {
+            e.check_enum("event_notification_type", event_notification_type);
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("EventDataType")
     }
 }
@@ -134,8 +149,10 @@ mod tests {
                 name: "EVSE".to_string(),
                 instance: None,
                 evse: None,
+                custom_data: None,
             },
             variable: Default::default(), // Placeholder
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&event_data).unwrap();
@@ -164,8 +181,10 @@ mod tests {
                 name: "EVSE".to_string(),
                 instance: None,
                 evse: None,
+                custom_data: None,
             },
             variable: Default::default(),
+            custom_data: None,
         };
         assert!(event_data_minimal.validate().is_ok());
 
@@ -186,8 +205,10 @@ mod tests {
                 name: "EVSE".to_string(),
                 instance: None,
                 evse: None,
+                custom_data: None,
             },
             variable: Default::default(),
+            custom_data: None,
         };
         assert!(event_data_full_lengths.validate().is_ok());
     }
@@ -211,8 +232,10 @@ mod tests {
                 name: "EVSE".to_string(),
                 instance: None,
                 evse: None,
+                custom_data: None,
             },
             variable: Default::default(),
+            custom_data: None,
         };
         let err = event_data.validate().unwrap_err();
         assert_invalid_fields(&err, &["event_id"]);
@@ -237,8 +260,10 @@ mod tests {
                 name: "EVSE".to_string(),
                 instance: None,
                 evse: None,
+                custom_data: None,
             },
             variable: Default::default(),
+            custom_data: None,
         };
         let err = event_data.validate().unwrap_err();
         assert_invalid_fields(&err, &["cause"]);
@@ -263,8 +288,10 @@ mod tests {
                 name: "EVSE".to_string(),
                 instance: None,
                 evse: None,
+                custom_data: None,
             },
             variable: Default::default(),
+            custom_data: None,
         };
         let err = event_data.validate().unwrap_err();
         assert_invalid_fields(&err, &["actual_value"]);
@@ -289,8 +316,10 @@ mod tests {
                 name: "EVSE".to_string(),
                 instance: None,
                 evse: None,
+                custom_data: None,
             },
             variable: Default::default(),
+            custom_data: None,
         };
         let err = event_data.validate().unwrap_err();
         assert_invalid_fields(&err, &["tech_code"]);
@@ -315,8 +344,10 @@ mod tests {
                 name: "EVSE".to_string(),
                 instance: None,
                 evse: None,
+                custom_data: None,
             },
             variable: Default::default(),
+            custom_data: None,
         };
         let err = event_data.validate().unwrap_err();
         assert_invalid_fields(&err, &["tech_info"]);
@@ -341,8 +372,10 @@ mod tests {
                 name: "EVSE".to_string(),
                 instance: None,
                 evse: None,
+                custom_data: None,
             },
             variable: Default::default(),
+            custom_data: None,
         };
         let err = event_data.validate().unwrap_err();
         assert_invalid_fields(&err, &["transaction_id"]);
@@ -367,8 +400,10 @@ mod tests {
                 name: "EVSE".to_string(),
                 instance: None,
                 evse: None,
+                custom_data: None,
             },
             variable: Default::default(),
+            custom_data: None,
         };
         let err = event_data.validate().unwrap_err();
         assert_invalid_fields(&err, &["variable_monitoring_id"]);
@@ -393,8 +428,10 @@ mod tests {
                 name: "EVSE".to_string(),
                 instance: None,
                 evse: None,
+                custom_data: None,
             },
             variable: Default::default(),
+            custom_data: None,
         };
         let err = event_data.validate().unwrap_err();
         assert_invalid_fields(&err, &["severity"]);
@@ -419,8 +456,10 @@ mod tests {
                 name: "EVSE".to_string(),
                 instance: None,
                 evse: None,
+                custom_data: None,
             },
             variable: Default::default(),
+            custom_data: None,
         };
         let err = event_data.validate().unwrap_err();
         assert_invalid_fields(
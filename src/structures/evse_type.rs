@@ -1,17 +1,22 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 
 /// Electric Vehicle Supply Equipment
 /// Used by: Common::ComponentType, TriggerMessageRequest, ChangeAvailabilityRequest, TransactionEventRequest
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct EVSEType {
     /// Required. EVSE Identifier. This contains a number (> 0) designating an EVSE of the Charging Station.
     pub id: i32,
     /// Optional. An id to designate a specific connector (on an EVSE) by connector index number.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub connector_id: Option<i32>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for EVSEType {
@@ -19,6 +24,7 @@ impl Default for EVSEType {
         Self {
             id: 1,
             connector_id: None,
+            custom_data: None,
         }
     }
 }
@@ -35,6 +41,10 @@ impl OcppEntity for EVSEType {
             e.check_bounds("connector_id", 0, i32::MAX, connector_id);
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("EVSEType")
     }
 }
@@ -2,11 +2,13 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 
 /// Represents a copy of the firmware that can be loaded/updated on the Charging Station.
 /// Used by: UpdateFirmwareRequest
-#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
 pub struct FirmwareType {
     /// Required. URI defining the origin of the firmware.
     pub location: String,
@@ -21,6 +23,9 @@ pub struct FirmwareType {
     /// Optional. Base64 encoded firmware signature.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub signature: Option<String>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for FirmwareType {
@@ -39,6 +44,76 @@ impl OcppEntity for FirmwareType {
             e.check_cardinality("signature", 0, 800, &signature.chars());
         }
 
+        if self.signing_certificate.is_some() != self.signature.is_some() {
+            e.push_relation_error(
+                "signing_certificate",
+                "signature",
+                "signing_certificate and signature SHALL either both be present or both be absent",
+            );
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("FirmwareType")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::assert_invalid_fields;
+
+    #[test]
+    fn test_validate_success() {
+        let firmware = FirmwareType {
+            location: "https://example.com/firmware.bin".to_string(),
+            signing_certificate: Some("-----BEGIN CERTIFICATE-----".to_string()),
+            signature: Some("c2lnbmF0dXJl".to_string()),
+            ..Default::default()
+        };
+        assert!(firmware.validate().is_ok());
+        assert!(FirmwareType::default().validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_failure_location_too_long() {
+        let firmware = FirmwareType {
+            location: "a".repeat(2001),
+            ..Default::default()
+        };
+        assert_invalid_fields(&firmware.validate().unwrap_err(), &["location"]);
+    }
+
+    #[test]
+    fn test_validate_failure_signature_without_certificate() {
+        let mut firmware = FirmwareType {
+            signature: Some("c2lnbmF0dXJl".to_string()),
+            ..Default::default()
+        };
+        assert_invalid_fields(
+            &firmware.validate().unwrap_err(),
+            &["signing_certificate", "signature"],
+        );
+
+        firmware.signature = None;
+        firmware.signing_certificate = Some("-----BEGIN CERTIFICATE-----".to_string());
+        assert_invalid_fields(
+            &firmware.validate().unwrap_err(),
+            &["signing_certificate", "signature"],
+        );
+    }
+
+    #[test]
+    fn test_serialization_deserialization() {
+        let firmware = FirmwareType {
+            location: "https://example.com/firmware.bin".to_string(),
+            install_date_time: Some(Utc::now()),
+            ..Default::default()
+        };
+        let serialized = serde_json::to_string(&firmware).unwrap();
+        let deserialized: FirmwareType = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(firmware, deserialized);
+    }
+}
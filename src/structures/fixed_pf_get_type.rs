@@ -1,11 +1,13 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::fixed_pf_type::FixedPFType;
 use crate::traits::OcppEntity;
 
 /// Used by: ReportDERControlRequest
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct FixedPFGetType {
     /// Required. Id of setting.
     pub id: String,
@@ -14,7 +16,11 @@ pub struct FixedPFGetType {
     /// Required. True if this setting is superseded by a lower priority setting.
     pub is_superseded: bool,
     /// Required. FixedPF for AbsorbW or InjectW
+    #[serde(rename = "fixedPF")]
     pub fixed_pf: FixedPFType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for FixedPFGetType {
@@ -27,6 +33,10 @@ impl OcppEntity for FixedPFGetType {
 
         e.check_member("fixed_pf", &self.fixed_pf);
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("FixedPFGetType")
     }
 }
@@ -2,10 +2,12 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 
 /// Used by: Common::FixedPFGetType, SetDERControlRequest
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct FixedPFType {
     /// Required. Priority of setting (0=highest)
     pub priority: i32,
@@ -19,6 +21,9 @@ pub struct FixedPFType {
     /// Optional. Duration in seconds that this setting is active.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub duration: Option<f64>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for FixedPFType {
@@ -34,6 +39,10 @@ impl OcppEntity for FixedPFType {
             e.check_bounds("duration", 0.0, f64::MAX, duration);
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("FixedPFType")
     }
 }
@@ -1,11 +1,13 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::fixed_var_type::FixedVarType;
 use crate::traits::OcppEntity;
 
 /// Used by: ReportDERControlRequest
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct FixedVarGetType {
     /// Required. Id of setting.
     pub id: String,
@@ -15,6 +17,9 @@ pub struct FixedVarGetType {
     pub is_superseded: bool,
     /// Required. Fixed Var setpoint
     pub fixed_var: FixedVarType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for FixedVarGetType {
@@ -27,6 +32,10 @@ impl OcppEntity for FixedVarGetType {
 
         e.check_member("fixed_var", &self.fixed_var);
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("FixedVarGetType")
     }
 }
@@ -1,11 +1,13 @@
 use crate::enums::der_unit_enum_type::DERUnitEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 /// Used by: Common::FixedVarGetType, SetDERControlRequest
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct FixedVarType {
     /// Required. Priority of setting (0=highest)
     pub priority: i32,
@@ -19,6 +21,9 @@ pub struct FixedVarType {
     /// Optional. Duration in seconds that this setting is active.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub duration: Option<f64>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for FixedVarType {
@@ -34,6 +39,12 @@ impl OcppEntity for FixedVarType {
             e.check_bounds("duration", 0.0, f64::MAX, duration);
         }
 
+        e.check_enum("unit", &self.unit);
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("FixedVarType")
     }
 }
@@ -54,6 +65,7 @@ mod tests {
             unit: DERUnitEnumType::PctMaxVar,
             start_time: Some(Utc::now()),
             duration: Some(100.0),
+            custom_data: None,
         };
         assert!(fixed_var_type.validate().is_ok());
     }
@@ -66,6 +78,7 @@ mod tests {
             unit: DERUnitEnumType::PctMaxVar,
             start_time: None,
             duration: None,
+            custom_data: None,
         };
         let result = fixed_var_type.validate();
         assert!(result.is_err());
@@ -82,6 +95,7 @@ mod tests {
             unit: DERUnitEnumType::PctMaxVar,
             start_time: None,
             duration: None,
+            custom_data: None,
         };
         let result = fixed_var_type.validate();
         assert!(result.is_err());
@@ -98,6 +112,7 @@ mod tests {
             unit: DERUnitEnumType::PctMaxVar,
             start_time: None,
             duration: Some(-10.0),
+            custom_data: None,
         };
         let result = fixed_var_type.validate();
         assert!(result.is_err());
@@ -114,6 +129,7 @@ mod tests {
             unit: DERUnitEnumType::PctMaxVar,
             start_time: Some(Utc.timestamp_opt(1672531200, 0).unwrap()),
             duration: Some(600.0),
+            custom_data: None,
         };
 
         // Serialize to JSON
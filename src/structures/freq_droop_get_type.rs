@@ -1,11 +1,13 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::freq_droop_type::FreqDroopType;
 use crate::traits::OcppEntity;
 
 /// Used by: ReportDERControlRequest
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct FreqDroopGetType {
     /// Required. Id of setting
     pub id: String,
@@ -15,6 +17,9 @@ pub struct FreqDroopGetType {
     pub is_superseded: bool,
     /// Required. FreqDroop parameters
     pub freq_droop: FreqDroopType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for FreqDroopGetType {
@@ -27,6 +32,10 @@ impl OcppEntity for FreqDroopGetType {
 
         e.check_member("freq_droop", &self.freq_droop);
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("FreqDroopGetType")
     }
 }
@@ -45,6 +54,7 @@ mod tests {
             is_default: true,
             is_superseded: false,
             freq_droop: FreqDroopType::default(),
+            custom_data: None,
         };
         assert!(freq_droop_get_type.validate().is_ok());
     }
@@ -56,6 +66,7 @@ mod tests {
             is_default: true,
             is_superseded: false,
             freq_droop: FreqDroopType::default(),
+            custom_data: None,
         };
         let err = freq_droop_get_type.validate().unwrap_err();
         assert_num_field_errors(&err, 1);
@@ -69,6 +80,7 @@ mod tests {
             is_default: true,
             is_superseded: false,
             freq_droop: FreqDroopType::default(),
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&original_struct).unwrap();
@@ -2,10 +2,12 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 
 /// Used by: Common::FreqDroopGetType, SetDERControlRequest
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct FreqDroopType {
     /// Required. Priority of setting (0=highest)
     pub priority: i32,
@@ -25,6 +27,9 @@ pub struct FreqDroopType {
     /// Optional. Duration in seconds that this setting is active.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub duration: Option<f64>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for FreqDroopType {
@@ -38,6 +43,7 @@ impl Default for FreqDroopType {
             response_time: 0.0,
             start_time: None,
             duration: None,
+            custom_data: None,
         }
     }
 }
@@ -54,6 +60,10 @@ impl OcppEntity for FreqDroopType {
             e.check_bounds("duration", 0.0, f64::MAX, duration);
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("FreqDroopType")
     }
 }
@@ -76,6 +86,7 @@ mod tests {
             response_time: 1.0,
             start_time: Some(Utc::now()),
             duration: Some(3600.0),
+            custom_data: None,
         };
         assert!(freq_droop_type.validate().is_ok());
     }
@@ -91,6 +102,7 @@ mod tests {
             response_time: 1.0,
             start_time: None,
             duration: None,
+            custom_data: None,
         };
         let result = freq_droop_type.validate();
         assert!(result.is_err());
@@ -109,6 +121,7 @@ mod tests {
             response_time: 1.0,
             start_time: None,
             duration: Some(-1.0),
+            custom_data: None,
         };
         let err = freq_droop_type.validate().unwrap_err();
         assert_invalid_fields(&err, &["duration"]);
@@ -126,6 +139,7 @@ mod tests {
             response_time: 2.0,
             start_time: Some(Utc.timestamp_opt(1672531200, 0).unwrap()),
             duration: Some(1800.0),
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&original_struct).unwrap();
@@ -1,13 +1,15 @@
 use crate::enums::attribute_enum_type::AttributeEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::component_type::ComponentType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::variable_type::VariableType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
 
 /// Class to hold parameters for GetVariables request.
 /// Used by: GetVariablesRequest
-#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
 pub struct GetVariableDataType {
     /// Optional. Attribute type for which value is requested. When absent, default Actual is assumed.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -16,6 +18,9 @@ pub struct GetVariableDataType {
     pub component: ComponentType,
     /// Required. Variable for which the attribute value is requested.
     pub variable: VariableType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for GetVariableDataType {
@@ -28,6 +33,14 @@ impl OcppEntity for GetVariableDataType {
 
         e.check_member("variable", &self.variable);
 
+        if let Some(attribute_type) = &self.attribute_type {
+            e.check_enum("attribute_type", attribute_type);
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("GetVariableDataType")
     }
 }
@@ -45,6 +58,7 @@ mod tests {
             attribute_type: Some(AttributeEnumType::Actual),
             component: ComponentType::default(),
             variable: VariableType::default(),
+            custom_data: None,
         };
         assert!(get_variable_data_type.validate().is_ok());
 
@@ -52,6 +66,7 @@ mod tests {
             attribute_type: None,
             component: ComponentType::default(),
             variable: VariableType::default(),
+            custom_data: None,
         };
         assert!(get_variable_data_type_none.validate().is_ok());
     }
@@ -62,6 +77,7 @@ mod tests {
             attribute_type: Some(AttributeEnumType::Actual),
             component: ComponentType::default(),
             variable: VariableType::default(),
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&original_struct).unwrap();
@@ -72,6 +88,7 @@ mod tests {
             attribute_type: None,
             component: ComponentType::default(),
             variable: VariableType::default(),
+            custom_data: None,
         };
 
         let serialized_none = serde_json::to_string(&original_struct_none).unwrap();
@@ -2,6 +2,7 @@ use crate::enums::attribute_enum_type::AttributeEnumType;
 use crate::enums::get_variable_status_enum_type::GetVariableStatusEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::component_type::ComponentType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::status_info_type::StatusInfoType;
 use crate::structures::variable_type::VariableType;
 use crate::traits::OcppEntity;
@@ -9,7 +10,8 @@ use serde::{Deserialize, Serialize};
 
 /// Class to hold results of GetVariables request.
 /// Used by: GetVariablesResponse
-#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
 pub struct GetVariableResultType {
     /// Required.
     pub attribute_status: GetVariableStatusEnumType,
@@ -26,6 +28,9 @@ pub struct GetVariableResultType {
     /// Optional. Detailed attribute status information.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub attribute_status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for GetVariableResultType {
@@ -45,6 +50,16 @@ impl OcppEntity for GetVariableResultType {
             e.check_member("attribute_status_info", attribute_status_info);
         }
 
+        e.check_enum("attribute_status", &self.attribute_status);
+
+        if let Some(attribute_type) = &self.attribute_type {
+            e.check_enum("attribute_type", attribute_type);
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("GetVariableResultType")
     }
 }
@@ -67,6 +82,7 @@ mod tests {
             component: ComponentType::default(),
             variable: VariableType::default(),
             attribute_status_info: Some(StatusInfoType::default()),
+            custom_data: None,
         };
         assert!(result_type.validate().is_ok());
 
@@ -77,6 +93,7 @@ mod tests {
             component: ComponentType::default(),
             variable: VariableType::default(),
             attribute_status_info: None,
+            custom_data: None,
         };
         assert!(result_type_minimal.validate().is_ok());
     }
@@ -90,6 +107,7 @@ mod tests {
             component: ComponentType::default(),
             variable: VariableType::default(),
             attribute_status_info: None,
+            custom_data: None,
         };
         let result = result_type.validate();
         assert!(result.is_err());
@@ -114,6 +132,7 @@ mod tests {
             component: ComponentType::default(),
             variable: VariableType::default(),
             attribute_status_info: Some(StatusInfoType::default()),
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&original_struct).unwrap();
@@ -1,16 +1,21 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::gradient_type::GradientType;
 use crate::traits::OcppEntity;
 
 /// Used by: ReportDERControlRequest
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct GradientGetType {
     /// Required. Id of setting
     pub id: String,
     /// Required. Gradient setting
     pub gradient: GradientType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for GradientGetType {
@@ -23,6 +28,10 @@ impl OcppEntity for GradientGetType {
 
         e.check_member("gradient", &self.gradient);
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("GradientGetType")
     }
 }
@@ -39,6 +48,7 @@ mod tests {
         let gradient_get_type = GradientGetType {
             id: "setting1".to_string(),
             gradient: GradientType::default(),
+            custom_data: None,
         };
         assert!(gradient_get_type.validate().is_ok());
     }
@@ -48,6 +58,7 @@ mod tests {
         let gradient_get_type = GradientGetType {
             id: "a".repeat(37), // Invalid length
             gradient: GradientType::default(),
+            custom_data: None,
         };
         let err = gradient_get_type.validate().unwrap_err();
         assert_num_field_errors(&err, 1);
@@ -59,6 +70,7 @@ mod tests {
         let original_struct = GradientGetType {
             id: "setting1".to_string(),
             gradient: GradientType::default(),
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&original_struct).unwrap();
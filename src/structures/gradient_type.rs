@@ -2,10 +2,12 @@ use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 
 /// Used by: Common::GradientGetType, SetDERControlRequest
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct GradientType {
     /// Required. Priority of setting (0=highest)
     pub priority: i32,
@@ -13,6 +15,9 @@ pub struct GradientType {
     pub gradient: f64,
     /// Required. Soft-start ramp rate in seconds (0 if not applicable)
     pub soft_gradient: f64,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for GradientType {
@@ -21,6 +26,7 @@ impl Default for GradientType {
             priority: 0,
             gradient: 0.0,
             soft_gradient: 0.0,
+            custom_data: None,
         }
     }
 }
@@ -41,6 +47,10 @@ impl OcppEntity for GradientType {
         e.check_bounds("gradient", 0.0, f64::MAX, self.gradient);
         e.check_bounds("soft_gradient", 0.0, f64::MAX, self.soft_gradient);
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("GradientType")
     }
 }
@@ -57,6 +67,7 @@ mod tests {
             priority: 0,
             gradient: 10.0,
             soft_gradient: 5.0,
+            custom_data: None,
         };
         assert!(gradient_type.validate().is_ok());
     }
@@ -67,6 +78,7 @@ mod tests {
             priority: -1, // Invalid
             gradient: 10.0,
             soft_gradient: 5.0,
+            custom_data: None,
         };
         let result = gradient_type.validate();
         assert!(result.is_err());
@@ -81,6 +93,7 @@ mod tests {
             priority: 0,
             gradient: -1.0, // Invalid
             soft_gradient: 5.0,
+            custom_data: None,
         };
         let err = gradient_type.validate().unwrap_err();
         assert_invalid_fields(&err, &["gradient"]);
@@ -93,6 +106,7 @@ mod tests {
             priority: 0,
             gradient: 10.0,
             soft_gradient: -1.0, // Invalid
+            custom_data: None,
         };
         let err = gradient_type.validate().unwrap_err();
         assert_invalid_fields(&err, &["soft_gradient"]);
@@ -105,6 +119,7 @@ mod tests {
             priority: 1,
             gradient: 20.0,
             soft_gradient: 15.0,
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&original_struct).unwrap();
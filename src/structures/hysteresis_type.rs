@@ -1,10 +1,12 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 
 /// Used by: Common::DERCurveType
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
 pub struct HysteresisType {
     /// Optional. High value for return to normal operation after a grid event, in absolute value. This value adopts the same unit as defined by yUnit
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -18,6 +20,9 @@ pub struct HysteresisType {
     /// Optional. Set default rate of change (ramp rate %/s) for the EV to return to normal operation after a grid event
     #[serde(skip_serializing_if = "Option::is_none")]
     pub hysteresis_gradient: Option<f64>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 #[typetag::serde]
@@ -38,6 +43,10 @@ impl OcppEntity for HysteresisType {
             );
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("HysteresisType")
     }
 }
@@ -54,6 +63,7 @@ mod tests {
             hysteresis_low: Some(49.0),
             hysteresis_delay: Some(2.5),
             hysteresis_gradient: Some(0.1),
+            custom_data: None,
         };
         assert!(hysteresis_type_full.validate().is_ok());
 
@@ -62,6 +72,7 @@ mod tests {
             hysteresis_low: None,
             hysteresis_delay: None,
             hysteresis_gradient: None,
+            custom_data: None,
         };
         assert!(hysteresis_type_minimal.validate().is_ok());
     }
@@ -73,6 +84,7 @@ mod tests {
             hysteresis_low: Some(49.0),
             hysteresis_delay: Some(2.5),
             hysteresis_gradient: Some(0.1),
+            custom_data: None,
         };
 
         let serialized_full = serde_json::to_string(&original_struct_full).unwrap();
@@ -84,6 +96,7 @@ mod tests {
             hysteresis_low: None,
             hysteresis_delay: None,
             hysteresis_gradient: None,
+            custom_data: None,
         };
         let serialized_minimal = serde_json::to_string(&original_struct_minimal).unwrap();
         let deserialized_minimal: HysteresisType =
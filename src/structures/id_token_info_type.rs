@@ -1,5 +1,6 @@
 use crate::enums::authorization_status_enum_type::AuthorizationStatusEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::id_token_type::IdTokenType;
 use crate::structures::message_content_type::MessageContentType;
 use crate::traits::OcppEntity;
@@ -34,6 +35,9 @@ pub struct IdTokenInfoType {
     /// Optional. Personal message that can be shown to the EV Driver.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub personal_message: Option<MessageContentType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for IdTokenInfoType {
@@ -71,6 +75,12 @@ impl OcppEntity for IdTokenInfoType {
             e.check_member("personal_message", message);
         }
 
+        e.check_enum("status", &self.status);
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("IdTokenInfoType")
     }
 }
@@ -91,6 +101,7 @@ mod tests {
             evse_id: Some(vec![1, 3]),
             group_id_token: Some(IdTokenType::default()),
             personal_message: Some(MessageContentType::default()),
+            custom_data: None,
         };
         assert!(full_struct.validate().is_ok());
 
@@ -103,6 +114,7 @@ mod tests {
             evse_id: None,
             group_id_token: None,
             personal_message: None,
+            custom_data: None,
         };
         assert!(minimal_struct.validate().is_ok());
     }
@@ -118,6 +130,7 @@ mod tests {
             evse_id: None,
             group_id_token: None,
             personal_message: None,
+            custom_data: None,
         };
         let result = id_token_info_type.validate();
         assert!(result.is_err());
@@ -144,6 +157,7 @@ mod tests {
             evse_id: None,
             group_id_token: None,
             personal_message: None,
+            custom_data: None,
         };
         let result = id_token_info_type.validate();
         assert!(result.is_err());
@@ -170,6 +184,7 @@ mod tests {
             language2: None,
             group_id_token: None,
             personal_message: None,
+            custom_data: None,
         };
         let result = id_token_info_type.validate();
         assert!(result.is_err());
@@ -196,6 +211,7 @@ mod tests {
             evse_id: Some(vec![1, 3]),
             group_id_token: Some(IdTokenType::default()),
             personal_message: Some(MessageContentType::default()),
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&original_struct).unwrap();
@@ -211,6 +227,7 @@ mod tests {
             evse_id: None,
             group_id_token: None,
             personal_message: None,
+            custom_data: None,
         };
         let serialized_minimal = serde_json::to_string(&original_struct_minimal).unwrap();
         let deserialized_minimal: IdTokenInfoType =
@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::additional_info_type::AdditionalInfoType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 
 /// Contains a case insensitive identifier to use for the authorization and the type of authorization to support multiple forms of identifiers.
@@ -17,6 +18,9 @@ pub struct IdTokenType {
     /// Optional. AdditionalInfo can be used to send extra information.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub additional_info: Option<Vec<AdditionalInfoType>>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for IdTokenType {
@@ -25,6 +29,7 @@ impl Default for IdTokenType {
             id_token: "".to_string(),
             r#type: "".to_string(),
             additional_info: None,
+            custom_data: None,
         }
     }
 }
@@ -42,6 +47,10 @@ impl OcppEntity for IdTokenType {
             e.check_iter_member("additional_info", additional_info.iter());
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("IdTokenType")
     }
 }
@@ -58,6 +67,7 @@ mod tests {
             id_token: "my_token_123".to_string(),
             r#type: "RFID".to_string(),
             additional_info: Some(vec![AdditionalInfoType::default()]),
+            custom_data: None,
         };
         assert!(id_token_type.validate().is_ok());
 
@@ -65,6 +75,7 @@ mod tests {
             id_token: "my_token_123".to_string(),
             r#type: "RFID".to_string(),
             additional_info: None,
+            custom_data: None,
         };
         assert!(id_token_type_minimal.validate().is_ok());
     }
@@ -75,6 +86,7 @@ mod tests {
             id_token: "a".repeat(256), // Invalid length
             r#type: "RFID".to_string(),
             additional_info: None,
+            custom_data: None,
         };
         let result = id_token_type.validate();
         assert!(result.is_err());
@@ -96,6 +108,7 @@ mod tests {
             id_token: "my_token_123".to_string(),
             r#type: "a".repeat(21), // Invalid length
             additional_info: None,
+            custom_data: None,
         };
         let result = id_token_type.validate();
         assert!(result.is_err());
@@ -117,6 +130,7 @@ mod tests {
             id_token: "my_token_123".to_string(),
             r#type: "RFID".to_string(),
             additional_info: Some(vec![AdditionalInfoType::default()]),
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&original_struct).unwrap();
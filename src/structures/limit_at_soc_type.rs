@@ -1,20 +1,29 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 
 /// Used by: Common::ChargingScheduleType
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct LimitAtSOCType {
     /// Required. The SoC value beyond which the charging rate limit should be applied.
     pub soc: i32,
     /// Required. Charging rate limit beyond the SoC value.
     pub limit: f64,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for LimitAtSOCType {
     fn default() -> Self {
-        Self { soc: 0, limit: 0.0 }
+        Self {
+            soc: 0,
+            limit: 0.0,
+            custom_data: None,
+        }
     }
 }
 #[typetag::serde]
@@ -26,6 +35,10 @@ impl OcppEntity for LimitAtSOCType {
 
         e.check_bounds("soc", 0, 100, self.soc);
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("LimitAtSOCType")
     }
 }
@@ -41,12 +54,14 @@ mod tests {
         let limit_at_soc_type = LimitAtSOCType {
             soc: 50,
             limit: 7.5,
+            custom_data: None,
         };
         assert!(limit_at_soc_type.validate().is_ok());
 
         let limit_at_soc_type_bounds = LimitAtSOCType {
             soc: 0,
             limit: 100.0,
+            custom_data: None,
         };
         assert!(limit_at_soc_type_bounds.validate().is_ok());
     }
@@ -56,6 +71,7 @@ mod tests {
         let limit_at_soc_type = LimitAtSOCType {
             soc: -1, // Invalid
             limit: 7.5,
+            custom_data: None,
         };
         let err = limit_at_soc_type.validate().unwrap_err();
         assert_invalid_fields(&err, &["soc"]);
@@ -67,6 +83,7 @@ mod tests {
         let limit_at_soc_type = LimitAtSOCType {
             soc: 101, // Invalid
             limit: 7.5,
+            custom_data: None,
         };
         let err = limit_at_soc_type.validate().unwrap_err();
         assert_invalid_fields(&err, &["soc"]);
@@ -78,6 +95,7 @@ mod tests {
         let original_struct = LimitAtSOCType {
             soc: 50,
             limit: 7.5,
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&original_struct).unwrap();
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::limit_max_discharge_type::LimitMaxDischargeType;
 use crate::traits::OcppEntity;
 
@@ -16,6 +17,9 @@ pub struct LimitMaxDischargeGetType {
     pub is_superseded: bool,
     /// Required. Maximum discharge power as percentage or rated capability
     pub limit_max_discharge: LimitMaxDischargeType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for LimitMaxDischargeGetType {
@@ -28,6 +32,10 @@ impl OcppEntity for LimitMaxDischargeGetType {
 
         e.check_member("limit_max_discharge", &self.limit_max_discharge);
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("LimitMaxDischargeGetType")
     }
 }
@@ -45,6 +53,7 @@ mod tests {
             is_default: true,
             is_superseded: false,
             limit_max_discharge: LimitMaxDischargeType::default(),
+            custom_data: None,
         };
         assert!(limit_max_discharge_get_type.validate().is_ok());
     }
@@ -56,6 +65,7 @@ mod tests {
             is_default: true,
             is_superseded: false,
             limit_max_discharge: LimitMaxDischargeType::default(),
+            custom_data: None,
         };
         let err = limit_max_discharge_get_type.validate().unwrap_err();
         assert_invalid_fields(&err, &["id"]);
@@ -69,6 +79,7 @@ mod tests {
             is_default: true,
             is_superseded: false,
             limit_max_discharge: LimitMaxDischargeType::default(),
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&original_struct).unwrap();
@@ -2,6 +2,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::der_curve_type::DERCurveType;
 use crate::traits::OcppEntity;
 
@@ -24,6 +25,9 @@ pub struct LimitMaxDischargeType {
     /// Optional. The curve is an interpolation of data points.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub power_monitoring_must_trip: Option<DERCurveType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 #[typetag::serde]
@@ -43,6 +47,10 @@ impl OcppEntity for LimitMaxDischargeType {
             e.check_member("power_monitoring_must_trip", curve);
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("LimitMaxDischargeType")
     }
 }
@@ -61,6 +69,7 @@ mod tests {
             start_time: Some(Utc.timestamp_opt(1672531200, 0).unwrap()),
             duration: Some(3600.0),
             power_monitoring_must_trip: Some(DERCurveType::default()),
+            custom_data: None,
         };
 
         if let Err(e) = limit_max_discharge_type.validate() {
@@ -83,6 +92,7 @@ mod tests {
             start_time: None,
             duration: None,
             power_monitoring_must_trip: None,
+            custom_data: None,
         };
         assert!(limit_max_discharge_type.validate().is_ok());
     }
@@ -95,6 +105,7 @@ mod tests {
             start_time: None,
             duration: None,
             power_monitoring_must_trip: None,
+            custom_data: None,
         };
         let result = limit_max_discharge_type.validate();
         assert!(result.is_err());
@@ -118,6 +129,7 @@ mod tests {
             start_time: None,
             duration: None,
             power_monitoring_must_trip: None,
+            custom_data: None,
         };
         let result = limit_max_discharge_type.validate();
         assert!(result.is_err());
@@ -141,6 +153,7 @@ mod tests {
             start_time: Some(Utc.timestamp_opt(1672531200, 0).unwrap()),
             duration: Some(3600.0),
             power_monitoring_must_trip: Some(DERCurveType::default()),
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&original_struct).unwrap();
@@ -2,6 +2,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 
 /// Generic class for the configuration of logging entries.
@@ -17,6 +18,9 @@ pub struct LogParametersType {
     /// Optional. The date and time of the latest logging information to include in the diagnostics.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub latest_timestamp: Option<DateTime<Utc>>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for LogParametersType {
@@ -25,6 +29,7 @@ impl Default for LogParametersType {
             remote_location: "".to_string(),
             oldest_timestamp: None,
             latest_timestamp: None,
+            custom_data: None,
         }
     }
 }
@@ -48,6 +53,10 @@ impl OcppEntity for LogParametersType {
             );
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("LogParametersType")
     }
 }
@@ -64,6 +73,7 @@ mod tests {
             remote_location: "http://example.com/logs".to_string(),
             oldest_timestamp: Some(Utc.timestamp_opt(1672531200, 0).unwrap()),
             latest_timestamp: Some(Utc.timestamp_opt(1672534800, 0).unwrap()),
+            custom_data: None,
         };
         assert!(log_parameters.validate().is_ok());
     }
@@ -74,6 +84,7 @@ mod tests {
             remote_location: "http://example.com/logs".to_string(),
             oldest_timestamp: None,
             latest_timestamp: None,
+            custom_data: None,
         };
         assert!(log_parameters.validate().is_ok());
     }
@@ -84,6 +95,7 @@ mod tests {
             remote_location: "a".repeat(2001), // Invalid length
             oldest_timestamp: None,
             latest_timestamp: None,
+            custom_data: None,
         };
         let result = log_parameters.validate();
         assert!(result.is_err());
@@ -105,6 +117,7 @@ mod tests {
             remote_location: "http://example.com/logs".to_string(),
             oldest_timestamp: Some(Utc.timestamp_opt(1672531200, 0).unwrap()),
             latest_timestamp: Some(Utc.timestamp_opt(1672534800, 0).unwrap()),
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&original_struct).unwrap();
@@ -1,5 +1,6 @@
 use crate::enums::message_format_enum_type::MessageFormatEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
 
@@ -15,6 +16,9 @@ pub struct MessageContentType {
     pub language: Option<String>,
     /// Required. Message contents.
     pub content: String,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for MessageContentType {
@@ -23,6 +27,7 @@ impl Default for MessageContentType {
             format: MessageFormatEnumType::ASCII,
             language: None,
             content: "".to_string(),
+            custom_data: None,
         }
     }
 }
@@ -39,6 +44,12 @@ impl OcppEntity for MessageContentType {
 
         e.check_cardinality("content", 0, 1024, &self.content.chars());
 
+        e.check_enum("format", &self.format);
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("MessageContentType")
     }
 }
@@ -55,6 +66,7 @@ mod tests {
             format: MessageFormatEnumType::ASCII,
             language: Some("en-US".to_string()),
             content: "Hello World!".to_string(),
+            custom_data: None,
         };
         assert!(message_content_type.validate().is_ok());
     }
@@ -65,6 +77,7 @@ mod tests {
             format: MessageFormatEnumType::ASCII,
             language: None,
             content: "Hello World!".to_string(),
+            custom_data: None,
         };
         assert!(message_content_type.validate().is_ok());
     }
@@ -75,6 +88,7 @@ mod tests {
             format: MessageFormatEnumType::ASCII,
             language: Some("a".repeat(9)), // Invalid length
             content: "Hello World!".to_string(),
+            custom_data: None,
         };
         let result = message_content_type.validate();
         assert!(result.is_err());
@@ -96,6 +110,7 @@ mod tests {
             format: MessageFormatEnumType::ASCII,
             language: None,
             content: "a".repeat(1025), // Invalid length
+            custom_data: None,
         };
         let result = message_content_type.validate();
         assert!(result.is_err());
@@ -117,6 +132,7 @@ mod tests {
             format: MessageFormatEnumType::ASCII,
             language: Some("en-US".to_string()),
             content: "Hello World!".to_string(),
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&original_struct).unwrap();
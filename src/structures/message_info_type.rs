@@ -2,6 +2,7 @@ use crate::enums::message_priority_enum_type::MessagePriorityEnumType;
 use crate::enums::message_state_enum_type::MessageStateEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::component_type::ComponentType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::message_content_type::MessageContentType;
 use crate::traits::OcppEntity;
 use chrono::{DateTime, Utc};
@@ -9,7 +10,7 @@ use serde::{Deserialize, Serialize};
 
 /// Contains message details for a message to be displayed on a Charging Station.
 /// Used by: SetDisplayMessageRequest, NotifyDisplayMessagesRequest
-#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct MessageInfoType {
     /// Required. Unique id within an exchange context. It is defined within the OCPP context as a positive integer value (greater or equal to zero).
@@ -34,8 +35,11 @@ pub struct MessageInfoType {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub display: Option<ComponentType>,
     /// Optional. Contains message details for extra languages to be displayed on a Charging Station.
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub message_extra: Vec<MessageContentType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for MessageInfoType {
@@ -69,6 +73,16 @@ impl OcppEntity for MessageInfoType {
         e.check_cardinality("message_extra", 0, 4, &self.message_extra.iter());
         e.check_iter_member("message_extra", self.message_extra.iter());
 
+        e.check_enum("priority", &self.priority);
+
+        if let Some(state) = &self.state {
+            e.check_enum("state", state);
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("MessageInfoType")
     }
 }
@@ -92,6 +106,7 @@ mod tests {
             message: MessageContentType::default(),
             display: Some(ComponentType::default()),
             message_extra: vec![MessageContentType::default(), MessageContentType::default()],
+            custom_data: None,
         };
         assert!(message_info.validate().is_ok());
     }
@@ -108,6 +123,7 @@ mod tests {
             message: MessageContentType::default(),
             display: None,
             message_extra: vec![],
+            custom_data: None,
         };
         assert!(message_info.validate().is_ok());
     }
@@ -124,6 +140,7 @@ mod tests {
             message: MessageContentType::default(),
             display: None,
             message_extra: vec![],
+            custom_data: None,
         };
         let err = message_info.validate().unwrap_err();
         assert_num_field_errors(&err, 1);
@@ -142,6 +159,7 @@ mod tests {
             message: MessageContentType::default(),
             display: None,
             message_extra: vec![],
+            custom_data: None,
         };
         let err = message_info.validate().unwrap_err();
         assert_num_field_errors(&err, 2);
@@ -160,6 +178,7 @@ mod tests {
             message: MessageContentType::default(),
             display: Some(ComponentType::default()),
             message_extra: vec![Default::default(); 2],
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&original_struct).unwrap();
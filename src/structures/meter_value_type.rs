@@ -2,6 +2,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::sampled_meter_value_type::SampledValueType;
 use crate::traits::OcppEntity;
 
@@ -15,6 +16,9 @@ pub struct MeterValueType {
     pub timestamp: DateTime<Utc>,
     /// Required. One or more measured values.
     pub sampled_value: Vec<SampledValueType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for MeterValueType {
@@ -22,6 +26,7 @@ impl Default for MeterValueType {
         Self {
             timestamp: Utc::now(),
             sampled_value: vec![],
+            custom_data: None,
         }
     }
 }
@@ -35,6 +40,10 @@ impl OcppEntity for MeterValueType {
         e.check_cardinality("sampled_value", 1, usize::MAX, &self.sampled_value.iter());
         e.check_iter_member("sampled_value", self.sampled_value.iter());
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("MeterValueType")
     }
 }
@@ -50,12 +59,14 @@ mod tests {
         let meter_value_type = MeterValueType {
             timestamp: Utc.timestamp_opt(1672531200, 0).unwrap(),
             sampled_value: vec![Default::default()],
+            custom_data: None,
         };
         assert!(meter_value_type.validate().is_ok());
 
         let meter_value_type_multiple = MeterValueType {
             timestamp: Utc.timestamp_opt(1672531200, 0).unwrap(),
             sampled_value: vec![Default::default(); 3],
+            custom_data: None,
         };
         assert!(meter_value_type_multiple.validate().is_ok());
     }
@@ -65,6 +76,7 @@ mod tests {
         let meter_value_type = MeterValueType {
             timestamp: Utc.timestamp_opt(1672531200, 0).unwrap(),
             sampled_value: vec![], // Invalid length
+            custom_data: None,
         };
         let result = meter_value_type.validate();
         assert!(result.is_err());
@@ -85,6 +97,7 @@ mod tests {
         let original_struct = MeterValueType {
             timestamp: Utc.timestamp_opt(1672531200, 0).unwrap(),
             sampled_value: vec![Default::default(); 2],
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&original_struct).unwrap();
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 
 /// Defines parameters required for initiating and maintaining wireless communication with other devices.
@@ -15,6 +16,9 @@ pub struct ModemType {
     /// Optional. This contains the IMSI of the modem's SIM card.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub imsi: Option<String>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 #[typetag::serde]
@@ -32,6 +36,10 @@ impl OcppEntity for ModemType {
             e.check_cardinality("imsi", 0, 20, &imsi.chars());
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("ModemType")
     }
 }
@@ -46,6 +54,7 @@ mod tests {
         let modem_type = ModemType {
             iccid: Some("12345678901234567890".to_string()),
             imsi: Some("09876543210987654321".to_string()),
+            custom_data: None,
         };
         assert!(modem_type.validate().is_ok());
     }
@@ -55,6 +64,7 @@ mod tests {
         let modem_type = ModemType {
             iccid: None,
             imsi: None,
+            custom_data: None,
         };
         assert!(modem_type.validate().is_ok());
     }
@@ -64,6 +74,7 @@ mod tests {
         let modem_type = ModemType {
             iccid: Some("a".repeat(21)), // Invalid length
             imsi: None,
+            custom_data: None,
         };
         let result = modem_type.validate();
         assert!(result.is_err());
@@ -84,6 +95,7 @@ mod tests {
         let modem_type = ModemType {
             iccid: None,
             imsi: Some("b".repeat(21)), // Invalid length
+            custom_data: None,
         };
         let result = modem_type.validate();
         assert!(result.is_err());
@@ -104,6 +116,7 @@ mod tests {
         let original_struct = ModemType {
             iccid: Some("some_iccid_value".to_string()),
             imsi: Some("some_imsi_value".to_string()),
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&original_struct).unwrap();
@@ -114,6 +127,7 @@ mod tests {
         let original_struct_minimal = ModemType {
             iccid: None,
             imsi: None,
+            custom_data: None,
         };
         let serialized_minimal = serde_json::to_string(&original_struct_minimal).unwrap();
         let deserialized_minimal: ModemType = serde_json::from_str(&serialized_minimal).unwrap();
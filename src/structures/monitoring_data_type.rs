@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::component_type::ComponentType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::variable_monitoring_type::VariableMonitoringType;
 use crate::structures::variable_type::VariableType;
 use crate::traits::OcppEntity;
@@ -17,6 +18,9 @@ pub struct MonitoringDataType {
     pub variable: VariableType,
     /// Required. List of monitors for this Component-Variable pair.
     pub variable_monitoring: Vec<VariableMonitoringType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for MonitoringDataType {
@@ -37,6 +41,10 @@ impl OcppEntity for MonitoringDataType {
         );
         e.check_iter_member("variable_monitoring", self.variable_monitoring.iter());
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("MonitoringDataType")
     }
 }
@@ -54,6 +62,7 @@ mod tests {
             component: ComponentType::default(),
             variable: VariableType::default(),
             variable_monitoring: vec![VariableMonitoringType::default(); 2],
+            custom_data: None,
         };
         assert!(monitoring_data.validate().is_ok());
     }
@@ -64,6 +73,7 @@ mod tests {
             component: ComponentType::default(),
             variable: VariableType::default(),
             variable_monitoring: vec![VariableMonitoringType::default()],
+            custom_data: None,
         };
         assert!(monitoring_data.validate().is_ok());
     }
@@ -74,6 +84,7 @@ mod tests {
             component: ComponentType::default(),
             variable: VariableType::default(),
             variable_monitoring: vec![], // Invalid: empty vector
+            custom_data: None,
         };
         let result = monitoring_data.validate();
         assert!(result.is_err());
@@ -95,6 +106,7 @@ mod tests {
             component: ComponentType::default(),
             variable: VariableType::default(),
             variable_monitoring: vec![VariableMonitoringType::default(); 2],
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&original_struct).unwrap();
@@ -3,6 +3,7 @@ use crate::enums::ocpp_transport_enum_type::OCPPTransportEnumType;
 use crate::enums::ocpp_version_enum_type::OCPPVersionEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::apn_type::APNType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::vpn_type::VPNType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
@@ -37,6 +38,27 @@ pub struct NetworkConnectionProfileType {
     /// Optional. Configuration data needed to make a data-connection over a cellular network.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub apn: Option<APNType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
+}
+
+impl Default for NetworkConnectionProfileType {
+    fn default() -> NetworkConnectionProfileType {
+        Self {
+            ocpp_version: None,
+            ocpp_interface: OCPPInterfaceEnumType::Any,
+            ocpp_transport: OCPPTransportEnumType::JSON,
+            message_timeout: 30,
+            ocpp_csms_url: "".to_string(),
+            security_profile: 0,
+            identity: None,
+            basic_auth_password: None,
+            vpn: None,
+            apn: None,
+            custom_data: None,
+        }
+    }
 }
 #[typetag::serde]
 impl OcppEntity for NetworkConnectionProfileType {
@@ -57,6 +79,18 @@ impl OcppEntity for NetworkConnectionProfileType {
             e.check_cardinality("basic_auth_password", 0, 64, &password.chars());
         }
 
+        if let Some(ocpp_version) = &self.ocpp_version {
+            e.check_enum("ocpp_version", ocpp_version);
+        }
+
+        e.check_enum("ocpp_interface", &self.ocpp_interface);
+
+        e.check_enum("ocpp_transport", &self.ocpp_transport);
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("NetworkConnectionProfileType")
     }
 }
@@ -80,6 +114,7 @@ mod tests {
             basic_auth_password: Some("password".to_string()),
             vpn: Some(VPNType::default()),
             apn: Some(APNType::default()),
+            custom_data: None,
         };
         assert!(profile.validate().is_ok());
     }
@@ -97,6 +132,7 @@ mod tests {
             basic_auth_password: None,
             vpn: None,
             apn: None,
+            custom_data: None,
         };
         assert!(profile.validate().is_ok());
     }
@@ -114,6 +150,7 @@ mod tests {
             basic_auth_password: None,
             vpn: None,
             apn: None,
+            custom_data: None,
         };
         let result = profile.validate();
         assert!(result.is_err());
@@ -142,6 +179,7 @@ mod tests {
             basic_auth_password: None,
             vpn: None,
             apn: None,
+            custom_data: None,
         };
         let result = profile.validate();
         assert!(result.is_err());
@@ -170,6 +208,7 @@ mod tests {
             basic_auth_password: Some("password".to_string()),
             vpn: Some(VPNType::default()),
             apn: Some(APNType::default()),
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&original_struct).unwrap();
@@ -1,5 +1,6 @@
 use crate::enums::hash_algorithm_enum_type::HashAlgorithmEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
 
@@ -17,7 +18,11 @@ pub struct OCSPRequestDataType {
     /// Required. The string representation of the hexadecimal value of the serial number.
     pub serial_number: String,
     /// Required. This contains the responder URL.
+    #[serde(rename = "responderURL")]
     pub responder_url: String,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for OCSPRequestDataType {
@@ -28,6 +33,7 @@ impl Default for OCSPRequestDataType {
             issuer_key_hash: "".to_string(),
             serial_number: "".to_string(),
             responder_url: "".to_string(),
+            custom_data: None,
         }
     }
 }
@@ -43,6 +49,12 @@ impl OcppEntity for OCSPRequestDataType {
         e.check_cardinality("serial_number", 0, 40, &self.serial_number.chars());
         e.check_cardinality("responder_url", 0, 2000, &self.responder_url.chars());
 
+        e.check_enum("hash_algorithm", &self.hash_algorithm);
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("OCSPRequestDataType")
     }
 }
@@ -60,6 +72,7 @@ mod tests {
             issuer_key_hash: "b".repeat(128),
             serial_number: "c".repeat(40),
             responder_url: "d".repeat(2000),
+            custom_data: None,
         };
         assert!(ocsp_request.validate().is_ok());
     }
@@ -72,6 +85,7 @@ mod tests {
             issuer_key_hash: "b".repeat(10),
             serial_number: "c".repeat(10),
             responder_url: "d".repeat(10),
+            custom_data: None,
         };
         let result = ocsp_request.validate();
         assert!(result.is_err());
@@ -95,6 +109,7 @@ mod tests {
             issuer_key_hash: "b".repeat(129), // Invalid length
             serial_number: "c".repeat(10),
             responder_url: "d".repeat(10),
+            custom_data: None,
         };
         let result = ocsp_request.validate();
         assert!(result.is_err());
@@ -118,6 +133,7 @@ mod tests {
             issuer_key_hash: "b".repeat(10),
             serial_number: "c".repeat(41), // Invalid length
             responder_url: "d".repeat(10),
+            custom_data: None,
         };
         let result = ocsp_request.validate();
         assert!(result.is_err());
@@ -141,6 +157,7 @@ mod tests {
             issuer_key_hash: "b".repeat(10),
             serial_number: "c".repeat(10),
             responder_url: "d".repeat(2001), // Invalid length
+            custom_data: None,
         };
         let result = ocsp_request.validate();
         assert!(result.is_err());
@@ -164,6 +181,7 @@ mod tests {
             issuer_key_hash: "some_key_hash".to_string(),
             serial_number: "some_serial".to_string(),
             responder_url: "https://ocsp.example.com".to_string(),
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&original_struct).unwrap();
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::overstay_rule_type::OverstayRuleType;
 use crate::structures::rational_number_type::RationalNumberType;
 use crate::traits::OcppEntity;
@@ -18,6 +19,9 @@ pub struct OverstayRuleListType {
     pub overstay_power_threshold: Option<RationalNumberType>,
     /// Required. Overstay rules that will be applied.
     pub overstay_rule: Vec<OverstayRuleType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for OverstayRuleListType {
@@ -33,6 +37,10 @@ impl OcppEntity for OverstayRuleListType {
         e.check_cardinality("overstay_rule", 1, 5, &self.overstay_rule.iter());
         e.check_iter_member("overstay_rule", self.overstay_rule.iter());
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("OverstayRuleListType")
     }
 }
@@ -50,6 +58,7 @@ mod tests {
             overstay_time_threshold: Some(3600),
             overstay_power_threshold: Some(RationalNumberType::default()),
             overstay_rule: vec![OverstayRuleType::default(), OverstayRuleType::default()],
+            custom_data: None,
         };
         assert!(rule_list.validate().is_ok());
     }
@@ -60,6 +69,7 @@ mod tests {
             overstay_time_threshold: None,
             overstay_power_threshold: None,
             overstay_rule: vec![OverstayRuleType::default()],
+            custom_data: None,
         };
 
         if let Err(e) = rule_list.validate() {
@@ -75,6 +85,7 @@ mod tests {
             overstay_time_threshold: None,
             overstay_power_threshold: None,
             overstay_rule: vec![], // Invalid: length 0, min 1
+            custom_data: None,
         };
         let err = rule_list.validate().unwrap_err();
         assert_invalid_fields(&err, &["overstay_rule"]);
@@ -87,6 +98,7 @@ mod tests {
             overstay_time_threshold: None,
             overstay_power_threshold: None,
             overstay_rule: vec![OverstayRuleType::default(); 6], // Invalid: length 6, max 5
+            custom_data: None,
         };
         let err = rule_list.validate().unwrap_err();
         assert_invalid_fields(&err, &["overstay_rule"]);
@@ -99,6 +111,7 @@ mod tests {
             overstay_time_threshold: Some(1800),
             overstay_power_threshold: Some(RationalNumberType::default()),
             overstay_rule: vec![OverstayRuleType::default()],
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&original_struct).unwrap();
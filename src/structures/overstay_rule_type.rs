@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::rational_number_type::RationalNumberType;
 use crate::traits::OcppEntity;
 
@@ -18,6 +19,9 @@ pub struct OverstayRuleType {
     pub overstay_fee_period: i32,
     /// Required. Fee that applies to this overstay.
     pub overstay_fee: RationalNumberType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for OverstayRuleType {
@@ -34,6 +38,10 @@ impl OcppEntity for OverstayRuleType {
         e.check_bounds("overstay_fee_period", 1, i32::MAX, self.overstay_fee_period);
         e.check_member("overstay_fee", &self.overstay_fee);
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("OverstayRuleType")
     }
 }
@@ -45,6 +53,7 @@ impl Default for OverstayRuleType {
             start_time: 0,
             overstay_fee_period: 1,
             overstay_fee: Default::default(),
+            custom_data: None,
         }
     }
 }
@@ -61,6 +70,7 @@ mod tests {
             start_time: 300,
             overstay_fee_period: 60,
             overstay_fee: RationalNumberType::default(),
+            custom_data: None,
         };
         assert!(overstay_rule.validate().is_ok());
     }
@@ -72,6 +82,7 @@ mod tests {
             start_time: 0,
             overstay_fee_period: 1,
             overstay_fee: RationalNumberType::default(),
+            custom_data: None,
         };
         assert!(overstay_rule.validate().is_ok());
     }
@@ -83,6 +94,7 @@ mod tests {
             start_time: 300,
             overstay_fee_period: 60,
             overstay_fee: RationalNumberType::default(),
+            custom_data: None,
         };
         let result = overstay_rule.validate();
         assert!(result.is_err());
@@ -105,6 +117,7 @@ mod tests {
             start_time: -1, // Invalid
             overstay_fee_period: 60,
             overstay_fee: RationalNumberType::default(),
+            custom_data: None,
         };
         let result = overstay_rule.validate();
         assert!(result.is_err());
@@ -127,6 +140,7 @@ mod tests {
             start_time: 300,
             overstay_fee_period: 0, // Invalid
             overstay_fee: RationalNumberType::default(),
+            custom_data: None,
         };
         let result = overstay_rule.validate();
         assert!(result.is_err());
@@ -149,6 +163,7 @@ mod tests {
             start_time: 300,
             overstay_fee_period: 60,
             overstay_fee: RationalNumberType::default(),
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&original_struct).unwrap();
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 
 /// Defines the stream parameters for a variable.
@@ -15,6 +16,9 @@ pub struct PeriodicEventStreamParamsType {
     /// Optional. Number of items to be sent together in stream.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub values: Option<i32>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 #[typetag::serde]
@@ -32,6 +36,10 @@ impl OcppEntity for PeriodicEventStreamParamsType {
             e.check_bounds("values", 0, i32::MAX, values);
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("PeriodicEventStreamParamsType")
     }
 }
@@ -46,6 +54,7 @@ mod tests {
         let params = PeriodicEventStreamParamsType {
             interval: Some(60),
             values: Some(10),
+            custom_data: None,
         };
         assert!(params.validate().is_ok());
     }
@@ -55,6 +64,7 @@ mod tests {
         let params = PeriodicEventStreamParamsType {
             interval: None,
             values: None,
+            custom_data: None,
         };
         assert!(params.validate().is_ok());
     }
@@ -64,6 +74,7 @@ mod tests {
         let params = PeriodicEventStreamParamsType {
             interval: Some(0),
             values: Some(0),
+            custom_data: None,
         };
         assert!(params.validate().is_ok());
     }
@@ -73,6 +84,7 @@ mod tests {
         let params = PeriodicEventStreamParamsType {
             interval: Some(-1), // Invalid value
             values: Some(10),
+            custom_data: None,
         };
         let result = params.validate();
         assert!(result.is_err());
@@ -93,6 +105,7 @@ mod tests {
         let params = PeriodicEventStreamParamsType {
             interval: Some(60),
             values: Some(-1), // Invalid value
+            custom_data: None,
         };
         let result = params.validate();
         assert!(result.is_err());
@@ -113,6 +126,7 @@ mod tests {
         let original_struct = PeriodicEventStreamParamsType {
             interval: Some(30),
             values: Some(5),
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&original_struct).unwrap();
@@ -124,6 +138,7 @@ mod tests {
         let original_struct_minimal = PeriodicEventStreamParamsType {
             interval: None,
             values: None,
+            custom_data: None,
         };
         let serialized_minimal = serde_json::to_string(&original_struct_minimal).unwrap();
         let deserialized_minimal: PeriodicEventStreamParamsType =
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 
 /// Part of ISO 15118-20 price schedule.
@@ -13,6 +14,9 @@ pub struct PriceLevelScheduleEntryType {
     pub duration: i32,
     /// Required. Defines the price level of this PriceLevelScheduleEntry.
     pub price_level: i32,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 #[typetag::serde]
@@ -25,6 +29,10 @@ impl OcppEntity for PriceLevelScheduleEntryType {
         e.check_bounds("duration", 0, i32::MAX, self.duration);
         e.check_bounds("price_level", 0, i32::MAX, self.price_level);
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("PriceLevelScheduleEntryType")
     }
 }
@@ -39,6 +47,7 @@ mod tests {
         let entry = PriceLevelScheduleEntryType {
             duration: 3600,
             price_level: 5,
+            custom_data: None,
         };
         assert!(entry.validate().is_ok());
     }
@@ -48,6 +57,7 @@ mod tests {
         let entry = PriceLevelScheduleEntryType {
             duration: 0,
             price_level: 0,
+            custom_data: None,
         };
         assert!(entry.validate().is_ok());
     }
@@ -57,6 +67,7 @@ mod tests {
         let entry = PriceLevelScheduleEntryType {
             duration: -1, // Invalid
             price_level: 5,
+            custom_data: None,
         };
         let result = entry.validate();
         assert!(result.is_err());
@@ -77,6 +88,7 @@ mod tests {
         let entry = PriceLevelScheduleEntryType {
             duration: 3600,
             price_level: -1, // Invalid
+            custom_data: None,
         };
         let result = entry.validate();
         assert!(result.is_err());
@@ -97,6 +109,7 @@ mod tests {
         let original_struct = PriceLevelScheduleEntryType {
             duration: 300,
             price_level: 10,
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&original_struct).unwrap();
@@ -2,6 +2,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::price_level_schedule_entry_type::PriceLevelScheduleEntryType;
 use crate::traits::OcppEntity;
 
@@ -21,6 +22,9 @@ pub struct PriceLevelScheduleType {
     pub number_of_price_levels: i32,
     /// Required. List of entries of the schedule.
     pub price_level_schedule_entries: Vec<PriceLevelScheduleEntryType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for PriceLevelScheduleType {
@@ -52,6 +56,10 @@ impl OcppEntity for PriceLevelScheduleType {
             self.price_level_schedule_entries.iter(),
         );
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("PriceLevelScheduleType")
     }
 }
@@ -71,6 +79,7 @@ mod tests {
             price_schedule_description: Some("Winter schedule".to_string()),
             number_of_price_levels: 10,
             price_level_schedule_entries: vec![Default::default()],
+            custom_data: None,
         };
         assert!(schedule.validate().is_ok());
     }
@@ -83,6 +92,7 @@ mod tests {
             price_schedule_description: None,
             number_of_price_levels: 10,
             price_level_schedule_entries: vec![Default::default()],
+            custom_data: None,
         };
         assert!(schedule.validate().is_ok());
     }
@@ -95,6 +105,7 @@ mod tests {
             price_schedule_description: Some("a".repeat(33)), // Invalid length
             number_of_price_levels: 10,
             price_level_schedule_entries: vec![Default::default()],
+            custom_data: None,
         };
         let err = schedule.validate().unwrap_err();
         assert_invalid_fields(&err, &["price_schedule_description"]);
@@ -109,6 +120,7 @@ mod tests {
             price_schedule_description: None,
             number_of_price_levels: 10,
             price_level_schedule_entries: vec![Default::default()],
+            custom_data: None,
         };
         let err = schedule.validate().unwrap_err();
         assert_invalid_fields(&err, &["price_schedule_id"]);
@@ -123,6 +135,7 @@ mod tests {
             price_schedule_description: None,
             number_of_price_levels: -1, // Invalid: must be >= 0
             price_level_schedule_entries: vec![Default::default()],
+            custom_data: None,
         };
         let err = schedule.validate().unwrap_err();
         assert_invalid_fields(&err, &["number_of_price_levels"]);
@@ -137,6 +150,7 @@ mod tests {
             price_schedule_description: None,
             number_of_price_levels: 10,
             price_level_schedule_entries: vec![], // Invalid: min 1
+            custom_data: None,
         };
         let err = schedule.validate().unwrap_err();
         assert_invalid_fields(&err, &["price_level_schedule_entries"]);
@@ -151,6 +165,7 @@ mod tests {
             price_schedule_description: None,
             number_of_price_levels: 10,
             price_level_schedule_entries: vec![Default::default(); 101], // Invalid: max 100
+            custom_data: None,
         };
         let err = schedule.validate().unwrap_err();
         assert_invalid_fields(&err, &["price_level_schedule_entries"]);
@@ -165,6 +180,7 @@ mod tests {
             price_schedule_description: Some("Test Schedule".to_string()),
             number_of_price_levels: 5,
             price_level_schedule_entries: vec![Default::default(), Default::default()],
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&original_struct).unwrap();
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::price_rule_type::PriceRuleType;
 use crate::traits::OcppEntity;
 
@@ -13,6 +14,9 @@ pub struct PriceRuleStackType {
     pub duration: i32,
     /// Required. Contains the price rules.
     pub price_rule: Vec<PriceRuleType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for PriceRuleStackType {
@@ -26,6 +30,10 @@ impl OcppEntity for PriceRuleStackType {
         e.check_cardinality("price_rule", 1, 8, &self.price_rule.iter());
         e.check_iter_member("price_rule", self.price_rule.iter());
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("PriceRuleStackType")
     }
 }
@@ -41,6 +49,7 @@ mod tests {
         let price_stack = PriceRuleStackType {
             duration: 3600,
             price_rule: vec![Default::default(), Default::default()],
+            custom_data: None,
         };
         assert!(price_stack.validate().is_ok());
     }
@@ -50,6 +59,7 @@ mod tests {
         let price_stack = PriceRuleStackType {
             duration: -1,
             price_rule: vec![Default::default()],
+            custom_data: None,
         };
         let err = price_stack.validate().unwrap_err();
         assert_invalid_fields(&err, &["duration"]);
@@ -61,6 +71,7 @@ mod tests {
         let price_stack = PriceRuleStackType {
             duration: 3600,
             price_rule: vec![],
+            custom_data: None,
         };
         let err = price_stack.validate().unwrap_err();
         assert_invalid_fields(&err, &["price_rule"]);
@@ -72,6 +83,7 @@ mod tests {
         let price_stack = PriceRuleStackType {
             duration: 3600,
             price_rule: vec![Default::default(); 9],
+            custom_data: None,
         };
         let err = price_stack.validate().unwrap_err();
         assert_invalid_fields(&err, &["price_rule"]);
@@ -83,6 +95,7 @@ mod tests {
         let original_struct = PriceRuleStackType {
             duration: 300,
             price_rule: vec![Default::default()],
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&original_struct).unwrap();
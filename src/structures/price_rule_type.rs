@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::rational_number_type::RationalNumberType;
 use crate::traits::OcppEntity;
 
@@ -26,6 +27,9 @@ pub struct PriceRuleType {
     pub parking_fee: Option<RationalNumberType>,
     /// Required. Power level above which this price rule applies.
     pub power_range_start: RationalNumberType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 #[typetag::serde]
@@ -63,6 +67,10 @@ impl OcppEntity for PriceRuleType {
 
         e.check_member("power_range_start", &self.power_range_start);
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("PriceRuleType")
     }
 }
@@ -82,6 +90,7 @@ mod tests {
             energy_fee: Default::default(),
             parking_fee: Some(Default::default()),
             power_range_start: Default::default(),
+            custom_data: None,
         };
         assert!(rule.validate().is_ok());
     }
@@ -95,6 +104,7 @@ mod tests {
             energy_fee: Default::default(),
             parking_fee: None,
             power_range_start: Default::default(),
+            custom_data: None,
         };
         assert!(rule.validate().is_ok());
     }
@@ -108,6 +118,7 @@ mod tests {
             energy_fee: Default::default(),
             parking_fee: None,
             power_range_start: Default::default(),
+            custom_data: None,
         };
         let result = rule.validate();
         assert!(result.is_err());
@@ -128,6 +139,7 @@ mod tests {
             energy_fee: Default::default(),
             parking_fee: None,
             power_range_start: Default::default(),
+            custom_data: None,
         };
         let result = rule.validate();
         assert!(result.is_err());
@@ -148,6 +160,7 @@ mod tests {
             energy_fee: Default::default(),
             parking_fee: None,
             power_range_start: Default::default(),
+            custom_data: None,
         };
         let err = rule.validate().unwrap_err();
         assert_num_field_errors(&err, 2);
@@ -163,6 +176,7 @@ mod tests {
             energy_fee: Default::default(),
             parking_fee: Some(Default::default()),
             power_range_start: Default::default(),
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&original_struct).unwrap();
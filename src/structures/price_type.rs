@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::tax_rate_type::TaxRateType;
 use crate::traits::OcppEntity;
 
@@ -18,6 +19,9 @@ pub struct PriceType {
     /// Optional. Tax percentages that were used to calculate inclTax from exclTax.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tax_rates: Option<Vec<TaxRateType>>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for PriceType {
@@ -26,6 +30,7 @@ impl Default for PriceType {
             excl_tax: Some(1.0),
             incl_tax: None,
             tax_rates: None,
+            custom_data: None,
         }
     }
 }
@@ -50,6 +55,10 @@ impl OcppEntity for PriceType {
             e.check_iter_member("tax_rates", tax_rates.iter());
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("PriceType")
     }
 }
@@ -66,6 +75,7 @@ mod tests {
             excl_tax: Some(10.0),
             incl_tax: None,
             tax_rates: None,
+            custom_data: None,
         };
         assert!(price.validate().is_ok());
     }
@@ -76,6 +86,7 @@ mod tests {
             excl_tax: None,
             incl_tax: Some(12.1),
             tax_rates: None,
+            custom_data: None,
         };
         assert!(price.validate().is_ok());
     }
@@ -86,6 +97,7 @@ mod tests {
             excl_tax: Some(10.0),
             incl_tax: Some(12.1),
             tax_rates: Some(vec![Default::default(), Default::default()]),
+            custom_data: None,
         };
         assert!(price.validate().is_ok());
     }
@@ -96,6 +108,7 @@ mod tests {
             excl_tax: None,
             incl_tax: None,
             tax_rates: None,
+            custom_data: None,
         };
         let err = price.validate().unwrap_err();
         assert_invalid_fields(&err, &["excl_tax", "incl_tax"]);
@@ -108,6 +121,7 @@ mod tests {
             excl_tax: Some(10.0),
             incl_tax: None,
             tax_rates: Some(vec![Default::default(); 6]), // Invalid: max 5
+            custom_data: None,
         };
         let result = price.validate();
         assert!(result.is_err());
@@ -125,6 +139,7 @@ mod tests {
             excl_tax: Some(10.0),
             incl_tax: Some(12.1),
             tax_rates: Some(vec![Default::default()]),
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&original_struct).unwrap();
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 
 /// Represents a rational number with an exponent and a value.
@@ -13,6 +14,9 @@ pub struct RationalNumberType {
     pub exponent: i32,
     /// Required. Value which shall be multiplied.
     pub value: i32,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for RationalNumberType {
@@ -20,6 +24,7 @@ impl Default for RationalNumberType {
         Self {
             exponent: 0,
             value: 1,
+            custom_data: None,
         }
     }
 }
@@ -28,8 +33,12 @@ impl OcppEntity for RationalNumberType {
     /// Validates the fields of RationalNumberType based on specified constraints.
     /// Returns `Ok(())` if all values are valid, or `Err(OcppError::StructureValidationError)` if validation fails.
     fn validate(&self) -> Result<(), OcppError> {
-        let e = StructureValidationBuilder::new();
+        let mut e = StructureValidationBuilder::new();
         // The spec does not provide bounds for exponent or value, so we only perform a basic build check.
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("RationalNumberType")
     }
 }
@@ -44,6 +53,7 @@ mod tests {
         let number = RationalNumberType {
             exponent: -1,
             value: 123,
+            custom_data: None,
         };
         assert!(number.validate().is_ok());
     }
@@ -53,6 +63,7 @@ mod tests {
         let original_struct = RationalNumberType {
             exponent: -2,
             value: 550,
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&original_struct).unwrap();
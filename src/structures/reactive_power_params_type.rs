@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
-use crate::errors::OcppError;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 
 /// Contains parameters for reactive power control.
@@ -18,6 +19,9 @@ pub struct ReactivePowerParamsType {
     /// Optional. Adjustment range for VRef time constant.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub autonomous_v_ref_time_constant: Option<f64>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 #[typetag::serde]
@@ -25,7 +29,13 @@ impl OcppEntity for ReactivePowerParamsType {
     /// Validates the fields of ReactivePowerParamsType based on specified constraints.
     /// Returns `Ok(())` if all values are valid, or `Err(OcppError::StructureValidationError)` if validation fails.
     fn validate(&self) -> Result<(), OcppError> {
-        Ok(())
+        let mut e = StructureValidationBuilder::new();
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("ReactivePowerParamsType")
     }
 }
 
@@ -40,6 +50,7 @@ mod tests {
             v_ref: Some(1.0),
             autonomous_v_ref_enable: Some(true),
             autonomous_v_ref_time_constant: Some(5.5),
+            custom_data: None,
         };
         assert!(params.validate().is_ok());
     }
@@ -50,6 +61,7 @@ mod tests {
             v_ref: None,
             autonomous_v_ref_enable: None,
             autonomous_v_ref_time_constant: None,
+            custom_data: None,
         };
         assert!(params.validate().is_ok());
     }
@@ -60,6 +72,7 @@ mod tests {
             v_ref: Some(1.0),
             autonomous_v_ref_enable: Some(false),
             autonomous_v_ref_time_constant: Some(10.0),
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&original_struct).unwrap();
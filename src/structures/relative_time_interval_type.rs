@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 
 /// Defines a relative time interval.
@@ -14,6 +15,9 @@ pub struct RelativeTimeIntervalType {
     /// Optional. Duration of the interval, in seconds.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub duration: Option<i32>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 #[typetag::serde]
@@ -28,6 +32,10 @@ impl OcppEntity for RelativeTimeIntervalType {
             e.check_bounds("duration", 0, i32::MAX, duration);
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("RelativeTimeIntervalType")
     }
 }
@@ -43,6 +51,7 @@ mod tests {
         let interval = RelativeTimeIntervalType {
             start: 60,
             duration: Some(3600),
+            custom_data: None,
         };
         assert!(interval.validate().is_ok());
     }
@@ -52,6 +61,7 @@ mod tests {
         let interval = RelativeTimeIntervalType {
             start: 0,
             duration: None,
+            custom_data: None,
         };
         assert!(interval.validate().is_ok());
     }
@@ -61,6 +71,7 @@ mod tests {
         let interval = RelativeTimeIntervalType {
             start: -1,
             duration: Some(3600),
+            custom_data: None,
         };
         let err = interval.validate().unwrap_err();
         assert_invalid_fields(&err, &["start"]);
@@ -72,6 +83,7 @@ mod tests {
         let interval = RelativeTimeIntervalType {
             start: 60,
             duration: Some(-1),
+            custom_data: None,
         };
         let err = interval.validate().unwrap_err();
         assert_invalid_fields(&err, &["duration"]);
@@ -83,6 +95,7 @@ mod tests {
         let original_struct = RelativeTimeIntervalType {
             start: 120,
             duration: Some(300),
+            custom_data: None,
         };
 
         let serialized = serde_json::to_string(&original_struct).unwrap();
@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::component_type::ComponentType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::variable_attribute_type::VariableAttributeType;
 use crate::structures::variable_characteristics_type::VariableCharacteristicsType;
 use crate::structures::variable_type::VariableType;
@@ -21,6 +22,9 @@ pub struct ReportDataType {
     /// Optional. Fixed read-only parameters of a variable.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub variable_characteristics: Option<VariableCharacteristicsType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for ReportDataType {
@@ -39,6 +43,10 @@ impl OcppEntity for ReportDataType {
             e.check_member("variable_characteristics", characteristics);
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("ReportDataType")
     }
 }
@@ -50,6 +58,7 @@ impl Default for ReportDataType {
             variable: Default::default(),
             variable_attribute: vec![Default::default()],
             variable_characteristics: None,
+            custom_data: None,
         }
     }
 }
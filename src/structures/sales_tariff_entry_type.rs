@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::consumption_cost_type::ConsumptionCostType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::relative_time_interval_type::RelativeTimeIntervalType;
 use crate::traits::OcppEntity;
 
@@ -19,6 +20,9 @@ pub struct SalesTariffEntryType {
     /// Optional. Defines additional means for further relative price information and/or alternative costs.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub consumption_cost: Option<Vec<ConsumptionCostType>>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 #[typetag::serde]
@@ -39,6 +43,10 @@ impl OcppEntity for SalesTariffEntryType {
             }
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("SalesTariffEntryType")
     }
 }
@@ -54,6 +62,7 @@ mod tests {
             e_price_level: None,
             relative_time_interval: Default::default(),
             consumption_cost: Some(vec![Default::default(); 3]),
+            custom_data: None,
         };
         assert!(entry.validate().is_ok());
     }
@@ -65,8 +74,10 @@ mod tests {
             relative_time_interval: RelativeTimeIntervalType {
                 start: 0,
                 duration: None,
+                custom_data: None,
             },
             consumption_cost: None,
+            custom_data: None,
         };
         assert!(entry.validate().is_ok());
     }
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::sales_tariff_entry_type::SalesTariffEntryType;
 use crate::traits::OcppEntity;
 
@@ -19,6 +20,9 @@ pub struct SalesTariffType {
     pub num_e_price_levels: Option<i32>,
     /// Required. Encapsulating element describing all relevant details for one time interval of the SalesTariff. The number of SalesTariffEntry elements is limited by the parameter maxScheduleTuples.
     pub sales_tariff_entry: Vec<SalesTariffEntryType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for SalesTariffType {
@@ -28,6 +32,7 @@ impl Default for SalesTariffType {
             sales_tariff_description: None,
             num_e_price_levels: None,
             sales_tariff_entry: vec![Default::default()],
+            custom_data: None,
         }
     }
 }
@@ -53,6 +58,10 @@ impl OcppEntity for SalesTariffType {
         );
         e.check_iter_member("sales_tariff_entry", self.sales_tariff_entry.iter());
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("SalesTariffType")
     }
 }
@@ -75,6 +84,7 @@ mod tests {
             sales_tariff_description: None,
             num_e_price_levels: None,
             sales_tariff_entry: vec![Default::default()],
+            custom_data: None,
         };
         assert!(tariff.validate().is_ok());
     }
@@ -3,6 +3,7 @@ use crate::enums::measurand_enum_type::MeasurandEnumType;
 use crate::enums::phase_enum_type::PhaseEnumType;
 use crate::enums::reading_context_enum_type::ReadingContextEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::signed_meter_value_type::SignedMeterValueType;
 use crate::structures::unit_of_measure_type::UnitOfMeasureType;
 use crate::traits::OcppEntity;
@@ -33,6 +34,9 @@ pub struct SampledValueType {
     /// Optional. Represents a UnitOfMeasure including a multiplier.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub unit_of_measure: Option<UnitOfMeasureType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for SampledValueType {
@@ -45,6 +49,7 @@ impl Default for SampledValueType {
             location: None,
             signed_meter_value: None,
             unit_of_measure: None,
+            custom_data: None,
         }
     }
 }
@@ -62,6 +67,26 @@ impl OcppEntity for SampledValueType {
             e.check_member("unit_of_measure", unit);
         }
 
+        if let Some(measurand) = &self.measurand {
+            e.check_enum("measurand", measurand);
+        }
+
+        if let Some(context) = &self.context {
+            e.check_enum("context", context);
+        }
+
+        if let Some(phase) = &self.phase {
+            e.check_enum("phase", phase);
+        }
+
+        if let Some(location) = &self.location {
+            e.check_enum("location", location);
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("SampledValueType")
     }
 }
@@ -87,6 +112,7 @@ mod tests {
             location: None,
             signed_meter_value: None,
             unit_of_measure: None,
+            custom_data: None,
         };
         assert!(sampled_value.validate().is_ok());
     }
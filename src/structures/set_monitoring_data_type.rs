@@ -1,6 +1,7 @@
 use crate::enums::monitor_enum_type::MonitorEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::component_type::ComponentType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::periodic_event_stream_params_type::PeriodicEventStreamParamsType;
 use crate::structures::variable_type::VariableType;
 use crate::traits::OcppEntity;
@@ -31,6 +32,9 @@ pub struct SetMonitoringDataType {
     /// Optional. When present, events from this monitor will be sent via a periodic event stream.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub periodic_event_stream: Option<PeriodicEventStreamParamsType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for SetMonitoringDataType {
@@ -44,6 +48,7 @@ impl Default for SetMonitoringDataType {
             component: Default::default(),
             variable: Default::default(),
             periodic_event_stream: None,
+            custom_data: None,
         }
     }
 }
@@ -64,6 +69,12 @@ impl OcppEntity for SetMonitoringDataType {
             e.check_member("periodic_event_stream", params);
         }
 
+        e.check_enum("monitor_type", &self.monitor_type);
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("SetMonitoringDataType")
     }
 }
@@ -90,6 +101,7 @@ mod tests {
             component: Default::default(),
             variable: Default::default(),
             periodic_event_stream: None,
+            custom_data: None,
         };
         assert!(monitoring_data.validate().is_ok());
     }
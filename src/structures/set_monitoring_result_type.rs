@@ -2,6 +2,7 @@ use crate::enums::monitor_enum_type::MonitorEnumType;
 use crate::enums::set_monitoring_status_enum_type::SetMonitoringStatusEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::component_type::ComponentType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::status_info_type::StatusInfoType;
 use crate::structures::variable_type::VariableType;
 use crate::traits::OcppEntity;
@@ -29,6 +30,9 @@ pub struct SetMonitoringResultType {
     /// Optional. Detailed status information.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for SetMonitoringResultType {
@@ -47,6 +51,14 @@ impl OcppEntity for SetMonitoringResultType {
             e.check_member("status_info", status_info);
         }
 
+        e.check_enum("status", &self.status);
+
+        e.check_enum("monitor_type", &self.monitor_type);
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("SetMonitoringResultType")
     }
 }
@@ -61,6 +73,7 @@ impl Default for SetMonitoringResultType {
             component: Default::default(),
             variable: Default::default(),
             status_info: None,
+            custom_data: None,
         }
     }
 }
@@ -1,6 +1,7 @@
 use crate::enums::attribute_enum_type::AttributeEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::component_type::ComponentType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::variable_type::VariableType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
@@ -19,6 +20,9 @@ pub struct SetVariableDataType {
     pub component: ComponentType,
     /// Required. The variable that needs to be set.
     pub variable: VariableType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for SetVariableDataType {
@@ -28,6 +32,7 @@ impl Default for SetVariableDataType {
             attribute_value: "".to_string(),
             component: Default::default(),
             variable: Default::default(),
+            custom_data: None,
         }
     }
 }
@@ -42,6 +47,14 @@ impl OcppEntity for SetVariableDataType {
         e.check_member("component", &self.component);
         e.check_member("variable", &self.variable);
 
+        if let Some(attribute_type) = &self.attribute_type {
+            e.check_enum("attribute_type", attribute_type);
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("SetVariableDataType")
     }
 }
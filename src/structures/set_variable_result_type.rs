@@ -2,6 +2,7 @@ use crate::enums::attribute_enum_type::AttributeEnumType;
 use crate::enums::set_variable_status_enum_type::SetVariableStatusEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::structures::component_type::ComponentType;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::status_info_type::StatusInfoType;
 use crate::structures::variable_type::VariableType;
 use crate::traits::OcppEntity;
@@ -23,6 +24,9 @@ pub struct SetVariableResultType {
     /// Optional. Detailed attribute status information.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub attribute_status_info: Option<StatusInfoType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for SetVariableResultType {
@@ -33,6 +37,7 @@ impl Default for SetVariableResultType {
             component: Default::default(),
             variable: Default::default(),
             attribute_status_info: None,
+            custom_data: None,
         }
     }
 }
@@ -49,6 +54,16 @@ impl OcppEntity for SetVariableResultType {
             e.check_member("attribute_status_info", status_info);
         }
 
+        if let Some(attribute_type) = &self.attribute_type {
+            e.check_enum("attribute_type", attribute_type);
+        }
+
+        e.check_enum("attribute_status", &self.attribute_status);
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("SetVariableResultType")
     }
 }
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 
 /// Represents a signed version of the meter value.
@@ -18,6 +19,9 @@ pub struct SignedMeterValueType {
     /// Optional. Base64 encoded public key.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub public_key: Option<String>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for SignedMeterValueType {
@@ -27,6 +31,7 @@ impl Default for SignedMeterValueType {
             signing_method: None,
             encoding_method: "".to_string(),
             public_key: None,
+            custom_data: None,
         }
     }
 }
@@ -53,6 +58,10 @@ impl OcppEntity for SignedMeterValueType {
             e.check_cardinality("public_key", 0, 2500, &public_key.chars());
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("SignedMeterValueType")
     }
 }
@@ -97,6 +106,7 @@ mod tests {
             encoding_method: "ocmf".to_string(),
             public_key: Some("key".to_string()),
             signing_method: Some("method".to_string()),
+            custom_data: None,
         };
         let serialized = serde_json::to_string(&original_struct).unwrap();
         let deserialized: SignedMeterValueType = serde_json::from_str(&serialized).unwrap();
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 
 /// Contains status details, especially when an error has occurred.
@@ -12,6 +13,9 @@ pub struct StatusInfoType {
     /// Optional. Additional text to provide detailed information.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub additional_info: Option<String>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for StatusInfoType {
@@ -19,6 +23,7 @@ impl Default for StatusInfoType {
         Self {
             reason_code: "".to_string(),
             additional_info: None,
+            custom_data: None,
         }
     }
 }
@@ -34,6 +39,10 @@ impl OcppEntity for StatusInfoType {
             e.check_cardinality("additional_info", 0, 1024, &additional_info.chars());
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("StatusInfoType")
     }
 }
@@ -48,6 +57,7 @@ mod tests {
         let data = StatusInfoType {
             reason_code: "ValidCode".to_string(),
             additional_info: Some("Additional info.".to_string()),
+            custom_data: None,
         };
         assert!(data.validate().is_ok());
     }
@@ -57,6 +67,7 @@ mod tests {
         let data = StatusInfoType {
             reason_code: "ValidCode".to_string(),
             additional_info: None,
+            custom_data: None,
         };
         assert!(data.validate().is_ok());
     }
@@ -83,6 +94,7 @@ mod tests {
         let original_struct = StatusInfoType {
             reason_code: "ValidCode".to_string(),
             additional_info: Some("Additional info.".to_string()),
+            custom_data: None,
         };
         let serialized = serde_json::to_string(&original_struct).unwrap();
         let deserialized: StatusInfoType = serde_json::from_str(&serialized).unwrap();
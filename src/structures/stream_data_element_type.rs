@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 
 /// Class to hold a stream data element.
@@ -12,6 +13,9 @@ pub struct StreamDataElementType {
     pub t: f64,
     /// Required. The recorded value.
     pub v: String,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for StreamDataElementType {
@@ -19,6 +23,7 @@ impl Default for StreamDataElementType {
         Self {
             t: 0.0,
             v: "".to_string(),
+            custom_data: None,
         }
     }
 }
@@ -31,6 +36,10 @@ impl OcppEntity for StreamDataElementType {
 
         e.check_cardinality("v", 0, 2500, &self.v.chars());
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("StreamDataElementType")
     }
 }
@@ -45,6 +54,7 @@ mod tests {
         let data = StreamDataElementType {
             t: 123.45,
             v: "100.0".to_string(),
+            custom_data: None,
         };
         assert!(data.validate().is_ok());
     }
@@ -62,6 +72,7 @@ mod tests {
         let original_struct = StreamDataElementType {
             t: 123.45,
             v: "100.0".to_string(),
+            custom_data: None,
         };
         let serialized = serde_json::to_string(&original_struct).unwrap();
         let deserialized: StreamDataElementType = serde_json::from_str(&serialized).unwrap();
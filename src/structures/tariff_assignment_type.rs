@@ -1,5 +1,6 @@
 use crate::enums::tariff_kind_enum_type::TariffKindEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -22,6 +23,9 @@ pub struct TariffAssignmentType {
     /// Optional. List of IdTokens related to the tariff.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id_tokens: Option<Vec<String>>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for TariffAssignmentType {
@@ -32,6 +36,7 @@ impl Default for TariffAssignmentType {
             valid_from: None,
             evse_ids: None,
             id_tokens: None,
+            custom_data: None,
         }
     }
 }
@@ -64,6 +69,12 @@ impl OcppEntity for TariffAssignmentType {
             }
         }
 
+        e.check_enum("tariff_kind", &self.tariff_kind);
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("TariffAssignmentType")
     }
 }
@@ -82,6 +93,7 @@ mod tests {
             valid_from: None,
             evse_ids: Some(vec![1, 2]),
             id_tokens: Some(vec!["token1".to_string(), "token2".to_string()]),
+            custom_data: None,
         };
         assert!(data.validate().is_ok());
     }
@@ -120,6 +132,7 @@ mod tests {
             valid_from: Some(Utc::now()),
             evse_ids: Some(vec![1, 2]),
             id_tokens: Some(vec!["token1".to_string()]),
+            custom_data: None,
         };
         let serialized = serde_json::to_string(&original_struct).unwrap();
         let deserialized: TariffAssignmentType = serde_json::from_str(&serialized).unwrap();
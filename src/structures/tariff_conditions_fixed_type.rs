@@ -2,6 +2,7 @@ use crate::enums::day_of_week_enum_type::DayOfWeekEnumType;
 use crate::enums::evse_kind_enum_type::EvseKindEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::iso::rfc_3339::{validate_rfc3339_24hr_time, validate_rfc3339_date};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
 
@@ -33,6 +34,9 @@ pub struct TariffConditionsFixedType {
     /// Optional. Type of ad-hoc payment, e.g. CC, Debit.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub payment_recognition: Option<String>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 #[typetag::serde]
@@ -70,6 +74,18 @@ impl OcppEntity for TariffConditionsFixedType {
             e.check_cardinality("payment_recognition", 0, 20, &payment_recognition.chars());
         }
 
+        if let Some(day_of_week) = &self.day_of_week {
+            e.check_iter_enum("day_of_week", day_of_week.iter());
+        }
+
+        if let Some(evse_kind) = &self.evse_kind {
+            e.check_enum("evse_kind", evse_kind);
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("TariffConditionsFixedType")
     }
 }
@@ -128,6 +144,7 @@ mod tests {
             evse_kind: Some(EvseKindEnumType::AC),
             payment_brand: Some("BrandX".to_string()),
             payment_recognition: Some("CC".to_string()),
+            custom_data: None,
         };
         let serialized = serde_json::to_string(&original_struct).unwrap();
         let deserialized: TariffConditionsFixedType = serde_json::from_str(&serialized).unwrap();
@@ -2,6 +2,7 @@ use crate::enums::day_of_week_enum_type::DayOfWeekEnumType;
 use crate::enums::evse_kind_enum_type::EvseKindEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::iso::rfc_3339::{validate_rfc3339_24hr_time, validate_rfc3339_date};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
 
@@ -63,6 +64,9 @@ pub struct TariffConditionsType {
     /// Optional. Maximum duration in seconds in the idle period.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_idle_time: Option<i32>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 #[typetag::serde]
@@ -132,6 +136,18 @@ impl OcppEntity for TariffConditionsType {
             e.check_bounds("max_idle_time", 0, i32::MAX, max_idle_time);
         }
 
+        if let Some(day_of_week) = &self.day_of_week {
+            e.check_iter_enum("day_of_week", day_of_week.iter());
+        }
+
+        if let Some(evse_kind) = &self.evse_kind {
+            e.check_enum("evse_kind", evse_kind);
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("TariffConditionsType")
     }
 }
@@ -170,6 +186,7 @@ mod tests {
             max_charging_time: Some(18000),
             min_idle_time: Some(60),
             max_idle_time: Some(1800),
+            custom_data: None,
         };
         assert!(data.validate().is_ok());
     }
@@ -209,6 +226,7 @@ mod tests {
             max_charging_time: None,
             min_idle_time: None,
             max_idle_time: None,
+            custom_data: None,
         };
         let serialized = serde_json::to_string(&original_struct).unwrap();
         let deserialized: TariffConditionsType = serde_json::from_str(&serialized).unwrap();
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::tariff_conditions_type::TariffConditionsType;
 use crate::traits::OcppEntity;
 
@@ -12,6 +13,9 @@ pub struct TariffEnergyPriceType {
     /// Optional. Conditions when this tariff element price is applicable. When absent, it is always applicable.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub conditions: Option<TariffConditionsType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for TariffEnergyPriceType {
@@ -19,6 +23,7 @@ impl Default for TariffEnergyPriceType {
         Self {
             price_kwh: 0.0,
             conditions: None,
+            custom_data: None,
         }
     }
 }
@@ -35,6 +40,10 @@ impl OcppEntity for TariffEnergyPriceType {
             e.check_member("conditions", conditions);
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("TariffEnergyPriceType")
     }
 }
@@ -49,6 +58,7 @@ mod tests {
         TariffEnergyPriceType {
             price_kwh: 0.25,
             conditions: None,
+            custom_data: None,
         }
     }
 
@@ -63,6 +73,7 @@ mod tests {
         let data = TariffEnergyPriceType {
             price_kwh: 0.30,
             conditions: Some(TariffConditionsType::default()),
+            custom_data: None,
         };
         assert!(data.validate().is_ok());
     }
@@ -79,6 +90,7 @@ mod tests {
         let original_struct = TariffEnergyPriceType {
             price_kwh: 0.25,
             conditions: Some(TariffConditionsType::default()),
+            custom_data: None,
         };
         let serialized = serde_json::to_string(&original_struct).unwrap();
         let deserialized: TariffEnergyPriceType = serde_json::from_str(&serialized).unwrap();
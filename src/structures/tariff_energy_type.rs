@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::tariff_energy_price_type::TariffEnergyPriceType;
 use crate::structures::tax_rate_type::TaxRateType;
 use crate::traits::OcppEntity;
@@ -13,6 +14,9 @@ pub struct TariffEnergyType {
     pub tax_rates: Option<Vec<TaxRateType>>,
     /// Required. Element tariff price and conditions.
     pub prices: Vec<TariffEnergyPriceType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for TariffEnergyType {
@@ -20,6 +24,7 @@ impl Default for TariffEnergyType {
         Self {
             tax_rates: None,
             prices: vec![TariffEnergyPriceType::default()],
+            custom_data: None,
         }
     }
 }
@@ -37,6 +42,10 @@ impl OcppEntity for TariffEnergyType {
         e.check_cardinality("prices", 1, usize::MAX, &self.prices.iter());
         e.check_iter_member("prices", self.prices.iter());
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("TariffEnergyType")
     }
 }
@@ -51,6 +60,7 @@ mod tests {
         TariffEnergyType {
             tax_rates: Some(vec![TaxRateType::default()]),
             prices: vec![TariffEnergyPriceType::default()],
+            custom_data: None,
         }
     }
 
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::tariff_conditions_fixed_type::TariffConditionsFixedType;
 use crate::traits::OcppEntity;
 
@@ -12,6 +13,9 @@ pub struct TariffFixedPriceType {
     /// Optional. Conditions when this tariff element price is applicable. When absent, it is always applicable.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub conditions: Option<TariffConditionsFixedType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for TariffFixedPriceType {
@@ -19,6 +23,7 @@ impl Default for TariffFixedPriceType {
         Self {
             price_fixed: 0.0,
             conditions: None,
+            custom_data: None,
         }
     }
 }
@@ -35,6 +40,10 @@ impl OcppEntity for TariffFixedPriceType {
             e.check_member("conditions", conditions);
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("TariffFixedPriceType")
     }
 }
@@ -49,6 +58,7 @@ mod tests {
         TariffFixedPriceType {
             price_fixed: 1.00,
             conditions: None,
+            custom_data: None,
         }
     }
 
@@ -63,6 +73,7 @@ mod tests {
         let data = TariffFixedPriceType {
             price_fixed: 2.50,
             conditions: Some(TariffConditionsFixedType::default()),
+            custom_data: None,
         };
         assert!(data.validate().is_ok());
     }
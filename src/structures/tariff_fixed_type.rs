@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::tariff_fixed_price_type::TariffFixedPriceType;
 use crate::structures::tax_rate_type::TaxRateType;
 use crate::traits::OcppEntity;
@@ -13,6 +14,9 @@ pub struct TariffFixedType {
     /// Optional. Applicable tax percentages for this tariff dimension. If omitted, no tax is applicable.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tax_rates: Option<Vec<TaxRateType>>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for TariffFixedType {
@@ -20,6 +24,7 @@ impl Default for TariffFixedType {
         Self {
             prices: vec![TariffFixedPriceType::default()],
             tax_rates: None,
+            custom_data: None,
         }
     }
 }
@@ -37,6 +42,10 @@ impl OcppEntity for TariffFixedType {
             e.check_iter_member("tax_rates", tax_rates.iter());
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("TariffFixedType")
     }
 }
@@ -50,6 +59,7 @@ mod tests {
         TariffFixedType {
             prices: vec![TariffFixedPriceType::default()],
             tax_rates: Some(vec![TaxRateType::default()]),
+            custom_data: None,
         }
     }
 
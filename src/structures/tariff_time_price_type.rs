@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::tariff_conditions_type::TariffConditionsType;
 use crate::traits::OcppEntity;
 
@@ -12,6 +13,9 @@ pub struct TariffTimePriceType {
     /// Optional. Conditions when this tariff element price is applicable. When absent, it is always applicable.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub conditions: Option<TariffConditionsType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for TariffTimePriceType {
@@ -19,6 +23,7 @@ impl Default for TariffTimePriceType {
         Self {
             price_minute: 0.0,
             conditions: None,
+            custom_data: None,
         }
     }
 }
@@ -35,6 +40,10 @@ impl OcppEntity for TariffTimePriceType {
             e.check_member("conditions", conditions);
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("TariffTimePriceType")
     }
 }
@@ -48,6 +57,7 @@ mod tests {
         TariffTimePriceType {
             price_minute: 0.50,
             conditions: None,
+            custom_data: None,
         }
     }
 
@@ -62,6 +72,7 @@ mod tests {
         let data = TariffTimePriceType {
             price_minute: 0.75,
             conditions: Some(TariffConditionsType::default()),
+            custom_data: None,
         };
         assert!(data.validate().is_ok());
     }
@@ -2,6 +2,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::message_content_type::MessageContentType;
 use crate::structures::price_type::PriceType;
 use crate::structures::tariff_energy_type::TariffEnergyType;
@@ -46,6 +47,9 @@ pub struct TariffType {
     /// Optional. Fixed fee for a reservation.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reservation_fixed: Option<TariffFixedType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for TariffType {
@@ -63,6 +67,7 @@ impl Default for TariffType {
             max_cost: None,
             reservation_time: None,
             reservation_fixed: None,
+            custom_data: None,
         }
     }
 }
@@ -112,6 +117,10 @@ impl OcppEntity for TariffType {
             e.check_member("reservation_fixed", reservation_fixed);
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("TariffType")
     }
 }
@@ -135,6 +144,7 @@ mod tests {
             max_cost: None,
             reservation_time: None,
             reservation_fixed: None,
+            custom_data: None,
         }
     }
 
@@ -160,6 +170,7 @@ mod tests {
             max_cost: Some(PriceType::default()),
             reservation_time: Some(TariffTimeType::default()),
             reservation_fixed: Some(TariffFixedType::default()),
+            custom_data: None,
         };
 
         if let Err(e) = data.validate() {
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
@@ -14,6 +15,9 @@ pub struct TaxRateType {
     /// Optional. Stack level for this type of tax. Default value, when absent, is 0.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stack: Option<i32>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for TaxRateType {
@@ -22,6 +26,7 @@ impl Default for TaxRateType {
             tax_type: "".to_string(),
             tax: 0.0,
             stack: None,
+            custom_data: None,
         }
     }
 }
@@ -40,6 +45,10 @@ impl OcppEntity for TaxRateType {
             e.check_bounds("stack", 0, i32::MAX, *stack);
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("TaxRateType")
     }
 }
@@ -54,6 +63,7 @@ mod tests {
             tax_type: "Federal".to_string(),
             tax: 0.15,
             stack: Some(1),
+            custom_data: None,
         }
     }
 
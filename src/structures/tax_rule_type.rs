@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::rational_number_type::RationalNumberType;
 use crate::traits::OcppEntity;
 
@@ -8,6 +9,7 @@ use crate::traits::OcppEntity;
 #[serde(rename_all = "camelCase")]
 pub struct TaxRuleType {
     /// Required. ID for the tax rule.
+    #[serde(rename = "taxRuleID")]
     pub tax_rule_id: i32,
     /// Optional. Human-readable string to identify the tax rule.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -25,6 +27,9 @@ pub struct TaxRuleType {
     pub applies_to_minimum_maximum_cost: bool,
     /// Required. Percentage of the total amount of applying fee (energy, parking, overstay, MinimumCost and/or MaximumCost).
     pub tax_rate: RationalNumberType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for TaxRuleType {
@@ -40,6 +45,10 @@ impl OcppEntity for TaxRuleType {
 
         e.check_member("tax_rate", &self.tax_rate);
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("TaxRuleType")
     }
 }
@@ -59,6 +68,7 @@ mod tests {
             applies_to_overstay_fee: false,
             applies_to_minimum_maximum_cost: true,
             tax_rate: RationalNumberType::default(),
+            custom_data: None,
         }
     }
 
@@ -79,6 +89,7 @@ mod tests {
             applies_to_overstay_fee: false,
             applies_to_minimum_maximum_cost: false,
             tax_rate: RationalNumberType::default(),
+            custom_data: None,
         };
         assert!(data.validate().is_ok());
     }
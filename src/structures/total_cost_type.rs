@@ -2,6 +2,7 @@ use crate::enums::tariff_cost_enum_type::TariffCostEnumType;
 use crate::errors::OcppError::FieldISOError;
 use crate::errors::{OcppError, StructureValidationBuilder};
 use crate::iso::iso_4217::CurrencyRegistry;
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::price_type::PriceType;
 use crate::structures::total_price_type::TotalPriceType;
 use crate::traits::OcppEntity;
@@ -34,6 +35,9 @@ pub struct TotalCostType {
     /// Optional. Sum of fixed cost related to reservation.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reservation_fixed: Option<PriceType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for TotalCostType {
@@ -48,6 +52,7 @@ impl Default for TotalCostType {
             reservation_time: None,
             total: Default::default(),
             reservation_fixed: None,
+            custom_data: None,
         }
     }
 }
@@ -88,6 +93,12 @@ impl OcppEntity for TotalCostType {
 
         e.check_member("total", &self.total);
 
+        e.check_enum("type_of_cost", &self.type_of_cost);
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("TotalCostType")
     }
 }
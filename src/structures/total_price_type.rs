@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
@@ -12,6 +13,9 @@ pub struct TotalPriceType {
     /// Optional. Price/cost including tax. Can be absent if exclTax is present.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub incl_tax: Option<f64>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for TotalPriceType {
@@ -19,6 +23,7 @@ impl Default for TotalPriceType {
         Self {
             excl_tax: Some(1.0),
             incl_tax: None,
+            custom_data: None,
         }
     }
 }
@@ -45,6 +50,10 @@ impl OcppEntity for TotalPriceType {
             );
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("TotalPriceType")
     }
 }
@@ -59,6 +68,7 @@ mod tests {
         let data = TotalPriceType {
             excl_tax: Some(-1.0),
             incl_tax: None,
+            custom_data: None,
         };
 
         assert!(data.validate().is_err());
@@ -69,6 +79,7 @@ mod tests {
         let data = TotalPriceType {
             excl_tax: None,
             incl_tax: Some(-1.0),
+            custom_data: None,
         };
 
         assert!(data.validate().is_err());
@@ -79,6 +90,7 @@ mod tests {
         let data = TotalPriceType {
             excl_tax: Some(-1.0),
             incl_tax: Some(-1.0),
+            custom_data: None,
         };
         assert!(data.validate().is_err());
 
@@ -97,6 +109,7 @@ mod tests {
         let data = TotalPriceType {
             excl_tax: Some(10.0),
             incl_tax: None,
+            custom_data: None,
         };
         assert!(data.validate().is_ok());
     }
@@ -106,6 +119,7 @@ mod tests {
         let data = TotalPriceType {
             excl_tax: None,
             incl_tax: Some(12.0),
+            custom_data: None,
         };
         assert!(data.validate().is_ok());
     }
@@ -115,6 +129,7 @@ mod tests {
         let data = TotalPriceType {
             excl_tax: Some(10.0),
             incl_tax: Some(12.0),
+            custom_data: None,
         };
         assert!(data.validate().is_ok());
     }
@@ -124,6 +139,7 @@ mod tests {
         let data = TotalPriceType {
             excl_tax: None,
             incl_tax: None,
+            custom_data: None,
         };
         assert!(data.validate().is_err());
     }
@@ -133,6 +149,7 @@ mod tests {
         let original_struct = TotalPriceType {
             excl_tax: Some(10.0),
             incl_tax: Some(12.0),
+            custom_data: None,
         };
         let serialized = serde_json::to_string(&original_struct).unwrap();
         let deserialized: TotalPriceType = serde_json::from_str(&serialized).unwrap();
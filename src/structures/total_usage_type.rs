@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
@@ -15,6 +16,9 @@ pub struct TotalUsageType {
     /// Optional. Total time of reservation in seconds.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reservation_time: Option<i32>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for TotalUsageType {
@@ -24,6 +28,7 @@ impl Default for TotalUsageType {
             charging_time: 0,
             idle_time: 0,
             reservation_time: None,
+            custom_data: None,
         }
     }
 }
@@ -41,6 +46,10 @@ impl OcppEntity for TotalUsageType {
             e.check_bounds("reservation_time", 0, i32::MAX, *reservation_time);
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("TotalUsageType")
     }
 }
@@ -56,6 +65,7 @@ mod tests {
             charging_time: 3600,
             idle_time: 120,
             reservation_time: Some(600),
+            custom_data: None,
         }
     }
 
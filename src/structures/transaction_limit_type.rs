@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone, Default)]
@@ -16,8 +17,11 @@ pub struct TransactionLimitType {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_time: Option<i32>,
     /// Optional. Maximum State of Charge of the EV in percentage.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "maxSoC", skip_serializing_if = "Option::is_none")]
     pub max_soc: Option<i32>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for TransactionLimitType {
@@ -38,6 +42,10 @@ impl OcppEntity for TransactionLimitType {
             e.check_bounds("max_soc", 0, 100, max_soc);
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("TransactionLimitType")
     }
 }
@@ -53,6 +61,7 @@ mod tests {
             max_energy: Some(25.0),
             max_time: Some(3600),
             max_soc: Some(80),
+            custom_data: None,
         }
     }
 
@@ -69,6 +78,7 @@ mod tests {
             max_energy: None,
             max_time: None,
             max_soc: None,
+            custom_data: None,
         };
         assert!(data.validate().is_ok());
     }
@@ -2,11 +2,12 @@ use crate::enums::charging_state_enum_type::ChargingStateEnumType;
 use crate::enums::operation_mode_enum_type::OperationModeEnumType;
 use crate::enums::reason_enum_type::ReasonEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::structures::transaction_limit_type::TransactionLimitType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionType {
     /// Required. This contains the Id of the transaction.
@@ -32,6 +33,9 @@ pub struct TransactionType {
     /// Optional. Maximum cost/energy/time allowed for this transaction.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub transaction_limit: Option<TransactionLimitType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for TransactionType {
@@ -53,6 +57,22 @@ impl OcppEntity for TransactionType {
             e.check_member("transaction_limit", transaction_limit);
         }
 
+        if let Some(charging_state) = &self.charging_state {
+            e.check_enum("charging_state", charging_state);
+        }
+
+        if let Some(stopped_reason) = &self.stopped_reason {
+            e.check_enum("stopped_reason", stopped_reason);
+        }
+
+        if let Some(operation_mode) = &self.operation_mode {
+            e.check_enum("operation_mode", operation_mode);
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("TransactionType")
     }
 }
@@ -73,6 +93,7 @@ mod tests {
             operation_mode: Some(OperationModeEnumType::LocalFrequency),
             tariff_id: Some("tariff-001".to_string()),
             transaction_limit: Some(TransactionLimitType::default()),
+            custom_data: None,
         }
     }
 
@@ -93,6 +114,7 @@ mod tests {
             operation_mode: None,
             tariff_id: None,
             transaction_limit: None,
+            custom_data: None,
         };
         assert!(data.validate().is_ok());
     }
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone, Default)]
@@ -12,6 +13,9 @@ pub struct UnitOfMeasureType {
     /// Optional. Multiplier, representing the exponent to base 10. Default is 0.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub multiplier: Option<i32>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for UnitOfMeasureType {
@@ -23,6 +27,10 @@ impl OcppEntity for UnitOfMeasureType {
             e.check_cardinality("unit", 0, 20, &unit.chars());
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("UnitOfMeasureType")
     }
 }
@@ -36,6 +44,7 @@ mod tests {
         UnitOfMeasureType {
             unit: Some("Wh".to_string()),
             multiplier: Some(3), // 10^3
+            custom_data: None,
         }
     }
 
@@ -50,6 +59,7 @@ mod tests {
         let data = UnitOfMeasureType {
             unit: None,
             multiplier: None,
+            custom_data: None,
         };
         assert!(data.validate().is_ok());
     }
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
@@ -10,37 +11,49 @@ pub struct V2XChargingParametersType {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub min_charge_power: Option<f64>,
     /// Optional. Minimum charge power on phase L2 in W.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "minChargePower_L2", skip_serializing_if = "Option::is_none")]
     pub min_charge_power_l2: Option<f64>,
     /// Optional. Minimum charge power on phase L3 in W.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "minChargePower_L3", skip_serializing_if = "Option::is_none")]
     pub min_charge_power_l3: Option<f64>,
     /// Optional. Maximum charge power in W.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_charge_power: Option<f64>,
     /// Optional. Maximum charge power on phase L2 in W.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "maxChargePower_L2", skip_serializing_if = "Option::is_none")]
     pub max_charge_power_l2: Option<f64>,
     /// Optional. Maximum charge power on phase L3 in W.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "maxChargePower_L3", skip_serializing_if = "Option::is_none")]
     pub max_charge_power_l3: Option<f64>,
     /// Optional. Minimum discharge power in W.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub min_discharge_power: Option<f64>,
     /// Optional. Minimum discharge power on phase L2 in W.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "minDischargePower_L2",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub min_discharge_power_l2: Option<f64>,
     /// Optional. Minimum discharge power on phase L3 in W.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "minDischargePower_L3",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub min_discharge_power_l3: Option<f64>,
     /// Optional. Maximum discharge power in W.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_discharge_power: Option<f64>,
     /// Optional. Maximum discharge power on phase L2 in W.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "maxDischargePower_L2",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub max_discharge_power_l2: Option<f64>,
     /// Optional. Maximum discharge power on phase L3 in W.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "maxDischargePower_L3",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub max_discharge_power_l3: Option<f64>,
     /// Optional. Minimum charge current in A.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -70,14 +83,23 @@ pub struct V2XChargingParametersType {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ev_max_energy_request: Option<f64>,
     /// Optional. Energy (in Wh) to minimum state of charge for V2X activity.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "evMinV2XEnergyRequest",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub ev_min_v2x_energy_request: Option<f64>,
     /// Optional. Energy (in Wh) to maximum state of charge for V2X activity.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "evMaxV2XEnergyRequest",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub ev_max_v2x_energy_request: Option<f64>,
     /// Optional. Target state of charge at departure as a percentage.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "targetSoC", skip_serializing_if = "Option::is_none")]
     pub target_soc: Option<i32>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 #[typetag::serde]
 impl OcppEntity for V2XChargingParametersType {
@@ -146,6 +168,10 @@ impl OcppEntity for V2XChargingParametersType {
             e.check_bounds("target_soc", 0, 100, val);
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("V2XChargingParametersType")
     }
 }
@@ -181,6 +207,7 @@ mod tests {
             ev_min_v2x_energy_request: Some(-15.0),
             ev_max_v2x_energy_request: Some(15.0),
             target_soc: Some(80),
+            custom_data: None,
         }
     }
 
@@ -217,6 +244,7 @@ mod tests {
             ev_min_v2x_energy_request: None,
             ev_max_v2x_energy_request: None,
             target_soc: None,
+            custom_data: None,
         };
         assert!(data.validate().is_ok());
     }
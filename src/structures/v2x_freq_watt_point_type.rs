@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
@@ -10,6 +11,9 @@ pub struct V2XFreqWattPointType {
     pub frequency: f64,
     /// Required. Power in W to charge (positive) or discharge (negative) at the specified frequency.
     pub power: f64,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for V2XFreqWattPointType {
@@ -17,6 +21,7 @@ impl Default for V2XFreqWattPointType {
         Self {
             frequency: 0.0,
             power: 0.0,
+            custom_data: None,
         }
     }
 }
@@ -29,6 +34,10 @@ impl OcppEntity for V2XFreqWattPointType {
         // Frequency is net frequency in Hz, should be non-negative.
         e.check_bounds("frequency", 0.0, f64::MAX, self.frequency);
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("V2XFreqWattPointType")
     }
 }
@@ -42,6 +51,7 @@ mod tests {
         V2XFreqWattPointType {
             frequency: 50.0,
             power: -1500.0,
+            custom_data: None,
         }
     }
 
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
-use crate::errors::OcppError;
+use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
@@ -10,6 +11,9 @@ pub struct V2XSignalWattPointType {
     pub signal: i32,
     /// Required. Power in W to charge (positive) or discharge (negative) at a specified frequency.
     pub power: f64,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for V2XSignalWattPointType {
@@ -17,6 +21,7 @@ impl Default for V2XSignalWattPointType {
         Self {
             signal: 0,
             power: 0.0,
+            custom_data: None,
         }
     }
 }
@@ -24,7 +29,13 @@ impl Default for V2XSignalWattPointType {
 impl OcppEntity for V2XSignalWattPointType {
     /// Validates the fields of V2XSignalWattPointType based on specified constraints.
     fn validate(&self) -> Result<(), OcppError> {
-        Ok(())
+        let mut e = StructureValidationBuilder::new();
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
+        e.build("V2XSignalWattPointType")
     }
 }
 
@@ -37,6 +48,7 @@ mod tests {
         V2XSignalWattPointType {
             signal: 10,
             power: 2500.5,
+            custom_data: None,
         }
     }
 
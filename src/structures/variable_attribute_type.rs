@@ -1,6 +1,7 @@
 use crate::enums::attribute_enum_type::AttributeEnumType;
 use crate::enums::mutability_enum_type::MutabilityEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
 
@@ -8,7 +9,7 @@ use serde::{Deserialize, Serialize};
 #[serde(rename_all = "camelCase")]
 pub struct VariableAttributeType {
     /// Optional. Type of attribute (e.g., Actual, MinSet, MaxSet). Defaults to Actual.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     pub attribute_type: Option<AttributeEnumType>,
     /// Optional. Value of the attribute. May be omitted if mutability is 'WriteOnly'.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -22,6 +23,9 @@ pub struct VariableAttributeType {
     /// Optional. If true, the value cannot be changed at runtime. Defaults to false.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub constant: Option<bool>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for VariableAttributeType {
@@ -32,6 +36,7 @@ impl Default for VariableAttributeType {
             mutability: Some(MutabilityEnumType::ReadWrite),
             persistent: Some(false),
             constant: Some(false),
+            custom_data: None,
         }
     }
 }
@@ -45,7 +50,7 @@ impl OcppEntity for VariableAttributeType {
             e.check_cardinality("value", 0, 2500, &value.chars());
         }
 
-        if self.attribute_type.is_none()
+        if self.value.is_none()
             && let Some(mutability) = &self.mutability
             && *mutability != MutabilityEnumType::WriteOnly
         {
@@ -56,6 +61,18 @@ impl OcppEntity for VariableAttributeType {
             )
         }
 
+        if let Some(attribute_type) = &self.attribute_type {
+            e.check_enum("attribute_type", attribute_type);
+        }
+
+        if let Some(mutability) = &self.mutability {
+            e.check_enum("mutability", mutability);
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("VariableAttributeType")
     }
 }
@@ -72,6 +89,7 @@ mod tests {
             mutability: Some(MutabilityEnumType::ReadWrite),
             persistent: Some(true),
             constant: Some(false),
+            custom_data: None,
         }
     }
 
@@ -89,6 +107,7 @@ mod tests {
             mutability: None,
             persistent: None,
             constant: None,
+            custom_data: None,
         };
         assert!(data.validate().is_ok());
     }
@@ -100,11 +119,22 @@ mod tests {
         assert!(data.validate().is_err());
     }
 
+    #[test]
+    fn test_validate_value_required_unless_write_only() {
+        let mut data = create_test_instance();
+        data.value = None;
+        assert!(data.validate().is_err());
+
+        data.mutability = Some(MutabilityEnumType::WriteOnly);
+        assert!(data.validate().is_ok());
+    }
+
     #[test]
     fn test_serialization_deserialization() {
         let original_struct = create_test_instance();
         let serialized = serde_json::to_string(&original_struct).unwrap();
         let deserialized: VariableAttributeType = serde_json::from_str(&serialized).unwrap();
         assert_eq!(original_struct, deserialized);
+        assert!(serialized.contains("\"type\":\"Actual\""));
     }
 }
@@ -1,5 +1,6 @@
 use crate::enums::data_enum_type::DataEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
 
@@ -25,6 +26,9 @@ pub struct VariableCharacteristicsType {
     pub values_list: Option<String>,
     /// Required. Flag indicating if this variable supports monitoring.
     pub supports_monitoring: bool,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for VariableCharacteristicsType {
@@ -37,6 +41,7 @@ impl Default for VariableCharacteristicsType {
             max_elements: None,
             values_list: None,
             supports_monitoring: false,
+            custom_data: None,
         }
     }
 }
@@ -59,6 +64,12 @@ impl OcppEntity for VariableCharacteristicsType {
             e.check_cardinality("values_list", 0, 1000, &values_list.chars());
         }
 
+        e.check_enum("data_type", &self.data_type);
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("VariableCharacteristicsType")
     }
 }
@@ -78,6 +89,7 @@ mod tests {
             max_elements: Some(10),
             values_list: Some("1,2,3,4".to_string()),
             supports_monitoring: true,
+            custom_data: None,
         }
     }
 
@@ -97,6 +109,7 @@ mod tests {
             max_elements: None,
             values_list: None,
             supports_monitoring: false,
+            custom_data: None,
         };
         assert!(data.validate().is_ok());
     }
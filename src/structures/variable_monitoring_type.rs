@@ -1,6 +1,7 @@
 use crate::enums::event_notification_enum_type::EventNotificationEnumType;
 use crate::enums::monitor_enum_type::MonitorEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
 
@@ -20,6 +21,9 @@ pub struct VariableMonitoringType {
     pub severity: i32,
     /// Required. Type of monitor for event notifications.
     pub event_notification_type: EventNotificationEnumType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for VariableMonitoringType {
@@ -31,6 +35,7 @@ impl Default for VariableMonitoringType {
             monitor_type: MonitorEnumType::Periodic,
             severity: 9,
             event_notification_type: EventNotificationEnumType::HardWiredNotification,
+            custom_data: None,
         }
     }
 }
@@ -44,6 +49,14 @@ impl OcppEntity for VariableMonitoringType {
 
         e.check_bounds("severity", 0, 9, self.severity);
 
+        e.check_enum("monitor_type", &self.monitor_type);
+
+        e.check_enum("event_notification_type", &self.event_notification_type);
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("VariableMonitoringType")
     }
 }
@@ -63,6 +76,7 @@ mod tests {
             monitor_type: MonitorEnumType::UpperThreshold,
             severity: 5,
             event_notification_type: EventNotificationEnumType::PreconfiguredMonitor,
+            custom_data: None,
         }
     }
 
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
@@ -11,6 +12,9 @@ pub struct VariableType {
     /// Optional. Name of instance in case the variable has multiple instances. Max length of 50.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub instance: Option<String>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for VariableType {
@@ -18,6 +22,7 @@ impl Default for VariableType {
         Self {
             name: "a".to_string(),
             instance: None,
+            custom_data: None,
         }
     }
 }
@@ -33,6 +38,10 @@ impl OcppEntity for VariableType {
             e.check_cardinality("instance", 0, 50, &instance.chars());
         }
 
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("VariableType")
     }
 }
@@ -46,6 +55,7 @@ mod tests {
         VariableType {
             name: "testName".to_string(),
             instance: Some("testInstance".to_string()),
+            custom_data: None,
         }
     }
 
@@ -60,6 +70,7 @@ mod tests {
         let data = VariableType {
             name: "testName".to_string(),
             instance: None,
+            custom_data: None,
         };
         assert!(data.validate().is_ok());
     }
@@ -1,5 +1,6 @@
 use crate::enums::power_during_cessation_enum_type::PowerDuringCessationEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
 
@@ -15,6 +16,9 @@ pub struct VoltageParamsType {
     /// Optional. Power to be fed-in during fault-ride through.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub power_during_cessation: Option<PowerDuringCessationEnumType>,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 #[typetag::serde]
@@ -31,6 +35,14 @@ impl OcppEntity for VoltageParamsType {
             );
         }
 
+        if let Some(power_during_cessation) = &self.power_during_cessation {
+            e.check_enum("power_during_cessation", power_during_cessation);
+        }
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("VoltageParamsType")
     }
 }
@@ -46,6 +58,7 @@ mod tests {
             hv10_min_mean_value: Some(253.0),
             hv10_min_mean_trip_delay: Some(50.0),
             power_during_cessation: Some(PowerDuringCessationEnumType::Active),
+            custom_data: None,
         }
     }
 
@@ -61,6 +74,7 @@ mod tests {
             hv10_min_mean_value: None,
             hv10_min_mean_trip_delay: None,
             power_during_cessation: None,
+            custom_data: None,
         };
         assert!(data.validate().is_ok());
     }
@@ -1,5 +1,6 @@
 use crate::enums::vpn_enum_type::VPNEnumType;
 use crate::errors::{OcppError, StructureValidationBuilder};
+use crate::structures::custom_data_type::CustomDataType;
 use crate::traits::OcppEntity;
 use serde::{Deserialize, Serialize};
 
@@ -20,6 +21,9 @@ pub struct VPNType {
     /// Required. Type of VPN.
     #[serde(rename = "type")]
     pub vpn_type: VPNEnumType,
+    /// Optional. Vendor-specific extension data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_data: Option<CustomDataType>,
 }
 
 impl Default for VPNType {
@@ -31,6 +35,7 @@ impl Default for VPNType {
             password: "".to_string(),
             key: "".to_string(),
             vpn_type: VPNEnumType::Ikev2,
+            custom_data: None,
         }
     }
 }
@@ -50,6 +55,12 @@ impl OcppEntity for VPNType {
             e.check_cardinality("group", 0, 50, &group.chars());
         }
 
+        e.check_enum("vpn_type", &self.vpn_type);
+
+        if let Some(custom_data) = &self.custom_data {
+            e.check_member("custom_data", custom_data);
+        }
+
         e.build("VPNType")
     }
 }
@@ -67,6 +78,7 @@ mod tests {
             password: "securepassword123".to_string(),
             key: "sharedsecretkey".to_string(),
             vpn_type: VPNEnumType::L2Tp,
+            custom_data: None,
         }
     }
 
@@ -85,6 +97,7 @@ mod tests {
             password: "securepassword123".to_string(),
             key: "sharedsecretkey".to_string(),
             vpn_type: VPNEnumType::L2Tp,
+            custom_data: None,
         };
         assert!(data.validate().is_ok());
     }
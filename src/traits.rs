@@ -17,6 +17,17 @@ pub trait OcppRequest: Debug + DynClone {
 
 dyn_clone::clone_trait_object!(OcppRequest); // Permit impl Clone of `dyn OcppEntity`
 
+/// An enumeration defined by the specification. With the `lenient-enums` feature, every
+/// enumeration gets an `Unknown(String)` variant that preserves values it does not define. It is
+/// named `UnknownValue(String)` in the enumerations that already define an `Unknown` value.
+pub trait OcppEnum {
+    /// The name of the enumeration, e.g. `ReasonEnumType`.
+    const NAME: &'static str;
+
+    /// Returns the preserved value if this is the `Unknown(String)` variant.
+    fn unknown_value(&self) -> Option<&str>;
+}
+
 pub trait OcppMessage {
     type Request: Default;
     type Response: Default;
@@ -28,3 +39,13 @@ pub trait OcppMessage {
         Self::Response::default()
     }
 }
+
+/// An OCPP message that is sent with the SEND message type. Such messages are unconfirmed, so
+/// they only have a request.
+pub trait OcppUnconfirmedMessage {
+    type Request: Default;
+
+    fn request() -> Self::Request {
+        Self::Request::default()
+    }
+}
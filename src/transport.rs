@@ -0,0 +1,648 @@
+use crate::errors::OcppError;
+use crate::ocppj::decoder::{IncomingFrame, decode_frame};
+use crate::security::{BasicAuthCredentials, validate_identity};
+use futures_util::stream::{SplitSink, SplitStream};
+use futures_util::{SinkExt, StreamExt};
+use serde::Serialize;
+use std::fmt;
+#[cfg(feature = "tls")]
+use std::sync::Arc;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::error::ProtocolError;
+use tokio_tungstenite::tungstenite::handshake::client::Request as ClientRequest;
+use tokio_tungstenite::tungstenite::handshake::server::{ErrorResponse, Request, Response};
+use tokio_tungstenite::tungstenite::http::header::AUTHORIZATION;
+use tokio_tungstenite::tungstenite::http::{HeaderValue, StatusCode};
+use tokio_tungstenite::tungstenite::{Error as WsError, Message};
+use tokio_tungstenite::{WebSocketStream, accept_hdr_async, client_async};
+
+pub mod csms;
+pub(crate) mod endpoint;
+pub mod station;
+#[cfg(feature = "tls")]
+pub mod tls;
+
+const SEC_WEBSOCKET_PROTOCOL: &str = "Sec-WebSocket-Protocol";
+
+/// The OCPP-J WebSocket subprotocols supported by this crate.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum Subprotocol {
+    /// OCPP 2.1, negotiated as `ocpp2.1`.
+    Ocpp21,
+    /// OCPP 2.0.1, negotiated as `ocpp2.0.1`.
+    Ocpp201,
+}
+
+impl Subprotocol {
+    /// The value of this subprotocol in the `Sec-WebSocket-Protocol` header.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Subprotocol::Ocpp21 => "ocpp2.1",
+            Subprotocol::Ocpp201 => "ocpp2.0.1",
+        }
+    }
+}
+
+impl fmt::Display for Subprotocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl TryFrom<&str> for Subprotocol {
+    type Error = OcppError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "ocpp2.1" => Ok(Subprotocol::Ocpp21),
+            "ocpp2.0.1" => Ok(Subprotocol::Ocpp201),
+            _ => Err(OcppError::InvalidEnumValueError {
+                enum_name: "Subprotocol".to_string(),
+                value: value.to_string(),
+            }),
+        }
+    }
+}
+
+/// Any byte stream a WebSocket can run over, e.g. a plain or TLS-wrapped `TcpStream`.
+pub(crate) trait Io: AsyncRead + AsyncWrite + Unpin + Send {}
+
+impl<T: AsyncRead + AsyncWrite + Unpin + Send> Io for T {}
+
+type Stream = WebSocketStream<Box<dyn Io>>;
+
+pub(crate) fn transport_error(e: impl fmt::Display) -> OcppError {
+    OcppError::TransportError {
+        reason: e.to_string(),
+    }
+}
+
+/// The sending half of an `OcppConnection`.
+pub struct OcppSender {
+    sink: SplitSink<Stream, Message>,
+}
+
+impl OcppSender {
+    /// Send a raw text frame.
+    pub async fn send_text(&mut self, text: String) -> Result<(), OcppError> {
+        self.sink
+            .send(Message::text(text))
+            .await
+            .map_err(transport_error)
+    }
+
+    /// Serialize and send an OCPP-J frame such as an `RcpCall` or `RcpCallResult`.
+    pub async fn send<T: Serialize + ?Sized>(&mut self, frame: &T) -> Result<(), OcppError> {
+        let text = serde_json::to_string(frame).map_err(transport_error)?;
+        self.send_text(text).await
+    }
+
+    /// Send a close frame and shut the connection down.
+    pub async fn close(&mut self) -> Result<(), OcppError> {
+        match self.sink.close().await {
+            Ok(()) | Err(WsError::ConnectionClosed) | Err(WsError::AlreadyClosed) => Ok(()),
+            Err(e) => Err(transport_error(e)),
+        }
+    }
+}
+
+/// The receiving half of an `OcppConnection`.
+pub struct OcppReceiver {
+    stream: SplitStream<Stream>,
+}
+
+impl OcppReceiver {
+    /// Receive the next text frame. Returns `None` once the connection is closed. Control frames
+    /// are handled transparently, and a binary frame yields a `MalformedFrameError` after which
+    /// the connection can still be used.
+    pub async fn recv_text(&mut self) -> Option<Result<String, OcppError>> {
+        loop {
+            match self.stream.next().await? {
+                Ok(Message::Text(text)) => return Some(Ok(text.to_string())),
+                Ok(Message::Binary(_)) => {
+                    return Some(Err(OcppError::MalformedFrameError {
+                        reason: "OCPP-J frames must be sent as text, got a binary frame"
+                            .to_string(),
+                    }));
+                }
+                Ok(Message::Close(_)) => return None,
+                Ok(Message::Ping(_) | Message::Pong(_) | Message::Frame(_)) => continue,
+                Err(WsError::ConnectionClosed) | Err(WsError::AlreadyClosed) => return None,
+                Err(e) => return Some(Err(transport_error(e))),
+            }
+        }
+    }
+
+    /// Receive and decode the next OCPP-J frame. Returns `None` once the connection is closed.
+    pub async fn recv(&mut self) -> Option<Result<IncomingFrame, OcppError>> {
+        let text = self.recv_text().await?;
+        Some(text.and_then(|text| decode_frame(&text)))
+    }
+}
+
+/// An open OCPP-J WebSocket connection, on either the Charging Station or the CSMS side.
+pub struct OcppConnection {
+    sender: OcppSender,
+    receiver: OcppReceiver,
+    subprotocol: Subprotocol,
+}
+
+impl OcppConnection {
+    fn new(stream: Stream, subprotocol: Subprotocol) -> Self {
+        let (sink, stream) = stream.split();
+        Self {
+            sender: OcppSender { sink },
+            receiver: OcppReceiver { stream },
+            subprotocol,
+        }
+    }
+
+    /// The subprotocol negotiated during the handshake.
+    pub fn subprotocol(&self) -> Subprotocol {
+        self.subprotocol
+    }
+
+    /// Send a raw text frame.
+    pub async fn send_text(&mut self, text: String) -> Result<(), OcppError> {
+        self.sender.send_text(text).await
+    }
+
+    /// Serialize and send an OCPP-J frame such as an `RcpCall` or `RcpCallResult`.
+    pub async fn send<T: Serialize + ?Sized>(&mut self, frame: &T) -> Result<(), OcppError> {
+        self.sender.send(frame).await
+    }
+
+    /// Receive the next text frame. Returns `None` once the connection is closed.
+    pub async fn recv_text(&mut self) -> Option<Result<String, OcppError>> {
+        self.receiver.recv_text().await
+    }
+
+    /// Receive and decode the next OCPP-J frame. Returns `None` once the connection is closed.
+    pub async fn recv(&mut self) -> Option<Result<IncomingFrame, OcppError>> {
+        self.receiver.recv().await
+    }
+
+    /// Send a close frame and shut the connection down.
+    pub async fn close(&mut self) -> Result<(), OcppError> {
+        self.sender.close().await
+    }
+
+    /// Split the connection so frames can be sent and received from separate tasks.
+    pub fn split(self) -> (OcppSender, OcppReceiver) {
+        (self.sender, self.receiver)
+    }
+}
+
+/// Options for connecting to a CSMS.
+#[derive(Clone, Debug, Default)]
+pub struct ClientOptions {
+    /// Credentials sent in the `Authorization` header, for security profiles 1 and 2.
+    pub basic_auth: Option<BasicAuthCredentials>,
+    /// The TLS configuration used for `wss://` urls, for security profiles 2 and 3.
+    #[cfg(feature = "tls")]
+    pub tls: Option<Arc<tls::ClientConfig>>,
+}
+
+/// The deviations that a `Lenient` decoding profile tolerated in an incoming message. See
+/// `ChargingStationBuilder::on_decoding_warnings` and `CsmsBuilder::on_decoding_warnings`.
+#[derive(Clone, Debug)]
+pub struct DecodingWarnings {
+    /// The message id of the CALL, CALLRESULT or SEND.
+    pub message_id: String,
+    /// The action of the message, or of the CALL a CALLRESULT answers.
+    pub action: String,
+    /// One error per deviation.
+    pub warnings: Vec<OcppError>,
+}
+
+/// Build the handshake request for `url`, offering the given subprotocols in order of preference.
+pub(crate) fn client_request(
+    url: &str,
+    subprotocols: &[Subprotocol],
+    basic_auth: Option<&BasicAuthCredentials>,
+) -> Result<ClientRequest, OcppError> {
+    if subprotocols.is_empty() {
+        return Err(transport_error("at least one subprotocol must be offered"));
+    }
+
+    let mut request = url.into_client_request().map_err(transport_error)?;
+    let offered = subprotocols
+        .iter()
+        .map(Subprotocol::as_str)
+        .collect::<Vec<_>>()
+        .join(", ");
+    request.headers_mut().insert(
+        SEC_WEBSOCKET_PROTOCOL,
+        HeaderValue::from_str(&offered).map_err(transport_error)?,
+    );
+
+    if let Some(credentials) = basic_auth {
+        request.headers_mut().insert(
+            AUTHORIZATION,
+            HeaderValue::from_str(&credentials.authorization_header()).map_err(transport_error)?,
+        );
+    }
+
+    Ok(request)
+}
+
+/// Open a TCP connection to the host of a handshake request.
+pub(crate) async fn connect_tcp(request: &ClientRequest) -> Result<TcpStream, OcppError> {
+    let uri = request.uri();
+    let host = uri
+        .host()
+        .ok_or_else(|| transport_error(format!("{uri} has no host")))?;
+    let port = uri
+        .port_u16()
+        .unwrap_or(if uri.scheme_str() == Some("wss") {
+            443
+        } else {
+            80
+        });
+
+    TcpStream::connect((host.trim_start_matches('[').trim_end_matches(']'), port))
+        .await
+        .map_err(transport_error)
+}
+
+/// Complete the client side of the WebSocket handshake over an already connected stream.
+pub(crate) async fn handshake_client(
+    request: ClientRequest,
+    stream: Box<dyn Io>,
+) -> Result<OcppConnection, OcppError> {
+    let offered = request
+        .headers()
+        .get(SEC_WEBSOCKET_PROTOCOL)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+
+    let (stream, response) = client_async(request, stream).await.map_err(|e| match e {
+        WsError::Protocol(ProtocolError::SecWebSocketSubProtocolError(_)) => {
+            OcppError::SubprotocolNegotiationError { offered }
+        }
+        WsError::Http(response) if response.status() == StatusCode::UNAUTHORIZED => {
+            OcppError::AuthenticationError {
+                reason: "the server rejected the credentials".to_string(),
+            }
+        }
+        e => transport_error(e),
+    })?;
+
+    let subprotocol = response
+        .headers()
+        .get(SEC_WEBSOCKET_PROTOCOL)
+        .and_then(|value| value.to_str().ok())
+        .ok_or_else(|| transport_error("the server did not select a subprotocol"))?;
+    let subprotocol = Subprotocol::try_from(subprotocol)?;
+
+    Ok(OcppConnection::new(stream, subprotocol))
+}
+
+/// Connect to an OCPP-J endpoint such as `ws://csms.example.com/ocpp/CS001`, offering the given
+/// subprotocols in order of preference.
+pub async fn connect(url: &str, subprotocols: &[Subprotocol]) -> Result<OcppConnection, OcppError> {
+    connect_with(url, subprotocols, &ClientOptions::default()).await
+}
+
+/// Connect to an OCPP-J endpoint with the given security options. `ws://` urls connect over plain
+/// TCP, `wss://` urls require a TLS configuration.
+pub async fn connect_with(
+    url: &str,
+    subprotocols: &[Subprotocol],
+    options: &ClientOptions,
+) -> Result<OcppConnection, OcppError> {
+    let request = client_request(url, subprotocols, options.basic_auth.as_ref())?;
+    let unsupported = || {
+        transport_error(format!(
+            "{url} is not a supported url, expected ws:// or, with the tls feature, wss://"
+        ))
+    };
+
+    #[cfg(feature = "tls")]
+    let tls = match request.uri().scheme_str() {
+        Some("ws") => None,
+        Some("wss") => Some(
+            options
+                .tls
+                .clone()
+                .ok_or_else(|| transport_error(format!("{url} requires a TLS configuration")))?,
+        ),
+        _ => return Err(unsupported()),
+    };
+    #[cfg(not(feature = "tls"))]
+    if request.uri().scheme_str() != Some("ws") {
+        return Err(unsupported());
+    }
+
+    let stream = connect_tcp(&request).await?;
+
+    #[cfg(feature = "tls")]
+    if let Some(config) = tls {
+        let host = request.uri().host().unwrap_or_default().to_string();
+        let stream = tls::connect(config, &host, stream).await?;
+        return handshake_client(request, stream).await;
+    }
+
+    handshake_client(request, Box::new(stream)).await
+}
+
+/// The HTTP upgrade request a connection was accepted from.
+#[derive(Clone, Debug)]
+pub struct ConnectionRequest {
+    /// The request path, e.g. `/ocpp/CS001`.
+    pub path: String,
+    /// The value of the `Authorization` header, if any.
+    pub authorization: Option<String>,
+}
+
+impl ConnectionRequest {
+    /// The Charging Station identity, which OCPP-J places percent-encoded in the last segment of
+    /// the path. Fails if the segment is empty, is not validly percent-encoded UTF-8, or does not
+    /// decode to a valid identity.
+    pub fn identity(&self) -> Result<String, OcppError> {
+        let segment = self.path.rsplit('/').next().unwrap_or_default();
+        let identity = percent_decode(segment).ok_or_else(|| {
+            OcppError::FieldValueError {
+                value: segment.to_string(),
+            }
+            .to_field_validation_error("identity")
+        })?;
+        validate_identity(&identity)?;
+        Ok(identity)
+    }
+}
+
+/// Decode the `%XX` escapes of a URL path segment, or `None` if an escape is malformed or the
+/// result is not UTF-8.
+fn percent_decode(segment: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(segment.len());
+    let mut rest = segment.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%' {
+            let hex = std::str::from_utf8(tail.get(..2)?).ok()?;
+            if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return None;
+            }
+            bytes.push(u8::from_str_radix(hex, 16).ok()?);
+            rest = &tail[2..];
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
+/// Pick the first of our supported subprotocols that the client offered.
+fn negotiate(offered: &str, supported: &[Subprotocol]) -> Option<Subprotocol> {
+    let offered: Vec<&str> = offered.split(',').map(str::trim).collect();
+    supported
+        .iter()
+        .find(|subprotocol| offered.contains(&subprotocol.as_str()))
+        .copied()
+}
+
+/// Complete the server side of the WebSocket handshake over an already accepted stream. The
+/// handshake is answered with `401 Unauthorized` if `authenticate` rejects the request with an
+/// `AuthenticationError`, and with `400 Bad Request` if it rejects it with any other error.
+pub(crate) async fn handshake_server(
+    stream: Box<dyn Io>,
+    supported: &[Subprotocol],
+    authenticate: impl FnOnce(&ConnectionRequest) -> Result<(), OcppError> + Unpin,
+) -> Result<(OcppConnection, ConnectionRequest), OcppError> {
+    let mut offered = String::new();
+    let mut selected = None;
+    let mut accepted = None;
+    let mut rejected = None;
+
+    // The error response type is dictated by tungstenite.
+    #[allow(clippy::result_large_err)]
+    let callback = |request: &Request, mut response: Response| {
+        let header = |name: &str| {
+            request
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(String::from)
+        };
+        let connection_request = ConnectionRequest {
+            path: request.uri().path().to_string(),
+            authorization: header(AUTHORIZATION.as_str()),
+        };
+
+        if let Err(e) = authenticate(&connection_request) {
+            let mut error = ErrorResponse::new(None);
+            *error.status_mut() = match e {
+                OcppError::AuthenticationError { .. } => StatusCode::UNAUTHORIZED,
+                _ => StatusCode::BAD_REQUEST,
+            };
+            rejected = Some(e);
+            return Err(error);
+        }
+
+        offered = header(SEC_WEBSOCKET_PROTOCOL).unwrap_or_default();
+        selected = negotiate(&offered, supported);
+        accepted = Some(connection_request);
+
+        // Without a common subprotocol, the handshake completes without the header and the
+        // connection is closed right after, as required by OCPP-J.
+        if let Some(subprotocol) = selected {
+            response.headers_mut().insert(
+                SEC_WEBSOCKET_PROTOCOL,
+                HeaderValue::from_static(subprotocol.as_str()),
+            );
+        }
+        Ok(response)
+    };
+
+    let stream = match accept_hdr_async(stream, callback).await {
+        Ok(stream) => stream,
+        Err(e) => return Err(rejected.unwrap_or_else(|| transport_error(e))),
+    };
+    let mut connection = OcppConnection::new(stream, Subprotocol::Ocpp21);
+
+    match (selected, accepted) {
+        (Some(subprotocol), Some(request)) => {
+            connection.subprotocol = subprotocol;
+            Ok((connection, request))
+        }
+        _ => {
+            connection.close().await?;
+            Err(OcppError::SubprotocolNegotiationError { offered })
+        }
+    }
+}
+
+/// Accept an incoming OCPP-J connection, negotiating one of the given subprotocols in order of
+/// preference. The connection is not authenticated, see `Csms` for security profiles.
+pub async fn accept(
+    stream: TcpStream,
+    supported: &[Subprotocol],
+) -> Result<(OcppConnection, ConnectionRequest), OcppError> {
+    handshake_server(Box::new(stream), supported, |_| Ok(())).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::clear_cache::{ClearCacheRequest, ClearCacheResponse};
+    use crate::ocppj::{RcpCall, RcpCallResult};
+    use tokio::net::TcpListener;
+
+    async fn serve(
+        supported: &'static [Subprotocol],
+    ) -> (
+        String,
+        tokio::task::JoinHandle<Result<(OcppConnection, ConnectionRequest), OcppError>>,
+    ) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}/ocpp/CS001", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            accept(stream, supported).await
+        });
+        (url, server)
+    }
+
+    #[test]
+    fn test_subprotocol_str() {
+        for subprotocol in [Subprotocol::Ocpp21, Subprotocol::Ocpp201] {
+            assert_eq!(
+                Subprotocol::try_from(subprotocol.as_str()).unwrap(),
+                subprotocol
+            );
+        }
+        assert!(Subprotocol::try_from("ocpp1.6").is_err());
+    }
+
+    #[test]
+    fn test_negotiate() {
+        let supported = [Subprotocol::Ocpp21, Subprotocol::Ocpp201];
+        assert_eq!(
+            negotiate("ocpp2.0.1, ocpp2.1", &supported),
+            Some(Subprotocol::Ocpp21)
+        );
+        assert_eq!(
+            negotiate("ocpp1.6,ocpp2.0.1", &supported),
+            Some(Subprotocol::Ocpp201)
+        );
+        assert_eq!(negotiate("ocpp1.6", &supported), None);
+        assert_eq!(negotiate("", &supported), None);
+    }
+
+    #[test]
+    fn test_connection_request_identity() {
+        let identity = |path: &str| {
+            ConnectionRequest {
+                path: path.to_string(),
+                authorization: None,
+            }
+            .identity()
+        };
+
+        assert_eq!(identity("/ocpp/CS001").unwrap(), "CS001");
+        assert_eq!(identity("/ocpp/CS%2D01").unwrap(), "CS-01");
+        assert_eq!(identity("/ocpp/CS%2d01").unwrap(), "CS-01");
+        assert!(identity("/ocpp/").is_err());
+        assert!(identity("/ocpp/CS%2").is_err());
+        assert!(identity("/ocpp/CS%G1").is_err());
+        assert!(identity("/ocpp/CS%FF").is_err());
+        assert!(identity("/ocpp/CS%20001").is_err());
+        assert!(identity("/ocpp/CS%3A001").is_err());
+        assert!(identity(&format!("/ocpp/{}", "a".repeat(49))).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_exchange_frames() {
+        let (url, server) = serve(&[Subprotocol::Ocpp21, Subprotocol::Ocpp201]).await;
+        let mut client = connect(&url, &[Subprotocol::Ocpp21]).await.unwrap();
+        assert_eq!(client.subprotocol(), Subprotocol::Ocpp21);
+
+        let (mut server, request) = server.await.unwrap().unwrap();
+        assert_eq!(request.path, "/ocpp/CS001");
+        assert_eq!(request.identity().unwrap(), "CS001");
+        assert_eq!(server.subprotocol(), Subprotocol::Ocpp21);
+
+        let call = RcpCall::new("1", Box::new(ClearCacheRequest::default())).unwrap();
+        client.send(&call).await.unwrap();
+        match server.recv().await.unwrap().unwrap() {
+            IncomingFrame::Call(call) => {
+                assert_eq!(call.message_id, "1");
+                assert_eq!(call.action(), "ClearCache");
+            }
+            other => panic!("Expected a CALL. Got {other:?} instead."),
+        }
+
+        let result = RcpCallResult::new("1", Box::new(ClearCacheResponse::default()));
+        server.send(&result).await.unwrap();
+        match client.recv().await.unwrap().unwrap() {
+            IncomingFrame::CallResult(result) => assert_eq!(result.message_id, "1"),
+            other => panic!("Expected a CALLRESULT. Got {other:?} instead."),
+        }
+
+        client.close().await.unwrap();
+        assert!(server.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_negotiates_older_subprotocol() {
+        let (url, server) = serve(&[Subprotocol::Ocpp21, Subprotocol::Ocpp201]).await;
+        let client = connect(&url, &[Subprotocol::Ocpp201]).await.unwrap();
+        assert_eq!(client.subprotocol(), Subprotocol::Ocpp201);
+
+        let (server, _) = server.await.unwrap().unwrap();
+        assert_eq!(server.subprotocol(), Subprotocol::Ocpp201);
+    }
+
+    #[tokio::test]
+    async fn test_negotiation_failure() {
+        let (url, server) = serve(&[Subprotocol::Ocpp21]).await;
+        let client = connect(&url, &[Subprotocol::Ocpp201]).await;
+        assert!(matches!(
+            client,
+            Err(OcppError::SubprotocolNegotiationError { .. })
+        ));
+        assert!(matches!(
+            server.await.unwrap(),
+            Err(OcppError::SubprotocolNegotiationError { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_upgrade_is_not_a_negotiation_failure() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}/ocpp/CS001", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut request = [0; 1024];
+            let _ = stream.read(&mut request).await.unwrap();
+            // Switches protocols without the `Upgrade` and `Sec-WebSocket-Accept` headers.
+            stream
+                .write_all(
+                    b"HTTP/1.1 101 Switching Protocols\r\nSec-WebSocket-Protocol: ocpp2.1\r\n\r\n",
+                )
+                .await
+                .unwrap();
+        });
+
+        match connect(&url, &[Subprotocol::Ocpp21]).await {
+            Err(OcppError::TransportError { .. }) => {}
+            Err(e) => panic!("Expected a TransportError. Got {e:?} instead."),
+            Ok(_) => panic!("Expected the handshake to fail."),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_connect_rejects_non_ws_url() {
+        assert!(
+            connect("http://127.0.0.1:1/ocpp/CS001", &[Subprotocol::Ocpp21])
+                .await
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,700 @@
+use crate::errors::OcppError;
+use crate::messages::for_each_station_action;
+use crate::messages::notify_periodic_event_stream::NotifyPeriodicEventStreamRequest;
+use crate::ocppj::decoder::{CallRequest, DecodingProfile, RequestMessage, UnconfirmedMessage};
+use crate::ocppj::message_id::UuidMessageIdGenerator;
+use crate::security::BasicAuthCredentials;
+use crate::traits::OcppMessage;
+use crate::transport::endpoint::{CallFuture, Decoding, Dispatcher, Endpoint, SendFuture};
+#[cfg(feature = "tls")]
+use crate::transport::tls;
+use crate::transport::{
+    ConnectionRequest, DecodingWarnings, Io, Subprotocol, handshake_server, transport_error,
+};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::net::{TcpListener, TcpStream};
+
+macro_rules! impl_csms_handler {
+    ($($module:ident::$message:ident),* $(,)?) => {
+        /// Handles the requests Charging Stations send to the CSMS, with one method per action.
+        /// Every method has a default implementation, which answers the CALL with a `NotSupported`
+        /// CALLERROR, so only the actions the CSMS supports need to be implemented.
+        ///
+        /// Requests are validated before they reach a method, unless a `Lenient` decoding profile
+        /// tolerates their deviations, and responses are validated before they are sent. An error
+        /// returned by a method is sent as the CALLERROR it maps to.
+        pub trait CsmsHandler: Send + Sync + 'static {
+            $(
+                #[doc = concat!("Handle a `", stringify!($message), "Request`.")]
+                fn $module(
+                    &self,
+                    station: &ConnectedStation,
+                    request: <crate::messages::$module::$message as OcppMessage>::Request,
+                ) -> impl Future<
+                    Output = Result<
+                        <crate::messages::$module::$message as OcppMessage>::Response,
+                        OcppError,
+                    >,
+                > + Send {
+                    let _ = (station, request);
+                    async {
+                        Err(OcppError::UnhandledActionError {
+                            action: stringify!($message).to_string(),
+                        })
+                    }
+                }
+            )*
+
+            /// Handle a `NotifyPeriodicEventStreamRequest`, which is sent with SEND and therefore
+            /// not answered. Ignored unless implemented.
+            fn notify_periodic_event_stream(
+                &self,
+                station: &ConnectedStation,
+                request: NotifyPeriodicEventStreamRequest,
+            ) -> impl Future<Output = ()> + Send {
+                let _ = (station, request);
+                async {}
+            }
+        }
+
+        impl<H: CsmsHandler> Dispatcher for StationDispatcher<H> {
+            fn dispatch_call(&self, request: RequestMessage) -> Option<CallFuture> {
+                let handler = Arc::clone(&self.handler);
+                let station = self.station.clone();
+                match request {
+                    $(
+                        RequestMessage::$message(request) => Some(Box::pin(async move {
+                            handler.$module(&station, request).await.map(Into::into)
+                        })),
+                    )*
+                    // Actions that only the CSMS initiates.
+                    _ => None,
+                }
+            }
+
+            fn dispatch_send(&self, request: UnconfirmedMessage) -> Option<SendFuture> {
+                let handler = Arc::clone(&self.handler);
+                let station = self.station.clone();
+                match request {
+                    UnconfirmedMessage::NotifyPeriodicEventStream(request) => {
+                        Some(Box::pin(async move {
+                            handler.notify_periodic_event_stream(&station, request).await
+                        }))
+                    }
+                }
+            }
+        }
+    };
+}
+
+for_each_station_action!(impl_csms_handler);
+
+/// Routes the requests of a single Charging Station to the `CsmsHandler`.
+struct StationDispatcher<H> {
+    handler: Arc<H>,
+    station: ConnectedStation,
+}
+
+/// A Charging Station connected to the CSMS. Cloning it yields another handle to the same
+/// connection.
+#[derive(Clone)]
+pub struct ConnectedStation {
+    identity: String,
+    subprotocol: Subprotocol,
+    endpoint: Arc<Endpoint>,
+}
+
+impl ConnectedStation {
+    /// The identity of the Charging Station, taken from the last segment of the connection URL.
+    pub fn identity(&self) -> &str {
+        &self.identity
+    }
+
+    /// The subprotocol negotiated with the Charging Station.
+    pub fn subprotocol(&self) -> Subprotocol {
+        self.subprotocol
+    }
+
+    /// Whether the connection to the Charging Station has been closed.
+    pub fn is_closed(&self) -> bool {
+        self.endpoint.is_closed()
+    }
+
+    /// Send a CALL to the Charging Station and wait for its typed response. The request is
+    /// validated before it is sent. Only one CALL is outstanding per Charging Station at a time.
+    pub async fn call<R: CallRequest>(&self, request: R) -> Result<R::Response, OcppError> {
+        self.endpoint.call(request).await
+    }
+
+    /// Close the connection to the Charging Station.
+    pub async fn close(&self) -> Result<(), OcppError> {
+        self.endpoint.close().await
+    }
+}
+
+/// Looks up the expected basic authentication credentials of a Charging Station by its identity.
+type CredentialsLookup = Box<dyn Fn(&str) -> Option<BasicAuthCredentials> + Send + Sync>;
+
+/// Receives the identity of a Charging Station and the deviations tolerated in one of its messages.
+type StationWarningHook = Arc<dyn Fn(&str, &DecodingWarnings) + Send + Sync>;
+
+/// Configures a `Csms`.
+pub struct CsmsBuilder<H> {
+    handler: H,
+    subprotocols: Vec<Subprotocol>,
+    timeout: Duration,
+    basic_auth: Option<CredentialsLookup>,
+    #[cfg(feature = "tls")]
+    tls: Option<Arc<tls::ServerConfig>>,
+    decoding_profile: Option<DecodingProfile>,
+    on_decoding_warnings: Option<StationWarningHook>,
+}
+
+impl<H: CsmsHandler> CsmsBuilder<H> {
+    /// The subprotocols to accept, in order of preference. Defaults to OCPP 2.1, then 2.0.1.
+    pub fn subprotocols(mut self, subprotocols: &[Subprotocol]) -> Self {
+        self.subprotocols = subprotocols.to_vec();
+        self
+    }
+
+    /// How long to wait for the answer to a CALL. Defaults to 30 seconds.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Require HTTP Basic authentication, as in security profiles 1 and 2. `credentials` returns
+    /// the expected credentials of the Charging Station with the given identity, or `None` for
+    /// unknown stations. Stations that fail to authenticate are answered with `401 Unauthorized`.
+    pub fn basic_auth(
+        mut self,
+        credentials: impl Fn(&str) -> Option<BasicAuthCredentials> + Send + Sync + 'static,
+    ) -> Self {
+        self.basic_auth = Some(Box::new(credentials));
+        self
+    }
+
+    /// Check every incoming payload as per `profile` while decoding it. A `Strict` profile answers
+    /// a CALL with unknown fields or invalid values with a CALLERROR, a `Lenient` profile passes it
+    /// to the handler and reports the deviations to `on_decoding_warnings`. Without a profile,
+    /// unknown fields are ignored and incoming requests are validated before they reach the
+    /// handler.
+    pub fn decoding_profile(mut self, profile: DecodingProfile) -> Self {
+        self.decoding_profile = Some(profile);
+        self
+    }
+
+    /// Called with the identity of a Charging Station and the deviations that a `Lenient` decoding
+    /// profile tolerated in one of its messages, e.g. to log the bugs of the station.
+    pub fn on_decoding_warnings(
+        mut self,
+        hook: impl Fn(&str, &DecodingWarnings) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_decoding_warnings = Some(Arc::new(hook));
+        self
+    }
+
+    /// Accept connections over TLS, as in security profiles 2 and 3. See `tls::server_config` and
+    /// `tls::server_config_with_client_auth`. Stations whose client certificate was issued to
+    /// another identity are answered with `401 Unauthorized`.
+    #[cfg(feature = "tls")]
+    pub fn tls(mut self, config: Arc<tls::ServerConfig>) -> Self {
+        self.tls = Some(config);
+        self
+    }
+
+    pub fn build(self) -> Csms<H> {
+        Csms {
+            shared: Arc::new(Shared {
+                handler: Arc::new(self.handler),
+                subprotocols: self.subprotocols,
+                timeout: self.timeout,
+                basic_auth: self.basic_auth,
+                #[cfg(feature = "tls")]
+                tls: self.tls,
+                decoding_profile: self.decoding_profile,
+                on_decoding_warnings: self.on_decoding_warnings,
+                stations: Mutex::new(HashMap::new()),
+            }),
+        }
+    }
+}
+
+struct Shared<H> {
+    handler: Arc<H>,
+    subprotocols: Vec<Subprotocol>,
+    timeout: Duration,
+    basic_auth: Option<CredentialsLookup>,
+    #[cfg(feature = "tls")]
+    tls: Option<Arc<tls::ServerConfig>>,
+    decoding_profile: Option<DecodingProfile>,
+    on_decoding_warnings: Option<StationWarningHook>,
+    stations: Mutex<HashMap<String, ConnectedStation>>,
+}
+
+impl<H> Shared<H> {
+    fn stations(&self) -> std::sync::MutexGuard<'_, HashMap<String, ConnectedStation>> {
+        self.stations.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// The CSMS side of OCPP-J: accepts Charging Station connections, routes their requests to a
+/// `CsmsHandler` and issues CALLs to connected stations. Cloning it yields another handle to the
+/// same server.
+///
+/// ```no_run
+/// # async fn run() -> Result<(), ocpp_rs::errors::OcppError> {
+/// use chrono::Utc;
+/// use ocpp_rs::enums::registration_status_enum_type::RegistrationStatusEnumType;
+/// use ocpp_rs::errors::OcppError;
+/// use ocpp_rs::messages::boot_notification::{BootNotificationRequest, BootNotificationResponse};
+/// use ocpp_rs::transport::csms::{ConnectedStation, Csms, CsmsHandler};
+///
+/// struct Handler;
+///
+/// impl CsmsHandler for Handler {
+///     async fn boot_notification(
+///         &self,
+///         _station: &ConnectedStation,
+///         _request: BootNotificationRequest,
+///     ) -> Result<BootNotificationResponse, OcppError> {
+///         Ok(BootNotificationResponse {
+///             current_time: Utc::now(),
+///             interval: 300,
+///             status: RegistrationStatusEnumType::Accepted,
+///             ..Default::default()
+///         })
+///     }
+/// }
+///
+/// let listener = tokio::net::TcpListener::bind("0.0.0.0:9000").await.unwrap();
+/// Csms::builder(Handler).build().serve(listener).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Csms<H> {
+    shared: Arc<Shared<H>>,
+}
+
+impl<H> Clone for Csms<H> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<H: CsmsHandler> Csms<H> {
+    pub fn builder(handler: H) -> CsmsBuilder<H> {
+        CsmsBuilder {
+            handler,
+            subprotocols: vec![Subprotocol::Ocpp21, Subprotocol::Ocpp201],
+            timeout: Duration::from_secs(30),
+            basic_auth: None,
+            #[cfg(feature = "tls")]
+            tls: None,
+            decoding_profile: None,
+            on_decoding_warnings: None,
+        }
+    }
+
+    /// Accept Charging Station connections from `listener` until it fails. Connections whose
+    /// handshake fails are dropped.
+    pub async fn serve(&self, listener: TcpListener) -> Result<(), OcppError> {
+        loop {
+            let (stream, _) = listener.accept().await.map_err(transport_error)?;
+            let csms = self.clone();
+            tokio::spawn(async move { csms.accept(stream).await });
+        }
+    }
+
+    /// Accept a single Charging Station connection. A station that connects with the identity of
+    /// an already connected station replaces it.
+    pub async fn accept(&self, stream: TcpStream) -> Result<ConnectedStation, OcppError> {
+        #[cfg(feature = "tls")]
+        if let Some(config) = &self.shared.tls {
+            let (stream, common_name) = tls::accept(Arc::clone(config), stream).await?;
+            return self.accept_io(stream, common_name).await;
+        }
+
+        self.accept_io(Box::new(stream), None).await
+    }
+
+    /// Check the Charging Station identity of a connection request, that it matches the subject
+    /// common name of the client certificate, if any, and the `Authorization` header, if basic
+    /// authentication is required.
+    fn authenticate(
+        &self,
+        request: &ConnectionRequest,
+        common_name: Option<&str>,
+    ) -> Result<(), OcppError> {
+        let identity = request.identity()?;
+        #[cfg(feature = "tls")]
+        if let Some(common_name) = common_name {
+            tls::verify_common_name(common_name, &identity)?;
+        }
+        #[cfg(not(feature = "tls"))]
+        let _ = common_name;
+
+        let Some(credentials) = &self.shared.basic_auth else {
+            return Ok(());
+        };
+
+        let expected = credentials(&identity).ok_or_else(|| OcppError::AuthenticationError {
+            reason: format!("unknown Charging Station {identity}"),
+        })?;
+        expected.verify(&identity, request.authorization.as_deref())
+    }
+
+    async fn accept_io(
+        &self,
+        stream: Box<dyn Io>,
+        common_name: Option<String>,
+    ) -> Result<ConnectedStation, OcppError> {
+        let (connection, request) =
+            handshake_server(stream, &self.shared.subprotocols, |request| {
+                self.authenticate(request, common_name.as_deref())
+            })
+            .await?;
+        // The identity was already checked while authenticating the handshake.
+        let identity = request.identity()?;
+
+        let subprotocol = connection.subprotocol();
+        let (sender, receiver) = connection.split();
+        let decoding = Decoding {
+            profile: self.shared.decoding_profile,
+            on_warnings: self.shared.on_decoding_warnings.clone().map(|hook| {
+                let identity = identity.clone();
+                Arc::new(move |warnings: &DecodingWarnings| hook(&identity, warnings)) as _
+            }),
+        };
+        let endpoint = Arc::new(Endpoint::new(
+            sender,
+            Box::new(UuidMessageIdGenerator),
+            self.shared.timeout,
+            decoding,
+        ));
+        let station = ConnectedStation {
+            identity: identity.clone(),
+            subprotocol,
+            endpoint: Arc::clone(&endpoint),
+        };
+        let dispatcher = Arc::new(StationDispatcher {
+            handler: Arc::clone(&self.shared.handler),
+            station: station.clone(),
+        });
+
+        let replaced = self
+            .shared
+            .stations()
+            .insert(identity.clone(), station.clone());
+        if let Some(replaced) = replaced {
+            let _ = replaced.close().await;
+        }
+
+        let shared = Arc::clone(&self.shared);
+        tokio::spawn(async move {
+            Arc::clone(&endpoint).run(receiver, dispatcher).await;
+
+            let mut stations = shared.stations();
+            if stations
+                .get(&identity)
+                .is_some_and(|station| Arc::ptr_eq(&station.endpoint, &endpoint))
+            {
+                stations.remove(&identity);
+            }
+        });
+
+        Ok(station)
+    }
+
+    /// The Charging Station connected with the given identity, if any.
+    pub fn station(&self, identity: &str) -> Option<ConnectedStation> {
+        self.shared.stations().get(identity).cloned()
+    }
+
+    /// The identities of all connected Charging Stations.
+    pub fn identities(&self) -> Vec<String> {
+        self.shared.stations().keys().cloned().collect()
+    }
+
+    /// Send a CALL to the Charging Station with the given identity and wait for its typed
+    /// response.
+    pub async fn call<R: CallRequest>(
+        &self,
+        identity: &str,
+        request: R,
+    ) -> Result<R::Response, OcppError> {
+        let station =
+            self.station(identity)
+                .ok_or_else(|| OcppError::StationNotConnectedError {
+                    identity: identity.to_string(),
+                })?;
+        station.call(request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::generic_device_model_status::GenericDeviceModelStatusEnumType;
+    use crate::enums::registration_status_enum_type::RegistrationStatusEnumType;
+    use crate::enums::report_base_enum_type::ReportBaseEnumType;
+    use crate::messages::authorize::{AuthorizeRequest, AuthorizeResponse};
+    use crate::messages::boot_notification::{BootNotificationRequest, BootNotificationResponse};
+    use crate::messages::get_base_report::{GetBaseReportRequest, GetBaseReportResponse};
+    use crate::ocppj::RpcErrorCode;
+    use crate::ocppj::decoder::IncomingFrame;
+    use crate::structures::charging_station_type::ChargingStationType;
+    use crate::structures::id_token_type::IdTokenType;
+    use crate::transport::station::ChargingStation;
+    use chrono::Utc;
+
+    struct Handler;
+
+    impl CsmsHandler for Handler {
+        async fn boot_notification(
+            &self,
+            station: &ConnectedStation,
+            request: BootNotificationRequest,
+        ) -> Result<BootNotificationResponse, OcppError> {
+            assert_eq!(station.identity(), request.charging_station.model);
+            Ok(BootNotificationResponse {
+                current_time: Utc::now(),
+                interval: 300,
+                status: RegistrationStatusEnumType::Accepted,
+                ..Default::default()
+            })
+        }
+    }
+
+    async fn start_csms() -> (Csms<Handler>, String) {
+        serve_csms(Csms::builder(Handler).build()).await
+    }
+
+    async fn serve_csms(csms: Csms<Handler>) -> (Csms<Handler>, String) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}/ocpp", listener.local_addr().unwrap());
+        tokio::spawn({
+            let csms = csms.clone();
+            async move { csms.serve(listener).await }
+        });
+        (csms, url)
+    }
+
+    fn boot_request(identity: &str) -> BootNotificationRequest {
+        BootNotificationRequest {
+            charging_station: ChargingStationType {
+                model: identity.to_string(),
+                vendor_name: "Vendor".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    async fn wait_for_station(csms: &Csms<Handler>, identity: &str) -> ConnectedStation {
+        for _ in 0..100 {
+            if let Some(station) = csms.station(identity) {
+                return station;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("{identity} did not connect");
+    }
+
+    #[tokio::test]
+    async fn test_stations_identified_by_path() {
+        let (csms, url) = start_csms().await;
+
+        for identity in ["CS001", "CS002"] {
+            let station = ChargingStation::builder()
+                .connect(&format!("{url}/{identity}"), &[Subprotocol::Ocpp21])
+                .await
+                .unwrap();
+            let response = station.call(boot_request(identity)).await.unwrap();
+            assert_eq!(response.status, RegistrationStatusEnumType::Accepted);
+        }
+
+        let mut identities = csms.identities();
+        identities.sort();
+        assert_eq!(identities, ["CS001", "CS002"]);
+    }
+
+    #[tokio::test]
+    async fn test_station_identity_is_percent_decoded() {
+        let (csms, url) = start_csms().await;
+
+        let station = ChargingStation::builder()
+            .connect(&format!("{url}/CS%2D01"), &[Subprotocol::Ocpp21])
+            .await
+            .unwrap();
+        assert!(station.call(boot_request("CS-01")).await.is_ok());
+        wait_for_station(&csms, "CS-01").await;
+
+        for identity in ["CS%3A01", "CS%2", "CS%FF"] {
+            assert!(
+                ChargingStation::builder()
+                    .connect(&format!("{url}/{identity}"), &[Subprotocol::Ocpp21])
+                    .await
+                    .is_err()
+            );
+        }
+        assert_eq!(csms.identities(), ["CS-01"]);
+    }
+
+    #[tokio::test]
+    async fn test_lenient_decoding_profile() {
+        let reported = Arc::new(Mutex::new(vec![]));
+        let csms = Csms::builder(Handler)
+            .decoding_profile(DecodingProfile::Lenient)
+            .on_decoding_warnings({
+                let reported = Arc::clone(&reported);
+                move |identity: &str, warnings: &DecodingWarnings| {
+                    reported
+                        .lock()
+                        .unwrap()
+                        .push((identity.to_string(), warnings.clone()))
+                }
+            })
+            .build();
+        let (_csms, url) = serve_csms(csms).await;
+
+        let mut station =
+            crate::transport::connect(&format!("{url}/CS001"), &[Subprotocol::Ocpp21])
+                .await
+                .unwrap();
+        station
+            .send_text(
+                r#"[2, "1", "BootNotification", {"reason": "PowerUp", "chargingStation": {"model": "CS001", "vendorName": "V", "firmwareBuild": "7"}, "uptime": 3600}]"#
+                    .to_string(),
+            )
+            .await
+            .unwrap();
+        assert!(matches!(
+            station.recv().await.unwrap().unwrap(),
+            IncomingFrame::CallResult(result) if result.message_id == "1"
+        ));
+
+        let reported = reported.lock().unwrap();
+        let [(identity, warnings)] = reported.as_slice() else {
+            panic!("Expected a single report. Got {reported:?} instead.");
+        };
+        assert_eq!(identity, "CS001");
+        assert_eq!(warnings.message_id, "1");
+        assert_eq!(warnings.action, "BootNotification");
+        assert_eq!(warnings.warnings.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_unhandled_action() {
+        let (_csms, url) = start_csms().await;
+        let station = ChargingStation::builder()
+            .connect(&format!("{url}/CS001"), &[Subprotocol::Ocpp21])
+            .await
+            .unwrap();
+
+        let request = AuthorizeRequest {
+            id_token: IdTokenType {
+                id_token: "04E91C3A".to_string(),
+                r#type: "ISO14443".to_string(),
+                additional_info: None,
+                custom_data: None,
+            },
+            ..Default::default()
+        };
+        let result: Result<AuthorizeResponse, OcppError> = station.call(request).await;
+        assert!(matches!(
+            result,
+            Err(OcppError::CallErrorReceived {
+                error_code: RpcErrorCode::NotSupported,
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_call_station() {
+        let (csms, url) = start_csms().await;
+        let _station = ChargingStation::builder()
+            .handler(|request: GetBaseReportRequest| async move {
+                assert_eq!(request.report_base, ReportBaseEnumType::FullInventory);
+                Ok(GetBaseReportResponse {
+                    status: GenericDeviceModelStatusEnumType::Accepted,
+                    ..Default::default()
+                })
+            })
+            .connect(&format!("{url}/CS001"), &[Subprotocol::Ocpp201])
+            .await
+            .unwrap();
+
+        let station = wait_for_station(&csms, "CS001").await;
+        assert_eq!(station.subprotocol(), Subprotocol::Ocpp201);
+
+        let request = GetBaseReportRequest {
+            request_id: 1,
+            report_base: ReportBaseEnumType::FullInventory,
+            custom_data: None,
+        };
+        let response = csms.call("CS001", request).await.unwrap();
+        assert_eq!(response.status, GenericDeviceModelStatusEnumType::Accepted);
+
+        assert!(matches!(
+            csms.call("CS404", GetBaseReportRequest::default()).await,
+            Err(OcppError::StationNotConnectedError { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_disconnected_station_is_removed() {
+        let (csms, url) = start_csms().await;
+        let station = ChargingStation::builder()
+            .connect(&format!("{url}/CS001"), &[Subprotocol::Ocpp21])
+            .await
+            .unwrap();
+        wait_for_station(&csms, "CS001").await;
+
+        station.close().await.unwrap();
+        for _ in 0..100 {
+            if csms.station("CS001").is_none() {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("CS001 was not removed after disconnecting");
+    }
+
+    #[tokio::test]
+    async fn test_basic_auth() {
+        let csms = Csms::builder(Handler)
+            .basic_auth(|identity| BasicAuthCredentials::new(identity, "0123456789abcdef").ok())
+            .build();
+        let (csms, url) = serve_csms(csms).await;
+        let url = format!("{url}/CS001");
+
+        let valid = BasicAuthCredentials::new("CS001", "0123456789abcdef").unwrap();
+        let station = ChargingStation::builder()
+            .basic_auth(valid)
+            .connect(&url, &[Subprotocol::Ocpp21])
+            .await
+            .unwrap();
+        assert!(station.call(boot_request("CS001")).await.is_ok());
+        wait_for_station(&csms, "CS001").await;
+
+        let wrong_password = BasicAuthCredentials::new("CS001", "fedcba9876543210").unwrap();
+        let other_identity = BasicAuthCredentials::new("CS002", "0123456789abcdef").unwrap();
+        for credentials in [Some(wrong_password), Some(other_identity), None] {
+            let mut builder = ChargingStation::builder();
+            if let Some(credentials) = credentials {
+                builder = builder.basic_auth(credentials);
+            }
+            assert!(matches!(
+                builder.connect(&url, &[Subprotocol::Ocpp21]).await,
+                Err(OcppError::AuthenticationError { .. })
+            ));
+        }
+    }
+}
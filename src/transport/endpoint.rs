@@ -0,0 +1,336 @@
+use crate::errors::OcppError;
+use crate::ocppj::decoder::{
+    CallRequest, DecodingProfile, IncomingCall, IncomingFrame, IncomingSend, RawCallResult,
+    RequestMessage, ResponseMessage, UnconfirmedMessage, call_message_id, decode_frame,
+    decode_frame_with_profile,
+};
+use crate::ocppj::message_id::MessageIdGenerator;
+use crate::ocppj::pending::PendingCalls;
+use crate::ocppj::{MessageTypeId, RcpCall, RcpCallError, RcpSend};
+use crate::traits::{OcppEntity, OcppRequest};
+use crate::transport::{DecodingWarnings, OcppReceiver, OcppSender, transport_error};
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::{Mutex as AsyncMutex, oneshot};
+
+/// The eventual response of a handler for an incoming CALL.
+pub(crate) type CallFuture =
+    Pin<Box<dyn Future<Output = Result<ResponseMessage, OcppError>> + Send>>;
+
+/// The eventual completion of a handler for an incoming SEND.
+pub(crate) type SendFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// Routes incoming requests to user code.
+pub(crate) trait Dispatcher: Send + Sync + 'static {
+    /// Handle a CALL. Returns `None` if there is no handler for its action, in which case the CALL
+    /// is answered with a `NotSupported` CALLERROR.
+    fn dispatch_call(&self, request: RequestMessage) -> Option<CallFuture>;
+
+    /// Handle a SEND. Returns `None` if there is no handler for its action. A SEND is never
+    /// answered.
+    fn dispatch_send(&self, _request: UnconfirmedMessage) -> Option<SendFuture> {
+        None
+    }
+}
+
+/// Receives the deviations that a `Lenient` decoding profile tolerated in an incoming message.
+pub(crate) type WarningHook = Arc<dyn Fn(&DecodingWarnings) + Send + Sync>;
+
+/// How an `Endpoint` decodes incoming payloads.
+#[derive(Clone, Default)]
+pub(crate) struct Decoding {
+    /// Checks every incoming payload while decoding it. Without a profile, payloads are
+    /// deserialized as-is and incoming requests are validated before they are dispatched.
+    pub(crate) profile: Option<DecodingProfile>,
+    pub(crate) on_warnings: Option<WarningHook>,
+}
+
+/// Outgoing CALLs awaiting their answer.
+#[derive(Default)]
+struct State {
+    pending: PendingCalls,
+    waiters: HashMap<String, oneshot::Sender<Result<ResponseMessage, OcppError>>>,
+    closed: bool,
+}
+
+/// One side of an OCPP-J connection: sends CALLs and SENDs, matches incoming answers with their
+/// CALL and answers incoming CALLs through a `Dispatcher`. Shared by the Charging Station and CSMS
+/// runtimes.
+pub(crate) struct Endpoint {
+    sender: AsyncMutex<OcppSender>,
+    state: Mutex<State>,
+    outstanding: AsyncMutex<()>,
+    message_ids: Box<dyn MessageIdGenerator>,
+    timeout: Duration,
+    decoding: Decoding,
+}
+
+fn connection_closed() -> OcppError {
+    transport_error("the connection is closed")
+}
+
+impl Endpoint {
+    pub(crate) fn new(
+        sender: OcppSender,
+        message_ids: Box<dyn MessageIdGenerator>,
+        timeout: Duration,
+        decoding: Decoding,
+    ) -> Self {
+        Self {
+            sender: AsyncMutex::new(sender),
+            state: Mutex::new(State {
+                pending: PendingCalls::new(timeout),
+                ..Default::default()
+            }),
+            outstanding: AsyncMutex::new(()),
+            message_ids,
+            timeout,
+            decoding,
+        }
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub(crate) fn is_closed(&self) -> bool {
+        self.state().closed
+    }
+
+    async fn send_text(&self, text: String) -> Result<(), OcppError> {
+        self.sender.lock().await.send_text(text).await
+    }
+
+    /// Validate and send a CALL, then wait for its answer. Only one CALL is outstanding at a time,
+    /// concurrent callers wait for their turn.
+    pub(crate) async fn call<R: CallRequest>(&self, request: R) -> Result<R::Response, OcppError> {
+        request.validate()?;
+        let _outstanding = self.outstanding.lock().await;
+
+        let message_id = self.message_ids.next_id();
+        let (waiter, answer) = oneshot::channel();
+        let text = {
+            let call = RcpCall::new(&message_id, Box::new(request))?;
+            let text = serde_json::to_string(&call).map_err(transport_error)?;
+
+            let mut state = self.state();
+            if state.closed {
+                return Err(connection_closed());
+            }
+            state.pending.register(&call)?;
+            state.waiters.insert(message_id.clone(), waiter);
+            text
+        };
+
+        if let Err(e) = self.send_text(text).await {
+            self.forget(&message_id);
+            return Err(e);
+        }
+
+        let response = match tokio::time::timeout(self.timeout, answer).await {
+            Ok(Ok(outcome)) => outcome?,
+            Ok(Err(_)) => return Err(connection_closed()),
+            Err(_) => {
+                self.forget(&message_id);
+                return Err(OcppError::CallTimeoutError {
+                    message_id,
+                    action: R::ACTION.to_string(),
+                });
+            }
+        };
+
+        R::Response::try_from(response).map_err(|other| OcppError::PayloadDeserializationError {
+            action: R::ACTION.to_string(),
+            reason: format!("expected a {} response, got {}", R::ACTION, other.action()),
+        })
+    }
+
+    /// Validate and send an unconfirmed message with SEND.
+    pub(crate) async fn send<R>(&self, request: R) -> Result<(), OcppError>
+    where
+        R: OcppEntity + OcppRequest + Into<UnconfirmedMessage> + 'static,
+    {
+        request.validate()?;
+        if self.is_closed() {
+            return Err(connection_closed());
+        }
+
+        let text = {
+            let send = RcpSend::new(&self.message_ids.next_id(), Box::new(request))?;
+            serde_json::to_string(&send).map_err(transport_error)?
+        };
+        self.send_text(text).await
+    }
+
+    fn forget(&self, message_id: &str) {
+        let mut state = self.state();
+        state.pending.cancel(message_id);
+        state.waiters.remove(message_id);
+    }
+
+    fn complete(&self, message_id: &str, outcome: Result<ResponseMessage, OcppError>) {
+        if let Some(waiter) = self.state().waiters.remove(message_id) {
+            let _ = waiter.send(outcome);
+        }
+    }
+
+    fn complete_result(&self, result: RawCallResult) {
+        let message_id = result.message_id.clone();
+        let outcome = match self.decoding.profile {
+            Some(profile) => {
+                let outcome = self
+                    .state()
+                    .pending
+                    .resolve_result_with_profile(result, profile);
+                outcome.map(|completed| {
+                    let call = completed.value;
+                    self.report(&call.message_id, &call.action, completed.warnings);
+                    call
+                })
+            }
+            None => self.state().pending.resolve_result(result),
+        };
+        self.complete(&message_id, outcome.map(|completed| completed.response));
+    }
+
+    /// Pass the deviations tolerated in an incoming message to the warning hook, if any.
+    fn report(&self, message_id: &str, action: &str, warnings: Vec<OcppError>) {
+        if let Some(on_warnings) = &self.decoding.on_warnings
+            && !warnings.is_empty()
+        {
+            on_warnings(&DecodingWarnings {
+                message_id: message_id.to_string(),
+                action: action.to_string(),
+                warnings,
+            });
+        }
+    }
+
+    /// Decode a text frame as per the decoding profile, reporting the deviations it tolerated.
+    fn decode(&self, text: &str) -> Result<IncomingFrame, OcppError> {
+        let Some(profile) = self.decoding.profile else {
+            return decode_frame(text);
+        };
+
+        let decoded = decode_frame_with_profile(text, profile)?;
+        match &decoded.value {
+            IncomingFrame::Call(call) => {
+                self.report(&call.message_id, call.action(), decoded.warnings)
+            }
+            IncomingFrame::Send(send) => {
+                self.report(&send.message_id, send.action(), decoded.warnings)
+            }
+            // The payloads of CALLRESULTs are checked once matched with their CALL.
+            _ => {}
+        }
+        Ok(decoded.value)
+    }
+
+    fn complete_error(&self, error: RcpCallError) {
+        let message_id = error.message_id.clone();
+        let outcome = self.state().pending.resolve_error(error);
+        self.complete(&message_id, Err(outcome));
+    }
+
+    /// Answer an incoming CALL with the response of its handler, or with a CALLERROR.
+    async fn answer_call<D: Dispatcher>(&self, call: IncomingCall, dispatcher: &D) {
+        let IncomingCall {
+            message_id,
+            request,
+        } = call;
+        let action = request.action();
+
+        // A decoding profile already checked the request while decoding it.
+        let checked = match self.decoding.profile {
+            Some(_) => Ok(()),
+            None => request.validate(),
+        };
+        let outcome = match checked {
+            Ok(()) => match dispatcher.dispatch_call(request) {
+                Some(handler) => handler
+                    .await
+                    .and_then(|response| response.validate().map(|()| response)),
+                None => Err(OcppError::UnhandledActionError {
+                    action: action.to_string(),
+                }),
+            },
+            Err(e) => Err(e),
+        };
+
+        let _ = match outcome {
+            Ok(response) => {
+                self.send_frame(&(MessageTypeId::CallResult, &message_id, &response))
+                    .await
+            }
+            Err(e) => {
+                self.send_frame(&RcpCallError::from_ocpp_error(&message_id, &e))
+                    .await
+            }
+        };
+    }
+
+    async fn send_frame<T: serde::Serialize>(&self, frame: &T) -> Result<(), OcppError> {
+        self.sender.lock().await.send(frame).await
+    }
+
+    /// Handle a single text frame received from the peer.
+    fn receive<D: Dispatcher>(self: &Arc<Self>, text: String, dispatcher: &Arc<D>) {
+        match self.decode(&text) {
+            Ok(IncomingFrame::Call(call)) => {
+                let endpoint = Arc::clone(self);
+                let dispatcher = Arc::clone(dispatcher);
+                tokio::spawn(async move { endpoint.answer_call(call, &*dispatcher).await });
+            }
+            Ok(IncomingFrame::CallResult(result)) => self.complete_result(result),
+            Ok(IncomingFrame::CallError(error)) => self.complete_error(error),
+            // Reports a problem with one of our CALLRESULTs, which nothing is waiting on.
+            Ok(IncomingFrame::CallResultError(_)) => {}
+            Ok(IncomingFrame::Send(IncomingSend { request, .. })) => {
+                if let Some(handler) = dispatcher.dispatch_send(request) {
+                    tokio::spawn(handler);
+                }
+            }
+            Err(e) => {
+                if let Some(message_id) = call_message_id(&text) {
+                    let endpoint = Arc::clone(self);
+                    tokio::spawn(async move {
+                        let error = RcpCallError::from_ocpp_error(&message_id, &e);
+                        let _ = endpoint.send_frame(&error).await;
+                    });
+                }
+            }
+        }
+    }
+
+    /// Receive frames until the connection closes or fails, then close it and fail every CALL
+    /// still awaiting an answer. Frames that cannot be read, such as binary frames, are skipped.
+    pub(crate) async fn run<D: Dispatcher>(
+        self: Arc<Self>,
+        mut receiver: OcppReceiver,
+        dispatcher: Arc<D>,
+    ) {
+        while let Some(text) = receiver.recv_text().await {
+            match text {
+                Ok(text) => self.receive(text, &dispatcher),
+                Err(OcppError::MalformedFrameError { .. }) => continue,
+                Err(_) => break,
+            }
+        }
+
+        let _ = self.close().await;
+        let mut state = self.state();
+        state.closed = true;
+        for (_, waiter) in state.waiters.drain() {
+            let _ = waiter.send(Err(connection_closed()));
+        }
+    }
+
+    /// Close the connection.
+    pub(crate) async fn close(&self) -> Result<(), OcppError> {
+        self.state().closed = true;
+        self.sender.lock().await.close().await
+    }
+}
@@ -0,0 +1,578 @@
+use crate::errors::OcppError;
+use crate::ocppj::decoder::{CallRequest, DecodingProfile, RequestMessage, UnconfirmedMessage};
+use crate::ocppj::message_id::{MessageIdGenerator, UuidMessageIdGenerator};
+use crate::security::BasicAuthCredentials;
+use crate::traits::{OcppEntity, OcppRequest};
+use crate::transport::endpoint::{CallFuture, Decoding, Dispatcher, Endpoint};
+#[cfg(feature = "tls")]
+use crate::transport::tls;
+use crate::transport::{
+    ClientOptions, DecodingWarnings, OcppConnection, Subprotocol, connect_with,
+};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+type Handler = Box<dyn Fn(RequestMessage) -> CallFuture + Send + Sync>;
+
+/// The handlers of a Charging Station, keyed by action.
+#[derive(Default)]
+struct Handlers {
+    handlers: HashMap<&'static str, Handler>,
+}
+
+impl Dispatcher for Handlers {
+    fn dispatch_call(&self, request: RequestMessage) -> Option<CallFuture> {
+        self.handlers
+            .get(request.action())
+            .map(|handler| handler(request))
+    }
+}
+
+/// Configures and starts a `ChargingStation`.
+pub struct ChargingStationBuilder {
+    handlers: Handlers,
+    message_ids: Box<dyn MessageIdGenerator>,
+    timeout: Duration,
+    options: ClientOptions,
+    decoding: Decoding,
+}
+
+impl Default for ChargingStationBuilder {
+    fn default() -> Self {
+        Self {
+            handlers: Handlers::default(),
+            message_ids: Box::new(UuidMessageIdGenerator),
+            timeout: Duration::from_secs(30),
+            options: ClientOptions::default(),
+            decoding: Decoding::default(),
+        }
+    }
+}
+
+impl ChargingStationBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// How long to wait for the answer to a CALL. Defaults to 30 seconds.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// The generator of message ids for outgoing CALLs and SENDs. Defaults to UUIDs.
+    pub fn message_ids(mut self, message_ids: impl MessageIdGenerator + 'static) -> Self {
+        self.message_ids = Box::new(message_ids);
+        self
+    }
+
+    /// Check every incoming payload as per `profile` while decoding it. A `Strict` profile answers
+    /// a CALL with unknown fields or invalid values with a CALLERROR, a `Lenient` profile passes it
+    /// to its handler and reports the deviations to `on_decoding_warnings`. Without a profile,
+    /// unknown fields are ignored and incoming requests are validated before they reach their
+    /// handler.
+    pub fn decoding_profile(mut self, profile: DecodingProfile) -> Self {
+        self.decoding.profile = Some(profile);
+        self
+    }
+
+    /// Called with the deviations that a `Lenient` decoding profile tolerated in an incoming
+    /// message, e.g. to log the bugs of the CSMS.
+    pub fn on_decoding_warnings(
+        mut self,
+        hook: impl Fn(&DecodingWarnings) + Send + Sync + 'static,
+    ) -> Self {
+        self.decoding.on_warnings = Some(Arc::new(hook));
+        self
+    }
+
+    /// Authenticate with HTTP Basic authentication, as in security profiles 1 and 2.
+    pub fn basic_auth(mut self, credentials: BasicAuthCredentials) -> Self {
+        self.options.basic_auth = Some(credentials);
+        self
+    }
+
+    /// The TLS configuration used for `wss://` urls, as in security profiles 2 and 3. See
+    /// `tls::client_config` and `tls::client_config_with_certificate`.
+    #[cfg(feature = "tls")]
+    pub fn tls(mut self, config: Arc<tls::ClientConfig>) -> Self {
+        self.options.tls = Some(config);
+        self
+    }
+
+    /// Handle CSMS-initiated CALLs of the action of `R`. Incoming requests are validated before
+    /// they reach the handler, unless a `Lenient` decoding profile tolerates their deviations, and
+    /// responses are validated before they are sent. A CALL whose
+    /// action has no handler is answered with a `NotSupported` CALLERROR, and a handler error is
+    /// answered with the CALLERROR it maps to.
+    pub fn handler<R, F, Fut>(mut self, handler: F) -> Self
+    where
+        R: CallRequest,
+        F: Fn(R) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<R::Response, OcppError>> + Send + 'static,
+    {
+        let handler = move |request: RequestMessage| -> CallFuture {
+            match R::try_from(request) {
+                Ok(request) => {
+                    let response = handler(request);
+                    Box::pin(async move { response.await.map(Into::into) })
+                }
+                Err(other) => Box::pin(async move {
+                    Err(OcppError::UnknownActionError {
+                        action: other.action().to_string(),
+                    })
+                }),
+            }
+        };
+        self.handlers.handlers.insert(R::ACTION, Box::new(handler));
+        self
+    }
+
+    /// Start the runtime on an open connection. Must be called from within a Tokio runtime.
+    pub fn start(self, connection: OcppConnection) -> ChargingStation {
+        let subprotocol = connection.subprotocol();
+        let (sender, receiver) = connection.split();
+        let endpoint = Arc::new(Endpoint::new(
+            sender,
+            self.message_ids,
+            self.timeout,
+            self.decoding,
+        ));
+        tokio::spawn(Arc::clone(&endpoint).run(receiver, Arc::new(self.handlers)));
+
+        ChargingStation {
+            endpoint,
+            subprotocol,
+        }
+    }
+
+    /// Connect to the CSMS at `url`, e.g. `ws://csms.example.com/ocpp/CS001`, and start the
+    /// runtime.
+    pub async fn connect(
+        self,
+        url: &str,
+        subprotocols: &[Subprotocol],
+    ) -> Result<ChargingStation, OcppError> {
+        let connection = connect_with(url, subprotocols, &self.options).await?;
+        Ok(self.start(connection))
+    }
+}
+
+/// The Charging Station side of an OCPP-J connection. Cloning it yields another handle to the
+/// same connection.
+///
+/// ```no_run
+/// # async fn run() -> Result<(), ocpp_rs::errors::OcppError> {
+/// use ocpp_rs::messages::boot_notification::BootNotificationRequest;
+/// use ocpp_rs::messages::clear_cache::{ClearCacheRequest, ClearCacheResponse};
+/// use ocpp_rs::transport::Subprotocol;
+/// use ocpp_rs::transport::station::ChargingStation;
+///
+/// let station = ChargingStation::builder()
+///     .handler(|_: ClearCacheRequest| async { Ok(ClearCacheResponse::default()) })
+///     .connect("ws://127.0.0.1:9000/ocpp/CS001", &[Subprotocol::Ocpp21])
+///     .await?;
+/// let response = station.call(BootNotificationRequest::default()).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct ChargingStation {
+    endpoint: Arc<Endpoint>,
+    subprotocol: Subprotocol,
+}
+
+impl ChargingStation {
+    pub fn builder() -> ChargingStationBuilder {
+        ChargingStationBuilder::new()
+    }
+
+    /// The subprotocol negotiated with the CSMS.
+    pub fn subprotocol(&self) -> Subprotocol {
+        self.subprotocol
+    }
+
+    /// Whether the connection to the CSMS has been closed.
+    pub fn is_closed(&self) -> bool {
+        self.endpoint.is_closed()
+    }
+
+    /// Send a CALL to the CSMS and wait for its typed response. The request is validated before it
+    /// is sent. Only one CALL is outstanding at a time, concurrent calls are sent one after the
+    /// other. A CALLERROR answer is returned as `OcppError::CallErrorReceived`.
+    pub async fn call<R: CallRequest>(&self, request: R) -> Result<R::Response, OcppError> {
+        self.endpoint.call(request).await
+    }
+
+    /// Send an unconfirmed message, such as NotifyPeriodicEventStream, with SEND. The request is
+    /// validated before it is sent.
+    pub async fn send<R>(&self, request: R) -> Result<(), OcppError>
+    where
+        R: OcppEntity + OcppRequest + Into<UnconfirmedMessage> + 'static,
+    {
+        self.endpoint.send(request).await
+    }
+
+    /// Close the connection to the CSMS.
+    pub async fn close(&self) -> Result<(), OcppError> {
+        self.endpoint.close().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::clear_cache_status_enum_type::ClearCacheStatusEnumType;
+    use crate::enums::registration_status_enum_type::RegistrationStatusEnumType;
+    use crate::messages::boot_notification::{BootNotificationRequest, BootNotificationResponse};
+    use crate::messages::clear_cache::{ClearCacheRequest, ClearCacheResponse};
+    use crate::ocppj::RpcErrorCode;
+    use crate::ocppj::decoder::{IncomingFrame, ResponseMessage};
+    use crate::structures::charging_station_type::ChargingStationType;
+    use crate::transport::accept;
+    use chrono::Utc;
+    use futures_util::SinkExt;
+    use serde_json::{Value, json};
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::tungstenite::Message;
+
+    /// Connect a station to a bare loopback connection that plays the CSMS in the test.
+    async fn connect_station(builder: ChargingStationBuilder) -> (ChargingStation, OcppConnection) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}/ocpp/CS001", listener.local_addr().unwrap());
+        let csms = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            accept(stream, &[Subprotocol::Ocpp21]).await.unwrap().0
+        });
+
+        let station = builder.connect(&url, &[Subprotocol::Ocpp21]).await.unwrap();
+        (station, csms.await.unwrap())
+    }
+
+    fn boot_request() -> BootNotificationRequest {
+        BootNotificationRequest {
+            charging_station: ChargingStationType {
+                model: "Model".to_string(),
+                vendor_name: "Vendor".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    async fn recv_json(csms: &mut OcppConnection) -> Value {
+        serde_json::from_str(&csms.recv_text().await.unwrap().unwrap()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_call() {
+        let (station, mut csms) = connect_station(ChargingStation::builder()).await;
+
+        let call = tokio::spawn({
+            let station = station.clone();
+            async move { station.call(boot_request()).await }
+        });
+
+        let frame = recv_json(&mut csms).await;
+        assert_eq!(frame[0], 2);
+        assert_eq!(frame[2], "BootNotification");
+        let response = BootNotificationResponse {
+            current_time: Utc::now(),
+            interval: 300,
+            status: RegistrationStatusEnumType::Accepted,
+            ..Default::default()
+        };
+        csms.send(&json!([3, frame[1], response])).await.unwrap();
+
+        let received = call.await.unwrap().unwrap();
+        assert_eq!(received.interval, 300);
+        assert_eq!(received.status, RegistrationStatusEnumType::Accepted);
+    }
+
+    #[tokio::test]
+    async fn test_call_error() {
+        let (station, mut csms) = connect_station(ChargingStation::builder()).await;
+
+        let call = tokio::spawn({
+            let station = station.clone();
+            async move { station.call(boot_request()).await }
+        });
+
+        let frame = recv_json(&mut csms).await;
+        csms.send(&json!([4, frame[1], "SecurityError", "Nope", {}]))
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            call.await.unwrap(),
+            Err(OcppError::CallErrorReceived {
+                error_code: RpcErrorCode::SecurityError,
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_call_validates_request() {
+        let (station, _csms) = connect_station(ChargingStation::builder()).await;
+
+        let mut request = boot_request();
+        request.charging_station.model = "m".repeat(100);
+        assert!(matches!(
+            station.call(request).await,
+            Err(OcppError::StructureValidationError { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_call_timeout() {
+        let builder = ChargingStation::builder().timeout(Duration::from_millis(50));
+        let (station, mut csms) = connect_station(builder).await;
+
+        let result = station.call(ClearCacheRequest::default()).await;
+        assert!(matches!(
+            result,
+            Err(OcppError::CallTimeoutError { action, .. }) if action == "ClearCache"
+        ));
+
+        // A late answer to the timed out CALL is ignored and the next CALL goes through.
+        let late = recv_json(&mut csms).await;
+        csms.send(&json!([3, late[1], {"status": "Accepted"}]))
+            .await
+            .unwrap();
+
+        let call = tokio::spawn({
+            let station = station.clone();
+            async move { station.call(ClearCacheRequest::default()).await }
+        });
+        let frame = recv_json(&mut csms).await;
+        assert_ne!(frame[1], late[1]);
+        csms.send(&json!([3, frame[1], {"status": "Rejected"}]))
+            .await
+            .unwrap();
+        assert_eq!(
+            call.await.unwrap().unwrap().status,
+            ClearCacheStatusEnumType::Rejected
+        );
+    }
+
+    #[tokio::test]
+    async fn test_one_outstanding_call() {
+        let (station, mut csms) = connect_station(ChargingStation::builder()).await;
+
+        let calls: Vec<_> = (0..2)
+            .map(|_| {
+                let station = station.clone();
+                tokio::spawn(async move { station.call(ClearCacheRequest::default()).await })
+            })
+            .collect();
+
+        let first = recv_json(&mut csms).await;
+        // The second CALL must not be sent before the first is answered.
+        let early = tokio::time::timeout(Duration::from_millis(100), csms.recv_text()).await;
+        assert!(early.is_err());
+
+        csms.send(&json!([3, first[1], {"status": "Accepted"}]))
+            .await
+            .unwrap();
+        let second = recv_json(&mut csms).await;
+        csms.send(&json!([3, second[1], {"status": "Accepted"}]))
+            .await
+            .unwrap();
+
+        for call in calls {
+            assert!(call.await.unwrap().is_ok());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handlers() {
+        let builder = ChargingStation::builder().handler(|_: ClearCacheRequest| async {
+            Ok(ClearCacheResponse {
+                status: ClearCacheStatusEnumType::Accepted,
+                ..Default::default()
+            })
+        });
+        let (_station, mut csms) = connect_station(builder).await;
+
+        csms.send_text(r#"[2, "1", "ClearCache", {}]"#.to_string())
+            .await
+            .unwrap();
+        match csms.recv().await.unwrap().unwrap() {
+            IncomingFrame::CallResult(result) => {
+                assert_eq!(result.message_id, "1");
+                let response = ResponseMessage::from_payload("ClearCache", result.payload);
+                assert!(matches!(response, Ok(ResponseMessage::ClearCache(_))));
+            }
+            other => panic!("Expected a CALLRESULT. Got {other:?} instead."),
+        }
+
+        // No handler is registered for GetLog.
+        csms.send_text(r#"[2, "2", "GetLog", {"logType": "DiagnosticsLog", "requestId": 1, "log": {"remoteLocation": "ftp://example.com"}}]"#.to_string())
+            .await
+            .unwrap();
+        match csms.recv().await.unwrap().unwrap() {
+            IncomingFrame::CallError(error) => {
+                assert_eq!(error.message_id, "2");
+                assert_eq!(error.error_code, RpcErrorCode::NotSupported);
+            }
+            other => panic!("Expected a CALLERROR. Got {other:?} instead."),
+        }
+
+        // Undecodable CALLs are answered with a CALLERROR too.
+        csms.send_text(r#"[2, "3", "NoSuchAction", {}]"#.to_string())
+            .await
+            .unwrap();
+        match csms.recv().await.unwrap().unwrap() {
+            IncomingFrame::CallError(error) => {
+                assert_eq!(error.message_id, "3");
+                assert_eq!(error.error_code, RpcErrorCode::NotImplemented);
+            }
+            other => panic!("Expected a CALLERROR. Got {other:?} instead."),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handler_error() {
+        let builder = ChargingStation::builder().handler(|_: ClearCacheRequest| async {
+            Err(OcppError::FieldValueError {
+                value: "busy".to_string(),
+            })
+        });
+        let (_station, mut csms) = connect_station(builder).await;
+
+        csms.send_text(r#"[2, "1", "ClearCache", {}]"#.to_string())
+            .await
+            .unwrap();
+        match csms.recv().await.unwrap().unwrap() {
+            IncomingFrame::CallError(error) => {
+                assert_eq!(error.error_code, RpcErrorCode::PropertyConstraintViolation);
+            }
+            other => panic!("Expected a CALLERROR. Got {other:?} instead."),
+        }
+    }
+
+    fn clear_cache_builder() -> ChargingStationBuilder {
+        ChargingStation::builder().handler(|_: ClearCacheRequest| async {
+            Ok(ClearCacheResponse {
+                status: ClearCacheStatusEnumType::Accepted,
+                ..Default::default()
+            })
+        })
+    }
+
+    #[tokio::test]
+    async fn test_strict_decoding_profile() {
+        let builder = clear_cache_builder().decoding_profile(DecodingProfile::Strict);
+        let (station, mut csms) = connect_station(builder).await;
+
+        csms.send_text(r#"[2, "1", "ClearCache", {"force": true}]"#.to_string())
+            .await
+            .unwrap();
+        match csms.recv().await.unwrap().unwrap() {
+            IncomingFrame::CallError(error) => {
+                assert_eq!(error.message_id, "1");
+                assert_eq!(error.error_code, RpcErrorCode::FormatViolation);
+            }
+            other => panic!("Expected a CALLERROR. Got {other:?} instead."),
+        }
+
+        let call = tokio::spawn({
+            let station = station.clone();
+            async move { station.call(ClearCacheRequest::default()).await }
+        });
+        let frame = recv_json(&mut csms).await;
+        csms.send(&json!([3, frame[1], {"status": "Accepted", "cleared": 12}]))
+            .await
+            .unwrap();
+        assert!(matches!(
+            call.await.unwrap(),
+            Err(OcppError::SchemaValidationError { schema, .. }) if schema == "ClearCacheResponse"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_lenient_decoding_profile() {
+        let reported = Arc::new(std::sync::Mutex::new(vec![]));
+        let builder = clear_cache_builder()
+            .decoding_profile(DecodingProfile::Lenient)
+            .on_decoding_warnings({
+                let reported = Arc::clone(&reported);
+                move |warnings: &DecodingWarnings| reported.lock().unwrap().push(warnings.clone())
+            });
+        let (station, mut csms) = connect_station(builder).await;
+
+        csms.send_text(r#"[2, "1", "ClearCache", {"force": true}]"#.to_string())
+            .await
+            .unwrap();
+        assert!(matches!(
+            csms.recv().await.unwrap().unwrap(),
+            IncomingFrame::CallResult(_)
+        ));
+
+        let call = tokio::spawn({
+            let station = station.clone();
+            async move { station.call(ClearCacheRequest::default()).await }
+        });
+        let frame = recv_json(&mut csms).await;
+        csms.send(&json!([3, frame[1], {"status": "Accepted", "cleared": 12}]))
+            .await
+            .unwrap();
+        assert_eq!(
+            call.await.unwrap().unwrap().status,
+            ClearCacheStatusEnumType::Accepted
+        );
+
+        let reported = reported.lock().unwrap();
+        let summary: Vec<_> = reported
+            .iter()
+            .map(|w| (w.message_id.as_str(), w.action.as_str(), w.warnings.len()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("1", "ClearCache", 1),
+                (frame[1].as_str().unwrap(), "ClearCache", 1)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_binary_frame_is_skipped() {
+        let (station, mut csms) = connect_station(clear_cache_builder()).await;
+
+        csms.sender
+            .sink
+            .send(Message::Binary(vec![2, 0, 1].into()))
+            .await
+            .unwrap();
+        csms.send_text(r#"[2, "1", "ClearCache", {}]"#.to_string())
+            .await
+            .unwrap();
+        match csms.recv().await.unwrap().unwrap() {
+            IncomingFrame::CallResult(result) => assert_eq!(result.message_id, "1"),
+            other => panic!("Expected a CALLRESULT. Got {other:?} instead."),
+        }
+        assert!(!station.is_closed());
+    }
+
+    #[tokio::test]
+    async fn test_connection_closed() {
+        let (station, mut csms) = connect_station(ChargingStation::builder()).await;
+
+        let call = tokio::spawn({
+            let station = station.clone();
+            async move { station.call(ClearCacheRequest::default()).await }
+        });
+        recv_json(&mut csms).await;
+        csms.close().await.unwrap();
+
+        assert!(matches!(
+            call.await.unwrap(),
+            Err(OcppError::TransportError { .. })
+        ));
+        assert!(station.is_closed());
+    }
+}
@@ -0,0 +1,433 @@
+use crate::errors::OcppError;
+use crate::transport::{Io, transport_error};
+use std::path::Path;
+use std::sync::Arc;
+use tokio::net::TcpStream;
+use tokio_rustls::rustls::crypto::{CryptoProvider, ring};
+use tokio_rustls::rustls::pki_types::pem::PemObject;
+use tokio_rustls::rustls::pki_types::{CertificateDer, PrivateKeyDer, ServerName};
+use tokio_rustls::rustls::server::WebPkiClientVerifier;
+use tokio_rustls::rustls::{ConfigBuilder, RootCertStore};
+use tokio_rustls::{TlsAcceptor, TlsConnector};
+use x509_parser::certificate::X509Certificate;
+use x509_parser::prelude::FromDer;
+
+pub use tokio_rustls::rustls::{ClientConfig, ServerConfig};
+
+fn provider() -> Arc<CryptoProvider> {
+    Arc::new(ring::default_provider())
+}
+
+/// Read every certificate of a PEM file.
+pub fn load_certificates(
+    path: impl AsRef<Path>,
+) -> Result<Vec<CertificateDer<'static>>, OcppError> {
+    let path = path.as_ref();
+    let certificates = CertificateDer::pem_file_iter(path)
+        .and_then(|certificates| certificates.collect::<Result<Vec<_>, _>>())
+        .map_err(|e| transport_error(format!("cannot read {}: {e}", path.display())))?;
+
+    if certificates.is_empty() {
+        return Err(transport_error(format!(
+            "{} does not contain any certificate",
+            path.display()
+        )));
+    }
+
+    Ok(certificates)
+}
+
+/// Read the first private key of a PEM file.
+pub fn load_private_key(path: impl AsRef<Path>) -> Result<PrivateKeyDer<'static>, OcppError> {
+    let path = path.as_ref();
+    PrivateKeyDer::from_pem_file(path)
+        .map_err(|e| transport_error(format!("cannot read {}: {e}", path.display())))
+}
+
+fn root_store(ca_file: &Path) -> Result<Arc<RootCertStore>, OcppError> {
+    let mut roots = RootCertStore::empty();
+    for certificate in load_certificates(ca_file)? {
+        roots.add(certificate).map_err(transport_error)?;
+    }
+
+    Ok(Arc::new(roots))
+}
+
+fn client_builder(
+    ca_file: &Path,
+) -> Result<ConfigBuilder<ClientConfig, tokio_rustls::rustls::client::WantsClientCert>, OcppError> {
+    Ok(ClientConfig::builder_with_provider(provider())
+        .with_safe_default_protocol_versions()
+        .map_err(transport_error)?
+        .with_root_certificates(root_store(ca_file)?))
+}
+
+/// A client configuration for security profile 2, which trusts CSMS certificates issued by the
+/// certificate authorities in `ca_file`.
+pub fn client_config(ca_file: impl AsRef<Path>) -> Result<Arc<ClientConfig>, OcppError> {
+    Ok(Arc::new(
+        client_builder(ca_file.as_ref())?.with_no_client_auth(),
+    ))
+}
+
+/// A client configuration for security profile 3, which additionally authenticates the Charging
+/// Station with the certificate chain in `cert_file` and the private key in `key_file`.
+pub fn client_config_with_certificate(
+    ca_file: impl AsRef<Path>,
+    cert_file: impl AsRef<Path>,
+    key_file: impl AsRef<Path>,
+) -> Result<Arc<ClientConfig>, OcppError> {
+    let config = client_builder(ca_file.as_ref())?
+        .with_client_auth_cert(load_certificates(cert_file)?, load_private_key(key_file)?)
+        .map_err(transport_error)?;
+
+    Ok(Arc::new(config))
+}
+
+/// A server configuration for security profile 2, which presents the certificate chain in
+/// `cert_file` with the private key in `key_file`.
+pub fn server_config(
+    cert_file: impl AsRef<Path>,
+    key_file: impl AsRef<Path>,
+) -> Result<Arc<ServerConfig>, OcppError> {
+    let config = ServerConfig::builder_with_provider(provider())
+        .with_safe_default_protocol_versions()
+        .map_err(transport_error)?
+        .with_no_client_auth()
+        .with_single_cert(load_certificates(cert_file)?, load_private_key(key_file)?)
+        .map_err(transport_error)?;
+
+    Ok(Arc::new(config))
+}
+
+/// A server configuration for security profile 3, which additionally requires a client
+/// certificate issued by the certificate authorities in `client_ca_file`. A `Csms` also requires
+/// the subject common name of the client certificate to be the Charging Station identity.
+pub fn server_config_with_client_auth(
+    cert_file: impl AsRef<Path>,
+    key_file: impl AsRef<Path>,
+    client_ca_file: impl AsRef<Path>,
+) -> Result<Arc<ServerConfig>, OcppError> {
+    let verifier = WebPkiClientVerifier::builder_with_provider(
+        root_store(client_ca_file.as_ref())?,
+        provider(),
+    )
+    .build()
+    .map_err(transport_error)?;
+    let config = ServerConfig::builder_with_provider(provider())
+        .with_safe_default_protocol_versions()
+        .map_err(transport_error)?
+        .with_client_cert_verifier(verifier)
+        .with_single_cert(load_certificates(cert_file)?, load_private_key(key_file)?)
+        .map_err(transport_error)?;
+
+    Ok(Arc::new(config))
+}
+
+/// Run the client side of the TLS handshake with `host`.
+pub(crate) async fn connect(
+    config: Arc<ClientConfig>,
+    host: &str,
+    stream: TcpStream,
+) -> Result<Box<dyn Io>, OcppError> {
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+    let server_name = ServerName::try_from(host.to_string()).map_err(transport_error)?;
+    let stream = TlsConnector::from(config)
+        .connect(server_name, stream)
+        .await
+        .map_err(transport_error)?;
+
+    Ok(Box::new(stream))
+}
+
+/// Run the server side of the TLS handshake. Also returns the subject common name of the client
+/// certificate, if the client presented one.
+pub(crate) async fn accept(
+    config: Arc<ServerConfig>,
+    stream: TcpStream,
+) -> Result<(Box<dyn Io>, Option<String>), OcppError> {
+    let stream = TlsAcceptor::from(config)
+        .accept(stream)
+        .await
+        .map_err(transport_error)?;
+    let common_name = match stream.get_ref().1.peer_certificates() {
+        Some([certificate, ..]) => Some(common_name(certificate)?),
+        _ => None,
+    };
+
+    Ok((Box::new(stream), common_name))
+}
+
+/// The subject common name of a certificate.
+fn common_name(certificate: &CertificateDer) -> Result<String, OcppError> {
+    let (_, certificate) =
+        X509Certificate::from_der(certificate).map_err(|e| OcppError::AuthenticationError {
+            reason: format!("cannot parse the client certificate: {e}"),
+        })?;
+
+    certificate
+        .subject()
+        .iter_common_name()
+        .next()
+        .and_then(|common_name| common_name.as_str().ok())
+        .map(String::from)
+        .ok_or_else(|| OcppError::AuthenticationError {
+            reason: "the client certificate has no subject common name".to_string(),
+        })
+}
+
+/// Check that a client certificate was issued to the Charging Station with the given identity.
+/// Security profile 3 requires the subject common name to be the identity in the connection path.
+pub(crate) fn verify_common_name(common_name: &str, identity: &str) -> Result<(), OcppError> {
+    if common_name != identity {
+        return Err(OcppError::AuthenticationError {
+            reason: format!(
+                "the client certificate was issued to {common_name}, not to Charging Station {identity}"
+            ),
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::csms::{Csms, CsmsHandler};
+    use crate::transport::{ClientOptions, Subprotocol, connect_with, handshake_server};
+    use rcgen::{BasicConstraints, CertificateParams, CertifiedIssuer, DnType, IsCa, KeyPair};
+    use std::path::PathBuf;
+    use tokio::net::TcpListener;
+
+    /// PEM files of a certificate authority, a server certificate for `localhost` and client
+    /// certificates for `CS001` and `CS002`, written to a fresh temporary directory.
+    struct Pki {
+        dir: PathBuf,
+    }
+
+    impl Pki {
+        fn generate(name: &str) -> Self {
+            let dir =
+                std::env::temp_dir().join(format!("ocpp-rs-tls-{name}-{}", uuid::Uuid::new_v4()));
+            std::fs::create_dir_all(&dir).unwrap();
+
+            let mut ca_params = CertificateParams::new(Vec::<String>::new()).unwrap();
+            ca_params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+            let ca = CertifiedIssuer::self_signed(ca_params, KeyPair::generate().unwrap()).unwrap();
+            std::fs::write(dir.join("ca.pem"), ca.pem()).unwrap();
+
+            for (file, subject) in [
+                ("server", "localhost"),
+                ("client", "CS001"),
+                ("other-client", "CS002"),
+            ] {
+                let key = KeyPair::generate().unwrap();
+                let mut params = CertificateParams::new(vec![subject.to_string()]).unwrap();
+                params
+                    .distinguished_name
+                    .push(DnType::CommonName, subject.to_string());
+                let certificate = params.signed_by(&key, &ca).unwrap();
+                std::fs::write(dir.join(format!("{file}.pem")), certificate.pem()).unwrap();
+                std::fs::write(dir.join(format!("{file}.key")), key.serialize_pem()).unwrap();
+            }
+
+            Self { dir }
+        }
+
+        fn path(&self, file: &str) -> PathBuf {
+            self.dir.join(file)
+        }
+    }
+
+    impl Drop for Pki {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.dir);
+        }
+    }
+
+    /// Serve a single TLS connection and report whether its WebSocket handshake succeeded.
+    async fn serve(config: Arc<ServerConfig>) -> (String, tokio::task::JoinHandle<bool>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!(
+            "wss://localhost:{}/ocpp/CS001",
+            listener.local_addr().unwrap().port()
+        );
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            match accept(config, stream).await {
+                Ok((stream, _)) => handshake_server(stream, &[Subprotocol::Ocpp21], |_| Ok(()))
+                    .await
+                    .is_ok(),
+                Err(_) => false,
+            }
+        });
+        (url, server)
+    }
+
+    #[test]
+    fn test_load_pem_files() {
+        let pki = Pki::generate("load");
+        assert_eq!(load_certificates(pki.path("ca.pem")).unwrap().len(), 1);
+        assert!(load_private_key(pki.path("server.key")).is_ok());
+        assert!(load_certificates(pki.path("server.key")).is_err());
+        assert!(load_certificates(pki.path("missing.pem")).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_server_authentication() {
+        let pki = Pki::generate("profile2");
+        let config = server_config(pki.path("server.pem"), pki.path("server.key")).unwrap();
+        let (url, server) = serve(config).await;
+
+        let options = ClientOptions {
+            tls: Some(client_config(pki.path("ca.pem")).unwrap()),
+            ..Default::default()
+        };
+        let connection = connect_with(&url, &[Subprotocol::Ocpp21], &options).await;
+        assert!(connection.is_ok());
+        assert!(server.await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_untrusted_server() {
+        let pki = Pki::generate("untrusted");
+        let other = Pki::generate("other");
+        let config = server_config(pki.path("server.pem"), pki.path("server.key")).unwrap();
+        let (url, server) = serve(config).await;
+
+        let options = ClientOptions {
+            tls: Some(client_config(other.path("ca.pem")).unwrap()),
+            ..Default::default()
+        };
+        assert!(
+            connect_with(&url, &[Subprotocol::Ocpp21], &options)
+                .await
+                .is_err()
+        );
+        assert!(!server.await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_mutual_authentication() {
+        let pki = Pki::generate("profile3");
+        let config = server_config_with_client_auth(
+            pki.path("server.pem"),
+            pki.path("server.key"),
+            pki.path("ca.pem"),
+        )
+        .unwrap();
+
+        let (url, server) = serve(config.clone()).await;
+        let options = ClientOptions {
+            tls: Some(
+                client_config_with_certificate(
+                    pki.path("ca.pem"),
+                    pki.path("client.pem"),
+                    pki.path("client.key"),
+                )
+                .unwrap(),
+            ),
+            ..Default::default()
+        };
+        assert!(
+            connect_with(&url, &[Subprotocol::Ocpp21], &options)
+                .await
+                .is_ok()
+        );
+        assert!(server.await.unwrap());
+
+        // Without a client certificate, the server rejects the connection.
+        let (url, server) = serve(config).await;
+        let options = ClientOptions {
+            tls: Some(client_config(pki.path("ca.pem")).unwrap()),
+            ..Default::default()
+        };
+        assert!(
+            connect_with(&url, &[Subprotocol::Ocpp21], &options)
+                .await
+                .is_err()
+        );
+        assert!(!server.await.unwrap());
+    }
+
+    struct Handler;
+
+    impl CsmsHandler for Handler {}
+
+    #[tokio::test]
+    async fn test_client_certificate_must_match_identity() {
+        let pki = Pki::generate("identity");
+        let config = server_config_with_client_auth(
+            pki.path("server.pem"),
+            pki.path("server.key"),
+            pki.path("ca.pem"),
+        )
+        .unwrap();
+        let csms = Csms::builder(Handler).tls(config).build();
+
+        for (client, accepted) in [("client", true), ("other-client", false)] {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let url = format!(
+                "wss://localhost:{}/ocpp/CS001",
+                listener.local_addr().unwrap().port()
+            );
+            let server = tokio::spawn({
+                let csms = csms.clone();
+                async move {
+                    let (stream, _) = listener.accept().await.unwrap();
+                    csms.accept(stream).await
+                }
+            });
+
+            let options = ClientOptions {
+                tls: Some(
+                    client_config_with_certificate(
+                        pki.path("ca.pem"),
+                        pki.path(&format!("{client}.pem")),
+                        pki.path(&format!("{client}.key")),
+                    )
+                    .unwrap(),
+                ),
+                ..Default::default()
+            };
+            let connection = connect_with(&url, &[Subprotocol::Ocpp21], &options).await;
+            assert_eq!(connection.is_ok(), accepted);
+            match server.await.unwrap() {
+                Ok(station) => {
+                    assert!(accepted);
+                    assert_eq!(station.identity(), "CS001");
+                }
+                Err(e) => {
+                    assert!(!accepted);
+                    assert!(matches!(
+                        e,
+                        OcppError::AuthenticationError { reason } if reason.contains("CS002")
+                    ));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_verify_common_name() {
+        assert!(verify_common_name("CS001", "CS001").is_ok());
+        assert!(matches!(
+            verify_common_name("CS002", "CS001"),
+            Err(OcppError::AuthenticationError { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_wss_requires_tls_configuration() {
+        let result = connect_with(
+            "wss://localhost:1/ocpp/CS001",
+            &[Subprotocol::Ocpp21],
+            &ClientOptions::default(),
+        )
+        .await;
+        assert!(matches!(
+            result,
+            Err(OcppError::TransportError { reason }) if reason.contains("TLS configuration")
+        ));
+    }
+}